@@ -43,6 +43,7 @@ use rocket_contrib::databases::mysql::Opts;
 use rocket_okapi::swagger_ui::{make_swagger_ui, SwaggerUIConfig, UrlObject};
 use rocket_prometheus::PrometheusMetrics;
 
+use crate::modules::data::tools::RetrieveMapEncounter;
 use crate::modules::{account, armory, data, instance, live_data_processor, tooltip, utility};
 
 #[cfg(test)]
@@ -69,7 +70,7 @@ fn main() {
     let armory = armory::Armory::default().init(&mut conn);
     let tooltip = tooltip::Tooltip::default();
     let live_data_processor = live_data_processor::LiveDataProcessor::default().init(&mut conn);
-    let instance = instance::Instance::default().init(instance_conn);
+    let instance = instance::Instance::default().init(instance_conn, data.get_all_map_encounters());
     let utility = utility::Utility::default().init(&mut conn);
 
     let prometheus = PrometheusMetrics::new();
@@ -144,6 +145,15 @@ fn main() {
                 account::transfer::update::nickname,
                 account::transfer::update::update_patreons,
                 account::transfer::update::update_patreons_post,
+                account::transfer::group::get_groups,
+                account::transfer::group::create_group,
+                account::transfer::group::rename_group,
+                account::transfer::group::delete_group,
+                account::transfer::group::invite_member,
+                account::transfer::group::accept_invite,
+                account::transfer::group::leave_group,
+                account::transfer::group::update_group_role,
+                account::transfer::group::kick_member,
             ],
         )
         .mount(
@@ -222,6 +232,7 @@ fn main() {
                 data::transfer::encounter::get_encounter,
                 data::transfer::encounter::get_all_encounters,
                 data::transfer::encounter::get_all_encounters_localized,
+                data::transfer::map_encounter::get_map_encounters,
                 data::transfer::encounter_npc::get_encounter_npc,
                 data::transfer::encounter_npc::get_all_encounter_npcs,
                 data::transfer::addon::get_addon,
@@ -239,6 +250,10 @@ fn main() {
                 armory::transfer::character::delete_character,
                 armory::transfer::character::delete_character_by_uid,
                 armory::transfer::character::get_characters_by_name,
+                armory::transfer::character_claim::request_character_claim,
+                armory::transfer::character_claim::revoke_character_claim,
+                armory::transfer::character_claim::get_character_claims,
+                armory::transfer::character_claim::get_character_alts,
                 armory::transfer::guild::get_guild,
                 armory::transfer::guild::get_guilds_by_name,
                 armory::transfer::guild::delete_guild,
@@ -248,13 +263,19 @@ fn main() {
                 armory::transfer::character_history::set_character_history,
                 armory::transfer::character_history::get_character_history,
                 armory::transfer::character_history::delete_character_history,
+                armory::transfer::character_history::get_character_history_diff,
+                armory::transfer::character_history::get_character_timeline,
                 armory::transfer::character_search::get_character_search_result,
                 armory::transfer::character_viewer::get_character_viewer,
                 armory::transfer::character_viewer::get_character_viewer_by_history,
                 armory::transfer::character_viewer::get_character_viewer_by_history_date,
                 armory::transfer::character_viewer::get_character_viewer_picture,
+                armory::transfer::character_viewer::get_character_viewer_card,
+                armory::transfer::gear_audit::get_character_gear_audit,
+                armory::transfer::gear_comparison::get_gear_comparison,
                 armory::transfer::guild_viewer::get_guild_view,
-                armory::transfer::guild_viewer::get_guild_roster
+                armory::transfer::guild_viewer::get_guild_roster,
+                armory::transfer::guild_viewer::get_guild_activity
             ],
         )
         .mount(
@@ -275,12 +296,14 @@ fn main() {
                 live_data_processor::transfer::instance_reset::set_instance_resets,
                 live_data_processor::transfer::upload::upload_log,
                 live_data_processor::transfer::upload::get_upload_progress,
+                live_data_processor::transfer::live_feed::get_live_feed,
             ],
         )
         .mount(
             "/API/instance",
             routes_with_openapi![
                 instance::transfer::export::get_instance_event_type,
+                instance::transfer::export::get_instance_event_type_filtered,
                 instance::transfer::export::get_instance_meta,
                 instance::transfer::export::get_instance_participants,
                 instance::transfer::export::get_instance_attempts,
@@ -306,10 +329,37 @@ fn main() {
                 instance::transfer::ranking::get_instance_ranking_tps_by_server_and_season,
                 instance::transfer::ranking::unrank_attempt,
                 instance::transfer::delete::delete_instance,
+                instance::transfer::delete::restore_instance,
+                instance::transfer::delete::get_deleted_instances,
+                instance::transfer::delete::get_audit_log,
                 instance::transfer::speed_run::get_speed_runs,
                 instance::transfer::speed_run::get_speed_runs_by_season,
                 instance::transfer::speed_kill::get_speed_kills,
                 instance::transfer::speed_kill::get_speed_kills_by_season,
+                instance::transfer::guild_progression::get_guild_progression,
+                instance::transfer::guild_progression::get_progression_rankings_by_season,
+                instance::transfer::character_merge::merge_character,
+                instance::transfer::character_merge::undo_character_merge,
+                instance::transfer::character_merge::get_character_merges,
+                instance::transfer::season::get_seasons,
+                instance::transfer::season::get_seasons_by_server,
+                instance::transfer::season::create_season,
+                instance::transfer::season::update_season,
+                instance::transfer::season::delete_season,
+                instance::transfer::loot::get_instance_loot,
+                instance::transfer::loot::get_character_loot,
+                instance::transfer::loot::get_guild_loot,
+                instance::transfer::attendance::get_guild_attendance,
+                instance::transfer::attendance::get_attendance_aliases,
+                instance::transfer::attendance::set_attendance_alias,
+                instance::transfer::attendance::delete_attendance_alias,
+                instance::transfer::pvp::get_arena_team_history,
+                instance::transfer::pvp::get_composition_win_rates,
+                instance::transfer::pvp::get_battleground_win_rate,
+                instance::transfer::pvp::get_pvp_match_summary,
+                instance::transfer::instance_search::search_instances,
+                instance::transfer::instance_comparison::compare_instances,
+                instance::transfer::gear_audit::get_instance_gear_audit,
             ],
         )
         .mount("/API/utility", routes_with_openapi![
@@ -0,0 +1,20 @@
+use schemars::JsonSchema;
+
+use crate::modules::account::domain_value::GroupRole;
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq)]
+pub struct GroupInformation {
+    pub id: u32,
+    pub name: String,
+    pub role: GroupRole,
+    pub accepted: bool,
+    pub members: Vec<GroupMemberInformation>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq)]
+pub struct GroupMemberInformation {
+    pub member_id: u32,
+    pub nickname: String,
+    pub role: GroupRole,
+    pub accepted: bool,
+}
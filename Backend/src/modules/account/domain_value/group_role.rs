@@ -0,0 +1,32 @@
+use schemars::JsonSchema;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub enum GroupRole {
+    Owner,
+    Officer,
+    Member,
+}
+
+impl GroupRole {
+    pub fn new(role: u8) -> Option<Self> {
+        match role {
+            0 => Some(Self::Owner),
+            1 => Some(Self::Officer),
+            2 => Some(Self::Member),
+            _ => None,
+        }
+    }
+
+    pub fn to_u8(&self) -> u8 {
+        match self {
+            GroupRole::Owner => 0,
+            GroupRole::Officer => 1,
+            GroupRole::Member => 2,
+        }
+    }
+
+    /// Owner and officers may invite and kick members
+    pub fn can_manage_members(&self) -> bool {
+        *self != GroupRole::Member
+    }
+}
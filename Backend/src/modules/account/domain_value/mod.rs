@@ -1,3 +1,7 @@
 pub use self::account_information::AccountInformation;
+pub use self::group_information::{GroupInformation, GroupMemberInformation};
+pub use self::group_role::GroupRole;
 
 mod account_information;
+mod group_information;
+mod group_role;
@@ -22,6 +22,13 @@ pub enum Failure {
     TooManyDays,
     DateInThePast,
     TokenPurposeLength,
+    InvalidGroupName,
+    GroupNotFound,
+    InsufficientGroupRights,
+    MemberNotFound,
+    AlreadyGroupMember,
+    OwnerCannotLeave,
+    InvalidGroupRole,
     Unknown,
 }
 
@@ -47,6 +54,13 @@ impl<'r> Responder<'r> for Failure {
             Failure::DateInThePast => Status::new(532, "DateInThePast"),
             Failure::TokenPurposeLength => Status::new(533, "TokenPurposeLength"),
             Failure::InvalidPasswordCharacters => Status::new(535, "InvalidPasswordCharacters"),
+            Failure::InvalidGroupName => Status::new(536, "InvalidGroupName"),
+            Failure::GroupNotFound => Status::new(537, "GroupNotFound"),
+            Failure::InsufficientGroupRights => Status::new(538, "InsufficientGroupRights"),
+            Failure::MemberNotFound => Status::new(539, "MemberNotFound"),
+            Failure::AlreadyGroupMember => Status::new(540, "AlreadyGroupMember"),
+            Failure::OwnerCannotLeave => Status::new(541, "OwnerCannotLeave"),
+            Failure::InvalidGroupRole => Status::new(542, "InvalidGroupRole"),
             Failure::Unknown => Status::new(599, "Unknown"),
         };
         Response::build().status(status).sized_body(Cursor::new(body)).ok()
@@ -72,6 +86,13 @@ impl<'r> OpenApiResponder<'r> for Failure {
         add_schema_response(&mut responses, 532, "text/plain", schema.clone())?;
         add_schema_response(&mut responses, 533, "text/plain", schema.clone())?;
         add_schema_response(&mut responses, 535, "text/plain", schema.clone())?;
+        add_schema_response(&mut responses, 536, "text/plain", schema.clone())?;
+        add_schema_response(&mut responses, 537, "text/plain", schema.clone())?;
+        add_schema_response(&mut responses, 538, "text/plain", schema.clone())?;
+        add_schema_response(&mut responses, 539, "text/plain", schema.clone())?;
+        add_schema_response(&mut responses, 540, "text/plain", schema.clone())?;
+        add_schema_response(&mut responses, 541, "text/plain", schema.clone())?;
+        add_schema_response(&mut responses, 542, "text/plain", schema.clone())?;
         add_schema_response(&mut responses, 599, "text/plain", schema)?;
         Ok(responses)
     }
@@ -0,0 +1,7 @@
+use schemars::JsonSchema;
+
+#[derive(Deserialize, Serialize, Debug, JsonSchema)]
+pub struct GroupRename {
+    pub group_id: u32,
+    pub name: String,
+}
@@ -0,0 +1,8 @@
+use schemars::JsonSchema;
+
+#[derive(Deserialize, Serialize, Debug, JsonSchema)]
+pub struct GroupRoleUpdate {
+    pub group_id: u32,
+    pub member_id: u32,
+    pub role: u8,
+}
@@ -1,9 +1,16 @@
-pub use self::{create_member::CreateMember, create_token::CreateToken, credentials::Credentials, failure::Failure, prolong_token::ProlongToken};
+pub use self::{
+    create_member::CreateMember, create_token::CreateToken, credentials::Credentials, failure::Failure, group_invite::GroupInvite, group_kick::GroupKick, group_rename::GroupRename, group_role_update::GroupRoleUpdate,
+    prolong_token::ProlongToken,
+};
 pub use self::patreon_response::*;
 
 mod create_member;
 mod create_token;
 mod credentials;
 mod failure;
+mod group_invite;
+mod group_kick;
+mod group_rename;
+mod group_role_update;
 mod prolong_token;
-mod patreon_response;
\ No newline at end of file
+mod patreon_response;
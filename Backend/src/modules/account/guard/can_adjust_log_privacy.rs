@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use okapi::openapi3::Responses;
 use rocket::{
     http::Status,
@@ -8,10 +10,15 @@ use rocket::{
 };
 use rocket_okapi::{gen::OpenApiGenerator, response::OpenApiResponder, util::add_schema_response};
 
-use crate::modules::account::{tools::Token, Account};
+use crate::modules::account::{
+    tools::{Groups, Token},
+    Account,
+};
+use crate::modules::armory::{tools::CharacterClaims, Armory};
 use crate::MainDb;
 
-pub struct CanAdjustLogPrivacy(pub u32);
+/// Member id and the groups the member may share logs with
+pub struct CanAdjustLogPrivacy(pub u32, pub BTreeSet<u32>);
 
 impl<'a, 'r> FromRequest<'a, 'r> for CanAdjustLogPrivacy {
     type Error = ();
@@ -41,13 +48,19 @@ impl<'a, 'r> FromRequest<'a, 'r> for CanAdjustLogPrivacy {
         }
 
         let member_id = validation.unwrap();
-        let member_map = acc_res.member.read().unwrap();
-        let member = member_map.get(&member_id).unwrap();
-        if (member.access_rights & 4) == 0 {
-            return Failure((Status::Unauthorized, ()));
+        {
+            let member_map = acc_res.member.read().unwrap();
+            let member = member_map.get(&member_id).unwrap();
+            if (member.access_rights & 4) == 0 {
+                return Failure((Status::Unauthorized, ()));
+            }
         }
 
-        Success(CanAdjustLogPrivacy(member_id))
+        let claimed_guild_ids = match req.guard::<State<'_, Armory>>() {
+            Success(armory) => armory.get_claimed_guild_ids(member_id),
+            _ => Vec::new(),
+        };
+        Success(CanAdjustLogPrivacy(member_id, acc_res.get_member_and_guild_groups(member_id, &claimed_guild_ids)))
     }
 }
 
@@ -1,7 +1,8 @@
-pub use self::{authenticate::Authenticate, can_adjust_log_privacy::CanAdjustLogPrivacy, current_user::CurrentUser, is_moderator::IsModerator, server_owner::ServerOwner};
+pub use self::{authenticate::Authenticate, can_adjust_log_privacy::CanAdjustLogPrivacy, current_user::CurrentUser, is_moderator::IsModerator, server_owner::ServerOwner, viewer::Viewer};
 
 mod authenticate;
 mod current_user;
 mod server_owner;
 mod is_moderator;
 mod can_adjust_log_privacy;
+mod viewer;
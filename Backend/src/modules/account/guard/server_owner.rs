@@ -10,7 +10,8 @@ use rocket_okapi::{gen::OpenApiGenerator, response::OpenApiResponder, util::add_
 
 use crate::modules::{account::guard::Authenticate, data::Data};
 
-pub struct ServerOwner(pub u32);
+/// Server id and member id of the owner
+pub struct ServerOwner(pub u32, pub u32);
 
 impl<'a, 'r> FromRequest<'a, 'r> for ServerOwner {
     type Error = ();
@@ -30,7 +31,7 @@ impl<'a, 'r> FromRequest<'a, 'r> for ServerOwner {
             }
 
             let (id, _) = server_res.unwrap();
-            Success(ServerOwner(*id))
+            Success(ServerOwner(*id, authenticate.0))
         })
     }
 }
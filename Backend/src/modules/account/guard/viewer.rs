@@ -0,0 +1,71 @@
+use std::collections::BTreeSet;
+
+use okapi::openapi3::Responses;
+use rocket::{
+    http::Status,
+    outcome::Outcome::*,
+    request::{self, FromRequest, Request, State},
+    response::Responder,
+    Response,
+};
+use rocket_okapi::{gen::OpenApiGenerator, response::OpenApiResponder};
+
+use crate::modules::account::guard::CurrentUser;
+use crate::modules::account::{tools::Groups, Account};
+use crate::modules::armory::{tools::CharacterClaims, Armory};
+
+/// Caller of a read route, anonymous if not authenticated.
+/// Carries everything that is required to decide if a private instance may be read.
+/// The groups include the guilds of the confirmed claimed characters.
+#[derive(Debug, Clone, Default)]
+pub struct Viewer {
+    pub member_id: Option<u32>,
+    pub is_moderator: bool,
+    pub groups: BTreeSet<u32>,
+}
+
+impl<'a, 'r> FromRequest<'a, 'r> for Viewer {
+    type Error = ();
+
+    fn from_request(req: &'a Request<'r>) -> request::Outcome<Self, ()> {
+        let member_id = match req.guard::<CurrentUser>() {
+            Success(CurrentUser(Some(member_id))) => member_id,
+            _ => return Success(Viewer::default()),
+        };
+
+        let account = req.guard::<State<'_, Account>>();
+        if account.is_failure() {
+            return Success(Viewer::default());
+        }
+
+        let acc_res = account.unwrap();
+        let is_moderator = {
+            let member_map = acc_res.member.read().unwrap();
+            member_map.get(&member_id).map(|member| (member.access_rights & 1) == 1).unwrap_or(false)
+        };
+        let claimed_guild_ids = match req.guard::<State<'_, Armory>>() {
+            Success(armory) => armory.get_claimed_guild_ids(member_id),
+            _ => Vec::new(),
+        };
+
+        Success(Viewer {
+            member_id: Some(member_id),
+            is_moderator,
+            groups: acc_res.get_member_and_guild_groups(member_id, &claimed_guild_ids),
+        })
+    }
+}
+
+// This implementation is required from OpenAPI, it does nothing here
+// and is not supposed to be used!
+impl Responder<'static> for Viewer {
+    fn respond_to(self, _: &Request) -> Result<Response<'static>, Status> {
+        Response::build().status(Status::Ok).ok()
+    }
+}
+
+impl OpenApiResponder<'static> for Viewer {
+    fn responses(_gen: &mut OpenApiGenerator) -> rocket_okapi::Result<Responses> {
+        Ok(Responses::default())
+    }
+}
@@ -6,7 +6,8 @@ use str_util::sha3;
 
 use crate::modules::account::{
     language::init::Init,
-    material::{APIToken, Member},
+    domain_value::GroupRole,
+    material::{APIToken, Group, GroupMember, Member},
 };
 
 #[derive(Debug)]
@@ -16,6 +17,7 @@ pub struct Account {
     pub api_token_to_member_id: RwLock<HashMap<String, u32>>,
     pub api_tokens: RwLock<HashMap<u32, Vec<APIToken>>>,
     pub requires_mail_confirmation: RwLock<HashMap<String, u32>>,
+    pub groups: RwLock<HashMap<u32, Group>>,
 }
 
 // Important: Always lock resources bottom to too, in order to prevent running into a deadlock
@@ -30,6 +32,7 @@ impl Default for Account {
             api_tokens: RwLock::new(HashMap::new()),
             api_token_to_member_id: RwLock::new(HashMap::new()),
             requires_mail_confirmation: RwLock::new(HashMap::new()),
+            groups: RwLock::new(HashMap::new()),
         }
     }
 }
@@ -37,6 +40,7 @@ impl Default for Account {
 impl Account {
     pub fn init(self, db_main: &mut (impl Select + Execute)) -> Self {
         {
+            let mut groups = self.groups.write().unwrap();
             let mut requires_mail_confirmation = self.requires_mail_confirmation.write().unwrap();
             let mut api_token_to_member_id = self.api_token_to_member_id.write().unwrap();
             let mut api_token = self.api_tokens.write().unwrap();
@@ -95,6 +99,25 @@ impl Account {
                 api_token_to_member_id.insert(entry.token.as_ref().unwrap().clone(), entry.member_id);
                 api_token.get_mut(&entry.member_id).unwrap().push(entry);
             }
+
+            for (id, name) in db_main.select("SELECT id, group_name FROM account_privacy_group", |mut row| (row.take::<u32, usize>(0).unwrap(), row.take::<String, usize>(1).unwrap())) {
+                groups.insert(id, Group { id, name, members: HashMap::new() });
+            }
+
+            for (group_id, entry) in db_main.select("SELECT group_id, member_id, role, accepted FROM account_privacy_group_member", |mut row| {
+                (
+                    row.take::<u32, usize>(0).unwrap(),
+                    GroupMember {
+                        member_id: row.take(1).unwrap(),
+                        role: GroupRole::new(row.take(2).unwrap()).unwrap(),
+                        accepted: row.take(3).unwrap(),
+                    },
+                )
+            }) {
+                if let Some(group) = groups.get_mut(&group_id) {
+                    group.members.insert(entry.member_id, entry);
+                }
+            }
         }
 
         self
@@ -0,0 +1,28 @@
+use std::collections::HashMap;
+
+use crate::modules::account::domain_value::GroupRole;
+
+#[derive(Debug, Clone)]
+pub struct Group {
+    pub id: u32,
+    pub name: String,
+    pub members: HashMap<u32, GroupMember>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GroupMember {
+    pub member_id: u32,
+    pub role: GroupRole,
+    // Invitations are pending until the invited member accepts them
+    pub accepted: bool,
+}
+
+impl Group {
+    pub fn is_accepted_member(&self, member_id: u32) -> bool {
+        self.members.get(&member_id).map(|member| member.accepted).unwrap_or(false)
+    }
+
+    pub fn accepted_role(&self, member_id: u32) -> Option<GroupRole> {
+        self.members.get(&member_id).filter(|member| member.accepted).map(|member| member.role)
+    }
+}
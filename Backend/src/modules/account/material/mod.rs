@@ -1,5 +1,11 @@
-pub use self::{account::Account, api_token::APIToken, member::Member};
+pub use self::{
+    account::Account,
+    api_token::APIToken,
+    group::{Group, GroupMember},
+    member::Member,
+};
 
 mod account;
 mod api_token;
+mod group;
 mod member;
@@ -1,4 +1,5 @@
 pub use self::material::Account;
+pub use self::tools::{get_guild_group_id, Groups};
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,97 @@
+use crate::modules::account::tests::helper::get_create_member;
+use crate::modules::account::{
+    domain_value::GroupRole,
+    dto::Failure,
+    material::Account,
+    tools::{Create, Groups},
+};
+use crate::tests::TestContainer;
+
+#[test]
+fn create_group() {
+    let container = TestContainer::new(false);
+    let (mut conn, _dns, _node) = container.run();
+
+    let account = Account::default();
+    let post_obj = get_create_member("abc", "abc@abc.de", "Password123456Password123456Password123456");
+    let api_token = account.create(&mut conn, &post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).unwrap();
+
+    let group = account.create_group(&mut conn, "  Raid Team  ", api_token.member_id).unwrap();
+    assert_eq!(group.name, "Raid Team".to_string());
+    assert_eq!(group.role, GroupRole::Owner);
+    assert!(group.accepted);
+    assert_eq!(group.members.len(), 1);
+    assert!(account.get_member_groups(api_token.member_id).contains(&group.id));
+    assert!(matches!(account.create_group(&mut conn, "   ", api_token.member_id), Err(Failure::InvalidGroupName)));
+}
+
+#[test]
+fn invite_and_accept() {
+    let container = TestContainer::new(false);
+    let (mut conn, _dns, _node) = container.run();
+
+    let account = Account::default();
+    let post_obj = get_create_member("abc", "abc@abc.de", "Password123456Password123456Password123456");
+    let post_obj_two = get_create_member("abcd", "abc2@abc.de", "Password123456Password123456Password123456");
+    let owner = account.create(&mut conn, &post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).unwrap().member_id;
+    let invited = account.create(&mut conn, &post_obj_two.credentials.mail, &post_obj_two.nickname, &post_obj_two.credentials.password).unwrap().member_id;
+    let group = account.create_group(&mut conn, "Raid Team", owner).unwrap();
+
+    let invite = account.invite_member(&mut conn, group.id, "ABCD", owner);
+    assert!(invite.is_ok());
+    assert!(!account.get_member_groups(invited).contains(&group.id));
+    assert!(matches!(account.invite_member(&mut conn, group.id, "abcd", owner), Err(Failure::AlreadyGroupMember)));
+    assert!(matches!(account.invite_member(&mut conn, group.id, "unknown", owner), Err(Failure::MemberNotFound)));
+
+    let accepted = account.accept_invite(&mut conn, group.id, invited).unwrap();
+    assert!(accepted.accepted);
+    assert_eq!(accepted.role, GroupRole::Member);
+    assert!(account.get_member_groups(invited).contains(&group.id));
+}
+
+#[test]
+fn member_rights() {
+    let container = TestContainer::new(false);
+    let (mut conn, _dns, _node) = container.run();
+
+    let account = Account::default();
+    let post_obj = get_create_member("abc", "abc@abc.de", "Password123456Password123456Password123456");
+    let post_obj_two = get_create_member("abcd", "abc2@abc.de", "Password123456Password123456Password123456");
+    let post_obj_three = get_create_member("abcde", "abc3@abc.de", "Password123456Password123456Password123456");
+    let owner = account.create(&mut conn, &post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).unwrap().member_id;
+    let member = account.create(&mut conn, &post_obj_two.credentials.mail, &post_obj_two.nickname, &post_obj_two.credentials.password).unwrap().member_id;
+    let _ = account.create(&mut conn, &post_obj_three.credentials.mail, &post_obj_three.nickname, &post_obj_three.credentials.password).unwrap();
+    let group = account.create_group(&mut conn, "Raid Team", owner).unwrap();
+    let _ = account.invite_member(&mut conn, group.id, "abcd", owner).unwrap();
+    let _ = account.accept_invite(&mut conn, group.id, member).unwrap();
+
+    assert!(matches!(account.invite_member(&mut conn, group.id, "abcde", member), Err(Failure::InsufficientGroupRights)));
+    assert!(matches!(account.kick_member(&mut conn, group.id, owner, member), Err(Failure::InsufficientGroupRights)));
+    assert!(matches!(account.update_group_role(&mut conn, group.id, member, 0, owner), Err(Failure::InvalidGroupRole)));
+
+    let promoted = account.update_group_role(&mut conn, group.id, member, 1, owner).unwrap();
+    assert!(promoted.members.iter().any(|entry| entry.member_id == member && entry.role == GroupRole::Officer));
+    assert!(account.invite_member(&mut conn, group.id, "abcde", member).is_ok());
+}
+
+#[test]
+fn leave_and_delete_group() {
+    let container = TestContainer::new(false);
+    let (mut conn, _dns, _node) = container.run();
+
+    let account = Account::default();
+    let post_obj = get_create_member("abc", "abc@abc.de", "Password123456Password123456Password123456");
+    let post_obj_two = get_create_member("abcd", "abc2@abc.de", "Password123456Password123456Password123456");
+    let owner = account.create(&mut conn, &post_obj.credentials.mail, &post_obj.nickname, &post_obj.credentials.password).unwrap().member_id;
+    let member = account.create(&mut conn, &post_obj_two.credentials.mail, &post_obj_two.nickname, &post_obj_two.credentials.password).unwrap().member_id;
+    let group = account.create_group(&mut conn, "Raid Team", owner).unwrap();
+    let _ = account.invite_member(&mut conn, group.id, "abcd", owner).unwrap();
+
+    assert!(matches!(account.leave_group(&mut conn, group.id, owner), Err(Failure::OwnerCannotLeave)));
+    assert!(account.leave_group(&mut conn, group.id, member).is_ok());
+    assert!(account.get_groups(member).is_empty());
+
+    assert!(matches!(account.delete_group(&mut conn, group.id, member), Err(Failure::InsufficientGroupRights)));
+    assert!(account.delete_group(&mut conn, group.id, owner).is_ok());
+    assert!(account.get_groups(owner).is_empty());
+}
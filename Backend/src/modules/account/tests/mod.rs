@@ -3,6 +3,7 @@ mod create_transfer;
 mod delete;
 mod forgot;
 mod get;
+mod group;
 mod login;
 mod token;
 mod update;
@@ -3,7 +3,7 @@ use crate::util::database::*;
 use language::{domain_value::Language, tools::Get};
 use str_util::{sha3, strformat};
 
-use crate::modules::account::{dto::Failure, material::Account};
+use crate::modules::account::{domain_value::GroupRole, dto::Failure, material::Account};
 
 pub trait Delete {
     fn issue_delete(&self, db_main: &mut impl Execute, member_id: u32) -> Result<(), Failure>;
@@ -38,6 +38,7 @@ impl Delete for Account {
     }
 
     fn confirm_delete(&self, db_main: &mut impl Execute, delete_id: &str) -> Result<(), Failure> {
+        let mut groups = self.groups.write().unwrap();
         let mut requires_mail_confirmation = self.requires_mail_confirmation.write().unwrap();
         let mut api_token_to_member_id = self.api_token_to_member_id.write().unwrap();
         let mut api_token = self.api_tokens.write().unwrap();
@@ -81,6 +82,12 @@ impl Delete for Account {
                 }
                 api_token.get_mut(&member_id).unwrap().clear();
                 api_token.remove(&member_id);
+
+                // Owned groups are deleted as well, other memberships are just dropped
+                groups.retain(|_, group| group.members.get(&member_id).map(|group_member| group_member.role != GroupRole::Owner).unwrap_or(true));
+                for group in groups.values_mut() {
+                    group.members.remove(&member_id);
+                }
             }
 
             member.remove(&member_id);
@@ -0,0 +1,315 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::modules::account::{
+    domain_value::{GroupInformation, GroupMemberInformation, GroupRole},
+    dto::Failure,
+    material::{Account, Group, GroupMember, Member},
+};
+use crate::params;
+use crate::util::database::*;
+
+static GUILD_GROUP_FLAG: u32 = 0x8000_0000;
+
+pub trait Groups {
+    fn get_groups(&self, member_id: u32) -> Vec<GroupInformation>;
+    fn get_member_groups(&self, member_id: u32) -> BTreeSet<u32>;
+    fn get_member_and_guild_groups(&self, member_id: u32, guild_ids: &[u32]) -> BTreeSet<u32>;
+    fn create_group(&self, db_main: &mut (impl Execute + Select), name: &str, member_id: u32) -> Result<GroupInformation, Failure>;
+    fn rename_group(&self, db_main: &mut impl Execute, group_id: u32, name: &str, member_id: u32) -> Result<GroupInformation, Failure>;
+    fn delete_group(&self, db_main: &mut impl Execute, group_id: u32, member_id: u32) -> Result<(), Failure>;
+    fn invite_member(&self, db_main: &mut impl Execute, group_id: u32, nickname: &str, member_id: u32) -> Result<GroupInformation, Failure>;
+    fn accept_invite(&self, db_main: &mut impl Execute, group_id: u32, member_id: u32) -> Result<GroupInformation, Failure>;
+    fn leave_group(&self, db_main: &mut impl Execute, group_id: u32, member_id: u32) -> Result<(), Failure>;
+    fn update_group_role(&self, db_main: &mut impl Execute, group_id: u32, target_member_id: u32, role: u8, member_id: u32) -> Result<GroupInformation, Failure>;
+    fn kick_member(&self, db_main: &mut impl Execute, group_id: u32, target_member_id: u32, member_id: u32) -> Result<GroupInformation, Failure>;
+}
+
+impl Groups for Account {
+    fn get_groups(&self, member_id: u32) -> Vec<GroupInformation> {
+        let groups = self.groups.read().unwrap();
+        let member = self.member.read().unwrap();
+        groups.values().filter(|group| group.members.contains_key(&member_id)).map(|group| group_information(group, member_id, &member)).collect()
+    }
+
+    fn get_member_groups(&self, member_id: u32) -> BTreeSet<u32> {
+        let groups = self.groups.read().unwrap();
+        groups.values().filter(|group| group.is_accepted_member(member_id)).map(|group| group.id).collect()
+    }
+
+    fn get_member_and_guild_groups(&self, member_id: u32, guild_ids: &[u32]) -> BTreeSet<u32> {
+        let mut groups = self.get_member_groups(member_id);
+        groups.extend(guild_ids.iter().map(|guild_id| get_guild_group_id(*guild_id)));
+        groups
+    }
+
+    fn create_group(&self, db_main: &mut (impl Execute + Select), name: &str, member_id: u32) -> Result<GroupInformation, Failure> {
+        let name = valid_group_name(name)?;
+
+        // The following part needs to be transactional
+        let mut groups = self.groups.write().unwrap();
+        let member = self.member.read().unwrap();
+
+        if !db_main.execute_wparams(
+            "INSERT INTO account_privacy_group (group_name, member_id) VALUES (:group_name, :member_id)",
+            params!(
+              "group_name" => name.clone(),
+              "member_id" => member_id
+            ),
+        ) {
+            return Err(Failure::Unknown);
+        }
+
+        let group_id = db_main
+            .select_wparams_value(
+                "SELECT id FROM account_privacy_group WHERE member_id=:member_id ORDER BY id DESC LIMIT 1",
+                |mut row| row.take::<u32, usize>(0).unwrap(),
+                params!(
+                  "member_id" => member_id
+                ),
+            )
+            .ok_or(Failure::Unknown)?;
+
+        if !db_main.execute_wparams(
+            "INSERT INTO account_privacy_group_member (group_id, member_id, role, accepted) VALUES (:group_id, :member_id, :role, 1)",
+            params!(
+              "group_id" => group_id,
+              "member_id" => member_id,
+              "role" => GroupRole::Owner.to_u8()
+            ),
+        ) {
+            return Err(Failure::Unknown);
+        }
+
+        let mut group = Group { id: group_id, name, members: HashMap::new() };
+        group.members.insert(
+            member_id,
+            GroupMember {
+                member_id,
+                role: GroupRole::Owner,
+                accepted: true,
+            },
+        );
+        let result = group_information(&group, member_id, &member);
+        groups.insert(group_id, group);
+        Ok(result)
+    }
+
+    fn rename_group(&self, db_main: &mut impl Execute, group_id: u32, name: &str, member_id: u32) -> Result<GroupInformation, Failure> {
+        let name = valid_group_name(name)?;
+
+        let mut groups = self.groups.write().unwrap();
+        let member = self.member.read().unwrap();
+        let group = groups.get_mut(&group_id).ok_or(Failure::GroupNotFound)?;
+        if group.accepted_role(member_id) != Some(GroupRole::Owner) {
+            return Err(Failure::InsufficientGroupRights);
+        }
+
+        if !db_main.execute_wparams(
+            "UPDATE account_privacy_group SET group_name=:group_name WHERE id=:id",
+            params!(
+              "group_name" => name.clone(),
+              "id" => group_id
+            ),
+        ) {
+            return Err(Failure::Unknown);
+        }
+
+        group.name = name;
+        Ok(group_information(group, member_id, &member))
+    }
+
+    fn delete_group(&self, db_main: &mut impl Execute, group_id: u32, member_id: u32) -> Result<(), Failure> {
+        let mut groups = self.groups.write().unwrap();
+        let group = groups.get(&group_id).ok_or(Failure::GroupNotFound)?;
+        if group.accepted_role(member_id) != Some(GroupRole::Owner) {
+            return Err(Failure::InsufficientGroupRights);
+        }
+
+        // Memberships are deleted by the foreign key constraint
+        if !db_main.execute_wparams(
+            "DELETE FROM account_privacy_group WHERE id=:id",
+            params!(
+              "id" => group_id
+            ),
+        ) {
+            return Err(Failure::Unknown);
+        }
+
+        groups.remove(&group_id);
+        Ok(())
+    }
+
+    fn invite_member(&self, db_main: &mut impl Execute, group_id: u32, nickname: &str, member_id: u32) -> Result<GroupInformation, Failure> {
+        let mut groups = self.groups.write().unwrap();
+        let member = self.member.read().unwrap();
+        let group = groups.get_mut(&group_id).ok_or(Failure::GroupNotFound)?;
+        if !group.accepted_role(member_id).map(|role| role.can_manage_members()).unwrap_or(false) {
+            return Err(Failure::InsufficientGroupRights);
+        }
+
+        let lower_name = nickname.to_lowercase();
+        let invited_member_id = member.values().find(|entry| entry.nickname.to_lowercase() == lower_name).map(|entry| entry.id).ok_or(Failure::MemberNotFound)?;
+        if group.members.contains_key(&invited_member_id) {
+            return Err(Failure::AlreadyGroupMember);
+        }
+
+        if !db_main.execute_wparams(
+            "INSERT INTO account_privacy_group_member (group_id, member_id, role, accepted) VALUES (:group_id, :member_id, :role, 0)",
+            params!(
+              "group_id" => group_id,
+              "member_id" => invited_member_id,
+              "role" => GroupRole::Member.to_u8()
+            ),
+        ) {
+            return Err(Failure::Unknown);
+        }
+
+        group.members.insert(
+            invited_member_id,
+            GroupMember {
+                member_id: invited_member_id,
+                role: GroupRole::Member,
+                accepted: false,
+            },
+        );
+        Ok(group_information(group, member_id, &member))
+    }
+
+    fn accept_invite(&self, db_main: &mut impl Execute, group_id: u32, member_id: u32) -> Result<GroupInformation, Failure> {
+        let mut groups = self.groups.write().unwrap();
+        let member = self.member.read().unwrap();
+        let group = groups.get_mut(&group_id).ok_or(Failure::GroupNotFound)?;
+        let group_member = group.members.get_mut(&member_id).ok_or(Failure::GroupNotFound)?;
+        if group_member.accepted {
+            return Err(Failure::AlreadyGroupMember);
+        }
+
+        if !db_main.execute_wparams(
+            "UPDATE account_privacy_group_member SET accepted=1 WHERE group_id=:group_id AND member_id=:member_id",
+            params!(
+              "group_id" => group_id,
+              "member_id" => member_id
+            ),
+        ) {
+            return Err(Failure::Unknown);
+        }
+
+        group_member.accepted = true;
+        Ok(group_information(group, member_id, &member))
+    }
+
+    fn leave_group(&self, db_main: &mut impl Execute, group_id: u32, member_id: u32) -> Result<(), Failure> {
+        let mut groups = self.groups.write().unwrap();
+        let group = groups.get_mut(&group_id).ok_or(Failure::GroupNotFound)?;
+        // Leaving also declines a pending invite
+        let group_member = group.members.get(&member_id).ok_or(Failure::GroupNotFound)?;
+        if group_member.role == GroupRole::Owner {
+            return Err(Failure::OwnerCannotLeave);
+        }
+
+        if !remove_group_member(db_main, group_id, member_id) {
+            return Err(Failure::Unknown);
+        }
+
+        group.members.remove(&member_id);
+        Ok(())
+    }
+
+    fn update_group_role(&self, db_main: &mut impl Execute, group_id: u32, target_member_id: u32, role: u8, member_id: u32) -> Result<GroupInformation, Failure> {
+        // Ownership can not be handed over
+        let role = GroupRole::new(role).filter(|role| *role != GroupRole::Owner).ok_or(Failure::InvalidGroupRole)?;
+
+        let mut groups = self.groups.write().unwrap();
+        let member = self.member.read().unwrap();
+        let group = groups.get_mut(&group_id).ok_or(Failure::GroupNotFound)?;
+        if group.accepted_role(member_id) != Some(GroupRole::Owner) {
+            return Err(Failure::InsufficientGroupRights);
+        }
+
+        let target = group.members.get_mut(&target_member_id).ok_or(Failure::MemberNotFound)?;
+        if target.role == GroupRole::Owner {
+            return Err(Failure::InvalidGroupRole);
+        }
+
+        if !db_main.execute_wparams(
+            "UPDATE account_privacy_group_member SET role=:role WHERE group_id=:group_id AND member_id=:member_id",
+            params!(
+              "role" => role.to_u8(),
+              "group_id" => group_id,
+              "member_id" => target_member_id
+            ),
+        ) {
+            return Err(Failure::Unknown);
+        }
+
+        target.role = role;
+        Ok(group_information(group, member_id, &member))
+    }
+
+    fn kick_member(&self, db_main: &mut impl Execute, group_id: u32, target_member_id: u32, member_id: u32) -> Result<GroupInformation, Failure> {
+        let mut groups = self.groups.write().unwrap();
+        let member = self.member.read().unwrap();
+        let group = groups.get_mut(&group_id).ok_or(Failure::GroupNotFound)?;
+        let role = group.accepted_role(member_id).filter(|role| role.can_manage_members()).ok_or(Failure::InsufficientGroupRights)?;
+        let target = group.members.get(&target_member_id).ok_or(Failure::MemberNotFound)?;
+
+        // Officers may only kick ordinary members
+        if target.role == GroupRole::Owner || (role == GroupRole::Officer && target.role == GroupRole::Officer) {
+            return Err(Failure::InsufficientGroupRights);
+        }
+
+        if !remove_group_member(db_main, group_id, target_member_id) {
+            return Err(Failure::Unknown);
+        }
+
+        group.members.remove(&target_member_id);
+        Ok(group_information(group, member_id, &member))
+    }
+}
+
+/// Guilds are implicit groups of every member with a confirmed character in them.
+/// The highest bit keeps their ids apart from the explicit groups.
+pub fn get_guild_group_id(guild_id: u32) -> u32 {
+    GUILD_GROUP_FLAG | guild_id
+}
+
+fn valid_group_name(name: &str) -> Result<String, Failure> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > 64 {
+        return Err(Failure::InvalidGroupName);
+    }
+    Ok(name.to_owned())
+}
+
+fn remove_group_member(db_main: &mut impl Execute, group_id: u32, member_id: u32) -> bool {
+    db_main.execute_wparams(
+        "DELETE FROM account_privacy_group_member WHERE group_id=:group_id AND member_id=:member_id",
+        params!(
+          "group_id" => group_id,
+          "member_id" => member_id
+        ),
+    )
+}
+
+fn group_information(group: &Group, member_id: u32, member: &HashMap<u32, Member>) -> GroupInformation {
+    let own_entry = group.members.get(&member_id);
+    let mut members: Vec<GroupMemberInformation> = group
+        .members
+        .values()
+        .map(|group_member| GroupMemberInformation {
+            member_id: group_member.member_id,
+            nickname: member.get(&group_member.member_id).map(|entry| entry.nickname.clone()).unwrap_or_default(),
+            role: group_member.role,
+            accepted: group_member.accepted,
+        })
+        .collect();
+    members.sort_by(|left, right| left.role.to_u8().cmp(&right.role.to_u8()).then_with(|| left.member_id.cmp(&right.member_id)));
+
+    GroupInformation {
+        id: group.id,
+        name: group.name.clone(),
+        role: own_entry.map(|entry| entry.role).unwrap_or(GroupRole::Member),
+        accepted: own_entry.map(|entry| entry.accepted).unwrap_or(false),
+        members,
+    }
+}
@@ -1,9 +1,10 @@
-pub use self::{create::Create, delete::Delete, forgot::Forgot, get::GetAccountInformation, login::Login, token::Token, update::Update};
+pub use self::{create::Create, delete::Delete, forgot::Forgot, get::GetAccountInformation, group::{get_guild_group_id, Groups}, login::Login, token::Token, update::Update};
 
 mod create;
 mod delete;
 mod forgot;
 mod get;
+mod group;
 mod login;
 mod token;
 mod update;
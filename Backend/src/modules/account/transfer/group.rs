@@ -0,0 +1,65 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::account::{
+    domain_value::GroupInformation,
+    dto::{Failure, GroupInvite, GroupKick, GroupRename, GroupRoleUpdate},
+    guard::Authenticate,
+    material::Account,
+    tools::Groups,
+};
+use crate::MainDb;
+
+#[openapi]
+#[get("/group")]
+pub fn get_groups(me: State<Account>, auth: Authenticate) -> Json<Vec<GroupInformation>> {
+    Json(me.get_groups(auth.0))
+}
+
+#[openapi]
+#[post("/group", format = "application/json", data = "<name>")]
+pub fn create_group(mut db_main: MainDb, me: State<Account>, auth: Authenticate, name: Json<String>) -> Result<Json<GroupInformation>, Failure> {
+    me.create_group(&mut *db_main, &name, auth.0).map(Json)
+}
+
+#[openapi]
+#[post("/group/rename", format = "application/json", data = "<params>")]
+pub fn rename_group(mut db_main: MainDb, me: State<Account>, auth: Authenticate, params: Json<GroupRename>) -> Result<Json<GroupInformation>, Failure> {
+    me.rename_group(&mut *db_main, params.group_id, &params.name, auth.0).map(Json)
+}
+
+#[openapi]
+#[delete("/group", format = "application/json", data = "<group_id>")]
+pub fn delete_group(mut db_main: MainDb, me: State<Account>, auth: Authenticate, group_id: Json<u32>) -> Result<(), Failure> {
+    me.delete_group(&mut *db_main, group_id.0, auth.0)
+}
+
+#[openapi]
+#[post("/group/invite", format = "application/json", data = "<params>")]
+pub fn invite_member(mut db_main: MainDb, me: State<Account>, auth: Authenticate, params: Json<GroupInvite>) -> Result<Json<GroupInformation>, Failure> {
+    me.invite_member(&mut *db_main, params.group_id, &params.nickname, auth.0).map(Json)
+}
+
+#[openapi]
+#[post("/group/accept", format = "application/json", data = "<group_id>")]
+pub fn accept_invite(mut db_main: MainDb, me: State<Account>, auth: Authenticate, group_id: Json<u32>) -> Result<Json<GroupInformation>, Failure> {
+    me.accept_invite(&mut *db_main, group_id.0, auth.0).map(Json)
+}
+
+#[openapi]
+#[post("/group/leave", format = "application/json", data = "<group_id>")]
+pub fn leave_group(mut db_main: MainDb, me: State<Account>, auth: Authenticate, group_id: Json<u32>) -> Result<(), Failure> {
+    me.leave_group(&mut *db_main, group_id.0, auth.0)
+}
+
+#[openapi]
+#[post("/group/role", format = "application/json", data = "<params>")]
+pub fn update_group_role(mut db_main: MainDb, me: State<Account>, auth: Authenticate, params: Json<GroupRoleUpdate>) -> Result<Json<GroupInformation>, Failure> {
+    me.update_group_role(&mut *db_main, params.group_id, params.member_id, params.role, auth.0).map(Json)
+}
+
+#[openapi]
+#[post("/group/kick", format = "application/json", data = "<params>")]
+pub fn kick_member(mut db_main: MainDb, me: State<Account>, auth: Authenticate, params: Json<GroupKick>) -> Result<Json<GroupInformation>, Failure> {
+    me.kick_member(&mut *db_main, params.group_id, params.member_id, auth.0).map(Json)
+}
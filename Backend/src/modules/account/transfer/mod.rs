@@ -2,6 +2,7 @@ pub mod create;
 pub mod delete;
 pub mod forgot;
 pub mod get;
+pub mod group;
 pub mod login;
 pub mod token;
 pub mod update;
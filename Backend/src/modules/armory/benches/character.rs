@@ -176,6 +176,7 @@ fn set_character(b: &mut Bencher) {
     };
     let character_dto = CharacterDto {
         server_uid: 12312452,
+        note: None,
         character_history: Some(character_history_dto),
     };
 
@@ -40,6 +40,59 @@ impl CharacterGear {
             character_gear: self.clone(),
         }
     }
+
+    pub fn get_slot(&self, inventory_type: InventoryType) -> Option<&CharacterItem> {
+        match inventory_type {
+            InventoryType::Head => self.head.as_ref(),
+            InventoryType::Neck => self.neck.as_ref(),
+            InventoryType::Shoulder => self.shoulder.as_ref(),
+            InventoryType::Back => self.back.as_ref(),
+            InventoryType::Chest => self.chest.as_ref(),
+            InventoryType::Shirt => self.shirt.as_ref(),
+            InventoryType::Tabard => self.tabard.as_ref(),
+            InventoryType::Wrist => self.wrist.as_ref(),
+            InventoryType::MainHand => self.main_hand.as_ref(),
+            InventoryType::OffHand => self.off_hand.as_ref(),
+            InventoryType::Ranged => self.ternary_hand.as_ref(),
+            InventoryType::Hands => self.glove.as_ref(),
+            InventoryType::Waist => self.belt.as_ref(),
+            InventoryType::Legs => self.leg.as_ref(),
+            InventoryType::Feet => self.boot.as_ref(),
+            InventoryType::Finger1 => self.ring1.as_ref(),
+            InventoryType::Finger2 => self.ring2.as_ref(),
+            InventoryType::Trinket1 => self.trinket1.as_ref(),
+            InventoryType::Trinket2 => self.trinket2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns false if the inventory type is not a gear slot
+    pub fn set_slot(&mut self, inventory_type: InventoryType, item: Option<CharacterItem>) -> bool {
+        let slot = match inventory_type {
+            InventoryType::Head => &mut self.head,
+            InventoryType::Neck => &mut self.neck,
+            InventoryType::Shoulder => &mut self.shoulder,
+            InventoryType::Back => &mut self.back,
+            InventoryType::Chest => &mut self.chest,
+            InventoryType::Shirt => &mut self.shirt,
+            InventoryType::Tabard => &mut self.tabard,
+            InventoryType::Wrist => &mut self.wrist,
+            InventoryType::MainHand => &mut self.main_hand,
+            InventoryType::OffHand => &mut self.off_hand,
+            InventoryType::Ranged => &mut self.ternary_hand,
+            InventoryType::Hands => &mut self.glove,
+            InventoryType::Waist => &mut self.belt,
+            InventoryType::Legs => &mut self.leg,
+            InventoryType::Feet => &mut self.boot,
+            InventoryType::Finger1 => &mut self.ring1,
+            InventoryType::Finger2 => &mut self.ring2,
+            InventoryType::Trinket1 => &mut self.trinket1,
+            InventoryType::Trinket2 => &mut self.trinket2,
+            _ => return false,
+        };
+        *slot = item;
+        true
+    }
 }
 
 impl Iterator for CharacterGearIterator {
@@ -0,0 +1,9 @@
+/// Guild of a character as of one of its history snapshots
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuildMembership {
+    pub character_id: u32,
+    pub character_name: String,
+    pub guild_id: Option<u32>,
+    pub rank_index: Option<u8>,
+    pub timestamp: u64,
+}
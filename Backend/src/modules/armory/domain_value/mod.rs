@@ -1,7 +1,7 @@
 pub use self::arena_team::ArenaTeam;
 pub use self::arena_team_size_type::ArenaTeamSizeType;
 pub use self::inventory_type::InventoryType;
-pub use self::{character_facial::CharacterFacial, character_gear::*, character_guild::CharacterGuild, character_info::CharacterInfo, character_item::CharacterItem, guild_rank::GuildRank, history_moment::HistoryMoment};
+pub use self::{character_facial::CharacterFacial, character_gear::*, character_guild::CharacterGuild, character_info::CharacterInfo, character_item::CharacterItem, guild_membership::GuildMembership, guild_rank::GuildRank, history_moment::HistoryMoment};
 
 mod arena_team;
 mod arena_team_size_type;
@@ -10,6 +10,7 @@ mod character_gear;
 mod character_guild;
 mod character_info;
 mod character_item;
+mod guild_membership;
 mod guild_rank;
 mod history_moment;
 mod inventory_type;
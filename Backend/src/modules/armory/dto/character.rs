@@ -3,6 +3,8 @@ use crate::{dto::CheckPlausability, modules::armory::dto::CharacterHistoryDto};
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CharacterDto {
     pub server_uid: u64,
+    /// In-game text of the character, e.g. the public guild note, that is checked for character claim tokens
+    pub note: Option<String>,
     pub character_history: Option<CharacterHistoryDto>,
 }
 
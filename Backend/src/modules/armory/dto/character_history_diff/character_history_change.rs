@@ -0,0 +1,40 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub enum CharacterHistoryChange {
+    GearSwap {
+        inventory_type: u8,
+        previous_item_id: Option<u32>,
+        item_id: Option<u32>,
+        // Raid in which the new item was looted, if it was logged
+        looted_instance_meta_id: Option<u32>,
+    },
+    EnchantChange {
+        inventory_type: u8,
+        item_id: u32,
+        previous_enchant_id: Option<u32>,
+        enchant_id: Option<u32>,
+    },
+    GemChange {
+        inventory_type: u8,
+        item_id: u32,
+        previous_gem_ids: Vec<Option<u32>>,
+        gem_ids: Vec<Option<u32>>,
+    },
+    TalentChange {
+        previous_talent_specialization: Option<String>,
+        talent_specialization: Option<String>,
+    },
+    GuildJoin {
+        guild_id: u32,
+    },
+    GuildLeave {
+        guild_id: u32,
+    },
+    LevelUp {
+        previous_level: u8,
+        level: u8,
+    },
+    Rename {
+        previous_name: String,
+        name: String,
+    },
+}
@@ -0,0 +1,11 @@
+use crate::modules::armory::dto::CharacterHistoryChange;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CharacterHistoryDiff {
+    pub character_id: u32,
+    pub previous_history_id: u32,
+    pub previous_timestamp: u64,
+    pub history_id: u32,
+    pub timestamp: u64,
+    pub changes: Vec<CharacterHistoryChange>,
+}
@@ -0,0 +1,8 @@
+use crate::modules::armory::dto::CharacterHistoryChange;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CharacterTimelineEntry {
+    pub history_id: u32,
+    pub timestamp: u64,
+    pub changes: Vec<CharacterHistoryChange>,
+}
@@ -0,0 +1,5 @@
+pub use self::{character_history_change::CharacterHistoryChange, character_history_diff::CharacterHistoryDiff, character_timeline_entry::CharacterTimelineEntry};
+
+mod character_history_change;
+mod character_history_diff;
+mod character_timeline_entry;
@@ -1,6 +1,6 @@
 use crate::{
     dto::SelectOption,
-    modules::armory::dto::{CharacterStat, CharacterViewerGearDto, CharacterViewerGuildDto, CharacterViewerProfessionDto, CharacterViewerTalentsDto},
+    modules::armory::dto::{CharacterStat, CharacterViewerGearDto, CharacterViewerGuildDto, CharacterViewerProfessionDto, CharacterViewerSheetDto, CharacterViewerTalentsDto},
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -16,6 +16,8 @@ pub struct CharacterViewerDto {
     pub server_id: u32,
     pub server_name: String,
     pub stats: Vec<CharacterStat>,
+    /// Only if the base attributes and coefficients of the race, class and level are known
+    pub sheet: Option<CharacterViewerSheetDto>,
     pub guild: Option<CharacterViewerGuildDto>,
     pub history: Vec<SelectOption<u32>>,
     pub gear: CharacterViewerGearDto,
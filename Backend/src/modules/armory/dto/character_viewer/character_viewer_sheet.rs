@@ -0,0 +1,27 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CharacterViewerSheetDto {
+    pub strength: u32,
+    pub agility: u32,
+    pub stamina: u32,
+    pub intellect: u32,
+    pub spirit: u32,
+    pub health: u32,
+    pub mana: u32,
+    pub armor: u32,
+    pub attack_power: u32,
+    pub ranged_attack_power: u32,
+    pub spell_power: u32,
+    pub healing_power: u32,
+    // Chances in percent at the character's level
+    pub hit_chance: f64,
+    pub crit_chance: f64,
+    pub haste: f64,
+    pub spell_hit_chance: f64,
+    pub spell_crit_chance: f64,
+    pub spell_haste: f64,
+    pub defense: f64,
+    pub dodge_chance: f64,
+    pub parry_chance: f64,
+    pub block_chance: f64,
+    pub resilience: f64,
+}
@@ -0,0 +1,10 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CharacterViewerTalentDto {
+    pub tree_index: u8,
+    pub talent_index: u8,
+    pub rank: u8,
+    pub max_rank: u8,
+    pub spell_id: u32,
+    pub name: String,
+    pub icon: String,
+}
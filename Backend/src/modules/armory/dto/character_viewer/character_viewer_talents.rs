@@ -1,6 +1,14 @@
+use crate::modules::armory::dto::CharacterViewerTalentDto;
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CharacterViewerTalentsDto {
     pub icon: String,
     pub name: String,
     pub description: String,
+    pub tree_index: u8,
+    pub points: Vec<u32>,
+    pub points_available: u32,
+    /// False if more points are spent than the level allows or a talent exceeds its max rank
+    pub is_valid: bool,
+    pub talents: Vec<CharacterViewerTalentDto>,
 }
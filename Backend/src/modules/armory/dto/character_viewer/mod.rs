@@ -1,6 +1,7 @@
 pub use self::{
     character_stat::CharacterStat, character_viewer::CharacterViewerDto, character_viewer_gear::CharacterViewerGearDto, character_viewer_guild::CharacterViewerGuildDto, character_viewer_item::CharacterViewerItemDto,
-    character_viewer_model::CharacterViewerModel, character_viewer_profession::CharacterViewerProfessionDto, character_viewer_talents::CharacterViewerTalentsDto,
+    character_viewer_model::CharacterViewerModel, character_viewer_profession::CharacterViewerProfessionDto, character_viewer_sheet::CharacterViewerSheetDto,
+    character_viewer_talent::CharacterViewerTalentDto, character_viewer_talents::CharacterViewerTalentsDto,
 };
 
 mod character_stat;
@@ -10,4 +11,6 @@ mod character_viewer_guild;
 mod character_viewer_item;
 mod character_viewer_model;
 mod character_viewer_profession;
+mod character_viewer_sheet;
+mod character_viewer_talent;
 mod character_viewer_talents;
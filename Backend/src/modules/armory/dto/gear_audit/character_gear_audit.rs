@@ -0,0 +1,10 @@
+use crate::modules::armory::dto::GearAuditSlot;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CharacterGearAudit {
+    pub character_id: u32,
+    pub character_history_id: u32,
+    pub average_item_level: f64,
+    pub num_findings: u32,
+    pub slots: Vec<GearAuditSlot>,
+}
@@ -0,0 +1,12 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub enum GearAuditFinding {
+    MissingItem,
+    MissingEnchant,
+    // Socket index
+    EmptySocket(u8),
+    // Socket index, gem item id
+    LowQualityGem(u8, u32),
+    InactiveSocketBonus,
+    // Item level of the item
+    LowItemLevel(u16),
+}
@@ -0,0 +1,11 @@
+use crate::modules::armory::dto::GearAuditFinding;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct GearAuditSlot {
+    pub inventory_type: u8,
+    pub item_id: Option<u32>,
+    pub item_level: Option<u16>,
+    pub enchant_id: Option<u32>,
+    pub socket_bonus_active: Option<bool>,
+    pub findings: Vec<GearAuditFinding>,
+}
@@ -0,0 +1,5 @@
+pub use self::{character_gear_audit::CharacterGearAudit, gear_audit_finding::GearAuditFinding, gear_audit_slot::GearAuditSlot};
+
+mod character_gear_audit;
+mod gear_audit_finding;
+mod gear_audit_slot;
@@ -0,0 +1,14 @@
+use crate::modules::armory::dto::{CharacterViewerSheetDto, GearStatDelta, ItemsetBonusChange, SheetStatDelta};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GearComparison {
+    pub character_history_id: u32,
+    /// Only if the character sheet can be calculated
+    pub sheet_before: Option<CharacterViewerSheetDto>,
+    pub sheet_after: Option<CharacterViewerSheetDto>,
+    /// Only the sheet values that changed
+    pub sheet_deltas: Vec<SheetStatDelta>,
+    /// Only the gear stats that changed
+    pub stat_deltas: Vec<GearStatDelta>,
+    pub itemset_bonus_changes: Vec<ItemsetBonusChange>,
+}
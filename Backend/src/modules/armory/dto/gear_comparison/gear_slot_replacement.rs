@@ -0,0 +1,8 @@
+use crate::modules::armory::dto::CharacterItemDto;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GearSlotReplacement {
+    pub inventory_type: u8,
+    /// None empties the slot
+    pub item: Option<CharacterItemDto>,
+}
@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct GearStatDelta {
+    pub stat_type: u8,
+    pub name: String,
+    pub before: u16,
+    pub after: u16,
+    pub delta: i32,
+}
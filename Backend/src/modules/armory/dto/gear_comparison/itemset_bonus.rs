@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct ItemsetBonus {
+    pub threshold: u8,
+    pub spell_id: u32,
+    pub description: Option<String>,
+}
@@ -0,0 +1,11 @@
+use crate::modules::armory::dto::ItemsetBonus;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct ItemsetBonusChange {
+    pub itemset_id: u16,
+    pub name: Option<String>,
+    pub pieces_before: u8,
+    pub pieces_after: u8,
+    pub gained: Vec<ItemsetBonus>,
+    pub lost: Vec<ItemsetBonus>,
+}
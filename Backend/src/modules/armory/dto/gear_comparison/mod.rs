@@ -0,0 +1,8 @@
+pub use self::{gear_comparison::GearComparison, gear_slot_replacement::GearSlotReplacement, gear_stat_delta::GearStatDelta, itemset_bonus::ItemsetBonus, itemset_bonus_change::ItemsetBonusChange, sheet_stat_delta::SheetStatDelta};
+
+mod gear_comparison;
+mod gear_slot_replacement;
+mod gear_stat_delta;
+mod itemset_bonus;
+mod itemset_bonus_change;
+mod sheet_stat_delta;
@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct SheetStatDelta {
+    pub stat: String,
+    pub before: f64,
+    pub after: f64,
+    pub delta: f64,
+}
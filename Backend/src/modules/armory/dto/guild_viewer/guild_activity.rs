@@ -0,0 +1,17 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub enum GuildActivity {
+    // The first snapshot of a character within the guild counts as join
+    Join { rank_index: u8 },
+    Leave { rank_index: u8 },
+    Promotion { previous_rank_index: u8, rank_index: u8 },
+    Demotion { previous_rank_index: u8, rank_index: u8 },
+    Rename { previous_name: String, name: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct GuildActivityDto {
+    pub timestamp: u64,
+    pub character_id: Option<u32>,
+    pub character_name: Option<String>,
+    pub activity: GuildActivity,
+}
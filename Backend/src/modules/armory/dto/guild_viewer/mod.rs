@@ -1,4 +1,5 @@
-pub use self::{guild_viewer::GuildViewerDto, guild_viewer_member::GuildViewerMemberDto};
+pub use self::{guild_activity::*, guild_viewer::GuildViewerDto, guild_viewer_member::GuildViewerMemberDto};
 
+mod guild_activity;
 mod guild_viewer;
 mod guild_viewer_member;
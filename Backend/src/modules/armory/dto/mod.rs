@@ -2,8 +2,8 @@ pub use self::arena_team::ArenaTeamDto;
 pub use self::basic_character::BasicCharacter;
 pub use self::search_guild::SearchGuildDto;
 pub use self::{
-    armory_failure::ArmoryFailure, character::CharacterDto, character_facial::CharacterFacialDto, character_gear::CharacterGearDto, character_guild::CharacterGuildDto, character_history::CharacterHistoryDto, character_info::CharacterInfoDto,
-    character_item::CharacterItemDto, character_search::*, character_viewer::*, guild::GuildDto, guild_viewer::*,
+    armory_failure::ArmoryFailure, character::CharacterDto, character_facial::CharacterFacialDto, character_gear::CharacterGearDto, character_guild::CharacterGuildDto, character_history::CharacterHistoryDto, character_history_diff::*,
+    character_info::CharacterInfoDto, character_item::CharacterItemDto, character_search::*, character_viewer::*, gear_audit::*, gear_comparison::*, guild::GuildDto, guild_viewer::*,
 };
 
 mod arena_team;
@@ -16,8 +16,11 @@ mod character_info;
 mod character_item;
 mod guild;
 
+mod character_history_diff;
 mod character_search;
 mod character_viewer;
+mod gear_audit;
+mod gear_comparison;
 mod guild_viewer;
 
 mod armory_failure;
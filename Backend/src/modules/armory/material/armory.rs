@@ -2,21 +2,28 @@ use std::{collections::HashMap, sync::RwLock};
 
 use crate::modules::armory::{
     domain_value::{CharacterFacial, CharacterGear, CharacterGuild, CharacterInfo, CharacterItem, GuildRank, HistoryMoment},
-    material::{Character, CharacterHistory, Guild},
+    material::{Character, CharacterClaim, CharacterHistory, Guild},
 };
 use crate::modules::armory::domain_value::{ArenaTeam, ArenaTeamSizeType};
 use crate::params;
 use crate::util::database::*;
+use crate::util::lru_cache::LruCache;
+
+// Bounds the memory held by rendered gear cards
+static GEAR_CARD_CACHE_CAPACITY: usize = 512;
 
 #[derive(Debug)]
 pub struct Armory {
     pub characters: RwLock<HashMap<u32, Character>>,
     pub guilds: RwLock<HashMap<u32, Guild>>,
+    pub character_claims: RwLock<HashMap<u32, CharacterClaim>>,
 
     // Caches
     // TODO: Evict them at some point!
     pub cache_char_history: RwLock<HashMap<u32, CharacterHistory>>,
     pub cache_char_name_to_id: RwLock<HashMap<String, Vec<u32>>>,
+    // Anyone can request gear cards, hence the cache is bounded
+    pub cache_gear_card: RwLock<LruCache<u32, Vec<u8>>>,
 }
 
 impl Default for Armory {
@@ -24,8 +31,10 @@ impl Default for Armory {
         Armory {
             characters: RwLock::new(HashMap::new()),
             guilds: RwLock::new(HashMap::new()),
+            character_claims: RwLock::new(HashMap::new()),
             cache_char_history: RwLock::new(HashMap::new()),
             cache_char_name_to_id: RwLock::new(HashMap::new()),
+            cache_gear_card: RwLock::new(LruCache::new(GEAR_CARD_CACHE_CAPACITY)),
         }
     }
 }
@@ -34,6 +43,7 @@ impl Armory {
     pub fn init(self, db_main: &mut impl Select) -> Self {
         self.characters.write().unwrap().init(db_main);
         self.guilds.write().unwrap().init(db_main);
+        self.character_claims.write().unwrap().init(db_main);
         {
             let chars = self.characters.read().unwrap();
             let mut cache = self.cache_char_name_to_id.write().unwrap();
@@ -51,6 +61,7 @@ impl Armory {
     pub fn update(&self, db_main: &mut impl Select) {
         self.characters.write().unwrap().init(db_main);
         self.guilds.write().unwrap().init(db_main);
+        self.character_claims.write().unwrap().init(db_main);
     }
 }
 
@@ -96,7 +107,8 @@ impl Init for HashMap<u32, Character> {
 
         // Load the actual newest character history data
         db.select_wparams(
-            "SELECT ach.*, agr.name AS guild_rank_name, acf.*, aci.*, ag.id, ai1.*, ai2.*, ai3.*, ai4.*, ai5.*, ai6.*, ai7.*, ai8.*, ai9.*, ai10.*, ai11.*, ai12.*, ai13.*, ai14.*, ai15.*, ai16.*, ai17.*, ai18.*, ai19.*, aat1.*, aat2.*, aat3.* FROM \
+            "SELECT ach.id, ach.character_id, ach.character_info_id, ach.character_name, ach.guild_id, ach.guild_rank, ach.title, ach.prof_skill_points1, ach.prof_skill_points2, ach.facial, ach.arena2, ach.arena3, ach.arena5, ach.timestamp, \
+             agr.name AS guild_rank_name, acf.*, aci.*, ag.id, ai1.*, ai2.*, ai3.*, ai4.*, ai5.*, ai6.*, ai7.*, ai8.*, ai9.*, ai10.*, ai11.*, ai12.*, ai13.*, ai14.*, ai15.*, ai16.*, ai17.*, ai18.*, ai19.*, aat1.*, aat2.*, aat3.*, ach.first_seen FROM \
              armory_character_history ach JOIN (SELECT MAX(id) id FROM armory_character_history GROUP BY character_id) ach_max ON ach.id = ach_max.id LEFT JOIN armory_character_facial acf ON acf.id = ach.facial JOIN armory_character_info aci ON \
              ach.character_info_id = aci.id JOIN armory_gear ag ON aci.gear_id = ag.id LEFT JOIN armory_item ai1 ON ag.head = ai1.id LEFT JOIN armory_item ai2 ON ag.neck = ai2.id LEFT JOIN armory_item ai3 ON ag.shoulder = ai3.id LEFT JOIN \
              armory_item ai4 ON ag.back = ai4.id LEFT JOIN armory_item ai5 ON ag.chest = ai5.id LEFT JOIN armory_item ai6 ON ag.shirt = ai6.id LEFT JOIN armory_item ai7 ON ag.tabard = ai7.id LEFT JOIN armory_item ai8 ON ag.wrist = ai8.id LEFT JOIN \
@@ -166,6 +178,7 @@ impl Init for HashMap<u32, Character> {
                     profession_skill_points2: row.take_opt(8).unwrap().ok(),
                     arena_teams,
                     timestamp: row.take(13).unwrap(),
+                    first_seen: row.take(198).unwrap(),
                     facial: row.take_opt(15).unwrap().ok().map(|facial_id: u32| CharacterFacial {
                         id: facial_id,
                         skin_color: row.take(16).unwrap(),
@@ -259,3 +272,22 @@ impl Init for HashMap<u32, Guild> {
             });
     }
 }
+
+impl Init for HashMap<u32, CharacterClaim> {
+    fn init(&mut self, db: &mut impl Select) {
+        // Claims are confirmed and revoked in place, hence they are always reloaded entirely
+        self.clear();
+        db.select("SELECT id, member_id, character_id, token, requested_ts, confirmed_ts FROM armory_character_claim", |mut row| CharacterClaim {
+            id: row.take(0).unwrap(),
+            member_id: row.take(1).unwrap(),
+            character_id: row.take(2).unwrap(),
+            token: row.take(3).unwrap(),
+            requested_ts: row.take(4).unwrap(),
+            confirmed_ts: row.take_opt(5).unwrap().ok(),
+        })
+            .into_iter()
+            .for_each(|result| {
+                self.insert(result.id, result);
+            });
+    }
+}
@@ -0,0 +1,15 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CharacterClaim {
+    pub id: u32,
+    pub member_id: u32,
+    pub character_id: u32,
+    pub token: String,
+    pub requested_ts: u64,
+    pub confirmed_ts: Option<u64>,
+}
+
+impl CharacterClaim {
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed_ts.is_some()
+    }
+}
@@ -17,6 +17,8 @@ pub struct CharacterHistory {
     pub facial: Option<CharacterFacial>,
     pub arena_teams: Vec<ArenaTeam>,
     pub timestamp: u64,
+    // The timestamp is refreshed as long as the character stays unchanged, this is when the snapshot was taken
+    pub first_seen: u64,
 }
 
 impl PartialEq for CharacterHistory {
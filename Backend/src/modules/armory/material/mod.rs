@@ -1,6 +1,7 @@
-pub use self::{armory::Armory, character::Character, character_history::CharacterHistory, guild::Guild};
+pub use self::{armory::Armory, character::Character, character_claim::CharacterClaim, character_history::CharacterHistory, guild::Guild};
 
 mod armory;
 mod character;
+mod character_claim;
 mod character_history;
 mod guild;
@@ -0,0 +1,109 @@
+use super::helper::get_character;
+use crate::modules::armory::domain_value::{CharacterGear, CharacterGuild, CharacterInfo, GuildRank};
+use crate::modules::armory::material::{Character, CharacterClaim, CharacterHistory};
+use crate::modules::armory::tools::{contains_claim_token, CharacterClaims};
+use crate::modules::armory::Armory;
+
+fn get_guild_character(character_id: u32, guild_id: Option<u32>) -> Character {
+    Character {
+        id: character_id,
+        server_id: 1,
+        server_uid: character_id as u64,
+        last_update: Some(CharacterHistory {
+            id: character_id,
+            character_id,
+            character_info: CharacterInfo {
+                id: character_id,
+                gear: CharacterGear {
+                    id: character_id,
+                    head: None,
+                    neck: None,
+                    shoulder: None,
+                    back: None,
+                    chest: None,
+                    shirt: None,
+                    tabard: None,
+                    wrist: None,
+                    main_hand: None,
+                    off_hand: None,
+                    ternary_hand: None,
+                    glove: None,
+                    belt: None,
+                    leg: None,
+                    boot: None,
+                    ring1: None,
+                    ring2: None,
+                    trinket1: None,
+                    trinket2: None,
+                },
+                hero_class_id: 1,
+                level: 60,
+                gender: false,
+                profession1: None,
+                profession2: None,
+                talent_specialization: None,
+                race_id: 1,
+            },
+            character_name: "Peter".to_string(),
+            character_guild: guild_id.map(|guild_id| CharacterGuild {
+                guild_id,
+                rank: GuildRank { index: 0, name: "Member".to_string() },
+            }),
+            character_title: None,
+            profession_skill_points1: None,
+            profession_skill_points2: None,
+            facial: None,
+            arena_teams: Vec::new(),
+            timestamp: 1000,
+            first_seen: 1000,
+        }),
+        history_moments: Vec::new(),
+    }
+}
+
+fn get_claim(id: u32, member_id: u32, character_id: u32, confirmed_ts: Option<u64>) -> CharacterClaim {
+    CharacterClaim {
+        id,
+        member_id,
+        character_id,
+        token: "LPAB12CD34".to_string(),
+        requested_ts: 1000,
+        confirmed_ts,
+    }
+}
+
+#[test]
+fn contains_claim_token_in_note() {
+    let mut character_dto = get_character();
+    assert!(!contains_claim_token(&character_dto, "LPAB12CD34"));
+
+    character_dto.note = Some("Main tank, lpab12cd34".to_string());
+    assert!(contains_claim_token(&character_dto, "LPAB12CD34"));
+    assert!(!contains_claim_token(&character_dto, "LPAB12CD35"));
+}
+
+#[test]
+fn contains_claim_token_not_in_guild_rank_name() {
+    let mut character_dto = get_character();
+    character_dto.character_history.as_mut().unwrap().character_guild.as_mut().unwrap().rank.name = "LPAB12CD34".to_string();
+    assert!(!contains_claim_token(&character_dto, "LPAB12CD34"));
+}
+
+#[test]
+fn claimed_guild_ids_of_confirmed_claims() {
+    let armory = Armory::default();
+    {
+        let mut characters = armory.characters.write().unwrap();
+        for character in [get_guild_character(1, Some(5)), get_guild_character(2, Some(5)), get_guild_character(3, Some(6)), get_guild_character(4, None), get_guild_character(5, Some(7))].iter() {
+            characters.insert(character.id, character.clone());
+        }
+        let mut character_claims = armory.character_claims.write().unwrap();
+        for claim in [get_claim(1, 1, 1, Some(2000)), get_claim(2, 1, 2, Some(2000)), get_claim(3, 1, 3, Some(2000)), get_claim(4, 1, 4, Some(2000)), get_claim(5, 1, 5, None), get_claim(6, 2, 5, Some(2000))].iter() {
+            character_claims.insert(claim.id, claim.clone());
+        }
+    }
+
+    assert_eq!(armory.get_claimed_guild_ids(1), vec![5, 6]);
+    assert_eq!(armory.get_claimed_guild_ids(2), vec![7]);
+    assert!(armory.get_claimed_guild_ids(3).is_empty());
+}
@@ -15,7 +15,7 @@ fn set_character_history() {
     let (mut conn, _dns, _node) = container.run();
 
     let armory = Armory::default();
-    let character_dto = CharacterDto { server_uid: 123124, character_history: None };
+    let character_dto = CharacterDto { server_uid: 123124, note: None, character_history: None };
     let character_history_dto = get_character_history();
     let timestamp = time_util::now() * 1000;
 
@@ -65,7 +65,7 @@ fn test_set_character_history_character_history_dto_inplausible() {
 
     // Arrange
     let armory = Armory::default();
-    let character_dto = CharacterDto { server_uid: 123124, character_history: None };
+    let character_dto = CharacterDto { server_uid: 123124, note: None, character_history: None };
     let mut character_history_dto = get_character_history();
     let timestamp = time_util::now() * 1000;
     character_history_dto.character_name = String::from("");
@@ -122,7 +122,7 @@ fn test_set_character_history_update_not_successful() {
     // Arrange
     let armory = Armory::default();
     let mut db_mock = DbMock {};
-    let character_dto = CharacterDto { server_uid: 123124, character_history: None };
+    let character_dto = CharacterDto { server_uid: 123124, note: None, character_history: None };
     let mut character_history_dto = get_character_history();
     let timestamp = time_util::now() * 1000;
     character_history_dto.character_guild = None;
@@ -152,7 +152,7 @@ fn test_set_character_history_different_value() {
 
     // Arrange
     let armory = Armory::default();
-    let character_dto = CharacterDto { server_uid: 123124, character_history: None };
+    let character_dto = CharacterDto { server_uid: 123124, note: None, character_history: None };
     let mut character_history_dto = get_character_history();
     let timestamp = time_util::now() * 1000;
 
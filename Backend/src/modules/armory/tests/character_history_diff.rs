@@ -0,0 +1,155 @@
+use crate::modules::armory::domain_value::{CharacterGear, CharacterGuild, CharacterInfo, CharacterItem, GuildRank, InventoryType};
+use crate::modules::armory::dto::{CharacterHistoryChange, CharacterTimelineEntry};
+use crate::modules::armory::material::CharacterHistory;
+use crate::modules::armory::tools::{build_character_timeline, diff_character_history, link_looted_items};
+
+fn get_character_item(item_id: u32, enchant_id: Option<u32>, gem_ids: Vec<Option<u32>>) -> Option<CharacterItem> {
+    Some(CharacterItem {
+        id: 0,
+        item_id,
+        random_property_id: None,
+        enchant_id,
+        gem_ids,
+    })
+}
+
+fn get_character_history(id: u32, character_name: &str, level: u8, guild_id: Option<u32>, talent_specialization: Option<&str>, head: Option<CharacterItem>, chest: Option<CharacterItem>) -> CharacterHistory {
+    CharacterHistory {
+        id,
+        character_id: 1,
+        character_info: CharacterInfo {
+            id,
+            gear: CharacterGear {
+                id,
+                head,
+                neck: None,
+                shoulder: None,
+                back: None,
+                chest,
+                shirt: None,
+                tabard: None,
+                wrist: None,
+                main_hand: None,
+                off_hand: None,
+                ternary_hand: None,
+                glove: None,
+                belt: None,
+                leg: None,
+                boot: None,
+                ring1: None,
+                ring2: None,
+                trinket1: None,
+                trinket2: None,
+            },
+            hero_class_id: 1,
+            level,
+            gender: false,
+            profession1: None,
+            profession2: None,
+            talent_specialization: talent_specialization.map(|talents| talents.to_string()),
+            race_id: 1,
+        },
+        character_name: character_name.to_string(),
+        character_guild: guild_id.map(|guild_id| CharacterGuild {
+            guild_id,
+            rank: GuildRank { index: 0, name: "Member".to_string() },
+        }),
+        character_title: None,
+        profession_skill_points1: None,
+        profession_skill_points2: None,
+        facial: None,
+        arena_teams: Vec::new(),
+        timestamp: id as u64 * 1000,
+        first_seen: id as u64 * 1000,
+    }
+}
+
+#[test]
+fn diff_character_history_changes() {
+    let previous = get_character_history(1, "Peter", 59, Some(2), Some("05|00|00"), get_character_item(100, None, vec![None, None]), get_character_item(200, Some(5), vec![None, None]));
+    let current = get_character_history(2, "Petra", 60, Some(3), Some("05|10|00"), get_character_item(101, None, vec![None, None]), get_character_item(200, Some(6), vec![Some(7), None]));
+
+    let changes = diff_character_history(&previous, &current);
+
+    assert_eq!(
+        changes,
+        vec![
+            CharacterHistoryChange::Rename {
+                previous_name: "Peter".to_string(),
+                name: "Petra".to_string(),
+            },
+            CharacterHistoryChange::LevelUp { previous_level: 59, level: 60 },
+            CharacterHistoryChange::GuildLeave { guild_id: 2 },
+            CharacterHistoryChange::GuildJoin { guild_id: 3 },
+            CharacterHistoryChange::TalentChange {
+                previous_talent_specialization: Some("05|00|00".to_string()),
+                talent_specialization: Some("05|10|00".to_string()),
+            },
+            CharacterHistoryChange::GearSwap {
+                inventory_type: InventoryType::Head as u8,
+                previous_item_id: Some(100),
+                item_id: Some(101),
+                looted_instance_meta_id: None,
+            },
+            CharacterHistoryChange::EnchantChange {
+                inventory_type: InventoryType::Chest as u8,
+                item_id: 200,
+                previous_enchant_id: Some(5),
+                enchant_id: Some(6),
+            },
+            CharacterHistoryChange::GemChange {
+                inventory_type: InventoryType::Chest as u8,
+                item_id: 200,
+                previous_gem_ids: vec![None, None],
+                gem_ids: vec![Some(7), None],
+            },
+        ]
+    );
+    assert!(diff_character_history(&current, &current).is_empty());
+}
+
+#[test]
+fn character_timeline_dated_by_first_seen() {
+    let mut first = get_character_history(1, "Peter", 59, None, None, None, None);
+    first.timestamp = 5000;
+    let mut second = get_character_history(2, "Peter", 60, None, None, None, None);
+    // Refreshed by every unchanged update since
+    second.timestamp = 9000;
+    second.first_seen = 6000;
+    let mut third = get_character_history(3, "Peter", 60, None, None, None, None);
+    third.first_seen = 7000;
+
+    let timeline = build_character_timeline(vec![third, second, first]);
+
+    assert_eq!(timeline.len(), 1);
+    assert_eq!((timeline[0].history_id, timeline[0].timestamp), (2, 6000));
+    assert_eq!(timeline[0].changes, vec![CharacterHistoryChange::LevelUp { previous_level: 59, level: 60 }]);
+}
+
+#[test]
+fn link_looted_items_to_gear_swaps() {
+    let mut timeline = vec![CharacterTimelineEntry {
+        history_id: 2,
+        timestamp: 2000,
+        changes: vec![CharacterHistoryChange::GearSwap {
+            inventory_type: InventoryType::Head as u8,
+            previous_item_id: Some(100),
+            item_id: Some(101),
+            looted_instance_meta_id: None,
+        }],
+    }];
+    // Only loot before the snapshot counts, the most recent one wins
+    let loot = vec![(101, 10, 1_000_000), (101, 11, 1_500_000), (101, 12, 2_500_000), (100, 13, 1_600_000)];
+
+    link_looted_items(&mut timeline, &loot);
+
+    assert_eq!(
+        timeline[0].changes[0],
+        CharacterHistoryChange::GearSwap {
+            inventory_type: InventoryType::Head as u8,
+            previous_item_id: Some(100),
+            item_id: Some(101),
+            looted_instance_meta_id: Some(11),
+        }
+    );
+}
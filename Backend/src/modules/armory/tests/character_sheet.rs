@@ -0,0 +1,182 @@
+use crate::modules::armory::tools::{build_character_sheet, rating_per_percent};
+use crate::modules::data::domain_value::{ClassStatCoefficient, CombatRating, LevelStat, TalentStatModifier};
+use crate::modules::data::{Data, Stat};
+
+fn assert_chance(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 0.01, "expected {} but got {}", expected, actual);
+}
+
+#[test]
+fn rating_per_percent_at_level() {
+    // Level 60 is the reference level
+    assert_chance(rating_per_percent(14.0, 60), 14.0);
+    // Crit, hit and defense rating at level 70
+    assert_chance(rating_per_percent(14.0, 70), 22.08);
+    assert_chance(rating_per_percent(10.0, 70), 15.77);
+    assert_chance(rating_per_percent(1.5, 70), 2.37);
+    // Crit, hit and dodge rating at level 80
+    assert_chance(rating_per_percent(14.0, 80), 45.91);
+    assert_chance(rating_per_percent(10.0, 80), 32.79);
+    assert_chance(rating_per_percent(13.8, 80), 45.25);
+    // Low levels
+    assert_chance(rating_per_percent(14.0, 34), 7.0);
+    assert_chance(rating_per_percent(14.0, 5), rating_per_percent(14.0, 10));
+}
+
+fn get_level_stat(expansion_id: u8, hero_class_id: u8, level: u8) -> LevelStat {
+    LevelStat {
+        expansion_id,
+        race_id: 1,
+        hero_class_id,
+        level,
+        strength: 105,
+        agility: 60,
+        stamina: 100,
+        intellect: 70,
+        spirit: 70,
+        health: 1381,
+        mana: 1512,
+    }
+}
+
+fn get_class_stat_coefficient(expansion_id: u8, hero_class_id: u8, level: u8, agility_per_crit: f64, intellect_per_spell_crit: f64) -> ClassStatCoefficient {
+    ClassStatCoefficient {
+        expansion_id,
+        hero_class_id,
+        level,
+        agility_per_crit,
+        intellect_per_spell_crit: Some(intellect_per_spell_crit),
+    }
+}
+
+fn get_paladin_data() -> Data {
+    let mut data = Data::default();
+    data.level_stats.insert((1, 1, 2, 60), get_level_stat(1, 2, 60));
+    data.class_stat_coefficients.insert((1, 2, 60), get_class_stat_coefficient(1, 2, 60, 20.0, 54.0));
+    data.talent_stat_modifiers.insert(
+        (1, 2),
+        vec![TalentStatModifier {
+            expansion_id: 1,
+            hero_class_id: 2,
+            tree_index: 0,
+            talent_index: 0,
+            stat_type: 27,
+            value_per_rank: 2.0,
+        }],
+    );
+    data
+}
+
+#[test]
+fn character_sheet_base_stats_and_talents() {
+    let data = get_paladin_data();
+    let gear_stats = vec![
+        Stat { stat_type: 27, stat_value: 95 },
+        Stat { stat_type: 29, stat_value: 50 },
+        Stat { stat_type: 30, stat_value: 38 },
+        Stat { stat_type: 34, stat_value: 5000 },
+        Stat { stat_type: 9, stat_value: 40 },
+        // Vanilla items grant chances directly, e.g. "Improves your chance to hit by 1%"
+        Stat { stat_type: 7, stat_value: 2 },
+    ];
+
+    let sheet = build_character_sheet(&data, 1, 1, 2, 60, &Some("5|000|00".to_string()), &gear_stats).unwrap();
+
+    // Divine Strength 5/5 adds 10% to (105 + 95) strength
+    assert_eq!(sheet.strength, 220);
+    assert_eq!(sheet.agility, 60);
+    assert_eq!(sheet.stamina, 150);
+    assert_eq!(sheet.intellect, 108);
+    assert_eq!(sheet.ranged_attack_power, 0);
+    // 60 agility at 20 agility per 1% crit, 108 intellect at 54 intellect per 1% spell crit
+    assert_chance(sheet.crit_chance, 3.0);
+    assert_chance(sheet.spell_crit_chance, 2.0);
+    assert_chance(sheet.hit_chance, 2.0);
+
+    let untalented_sheet = build_character_sheet(&data, 1, 1, 2, 60, &None, &gear_stats).unwrap();
+    assert_eq!(untalented_sheet.strength, 200);
+}
+
+#[test]
+fn character_sheet_follows_attribute_tooltips() {
+    let data = get_paladin_data();
+    let sheet = build_character_sheet(&data, 1, 1, 2, 60, &None, &[]).unwrap();
+
+    // In-game tooltips: Stamina "Increases Health by 10", Intellect "Increases Mana by 15", Agility "Increases Armor by 2"
+    // and Strength "Increases Attack Power by 2" for paladins
+    let gear_stats = vec![
+        Stat { stat_type: 29, stat_value: 10 },
+        Stat { stat_type: 30, stat_value: 10 },
+        Stat { stat_type: 28, stat_value: 10 },
+        Stat { stat_type: 27, stat_value: 10 },
+    ];
+    let geared_sheet = build_character_sheet(&data, 1, 1, 2, 60, &None, &gear_stats).unwrap();
+    assert_eq!(geared_sheet.health - sheet.health, 100);
+    assert_eq!(geared_sheet.mana - sheet.mana, 150);
+    assert_eq!(geared_sheet.armor - sheet.armor, 20);
+    assert_eq!(geared_sheet.attack_power - sheet.attack_power, 20);
+}
+
+#[test]
+fn character_sheet_needs_all_its_data() {
+    let data = get_paladin_data();
+    let gear_stats = vec![Stat { stat_type: 29, stat_value: 50 }];
+
+    assert!(build_character_sheet(&data, 1, 1, 2, 60, &None, &gear_stats).is_some());
+    // No base attributes for other races and levels, no class coefficients for levels below the level cap
+    assert!(build_character_sheet(&data, 1, 2, 2, 60, &None, &gear_stats).is_none());
+    let mut data_without_coefficient = get_paladin_data();
+    data_without_coefficient.level_stats.insert((1, 1, 2, 59), get_level_stat(1, 2, 59));
+    assert!(build_character_sheet(&data_without_coefficient, 1, 1, 2, 59, &None, &gear_stats).is_none());
+    // No combat ratings for TBC
+    let mut data_without_ratings = get_paladin_data();
+    data_without_ratings.level_stats.insert((2, 1, 2, 70), get_level_stat(2, 2, 70));
+    data_without_ratings.class_stat_coefficients.insert((2, 2, 70), get_class_stat_coefficient(2, 2, 70, 25.0, 80.0));
+    assert!(build_character_sheet(&data_without_ratings, 2, 1, 2, 70, &None, &gear_stats).is_none());
+}
+
+#[test]
+fn character_sheet_ratings() {
+    let mut data = Data::default();
+    for (expansion_id, stat_type, rating) in [(3, 7, 10.0), (3, 8, 14.0), (3, 22, 1.5), (3, 10, 13.8), (3, 23, 8.0), (3, 24, 14.0), (2, 7, 10.0), (2, 8, 14.0), (2, 23, 8.0)].iter() {
+        data.combat_ratings.insert(
+            (*expansion_id, *stat_type),
+            CombatRating {
+                expansion_id: *expansion_id,
+                stat_type: *stat_type,
+                rating_per_percent: *rating,
+            },
+        );
+    }
+    data.level_stats.insert((3, 1, 8, 80), get_level_stat(3, 8, 80));
+    data.level_stats.insert((2, 1, 8, 70), get_level_stat(2, 8, 70));
+    data.class_stat_coefficients.insert((3, 8, 80), get_class_stat_coefficient(3, 8, 80, 51.02, 166.67));
+    data.class_stat_coefficients.insert((2, 8, 70), get_class_stat_coefficient(2, 8, 70, 25.0, 81.0));
+    let gear_stats = vec![
+        Stat { stat_type: 7, stat_value: 263 },
+        Stat { stat_type: 8, stat_value: 459 },
+        Stat { stat_type: 22, stat_value: 689 },
+        Stat { stat_type: 10, stat_value: 181 },
+    ];
+
+    let sheet = build_character_sheet(&data, 3, 1, 8, 80, &None, &gear_stats).unwrap();
+
+    // At level 80 the melee hit cap of 8% is 263 rating, the same rating is 10% spell hit at 26.23 rating per 1%
+    assert_chance(sheet.hit_chance, 8.02);
+    assert_chance(sheet.spell_hit_chance, 10.03);
+    // 45.91 crit rating per 1% for melee and spells
+    assert_chance(sheet.crit_chance, 10.0 + 60.0 / 51.02);
+    assert_chance(sheet.spell_crit_chance, 10.0 + 70.0 / 166.67);
+    // 689 defense rating is 140 defense skill, the crit immunity cap
+    assert_chance(sheet.defense, 140.08);
+    assert_chance(sheet.dodge_chance, 4.0 + 0.04 * 140.08);
+
+    // The spell hit cap of level 80 is 446 rating for 17%
+    let capped_sheet = build_character_sheet(&data, 3, 1, 8, 80, &None, &[Stat { stat_type: 7, stat_value: 446 }]).unwrap();
+    assert_chance(capped_sheet.spell_hit_chance, 17.0);
+
+    // Before WotLK melee hit rating does not apply to spells, the spell hit cap of level 70 is 202 rating for 16%
+    let tbc_sheet = build_character_sheet(&data, 2, 1, 8, 70, &None, &[Stat { stat_type: 7, stat_value: 158 }, Stat { stat_type: 23, stat_value: 202 }]).unwrap();
+    assert_chance(tbc_sheet.hit_chance, 10.02);
+    assert_chance(tbc_sheet.spell_hit_chance, 16.01);
+}
@@ -61,6 +61,7 @@ fn get_character_viewer() {
     };
     let character_dto = CharacterDto {
         server_uid: 1231223445,
+        note: None,
         character_history: Some(character_history_dto.to_owned()),
     };
 
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use crate::modules::armory::domain_value::{CharacterGear, CharacterItem, InventoryType};
+use crate::modules::armory::dto::GearAuditFinding;
+use crate::modules::armory::tools::audit_gear;
+use crate::modules::data::domain_value::{Gem, Item, ItemSocket};
+use crate::modules::data::Data;
+
+fn get_item(id: u32, quality: u8, inventory_type: u8, item_level: u16) -> Item {
+    Item {
+        expansion_id: 2,
+        id,
+        localization_id: 0,
+        icon: 0,
+        quality,
+        inventory_type: Some(inventory_type),
+        class_id: 4,
+        required_level: None,
+        bonding: None,
+        sheath: None,
+        itemset: None,
+        max_durability: None,
+        item_level: Some(item_level),
+        delay: None,
+        display_info: None,
+    }
+}
+
+fn get_character_item(item_id: u32, enchant_id: Option<u32>, gem_ids: Vec<Option<u32>>) -> Option<CharacterItem> {
+    Some(CharacterItem {
+        id: 0,
+        item_id,
+        random_property_id: None,
+        enchant_id,
+        gem_ids,
+    })
+}
+
+fn get_data() -> Data {
+    let mut data = Data::default();
+    let mut items = HashMap::new();
+    for item in [
+        get_item(1000, 4, 1, 115),
+        get_item(1001, 4, 2, 70),
+        get_item(1002, 4, 17, 120),
+        get_item(1003, 4, 0, 115),
+        get_item(2000, 3, 0, 70),
+        get_item(2001, 2, 0, 60),
+    ]
+    .iter()
+    {
+        items.insert(item.id, item.clone());
+    }
+    data.items.push(HashMap::new());
+    data.items.push(items);
+
+    let mut gems = HashMap::new();
+    gems.insert(
+        2000,
+        Gem {
+            expansion_id: 2,
+            item_id: 2000,
+            enchant_id: 0,
+            flag: 1,
+        },
+    );
+    gems.insert(
+        2001,
+        Gem {
+            expansion_id: 2,
+            item_id: 2001,
+            enchant_id: 0,
+            flag: 4,
+        },
+    );
+    data.gems.push(gems);
+
+    let mut item_sockets = HashMap::new();
+    item_sockets.insert(
+        1000,
+        ItemSocket {
+            expansion_id: 2,
+            item_id: 1000,
+            bonus: 0,
+            slots: vec![1, 2],
+        },
+    );
+    data.item_sockets.push(item_sockets);
+    data
+}
+
+#[test]
+fn audit_gear_findings() {
+    let data = get_data();
+    let gear = CharacterGear {
+        id: 0,
+        head: get_character_item(1000, None, vec![Some(2000), Some(2001), None, None]),
+        neck: get_character_item(1001, None, vec![None, None, None, None]),
+        shoulder: get_character_item(1003, Some(1), vec![None, None, None, None]),
+        back: get_character_item(1003, Some(1), vec![None, None, None, None]),
+        chest: get_character_item(1003, Some(1), vec![None, None, None, None]),
+        shirt: None,
+        tabard: None,
+        wrist: get_character_item(1003, Some(1), vec![None, None, None, None]),
+        main_hand: get_character_item(1002, Some(1), vec![None, None, None, None]),
+        off_hand: None,
+        ternary_hand: get_character_item(1003, None, vec![None, None, None, None]),
+        glove: get_character_item(1003, Some(1), vec![None, None, None, None]),
+        belt: get_character_item(1003, None, vec![None, None, None, None]),
+        leg: get_character_item(1003, Some(1), vec![None, None, None, None]),
+        boot: get_character_item(1003, Some(1), vec![None, None, None, None]),
+        ring1: get_character_item(1003, None, vec![None, None, None, None]),
+        ring2: get_character_item(1003, None, vec![None, None, None, None]),
+        trinket1: get_character_item(1003, None, vec![None, None, None, None]),
+        trinket2: get_character_item(1003, None, vec![None, None, None, None]),
+    };
+
+    let (average_item_level, slots) = audit_gear(&data, 2, &gear);
+
+    // The two hand weapon counts for the off hand as well
+    assert_eq!(average_item_level, 112.94);
+    assert_eq!(slots.len(), 16);
+    let head = slots.iter().find(|slot| slot.inventory_type == InventoryType::Head as u8).unwrap();
+    assert_eq!(head.socket_bonus_active, Some(false));
+    assert_eq!(head.findings, vec![GearAuditFinding::MissingEnchant, GearAuditFinding::LowQualityGem(1, 2001), GearAuditFinding::InactiveSocketBonus]);
+    let neck = slots.iter().find(|slot| slot.inventory_type == InventoryType::Neck as u8).unwrap();
+    assert_eq!(neck.findings, vec![GearAuditFinding::LowItemLevel(70)]);
+    assert!(slots
+        .iter()
+        .filter(|slot| slot.inventory_type != InventoryType::Head as u8 && slot.inventory_type != InventoryType::Neck as u8)
+        .all(|slot| slot.findings.is_empty()));
+}
+
+#[test]
+fn audit_gear_empty() {
+    let data = get_data();
+    let gear = CharacterGear {
+        id: 0,
+        head: None,
+        neck: None,
+        shoulder: None,
+        back: None,
+        chest: None,
+        shirt: None,
+        tabard: None,
+        wrist: None,
+        main_hand: None,
+        off_hand: None,
+        ternary_hand: None,
+        glove: None,
+        belt: None,
+        leg: None,
+        boot: None,
+        ring1: None,
+        ring2: None,
+        trinket1: None,
+        trinket2: None,
+    };
+
+    let (average_item_level, slots) = audit_gear(&data, 2, &gear);
+
+    assert_eq!(average_item_level, 0.0);
+    assert_eq!(slots.len(), 17);
+    assert!(slots.iter().all(|slot| slot.findings == vec![GearAuditFinding::MissingItem]));
+}
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use image::{Rgba, RgbaImage};
+
+use crate::modules::armory::dto::{CharacterViewerDto, CharacterViewerGearDto, CharacterViewerGuildDto, CharacterViewerItemDto, CharacterViewerSheetDto};
+use crate::modules::armory::util::gear_card::{get_glyph, get_hero_class_color, get_key_stats, get_quality_color, render_gear_card, text_width, CARD_HEIGHT, CARD_WIDTH};
+use crate::util::lru_cache::LruCache;
+
+fn item(item_id: u32, quality: u8) -> Option<CharacterViewerItemDto> {
+    Some(CharacterViewerItemDto {
+        item_id,
+        quality,
+        icon: format!("inv_{}", item_id),
+    })
+}
+
+fn character_viewer(mana: u32) -> CharacterViewerDto {
+    CharacterViewerDto {
+        history_id: 1,
+        character_id: 1,
+        gender: false,
+        faction: false,
+        race_id: 1,
+        hero_class_id: 11,
+        level: 70,
+        name: "Ättmaker".to_string(),
+        server_id: 1,
+        server_name: "Test".to_string(),
+        stats: Vec::new(),
+        sheet: Some(CharacterViewerSheetDto {
+            strength: 100,
+            agility: 200,
+            stamina: 300,
+            intellect: 50,
+            spirit: 60,
+            health: 10000,
+            mana,
+            armor: 5000,
+            attack_power: 1500,
+            ranged_attack_power: 200,
+            spell_power: 0,
+            healing_power: 0,
+            hit_chance: 5.0,
+            crit_chance: 25.5,
+            haste: 0.0,
+            spell_hit_chance: 0.0,
+            spell_crit_chance: 3.0,
+            spell_haste: 0.0,
+            defense: 350.0,
+            dodge_chance: 30.0,
+            parry_chance: 0.0,
+            block_chance: 0.0,
+            resilience: 0.0,
+        }),
+        guild: Some(CharacterViewerGuildDto {
+            guild_id: 1,
+            name: "Guild".to_string(),
+            rank: "Member".to_string(),
+        }),
+        history: Vec::new(),
+        gear: CharacterViewerGearDto {
+            gear_id: 1,
+            head: item(1, 5),
+            neck: None,
+            shoulder: None,
+            back: None,
+            chest: None,
+            shirt: None,
+            tabard: None,
+            wrist: None,
+            main_hand: None,
+            off_hand: None,
+            ternary_hand: None,
+            glove: item(2, 4),
+            belt: None,
+            leg: None,
+            boot: None,
+            ring1: None,
+            ring2: None,
+            trinket1: None,
+            trinket2: None,
+        },
+        profession1: None,
+        profession2: None,
+        talent_specialization: None,
+    }
+}
+
+#[test]
+fn render_gear_card_as_png() {
+    let mut item_levels = HashMap::new();
+    item_levels.insert(1, 120);
+    let icon_color = Rgba([1, 2, 3, 255]);
+    let png = render_gear_card(&character_viewer(0), &item_levels, 115.4, |icon| if icon == "inv_1" { Some(RgbaImage::from_pixel(64, 64, icon_color)) } else { None });
+
+    let card = image::load_from_memory(&png).unwrap().to_rgba8();
+    assert_eq!(card.dimensions(), (CARD_WIDTH, CARD_HEIGHT));
+    // Quality border and icon of the head, the glove has no icon
+    assert_eq!(*card.get_pixel(12, 64), get_quality_color(5));
+    assert_eq!(*card.get_pixel(30, 80), icon_color);
+    assert_eq!(*card.get_pixel(CARD_WIDTH - 13, 64), get_quality_color(4));
+    assert_ne!(*card.get_pixel(CARD_WIDTH - 30, 80), icon_color);
+    // Class coloured line below the header
+    assert_eq!(*card.get_pixel(200, 55), get_hero_class_color(11));
+}
+
+#[test]
+fn key_stats_omit_mana_without_mana() {
+    let key_stats = get_key_stats(&character_viewer(0), 115.44);
+    assert_eq!(key_stats[0], ("ITEM LEVEL".to_string(), "115.4".to_string()));
+    assert!(!key_stats.iter().any(|(label, _)| label == "MANA"));
+    assert!(key_stats.contains(&("ATTACK POWER".to_string(), "1500".to_string())));
+    assert!(key_stats.contains(&("CRIT".to_string(), "25.50%".to_string())));
+    assert!(get_key_stats(&character_viewer(3000), 0.0).contains(&("MANA".to_string(), "3000".to_string())));
+
+    let mut character_viewer = character_viewer(0);
+    character_viewer.sheet = None;
+    assert_eq!(get_key_stats(&character_viewer, 115.44), vec![("ITEM LEVEL".to_string(), "115.4".to_string())]);
+}
+
+#[test]
+fn latin1_and_cyrillic_characters_have_own_glyphs() {
+    let letters = ('À'..='ÿ').chain('Ѐ'..='я').filter(|chr| chr.is_alphabetic()).flat_map(|chr| chr.to_uppercase());
+    for chr in letters {
+        assert_ne!(get_glyph(chr), get_glyph('?'), "{}", chr);
+    }
+    assert_ne!(get_glyph('Ä'), get_glyph('A'));
+    assert_ne!(get_glyph('Ä'), get_glyph('Ö'));
+    assert_eq!(get_glyph('А'), get_glyph('A'));
+    assert_ne!(get_glyph('Ж'), get_glyph('Ш'));
+}
+
+#[test]
+fn unknown_characters_are_drawn_as_question_mark() {
+    assert_eq!(get_glyph('漢'), get_glyph('?'));
+    assert_ne!(get_glyph('A'), get_glyph('?'));
+    assert_eq!(text_width("", 1), 0);
+    assert_eq!(text_width("ABC", 2), 34);
+    assert_eq!(text_width("ß", 1), text_width("SS", 1));
+}
+
+#[test]
+fn gear_card_cache_evicts_least_recently_used() {
+    let mut cache = LruCache::new(2);
+    cache.insert(1, vec![1]);
+    cache.insert(2, vec![2]);
+    assert_eq!(cache.get(&1), Some(&vec![1]));
+    cache.insert(3, vec![3]);
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get(&2), None);
+    assert_eq!(cache.get(&1), Some(&vec![1]));
+    assert_eq!(cache.get(&3), Some(&vec![3]));
+
+    cache.insert(3, vec![4]);
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get(&3), Some(&vec![4]));
+}
@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+
+use crate::modules::armory::domain_value::{CharacterGear, CharacterItem, InventoryType};
+use crate::modules::armory::dto::{CharacterItemDto, CharacterViewerSheetDto, GearSlotReplacement};
+use crate::modules::armory::tools::{diff_character_sheets, diff_gear_stats, diff_itemset_pieces, get_character_gear_stats, get_itemset_pieces, replace_gear_slots};
+use crate::modules::data::domain_value::{Item, ItemsetEffect, Localization, Spell, SpellEffect};
+use crate::modules::data::{Data, Stat};
+
+fn get_item(id: u32, itemset: Option<u16>) -> Item {
+    Item {
+        expansion_id: 1,
+        id,
+        localization_id: 0,
+        icon: 0,
+        quality: 4,
+        inventory_type: Some(1),
+        class_id: 4,
+        required_level: None,
+        bonding: None,
+        sheath: None,
+        itemset,
+        max_durability: None,
+        item_level: Some(66),
+        delay: None,
+        display_info: None,
+    }
+}
+
+fn get_character_item(item_id: u32) -> Option<CharacterItem> {
+    Some(CharacterItem {
+        id: 1,
+        item_id,
+        random_property_id: None,
+        enchant_id: None,
+        gem_ids: vec![None, None, None, None],
+    })
+}
+
+fn get_gear() -> CharacterGear {
+    CharacterGear {
+        id: 0,
+        head: get_character_item(1000),
+        neck: None,
+        shoulder: get_character_item(1001),
+        back: None,
+        chest: get_character_item(1002),
+        shirt: None,
+        tabard: None,
+        wrist: None,
+        main_hand: None,
+        off_hand: None,
+        ternary_hand: None,
+        glove: None,
+        belt: None,
+        leg: None,
+        boot: None,
+        ring1: None,
+        ring2: None,
+        trinket1: None,
+        trinket2: None,
+    }
+}
+
+fn get_sheet(strength: u32, crit_chance: f64) -> CharacterViewerSheetDto {
+    CharacterViewerSheetDto {
+        strength,
+        agility: 0,
+        stamina: 0,
+        intellect: 0,
+        spirit: 0,
+        health: 0,
+        mana: 0,
+        armor: 0,
+        attack_power: 0,
+        ranged_attack_power: 0,
+        spell_power: 0,
+        healing_power: 0,
+        hit_chance: 0.0,
+        crit_chance,
+        haste: 0.0,
+        spell_hit_chance: 0.0,
+        spell_crit_chance: 0.0,
+        spell_haste: 0.0,
+        defense: 0.0,
+        dodge_chance: 0.0,
+        parry_chance: 0.0,
+        block_chance: 0.0,
+        resilience: 0.0,
+    }
+}
+
+fn get_item_of_type(id: u32, inventory_type: u8) -> Item {
+    let mut item = get_item(id, None);
+    item.inventory_type = Some(inventory_type);
+    item
+}
+
+fn get_replacement(inventory_type: InventoryType, item_id: u32) -> GearSlotReplacement {
+    GearSlotReplacement {
+        inventory_type: inventory_type as u8,
+        item: Some(CharacterItemDto {
+            item_id,
+            random_property_id: None,
+            enchant_id: None,
+            gem_ids: vec![],
+        }),
+    }
+}
+
+fn get_data_with_items(items: Vec<Item>) -> Data {
+    let mut data = Data::default();
+    data.items.push(items.into_iter().map(|item| (item.id, item)).collect());
+    data
+}
+
+#[test]
+fn replace_gear_slots_by_inventory_type() {
+    let data = get_data_with_items(vec![get_item(2000, None)]);
+    let replacements = vec![
+        GearSlotReplacement {
+            inventory_type: InventoryType::Head as u8,
+            item: Some(CharacterItemDto {
+                item_id: 2000,
+                random_property_id: None,
+                enchant_id: Some(5),
+                gem_ids: vec![],
+            }),
+        },
+        GearSlotReplacement {
+            inventory_type: InventoryType::Chest as u8,
+            item: None,
+        },
+    ];
+
+    let gear = replace_gear_slots(&data, 1, &get_gear(), &replacements).unwrap();
+    assert_eq!(gear.head.as_ref().map(|item| (item.item_id, item.enchant_id)), Some((2000, Some(5))));
+    assert!(gear.chest.is_none());
+    assert_eq!(gear.shoulder.as_ref().map(|item| item.item_id), Some(1001));
+
+    // Ammo and bags are no gear slots
+    for inventory_type in [InventoryType::Ammo as u8, InventoryType::FirstBag as u8, 255].iter() {
+        let replacement = GearSlotReplacement { inventory_type: *inventory_type, item: None };
+        assert!(replace_gear_slots(&data, 1, &get_gear(), &[replacement]).is_none());
+    }
+}
+
+#[test]
+fn replace_gear_slots_only_with_fitting_items() {
+    let data = get_data_with_items(vec![get_item(2000, None), get_item_of_type(2001, 12), get_item_of_type(2002, 11)]);
+
+    // A helmet is no trinket and a trinket no helmet, unknown items fit nowhere
+    assert!(replace_gear_slots(&data, 1, &get_gear(), &[get_replacement(InventoryType::Trinket1, 2000)]).is_none());
+    assert!(replace_gear_slots(&data, 1, &get_gear(), &[get_replacement(InventoryType::Head, 2001)]).is_none());
+    assert!(replace_gear_slots(&data, 1, &get_gear(), &[get_replacement(InventoryType::Head, 3000)]).is_none());
+
+    let gear = replace_gear_slots(&data, 1, &get_gear(), &[get_replacement(InventoryType::Trinket2, 2001), get_replacement(InventoryType::Finger1, 2002), get_replacement(InventoryType::Finger2, 2002)]).unwrap();
+    assert_eq!(gear.trinket2.as_ref().map(|item| item.item_id), Some(2001));
+    assert_eq!(gear.ring1.as_ref().map(|item| item.item_id), Some(2002));
+    assert_eq!(gear.ring2.as_ref().map(|item| item.item_id), Some(2002));
+}
+
+#[test]
+fn replace_gear_slots_two_hander_needs_empty_off_hand() {
+    let data = get_data_with_items(vec![get_item_of_type(2000, 17), get_item_of_type(2001, 13), get_item_of_type(2002, 14)]);
+    let mut gear = get_gear();
+    gear.main_hand = get_character_item(2001);
+    gear.off_hand = get_character_item(2002);
+
+    assert!(replace_gear_slots(&data, 1, &gear, &[get_replacement(InventoryType::MainHand, 2000)]).is_none());
+    assert!(replace_gear_slots(&data, 1, &gear, &[get_replacement(InventoryType::OffHand, 2000)]).is_none());
+
+    let replacements = vec![get_replacement(InventoryType::MainHand, 2000), GearSlotReplacement { inventory_type: InventoryType::OffHand as u8, item: None }];
+    let two_handed_gear = replace_gear_slots(&data, 1, &gear, &replacements).unwrap();
+    assert_eq!(two_handed_gear.main_hand.as_ref().map(|item| item.item_id), Some(2000));
+    assert!(two_handed_gear.off_hand.is_none());
+
+    // A shield can not be added to a two-hander either
+    assert!(replace_gear_slots(&data, 1, &two_handed_gear, &[get_replacement(InventoryType::OffHand, 2002)]).is_none());
+}
+
+#[test]
+fn diff_gear_stats_only_changes() {
+    let before = vec![Stat { stat_type: 7, stat_value: 10 }, Stat { stat_type: 3, stat_value: 5 }, Stat { stat_type: 5, stat_value: 1 }];
+    let after = vec![Stat { stat_type: 3, stat_value: 5 }, Stat { stat_type: 7, stat_value: 4 }, Stat { stat_type: 12, stat_value: 20 }];
+
+    assert_eq!(diff_gear_stats(&before, &after), vec![(5, 1, 0), (7, 10, 4), (12, 0, 20)]);
+    assert!(diff_gear_stats(&before, &before).is_empty());
+}
+
+#[test]
+fn diff_character_sheets_only_changes() {
+    let deltas = diff_character_sheets(&get_sheet(100, 5.0), &get_sheet(120, 4.5));
+
+    assert_eq!(deltas.len(), 2);
+    assert_eq!((deltas[0].stat.as_str(), deltas[0].delta), ("strength", 20.0));
+    assert_eq!((deltas[1].stat.as_str(), deltas[1].before, deltas[1].after, deltas[1].delta), ("crit_chance", 5.0, 4.5, -0.5));
+}
+
+#[test]
+fn itemset_pieces_of_gear() {
+    let mut data = Data::default();
+    let mut items = HashMap::new();
+    for item in [get_item(1000, Some(200)), get_item(1001, Some(200)), get_item(1002, Some(201)), get_item(2000, None)].iter() {
+        items.insert(item.id, item.clone());
+    }
+    data.items.push(items);
+    let mut gear = get_gear();
+
+    let pieces_before = get_itemset_pieces(&data, 1, &gear);
+    assert_eq!(pieces_before, vec![(200, 2), (201, 1)]);
+
+    gear.head = get_character_item(2000);
+    let pieces_after = get_itemset_pieces(&data, 1, &gear);
+    assert_eq!(pieces_after, vec![(200, 1), (201, 1)]);
+    assert_eq!(diff_itemset_pieces(&pieces_before, &pieces_after), vec![(200, 2, 1)]);
+    assert_eq!(diff_itemset_pieces(&pieces_after, &[]), vec![(200, 1, 0), (201, 1, 0)]);
+}
+
+fn get_itemset_bonus(spell_id: u32, threshold: u8, description: &str, points: i32) -> (ItemsetEffect, Spell, SpellEffect, Localization) {
+    (
+        ItemsetEffect {
+            id: spell_id,
+            expansion_id: 1,
+            itemset_id: 200,
+            threshold,
+            spell_id,
+        },
+        Spell {
+            id: spell_id,
+            expansion_id: 1,
+            localization_id: 0,
+            subtext_localization_id: 0,
+            cost: 0,
+            cost_in_percent: false,
+            power_type: 0,
+            cast_time: 0,
+            school_mask: 0,
+            dispel_type: 0,
+            range_max: 0,
+            cooldown: 0,
+            duration: 0,
+            icon: 0,
+            description_localization_id: spell_id,
+            aura_localization_id: 0,
+        },
+        SpellEffect {
+            id: spell_id,
+            expansion_id: 1,
+            spell_id,
+            points_lower: points,
+            points_upper: points,
+            chain_targets: 0,
+            radius: 0,
+        },
+        Localization {
+            id: spell_id,
+            language_id: 1,
+            content: description.to_string(),
+        },
+    )
+}
+
+#[test]
+fn gear_stats_only_include_reached_itemset_bonuses() {
+    let mut data = Data::default();
+    let mut items = HashMap::new();
+    for item in [get_item(1000, Some(200)), get_item(1001, Some(200)), get_item(1002, Some(201))].iter() {
+        items.insert(item.id, item.clone());
+    }
+    data.items.push(items);
+    let (mut itemset_effects, mut spells, mut spell_effects, mut localization) = (Vec::new(), HashMap::new(), HashMap::new(), HashMap::new());
+    for (itemset_effect, spell, spell_effect, spell_description) in vec![
+        get_itemset_bonus(1, 2, "Increases your hit rating by $s1.", 10),
+        get_itemset_bonus(2, 4, "Increases your critical strike rating by $s1.", 20),
+    ] {
+        itemset_effects.push(itemset_effect);
+        spells.insert(spell.id, spell);
+        spell_effects.insert(spell_effect.spell_id, vec![spell_effect]);
+        localization.insert(spell_description.id, spell_description);
+    }
+    let mut itemsets = HashMap::new();
+    itemsets.insert(200, itemset_effects);
+    data.itemset_effects.push(itemsets);
+    data.spells.push(spells);
+    data.spell_effects.push(spell_effects);
+    data.localization.push(localization);
+
+    // 2 of the pieces grant the hit rating, the critical strike rating needs 4 of them
+    let mut gear = get_gear();
+    assert_eq!(get_character_gear_stats(&data, 1, &gear), vec![Stat { stat_type: 7, stat_value: 10 }]);
+
+    gear.shoulder = None;
+    assert!(get_character_gear_stats(&data, 1, &gear).is_empty());
+}
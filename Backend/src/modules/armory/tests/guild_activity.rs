@@ -0,0 +1,51 @@
+use crate::modules::armory::domain_value::GuildMembership;
+use crate::modules::armory::dto::{GuildActivity, GuildActivityDto};
+use crate::modules::armory::tools::derive_guild_activity;
+
+fn get_guild_membership(character_id: u32, guild_id: Option<u32>, rank_index: Option<u8>, timestamp: u64) -> GuildMembership {
+    GuildMembership {
+        character_id,
+        character_name: format!("Character{}", character_id),
+        guild_id,
+        rank_index,
+        timestamp,
+    }
+}
+
+fn get_guild_activity(character_id: u32, timestamp: u64, activity: GuildActivity) -> GuildActivityDto {
+    GuildActivityDto {
+        timestamp,
+        character_id: Some(character_id),
+        character_name: Some(format!("Character{}", character_id)),
+        activity,
+    }
+}
+
+#[test]
+fn derive_guild_activity_from_memberships() {
+    let memberships = vec![
+        get_guild_membership(1, None, None, 100),
+        get_guild_membership(1, Some(5), Some(4), 200),
+        get_guild_membership(1, Some(5), Some(4), 250),
+        get_guild_membership(1, Some(5), Some(2), 300),
+        get_guild_membership(1, Some(5), Some(3), 400),
+        get_guild_membership(1, Some(6), Some(1), 500),
+        get_guild_membership(2, Some(5), Some(0), 150),
+        get_guild_membership(2, None, None, 350),
+        get_guild_membership(2, Some(5), Some(4), 450),
+    ];
+
+    let guild_activity = derive_guild_activity(5, &memberships);
+    assert_eq!(
+        guild_activity,
+        vec![
+            get_guild_activity(1, 200, GuildActivity::Join { rank_index: 4 }),
+            get_guild_activity(1, 300, GuildActivity::Promotion { previous_rank_index: 4, rank_index: 2 }),
+            get_guild_activity(1, 400, GuildActivity::Demotion { previous_rank_index: 2, rank_index: 3 }),
+            get_guild_activity(1, 500, GuildActivity::Leave { rank_index: 3 }),
+            get_guild_activity(2, 150, GuildActivity::Join { rank_index: 0 }),
+            get_guild_activity(2, 350, GuildActivity::Leave { rank_index: 0 }),
+            get_guild_activity(2, 450, GuildActivity::Join { rank_index: 4 }),
+        ]
+    );
+}
@@ -4,6 +4,7 @@ use crate::modules::armory::dto::CharacterDto;
 pub fn get_character() -> CharacterDto {
     CharacterDto {
         server_uid: 1231245,
+        note: None,
         character_history: Some(get_character_history()),
     }
 }
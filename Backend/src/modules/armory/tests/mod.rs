@@ -1,11 +1,19 @@
 mod character;
+mod character_claim;
 mod character_facial;
 mod character_gear;
 mod character_history;
+mod character_history_diff;
 mod character_info;
 mod character_item;
 mod character_search;
+mod character_sheet;
 mod character_viewer;
+mod gear_audit;
+mod gear_card;
+mod gear_comparison;
 mod guild;
+mod guild_activity;
+mod talent_specialization;
 
 mod helper;
@@ -0,0 +1,68 @@
+use crate::modules::armory::tools::{detect_talent_spec, has_valid_ranks, strip_talent_specialization, talent_points_available};
+use crate::modules::armory::util::talent_tree::{decode_talent_specialization, get_main_talent_tree, get_talent_points, get_talent_spec_id, get_talent_tree};
+use crate::modules::data::domain_value::{Talent, TalentSpecRule};
+
+fn talent_spec_rule(id: u32, talent_index: Option<u8>, name: &str) -> TalentSpecRule {
+    TalentSpecRule {
+        id,
+        expansion_id: 2,
+        hero_class_id: 11,
+        tree_index: 1,
+        talent_index,
+        min_rank: 3,
+        name: name.to_string(),
+    }
+}
+
+#[test]
+fn decode_talent_specialization_with_high_ranks() {
+    let talent_ranks = decode_talent_specialization("0a5|3|").unwrap();
+    assert_eq!(talent_ranks, vec![vec![0, 10, 5], vec![3], vec![]]);
+    assert_eq!(get_talent_points(&talent_ranks), vec![15, 3, 0]);
+    assert_eq!(get_main_talent_tree(&talent_ranks), Some(0));
+    assert!(decode_talent_specialization("05-|000").is_none());
+}
+
+#[test]
+fn talent_tree_does_not_panic_on_invalid_input() {
+    assert_eq!(get_talent_tree("005|0b0|000"), 1);
+    assert_eq!(get_talent_tree("0?5|000|000"), 0);
+    assert_eq!(get_talent_spec_id("000|000|501"), Some(3));
+    assert_eq!(get_talent_spec_id("000|000|000"), None);
+    assert_eq!(strip_talent_specialization(&Some("000|0c0|000".to_string())), Some("000|0c0|000".to_string()));
+    assert_eq!(strip_talent_specialization(&Some("000|000|000".to_string())), None);
+    assert_eq!(strip_talent_specialization(&Some("0#0|000|000".to_string())), None);
+}
+
+#[test]
+fn detect_talent_spec_by_rules() {
+    let talent_spec_rules = vec![talent_spec_rule(1, Some(4), "Feral Tank"), talent_spec_rule(2, None, "Feral DPS")];
+    let bear = decode_talent_specialization("00000|50003000050|0000").unwrap();
+    let cat = decode_talent_specialization("00000|50000000055|0000").unwrap();
+    let restoration = decode_talent_specialization("00000|50000000000|5555").unwrap();
+
+    assert_eq!(detect_talent_spec(&talent_spec_rules, &bear).unwrap().name, "Feral Tank");
+    assert_eq!(detect_talent_spec(&talent_spec_rules, &cat).unwrap().name, "Feral DPS");
+    assert!(detect_talent_spec(&talent_spec_rules, &restoration).is_none());
+    assert!(detect_talent_spec(&talent_spec_rules, &[]).is_none());
+}
+
+#[test]
+fn validate_talent_points() {
+    let talents = vec![Talent {
+        expansion_id: 2,
+        hero_class_id: 11,
+        tree_index: 1,
+        talent_index: 4,
+        spell_id: 16929,
+        max_rank: 3,
+    }];
+
+    assert_eq!(talent_points_available(70), 61);
+    assert_eq!(talent_points_available(9), 0);
+    assert!(has_valid_ranks(&talents, &decode_talent_specialization("0|00003|0").unwrap()));
+    assert!(!has_valid_ranks(&talents, &decode_talent_specialization("0|00005|0").unwrap()));
+    // Points in talents the talent table does not know
+    assert!(!has_valid_ranks(&talents, &decode_talent_specialization("5|0|0").unwrap()));
+    assert!(!has_valid_ranks(&talents, &decode_talent_specialization("0|000003|0").unwrap()));
+}
@@ -27,6 +27,8 @@ impl DeleteCharacter for Armory {
                     }
                 }
             }
+            // Claims are removed by the database cascade
+            self.character_claims.write().unwrap().retain(|_, claim| claim.character_id != id);
             return characters.remove(&id).ok_or(ArmoryFailure::InvalidInput).map(|_| ());
         }
         Err(ArmoryFailure::Database("delete_character".to_owned()))
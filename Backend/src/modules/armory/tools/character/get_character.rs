@@ -1,7 +1,8 @@
+use crate::modules::armory::domain_value::HistoryMoment;
 use crate::modules::armory::dto::BasicCharacter;
 use crate::modules::armory::material::CharacterHistory;
 use crate::modules::armory::tools::GetCharacterHistory;
-use crate::modules::armory::util::talent_tree::get_talent_tree;
+use crate::modules::armory::util::talent_tree::get_talent_spec_id;
 use crate::modules::armory::{material::Character, Armory};
 use crate::util::database::Select;
 
@@ -38,7 +39,7 @@ impl GetCharacter for Armory {
             server_id: character.server_id,
             hero_class_id: Some(char_history.character_info.hero_class_id),
             race_id: Some(char_history.character_info.race_id),
-            spec_id: char_history.character_info.talent_specialization.as_ref().map(|talents| get_talent_tree(&talents) + 1),
+            spec_id: char_history.character_info.talent_specialization.as_ref().and_then(|talents| get_talent_spec_id(talents)),
             name: Some(char_history.character_name),
         })
     }
@@ -74,14 +75,7 @@ impl GetCharacter for Armory {
             return None;
         }
 
-        let timestamp = timestamp / 1000;
-        let mut closest_history_moment = character.history_moments.first().unwrap();
-        for moment in character.history_moments.iter() {
-            if (timestamp as i64 - moment.timestamp as i64).abs() < (timestamp as i64 - closest_history_moment.timestamp as i64).abs() {
-                closest_history_moment = moment;
-            }
-        }
-
+        let closest_history_moment = get_closest_history_moment(&character.history_moments, timestamp / 1000)?;
         let char_history = if closest_history_moment.id == character.last_update.as_ref().unwrap().id {
             character.last_update.clone().unwrap()
         } else {
@@ -91,3 +85,14 @@ impl GetCharacter for Armory {
         Some(char_history)
     }
 }
+
+/// Timestamp in seconds, the first moment wins if two are equally close
+pub fn get_closest_history_moment(history_moments: &[HistoryMoment], timestamp: u64) -> Option<&HistoryMoment> {
+    let mut closest_history_moment = history_moments.first()?;
+    for moment in history_moments.iter() {
+        if (timestamp as i64 - moment.timestamp as i64).abs() < (timestamp as i64 - closest_history_moment.timestamp as i64).abs() {
+            closest_history_moment = moment;
+        }
+    }
+    Some(closest_history_moment)
+}
@@ -1,6 +1,7 @@
-pub use self::{create_character::CreateCharacter, delete_character::DeleteCharacter, get_character::GetCharacter, set_character::SetCharacter};
+pub use self::{create_character::CreateCharacter, delete_character::DeleteCharacter, get_character::{get_closest_history_moment, GetCharacter}, reassign_character::ReassignCharacter, set_character::SetCharacter};
 
 mod create_character;
 mod delete_character;
 mod get_character;
+mod reassign_character;
 mod set_character;
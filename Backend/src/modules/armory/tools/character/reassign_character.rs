@@ -0,0 +1,104 @@
+use crate::modules::armory::domain_value::HistoryMoment;
+use crate::modules::armory::dto::ArmoryFailure;
+use crate::modules::armory::tools::{GetCharacter, GetCharacterHistory};
+use crate::modules::armory::Armory;
+use crate::params;
+use crate::util::database::{Execute, Select};
+
+/// The reassignments only touch the DB, so they can be part of a transaction. Once it is committed, reload_reassigned_characters updates the armory
+pub trait ReassignCharacter {
+    fn reassign_character_history(&self, db_main: &mut impl Execute, history_ids: &[u32], previous_character_id: u32, character_id: u32) -> Result<(), ArmoryFailure>;
+    fn reassign_character_claims(&self, db_main: &mut impl Execute, claim_ids: &[u32], previous_character_id: u32, character_id: u32) -> Result<(), ArmoryFailure>;
+    fn reload_reassigned_characters(&self, db_main: &mut impl Select, history_ids: &[u32], claim_ids: &[u32], previous_character_id: u32, character_id: u32) -> Result<(), ArmoryFailure>;
+}
+
+impl ReassignCharacter for Armory {
+    fn reassign_character_history(&self, db_main: &mut impl Execute, history_ids: &[u32], previous_character_id: u32, character_id: u32) -> Result<(), ArmoryFailure> {
+        if self.get_character(character_id).is_none() {
+            return Err(ArmoryFailure::InvalidInput);
+        }
+
+        // Snapshots that belong to someone else by now are left alone
+        if !db_main.execute_batch_wparams(
+            "UPDATE armory_character_history SET character_id=:character_id WHERE id=:id AND character_id=:previous_character_id",
+            history_ids.to_vec(),
+            move |history_id| params!("character_id" => character_id, "id" => history_id, "previous_character_id" => previous_character_id),
+        ) {
+            return Err(ArmoryFailure::Database("reassign_character_history".to_owned()));
+        }
+        Ok(())
+    }
+
+    fn reassign_character_claims(&self, db_main: &mut impl Execute, claim_ids: &[u32], previous_character_id: u32, character_id: u32) -> Result<(), ArmoryFailure> {
+        if !db_main.execute_batch_wparams(
+            "UPDATE armory_character_claim SET character_id=:character_id WHERE id=:id AND character_id=:previous_character_id",
+            claim_ids.to_vec(),
+            move |claim_id| params!("character_id" => character_id, "id" => claim_id, "previous_character_id" => previous_character_id),
+        ) {
+            return Err(ArmoryFailure::Database("reassign_character_claims".to_owned()));
+        }
+        Ok(())
+    }
+
+    fn reload_reassigned_characters(&self, db_main: &mut impl Select, history_ids: &[u32], claim_ids: &[u32], previous_character_id: u32, character_id: u32) -> Result<(), ArmoryFailure> {
+        if self.get_character(previous_character_id).is_none() || self.get_character(character_id).is_none() {
+            return Err(ArmoryFailure::InvalidInput);
+        }
+
+        let moved_history_moments = {
+            let mut characters = self.characters.write().unwrap();
+            let previous_character = characters.get_mut(&previous_character_id).unwrap();
+            let (moved, kept): (Vec<HistoryMoment>, Vec<HistoryMoment>) = previous_character.history_moments.drain(..).partition(|history_moment| history_ids.contains(&history_moment.id));
+            previous_character.history_moments = kept;
+
+            let character = characters.get_mut(&character_id).unwrap();
+            character.history_moments.extend(moved.iter().cloned());
+            character.history_moments.sort_by(|left, right| left.id.cmp(&right.id));
+            moved
+        };
+
+        // Cached snapshots still carry the previous character id
+        {
+            let mut cache_char_history = self.cache_char_history.write().unwrap();
+            for history_moment in moved_history_moments.iter() {
+                if let Some(character_history) = cache_char_history.get_mut(&history_moment.id) {
+                    character_history.character_id = character_id;
+                }
+            }
+        }
+
+        {
+            let mut character_claims = self.character_claims.write().unwrap();
+            for claim_id in claim_ids {
+                if let Some(character_claim) = character_claims.get_mut(claim_id).filter(|character_claim| character_claim.character_id == previous_character_id) {
+                    character_claim.character_id = character_id;
+                }
+            }
+        }
+
+        for affected_character_id in [previous_character_id, character_id].iter() {
+            let last_history_id = self.get_character(*affected_character_id).and_then(|character| character.history_moments.iter().map(|history_moment| history_moment.id).max());
+            let last_update = match last_history_id {
+                Some(history_id) => Some(self.get_character_history(db_main, history_id)?),
+                None => None,
+            };
+
+            let mut characters = self.characters.write().unwrap();
+            let character = characters.get_mut(affected_character_id).unwrap();
+            let mut cache = self.cache_char_name_to_id.write().unwrap();
+            if let Some(previous_update) = character.last_update.as_ref() {
+                if let Some(vec) = cache.get_mut(&previous_update.character_name.to_lowercase()) {
+                    vec.retain(|char_id| char_id != affected_character_id);
+                }
+            }
+            if let Some(current_update) = last_update.as_ref() {
+                let vec = cache.entry(current_update.character_name.to_lowercase()).or_insert_with(Vec::new);
+                if !vec.contains(affected_character_id) {
+                    vec.push(*affected_character_id);
+                }
+            }
+            character.last_update = last_update;
+        }
+        Ok(())
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     modules::armory::{
         dto::{ArmoryFailure, CharacterDto},
         material::Character,
-        tools::{CreateCharacter, GetCharacter, SetCharacterHistory},
+        tools::{CharacterClaims, CreateCharacter, GetCharacter, SetCharacterHistory},
         Armory,
     },
 };
@@ -27,6 +27,7 @@ impl SetCharacter for Armory {
         }
         let character_id = character_id_res.unwrap();
         let current_character = self.get_character(character_id).unwrap();
+        self.confirm_character_claims(db_main, character_id, &update_character);
 
         // Set the character history
         if update_character.character_history.is_some() {
@@ -0,0 +1,143 @@
+use str_util::random;
+
+use crate::modules::armory::dto::{ArmoryFailure, CharacterDto};
+use crate::modules::armory::material::CharacterClaim;
+use crate::modules::armory::tools::GetCharacter;
+use crate::modules::armory::Armory;
+use crate::params;
+use crate::util::database::{Execute, Select};
+
+pub trait CharacterClaims {
+    fn request_character_claim(&self, db_main: &mut (impl Execute + Select), member_id: u32, character_id: u32) -> Result<CharacterClaim, ArmoryFailure>;
+    fn revoke_character_claim(&self, db_main: &mut impl Execute, member_id: u32, claim_id: u32) -> Result<(), ArmoryFailure>;
+    fn confirm_character_claims(&self, db_main: &mut impl Execute, character_id: u32, character: &CharacterDto);
+    fn get_character_claims(&self, member_id: u32) -> Vec<CharacterClaim>;
+    fn get_claimed_character_ids(&self, member_id: u32) -> Vec<u32>;
+    fn get_character_alts(&self, character_id: u32) -> Vec<u32>;
+    fn get_claimed_guild_ids(&self, member_id: u32) -> Vec<u32>;
+}
+
+impl CharacterClaims for Armory {
+    fn request_character_claim(&self, db_main: &mut (impl Execute + Select), member_id: u32, character_id: u32) -> Result<CharacterClaim, ArmoryFailure> {
+        if self.get_character(character_id).is_none() {
+            return Err(ArmoryFailure::InvalidInput);
+        }
+
+        let mut character_claims = self.character_claims.write().unwrap();
+        if let Some(existing_claim) = character_claims.values().find(|claim| claim.member_id == member_id && claim.character_id == character_id) {
+            return Ok(existing_claim.clone());
+        }
+
+        let token = format!("LP{}", random::alphanumeric(8)).to_uppercase();
+        let requested_ts = time_util::now();
+        if db_main.execute_wparams(
+            "INSERT INTO armory_character_claim (`member_id`, `character_id`, `token`, `requested_ts`) VALUES (:member_id, :character_id, :token, :requested_ts)",
+            params!(
+              "member_id" => member_id,
+              "character_id" => character_id,
+              "token" => token.clone(),
+              "requested_ts" => requested_ts
+            ),
+        ) {
+            if let Some(claim_id) = db_main.select_wparams_value(
+                "SELECT id FROM armory_character_claim WHERE member_id=:member_id AND character_id=:character_id",
+                |mut row| {
+                    let id: u32 = row.take(0).unwrap();
+                    id
+                },
+                params!(
+                  "member_id" => member_id,
+                  "character_id" => character_id
+                ),
+            ) {
+                let character_claim = CharacterClaim {
+                    id: claim_id,
+                    member_id,
+                    character_id,
+                    token,
+                    requested_ts,
+                    confirmed_ts: None,
+                };
+                character_claims.insert(claim_id, character_claim.clone());
+                return Ok(character_claim);
+            }
+        }
+        Err(ArmoryFailure::Database("request_character_claim".to_owned()))
+    }
+
+    fn revoke_character_claim(&self, db_main: &mut impl Execute, member_id: u32, claim_id: u32) -> Result<(), ArmoryFailure> {
+        let mut character_claims = self.character_claims.write().unwrap();
+        if !character_claims.get(&claim_id).map(|claim| claim.member_id == member_id).unwrap_or(false) {
+            return Err(ArmoryFailure::InvalidInput);
+        }
+
+        if db_main.execute_wparams("DELETE FROM armory_character_claim WHERE id=:id", params!("id" => claim_id)) {
+            character_claims.remove(&claim_id);
+            return Ok(());
+        }
+        Err(ArmoryFailure::Database("revoke_character_claim".to_owned()))
+    }
+
+    fn confirm_character_claims(&self, db_main: &mut impl Execute, character_id: u32, character: &CharacterDto) {
+        let mut character_claims = self.character_claims.write().unwrap();
+        let confirmed_claim_id = character_claims
+            .values()
+            .filter(|claim| claim.character_id == character_id && !claim.is_confirmed())
+            .find(|claim| contains_claim_token(character, &claim.token))
+            .map(|claim| claim.id);
+
+        if let Some(claim_id) = confirmed_claim_id {
+            let confirmed_ts = time_util::now();
+            if db_main.execute_wparams("UPDATE armory_character_claim SET confirmed_ts=:confirmed_ts WHERE id=:id", params!("confirmed_ts" => confirmed_ts, "id" => claim_id)) {
+                // A character belongs to one member only, so every other claim on it is void now
+                db_main.execute_wparams("DELETE FROM armory_character_claim WHERE character_id=:character_id AND id<>:id", params!("character_id" => character_id, "id" => claim_id));
+                character_claims.retain(|id, claim| claim.character_id != character_id || *id == claim_id);
+                character_claims.get_mut(&claim_id).unwrap().confirmed_ts = Some(confirmed_ts);
+            }
+        }
+    }
+
+    fn get_character_claims(&self, member_id: u32) -> Vec<CharacterClaim> {
+        let character_claims = self.character_claims.read().unwrap();
+        let mut result: Vec<CharacterClaim> = character_claims.values().filter(|claim| claim.member_id == member_id).cloned().collect();
+        result.sort_by(|left, right| left.requested_ts.cmp(&right.requested_ts));
+        result
+    }
+
+    fn get_claimed_character_ids(&self, member_id: u32) -> Vec<u32> {
+        let character_claims = self.character_claims.read().unwrap();
+        let mut result: Vec<u32> = character_claims.values().filter(|claim| claim.member_id == member_id && claim.is_confirmed()).map(|claim| claim.character_id).collect();
+        result.sort_unstable();
+        result
+    }
+
+    fn get_character_alts(&self, character_id: u32) -> Vec<u32> {
+        let member_id = {
+            let character_claims = self.character_claims.read().unwrap();
+            character_claims.values().find(|claim| claim.character_id == character_id && claim.is_confirmed()).map(|claim| claim.member_id)
+        };
+        member_id
+            .map(|member_id| self.get_claimed_character_ids(member_id).into_iter().filter(|alt_character_id| *alt_character_id != character_id).collect())
+            .unwrap_or_default()
+    }
+
+    fn get_claimed_guild_ids(&self, member_id: u32) -> Vec<u32> {
+        let claimed_character_ids = self.get_claimed_character_ids(member_id);
+        let characters = self.characters.read().unwrap();
+        let mut result: Vec<u32> = claimed_character_ids
+            .iter()
+            .filter_map(|character_id| characters.get(character_id))
+            .filter_map(|character| character.last_update.as_ref().and_then(|last_update| last_update.character_guild.as_ref()))
+            .map(|character_guild| character_guild.guild_id)
+            .collect();
+        result.sort_unstable();
+        result.dedup();
+        result
+    }
+}
+
+/// Only the character note counts, guild rank names are chosen by the guild master and not by the owner
+pub fn contains_claim_token(character: &CharacterDto, token: &str) -> bool {
+    let token = token.to_lowercase();
+    character.note.as_ref().map(|note| note.to_lowercase().contains(&token)).unwrap_or(false)
+}
@@ -71,8 +71,8 @@ impl CreateCharacterHistory for Armory {
           "timestamp" => timestamp / 1000
         );
         db_main.execute_wparams(
-            "INSERT INTO armory_character_history (`character_id`, `character_info_id`, `character_name`, `title`, `guild_id`, `guild_rank`, `prof_skill_points1`, `prof_skill_points2`, `facial`, `arena2`, `arena3`, `arena5`, `timestamp`) VALUES \
-             (:character_id, :character_info_id, :character_name, :title, :guild_id, :guild_rank, :prof_skill_points1, :prof_skill_points2, :facial, :arena2, :arena3, :arena5, :timestamp)",
+            "INSERT INTO armory_character_history (`character_id`, `character_info_id`, `character_name`, `title`, `guild_id`, `guild_rank`, `prof_skill_points1`, `prof_skill_points2`, `facial`, `arena2`, `arena3`, `arena5`, `timestamp`, `first_seen`) VALUES \
+             (:character_id, :character_info_id, :character_name, :title, :guild_id, :guild_rank, :prof_skill_points1, :prof_skill_points2, :facial, :arena2, :arena3, :arena5, :timestamp, :timestamp)",
             params,
         );
         if let Ok(character_history_res) = self.get_character_history_by_value(db_main, character_id, character_history_dto, timestamp) {
@@ -57,6 +57,7 @@ impl GetCharacterHistory for Armory {
                 facial: row.take_opt(9).unwrap().ok().and_then(|facial_id| self.get_character_facial(db_main, facial_id).ok()),
                 arena_teams,
                 timestamp: row.take(13).unwrap(),
+                first_seen: row.take(14).unwrap(),
             };
 
             let mut cache_char_hist = self.cache_char_history.write().unwrap();
@@ -110,7 +111,7 @@ impl GetCharacterHistory for Armory {
         );
 
         let mut result = db_main.select_wparams_value(
-            "SELECT id, timestamp FROM armory_character_history WHERE character_id=:character_id AND character_info_id=:character_info_id AND character_name=:character_name AND ((ISNULL(:guild_id) AND ISNULL(guild_id)) OR guild_id = :guild_id) AND \
+            "SELECT id, timestamp, first_seen FROM armory_character_history WHERE character_id=:character_id AND character_info_id=:character_info_id AND character_name=:character_name AND ((ISNULL(:guild_id) AND ISNULL(guild_id)) OR guild_id = :guild_id) AND \
              ((ISNULL(:guild_rank) AND ISNULL(guild_rank)) OR guild_rank = :guild_rank) AND ((ISNULL(:title) AND ISNULL(title)) OR title = :title) AND ((ISNULL(:prof_skill_points1) AND ISNULL(prof_skill_points1)) OR prof_skill_points1 = \
              :prof_skill_points1) AND ((ISNULL(:prof_skill_points2) AND ISNULL(prof_skill_points2)) OR prof_skill_points2 = :prof_skill_points2) AND ((ISNULL(:facial) AND ISNULL(facial)) OR facial = :facial) AND ((ISNULL(:arena2) AND \
              ISNULL(arena2)) OR arena2 = :arena2) AND ((ISNULL(:arena3) AND ISNULL(arena3)) OR arena3 = :arena3) AND ((ISNULL(:arena5) AND ISNULL(arena5)) OR arena5 = :arena5) AND timestamp >= :timestamp-60 ORDER BY timestamp ASC LIMIT 1",
@@ -133,6 +134,7 @@ impl GetCharacterHistory for Armory {
                 facial,
                 arena_teams,
                 timestamp: row.take(1).unwrap(),
+                first_seen: row.take(2).unwrap(),
             });
         }
         Err(ArmoryFailure::Database("get_character_history_by_value".to_owned()))
@@ -0,0 +1,228 @@
+use crate::modules::armory::domain_value::{CharacterGear, CharacterGuild, CharacterInfo, CharacterItem, GuildRank, InventoryType};
+use crate::modules::armory::dto::{ArmoryFailure, CharacterHistoryChange, CharacterHistoryDiff, CharacterTimelineEntry};
+use crate::modules::armory::material::CharacterHistory;
+use crate::modules::armory::tools::{GetCharacter, GetCharacterHistory};
+use crate::modules::armory::Armory;
+use crate::params;
+use crate::util::database::Select;
+
+pub trait DiffCharacterHistory {
+    fn get_character_history_diff(&self, db_main: &mut impl Select, previous_history_id: u32, history_id: u32) -> Result<CharacterHistoryDiff, ArmoryFailure>;
+    fn get_character_timeline(&self, db_main: &mut impl Select, character_id: u32) -> Result<Vec<CharacterTimelineEntry>, ArmoryFailure>;
+}
+
+impl DiffCharacterHistory for Armory {
+    fn get_character_history_diff(&self, db_main: &mut impl Select, previous_history_id: u32, history_id: u32) -> Result<CharacterHistoryDiff, ArmoryFailure> {
+        let previous = self.get_character_history(db_main, previous_history_id).map_err(|_| ArmoryFailure::InvalidInput)?;
+        let current = self.get_character_history(db_main, history_id).map_err(|_| ArmoryFailure::InvalidInput)?;
+        if previous.character_id != current.character_id {
+            return Err(ArmoryFailure::InvalidInput);
+        }
+
+        Ok(CharacterHistoryDiff {
+            character_id: current.character_id,
+            previous_history_id,
+            previous_timestamp: previous.first_seen,
+            history_id,
+            timestamp: current.first_seen,
+            changes: diff_character_history(&previous, &current),
+        })
+    }
+
+    fn get_character_timeline(&self, db_main: &mut impl Select, character_id: u32) -> Result<Vec<CharacterTimelineEntry>, ArmoryFailure> {
+        if self.get_character(character_id).is_none() {
+            return Err(ArmoryFailure::InvalidInput);
+        }
+
+        // All snapshots at once, facial and arena teams are not part of the timeline
+        let character_histories = db_main.select_wparams(
+            "SELECT ach.id, ach.character_name, ach.guild_id, ach.guild_rank, agr.name, ach.title, ach.prof_skill_points1, ach.prof_skill_points2, ach.timestamp, ach.first_seen, aci.*, ai1.*, ai2.*, ai3.*, ai4.*, ai5.*, ai6.*, ai7.*, ai8.*, ai9.*, \
+             ai10.*, ai11.*, ai12.*, ai13.*, ai14.*, ai15.*, ai16.*, ai17.*, ai18.*, ai19.* FROM armory_character_history ach JOIN armory_character_info aci ON ach.character_info_id = aci.id JOIN armory_gear ag ON aci.gear_id = ag.id LEFT JOIN \
+             armory_item ai1 ON ag.head = ai1.id LEFT JOIN armory_item ai2 ON ag.neck = ai2.id LEFT JOIN armory_item ai3 ON ag.shoulder = ai3.id LEFT JOIN armory_item ai4 ON ag.back = ai4.id LEFT JOIN armory_item ai5 ON ag.chest = ai5.id LEFT JOIN \
+             armory_item ai6 ON ag.shirt = ai6.id LEFT JOIN armory_item ai7 ON ag.tabard = ai7.id LEFT JOIN armory_item ai8 ON ag.wrist = ai8.id LEFT JOIN armory_item ai9 ON ag.main_hand = ai9.id LEFT JOIN armory_item ai10 ON ag.off_hand = ai10.id \
+             LEFT JOIN armory_item ai11 ON ag.ternary_hand = ai11.id LEFT JOIN armory_item ai12 ON ag.glove = ai12.id LEFT JOIN armory_item ai13 ON ag.belt = ai13.id LEFT JOIN armory_item ai14 ON ag.leg = ai14.id LEFT JOIN armory_item ai15 ON \
+             ag.boot = ai15.id LEFT JOIN armory_item ai16 ON ag.ring1 = ai16.id LEFT JOIN armory_item ai17 ON ag.ring2 = ai17.id LEFT JOIN armory_item ai18 ON ag.trinket1 = ai18.id LEFT JOIN armory_item ai19 ON ag.trinket2 = ai19.id LEFT JOIN \
+             armory_guild_rank agr ON agr.guild_id = ach.guild_id AND agr.rank_index = ach.guild_rank WHERE ach.character_id=:character_id",
+            move |mut row| {
+                let mut gear_slots: Vec<Option<CharacterItem>> = Vec::new();
+                for i in (19..171).step_by(8) {
+                    gear_slots.push(row.take_opt(i).unwrap().ok().map(|id| CharacterItem {
+                        id,
+                        item_id: row.take(i + 1).unwrap(),
+                        random_property_id: row.take_opt(i + 2).unwrap().ok(),
+                        enchant_id: row.take_opt(i + 3).unwrap().ok(),
+                        gem_ids: vec![row.take_opt(i + 4).unwrap().ok(), row.take_opt(i + 5).unwrap().ok(), row.take_opt(i + 6).unwrap().ok(), row.take_opt(i + 7).unwrap().ok()],
+                    }));
+                }
+                let mut gear_slots = gear_slots.into_iter();
+                let mut next_slot = || gear_slots.next().unwrap();
+
+                CharacterHistory {
+                    id: row.take(0).unwrap(),
+                    character_id,
+                    character_name: row.take(1).unwrap(),
+                    character_guild: row.take_opt(2).unwrap().ok().map(|guild_id| CharacterGuild {
+                        guild_id,
+                        rank: GuildRank {
+                            index: row.take(3).unwrap(),
+                            name: row.take_opt(4).unwrap().ok().unwrap_or_default(),
+                        },
+                    }),
+                    character_title: row.take_opt(5).unwrap().ok(),
+                    profession_skill_points1: row.take_opt(6).unwrap().ok(),
+                    profession_skill_points2: row.take_opt(7).unwrap().ok(),
+                    facial: None,
+                    arena_teams: Vec::new(),
+                    timestamp: row.take(8).unwrap(),
+                    first_seen: row.take(9).unwrap(),
+                    character_info: CharacterInfo {
+                        id: row.take(10).unwrap(),
+                        hero_class_id: row.take(12).unwrap(),
+                        level: row.take(13).unwrap(),
+                        gender: row.take(14).unwrap(),
+                        profession1: row.take_opt(15).unwrap().ok(),
+                        profession2: row.take_opt(16).unwrap().ok(),
+                        talent_specialization: row.take_opt(17).unwrap().ok(),
+                        race_id: row.take(18).unwrap(),
+                        gear: CharacterGear {
+                            id: row.take(11).unwrap(),
+                            head: next_slot(),
+                            neck: next_slot(),
+                            shoulder: next_slot(),
+                            back: next_slot(),
+                            chest: next_slot(),
+                            shirt: next_slot(),
+                            tabard: next_slot(),
+                            wrist: next_slot(),
+                            main_hand: next_slot(),
+                            off_hand: next_slot(),
+                            ternary_hand: next_slot(),
+                            glove: next_slot(),
+                            belt: next_slot(),
+                            leg: next_slot(),
+                            boot: next_slot(),
+                            ring1: next_slot(),
+                            ring2: next_slot(),
+                            trinket1: next_slot(),
+                            trinket2: next_slot(),
+                        },
+                    },
+                }
+            },
+            params!("character_id" => character_id),
+        );
+        Ok(build_character_timeline(character_histories))
+    }
+}
+
+/// Condensed to the snapshots that actually changed something notable, dated by when they were first seen
+pub fn build_character_timeline(mut character_histories: Vec<CharacterHistory>) -> Vec<CharacterTimelineEntry> {
+    character_histories.sort_by(|left, right| left.first_seen.cmp(&right.first_seen).then(left.id.cmp(&right.id)));
+    character_histories
+        .windows(2)
+        .filter_map(|histories| {
+            let changes = diff_character_history(&histories[0], &histories[1]);
+            if changes.is_empty() {
+                return None;
+            }
+            Some(CharacterTimelineEntry {
+                history_id: histories[1].id,
+                timestamp: histories[1].first_seen,
+                changes,
+            })
+        })
+        .collect()
+}
+
+pub fn diff_character_history(previous: &CharacterHistory, current: &CharacterHistory) -> Vec<CharacterHistoryChange> {
+    let mut changes = Vec::new();
+    if previous.character_name != current.character_name {
+        changes.push(CharacterHistoryChange::Rename {
+            previous_name: previous.character_name.clone(),
+            name: current.character_name.clone(),
+        });
+    }
+
+    if previous.character_info.level < current.character_info.level {
+        changes.push(CharacterHistoryChange::LevelUp {
+            previous_level: previous.character_info.level,
+            level: current.character_info.level,
+        });
+    }
+
+    let previous_guild_id = previous.character_guild.as_ref().map(|character_guild| character_guild.guild_id);
+    let guild_id = current.character_guild.as_ref().map(|character_guild| character_guild.guild_id);
+    if previous_guild_id != guild_id {
+        if let Some(previous_guild_id) = previous_guild_id {
+            changes.push(CharacterHistoryChange::GuildLeave { guild_id: previous_guild_id });
+        }
+        if let Some(guild_id) = guild_id {
+            changes.push(CharacterHistoryChange::GuildJoin { guild_id });
+        }
+    }
+
+    if previous.character_info.talent_specialization != current.character_info.talent_specialization {
+        changes.push(CharacterHistoryChange::TalentChange {
+            previous_talent_specialization: previous.character_info.talent_specialization.clone(),
+            talent_specialization: current.character_info.talent_specialization.clone(),
+        });
+    }
+
+    for inventory_type in (InventoryType::Head as u8)..=(InventoryType::Tabard as u8) {
+        let previous_item = previous.character_info.gear.get_slot(InventoryType::from_u8(inventory_type));
+        let item = current.character_info.gear.get_slot(InventoryType::from_u8(inventory_type));
+        let previous_item_id = previous_item.map(|character_item| character_item.item_id);
+        let item_id = item.map(|character_item| character_item.item_id);
+        if previous_item_id != item_id {
+            changes.push(CharacterHistoryChange::GearSwap {
+                inventory_type,
+                previous_item_id,
+                item_id,
+                looted_instance_meta_id: None,
+            });
+            continue;
+        }
+
+        if let (Some(previous_item), Some(item)) = (previous_item, item) {
+            if previous_item.enchant_id != item.enchant_id {
+                changes.push(CharacterHistoryChange::EnchantChange {
+                    inventory_type,
+                    item_id: item.item_id,
+                    previous_enchant_id: previous_item.enchant_id,
+                    enchant_id: item.enchant_id,
+                });
+            }
+            if previous_item.gem_ids != item.gem_ids {
+                changes.push(CharacterHistoryChange::GemChange {
+                    inventory_type,
+                    item_id: item.item_id,
+                    previous_gem_ids: previous_item.gem_ids.clone(),
+                    gem_ids: item.gem_ids.clone(),
+                });
+            }
+        }
+    }
+    changes
+}
+
+/// Links gear swaps to the raid the new item was looted in, given (item_id, instance_meta_id, looted_ts) in ms
+pub fn link_looted_items(timeline: &mut [CharacterTimelineEntry], loot: &[(u32, u32, u64)]) {
+    for entry in timeline.iter_mut() {
+        let timestamp = entry.timestamp;
+        for change in entry.changes.iter_mut() {
+            if let CharacterHistoryChange::GearSwap {
+                item_id: Some(item_id),
+                looted_instance_meta_id,
+                ..
+            } = change
+            {
+                // The most recent loot of that item before the snapshot was taken
+                *looted_instance_meta_id = loot
+                    .iter()
+                    .filter(|(looted_item_id, _, looted_ts)| *looted_item_id == *item_id && *looted_ts / 1000 <= timestamp)
+                    .max_by_key(|(_, _, looted_ts)| *looted_ts)
+                    .map(|(_, instance_meta_id, _)| *instance_meta_id);
+            }
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use crate::modules::{
+    armory::{domain_value::CharacterInfo, dto::CharacterViewerSheetDto, tools::get_character_gear_stats, util::talent_tree::decode_talent_specialization},
+    data::{
+        tools::{RetrieveClassStatCoefficient, RetrieveCombatRating, RetrieveLevelStat, RetrieveTalentStatModifier},
+        Data, Stat,
+    },
+};
+
+// Stat types that talents modify in percentage points rather than in percent
+static CHANCE_STAT_TYPES: [u8; 10] = [7, 8, 10, 11, 12, 23, 24, 26, 37, 42];
+
+pub fn calculate_character_sheet(data: &Data, expansion_id: u8, character_info: &CharacterInfo) -> Option<CharacterViewerSheetDto> {
+    let gear_stats = get_character_gear_stats(data, expansion_id, &character_info.gear);
+    build_character_sheet(data, expansion_id, character_info.race_id, character_info.hero_class_id, character_info.level, &character_info.talent_specialization, &gear_stats)
+}
+
+/// None unless the base attributes of the race, class and level, the class coefficients of the level and the combat ratings of the expansion are known.
+/// The coefficients only exist for the level caps, a sheet that leaves any of them out would be wrong rather than incomplete.
+pub fn build_character_sheet(data: &Data, expansion_id: u8, race_id: u8, hero_class_id: u8, level: u8, talent_specialization: &Option<String>, gear_stats: &[Stat]) -> Option<CharacterViewerSheetDto> {
+    let level_stat = data.get_level_stat(expansion_id, race_id, hero_class_id, level)?;
+    let coefficient = data.get_class_stat_coefficient(expansion_id, hero_class_id, level)?;
+    // Vanilla has no ratings, its items grant chances and defense skill directly
+    if expansion_id > 1 && data.get_combat_rating(expansion_id, 8).is_none() {
+        return None;
+    }
+
+    let mut stats: HashMap<u8, f64> = HashMap::new();
+    for stat in gear_stats {
+        *stats.entry(stat.stat_type).or_insert(0.0) += stat.stat_value as f64;
+    }
+    *stats.entry(27).or_insert(0.0) += level_stat.strength as f64;
+    *stats.entry(28).or_insert(0.0) += level_stat.agility as f64;
+    *stats.entry(29).or_insert(0.0) += level_stat.stamina as f64;
+    *stats.entry(30).or_insert(0.0) += level_stat.intellect as f64;
+    *stats.entry(31).or_insert(0.0) += level_stat.spirit as f64;
+
+    let talent_ranks = talent_specialization.as_deref().and_then(decode_talent_specialization).unwrap_or_default();
+    let mut chance_bonus: HashMap<u8, f64> = HashMap::new();
+    for modifier in data.get_talent_stat_modifiers(expansion_id, hero_class_id) {
+        let rank = talent_ranks.get(modifier.tree_index as usize).and_then(|tree| tree.get(modifier.talent_index as usize)).cloned().unwrap_or(0);
+        if rank == 0 {
+            continue;
+        }
+
+        let value = rank as f64 * modifier.value_per_rank;
+        if CHANCE_STAT_TYPES.contains(&modifier.stat_type) {
+            *chance_bonus.entry(modifier.stat_type).or_insert(0.0) += value;
+        } else if let Some(stat_value) = stats.get_mut(&modifier.stat_type) {
+            *stat_value *= 1.0 + value / 100.0;
+        }
+    }
+
+    let stat = |stat_type: u8| stats.get(&stat_type).cloned().unwrap_or(0.0).floor();
+    let bonus = |stat_type: u8| chance_bonus.get(&stat_type).cloned().unwrap_or(0.0);
+    // Percent (or defense skill) of the rating, converted with the coefficient of the given stat type
+    let convert_rating = |rating: f64, stat_type: u8| {
+        if expansion_id == 1 {
+            return rating;
+        }
+        data.get_combat_rating(expansion_id, stat_type)
+            .map(|combat_rating| rating / rating_per_percent(combat_rating.rating_per_percent, level))
+            .unwrap_or(0.0)
+    };
+    let rating = |stat_type: u8| convert_rating(stat(stat_type), stat_type);
+
+    let strength = stat(27);
+    let agility = stat(28);
+    let stamina = stat(29);
+    let intellect = stat(30);
+    let spirit = stat(31);
+    let level_f = level as f64;
+
+    let base_health = level_stat.health as f64;
+    let base_mana = level_stat.mana as f64;
+    let health = base_health + stamina.min(20.0) + (stamina - 20.0).max(0.0) * 10.0;
+    let mana = if base_mana > 0.0 { base_mana + intellect.min(20.0) + (intellect - 20.0).max(0.0) * 15.0 } else { 0.0 };
+
+    let attack_power = match hero_class_id {
+        1 | 2 | 6 => 3.0 * level_f + 2.0 * strength - 20.0,
+        3 | 4 => 2.0 * level_f + strength + agility - 20.0,
+        7 if expansion_id >= 3 => 2.0 * level_f + strength + agility - 20.0,
+        7 => 2.0 * level_f + 2.0 * strength - 20.0,
+        11 => 2.0 * strength - 20.0,
+        _ => strength - 10.0,
+    } + stat(9);
+    let ranged_attack_power = match hero_class_id {
+        3 if expansion_id >= 3 => 2.0 * level_f + agility - 10.0 + stat(9) + stat(25),
+        3 => 2.0 * level_f + 2.0 * agility - 10.0 + stat(9) + stat(25),
+        1 | 4 => level_f + agility - 10.0 + stat(9) + stat(25),
+        _ => 0.0,
+    };
+
+    // With WotLK hit, crit and haste ratings apply to spells as well, but at the spell rating coefficient
+    let spell_rating = |spell_stat_type: u8, stat_type: u8| {
+        let shared_rating = if expansion_id >= 3 { stat(stat_type) } else { 0.0 };
+        convert_rating(stat(spell_stat_type) + shared_rating, spell_stat_type)
+    };
+    let crit_from_agility = agility / coefficient.agility_per_crit;
+    let spell_crit_from_intellect = coefficient.intellect_per_spell_crit.map(|intellect_per_spell_crit| intellect / intellect_per_spell_crit).unwrap_or(0.0);
+
+    // Each point of defense skill adds 0.04% to dodge, parry and block
+    let defense = rating(22);
+    Some(CharacterViewerSheetDto {
+        strength: strength as u32,
+        agility: agility as u32,
+        stamina: stamina as u32,
+        intellect: intellect as u32,
+        spirit: spirit as u32,
+        health: health as u32,
+        mana: mana as u32,
+        armor: (stat(34) + 2.0 * agility) as u32,
+        attack_power: attack_power.max(0.0) as u32,
+        ranged_attack_power: ranged_attack_power.max(0.0) as u32,
+        spell_power: stat(13) as u32,
+        healing_power: stat(14) as u32,
+        hit_chance: round_chance(rating(7) + bonus(7)),
+        crit_chance: round_chance(rating(8) + bonus(8) + crit_from_agility),
+        haste: round_chance(rating(37) + bonus(37)),
+        spell_hit_chance: round_chance(spell_rating(23, 7) + bonus(23)),
+        spell_crit_chance: round_chance(spell_rating(24, 8) + bonus(24) + spell_crit_from_intellect),
+        spell_haste: round_chance(spell_rating(42, 37) + bonus(42)),
+        defense: round_chance(defense),
+        dodge_chance: round_chance(rating(10) + bonus(10) + 0.04 * defense),
+        parry_chance: round_chance(rating(11) + bonus(11) + 0.04 * defense),
+        block_chance: round_chance(rating(12) + bonus(12) + stat(26) + bonus(26) + 0.04 * defense),
+        resilience: round_chance(rating(39)),
+    })
+}
+
+/// Rating required for 1% at the given level, where rating_at_60 is the amount required at level 60
+pub fn rating_per_percent(rating_at_60: f64, level: u8) -> f64 {
+    let level = level as f64;
+    let scale = if level <= 10.0 {
+        2.0 / 52.0
+    } else if level <= 60.0 {
+        (level - 8.0) / 52.0
+    } else if level <= 70.0 {
+        82.0 / (262.0 - 3.0 * level)
+    } else {
+        82.0 / 52.0 * (131.0_f64 / 63.0).powf((level - 70.0) / 10.0)
+    };
+    rating_at_60 * scale
+}
+
+fn round_chance(chance: f64) -> f64 {
+    (chance * 100.0).round() / 100.0
+}
@@ -6,12 +6,12 @@ use crate::{
     modules::{
         armory::{
             domain_value::CharacterItem,
-            dto::{ArmoryFailure, CharacterViewerDto, CharacterViewerGearDto, CharacterViewerGuildDto, CharacterViewerItemDto, CharacterViewerProfessionDto, CharacterViewerTalentsDto},
-            tools::{get_character_stats, GetCharacter, GetCharacterHistory, GetGuild},
+            dto::{ArmoryFailure, CharacterViewerDto, CharacterViewerGearDto, CharacterViewerGuildDto, CharacterViewerItemDto, CharacterViewerProfessionDto},
+            tools::{build_talent_specialization, calculate_character_sheet, get_character_stats, GetCharacter, GetCharacterHistory, GetGuild},
             Armory,
         },
         data::{
-            tools::{RetrieveHeroClass, RetrieveIcon, RetrieveItem, RetrieveLocalization, RetrieveProfession, RetrieveRace, RetrieveServer, RetrieveTitle},
+            tools::{RetrieveIcon, RetrieveItem, RetrieveLocalization, RetrieveProfession, RetrieveRace, RetrieveServer, RetrieveTitle},
             Data,
         },
     },
@@ -88,16 +88,8 @@ impl CharacterViewer for Armory {
             })
         });
 
-        let talent_specialization = character_history.character_info.talent_specialization.clone().map(|description| {
-            let hero_class = data.get_hero_class(character_history.character_info.hero_class_id).unwrap();
-            let breakdown = description.split('|').map(|spec| spec.chars().map(|talent| talent.to_digit(10).unwrap()).sum::<u32>()).collect::<Vec<u32>>();
-            let breakdown_max = breakdown.iter().max().unwrap();
-            let breakdown_index = breakdown.iter().position(|value| *value == *breakdown_max).unwrap();
-            CharacterViewerTalentsDto {
-                icon: data.get_icon(hero_class.talents[breakdown_index].icon).unwrap().name,
-                name: data.get_localization(language_id, hero_class.talents[breakdown_index].localization_id).unwrap().content,
-                description,
-            }
+        let talent_specialization = character_history.character_info.talent_specialization.clone().and_then(|description| {
+            build_talent_specialization(data, server.expansion_id, language_id, character_history.character_info.hero_class_id, character_history.character_info.level, description)
         });
 
         Ok(CharacterViewerDto {
@@ -157,6 +149,7 @@ impl CharacterViewer for Armory {
             profession2,
             talent_specialization,
             stats: get_character_stats(data, language_id, server.expansion_id, &character_history.character_info.gear),
+            sheet: calculate_character_sheet(data, server.expansion_id, &character_history.character_info),
         })
     }
 
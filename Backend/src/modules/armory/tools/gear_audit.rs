@@ -0,0 +1,156 @@
+use crate::modules::armory::domain_value::{CharacterGear, InventoryType};
+use crate::modules::armory::dto::{ArmoryFailure, CharacterGearAudit, GearAuditFinding, GearAuditSlot};
+use crate::modules::armory::material::CharacterHistory;
+use crate::modules::armory::tools::GetCharacter;
+use crate::modules::armory::Armory;
+use crate::modules::data::tools::{RetrieveGem, RetrieveItem, RetrieveItemSocket, RetrieveServer};
+use crate::modules::data::Data;
+use crate::util::database::Select;
+
+/// Items this many levels below the average item level of the character are reported
+pub static LOW_ITEM_LEVEL_MARGIN: u16 = 15;
+
+// Every slot except shirt and tabard is audited
+static AUDITED_SLOTS: [InventoryType; 17] = [
+    InventoryType::Head,
+    InventoryType::Neck,
+    InventoryType::Shoulder,
+    InventoryType::Back,
+    InventoryType::Chest,
+    InventoryType::Wrist,
+    InventoryType::Hands,
+    InventoryType::Waist,
+    InventoryType::Legs,
+    InventoryType::Feet,
+    InventoryType::Finger1,
+    InventoryType::Finger2,
+    InventoryType::Trinket1,
+    InventoryType::Trinket2,
+    InventoryType::MainHand,
+    InventoryType::OffHand,
+    InventoryType::Ranged,
+];
+
+pub trait GearAudit {
+    fn get_character_gear_audit(&self, data: &Data, character_id: u32) -> Result<CharacterGearAudit, ArmoryFailure>;
+    fn get_character_gear_audit_moment(&self, db_main: &mut impl Select, data: &Data, character_id: u32, timestamp: u64) -> Option<CharacterGearAudit>;
+}
+
+impl GearAudit for Armory {
+    fn get_character_gear_audit(&self, data: &Data, character_id: u32) -> Result<CharacterGearAudit, ArmoryFailure> {
+        let character = self.get_character(character_id).ok_or(ArmoryFailure::InvalidInput)?;
+        let character_history = character.last_update.ok_or(ArmoryFailure::InvalidInput)?;
+        let server = data.get_server(character.server_id).ok_or(ArmoryFailure::InvalidInput)?;
+        Ok(audit_character_history(data, server.expansion_id, &character_history))
+    }
+
+    fn get_character_gear_audit_moment(&self, db_main: &mut impl Select, data: &Data, character_id: u32, timestamp: u64) -> Option<CharacterGearAudit> {
+        let character = self.get_character(character_id)?;
+        let server = data.get_server(character.server_id)?;
+        self.get_character_moment(db_main, character_id, timestamp)
+            .map(|character_history| audit_character_history(data, server.expansion_id, &character_history))
+    }
+}
+
+fn audit_character_history(data: &Data, expansion_id: u8, character_history: &CharacterHistory) -> CharacterGearAudit {
+    let (average_item_level, slots) = audit_gear(data, expansion_id, &character_history.character_info.gear);
+    CharacterGearAudit {
+        character_id: character_history.character_id,
+        character_history_id: character_history.id,
+        average_item_level,
+        num_findings: slots.iter().map(|slot| slot.findings.len() as u32).sum(),
+        slots,
+    }
+}
+
+/// Findings for each audited slot and the average item level, where empty slots count as item level 0
+pub fn audit_gear(data: &Data, expansion_id: u8, gear: &CharacterGear) -> (f64, Vec<GearAuditSlot>) {
+    let main_hand_inventory_type = gear.main_hand.as_ref().and_then(|item| data.get_item(expansion_id, item.item_id)).and_then(|item| item.inventory_type);
+    let wields_two_hand = main_hand_inventory_type == Some(17);
+
+    let mut item_level_sum = 0;
+    let mut slots = Vec::with_capacity(AUDITED_SLOTS.len());
+    for inventory_type in AUDITED_SLOTS.iter() {
+        let character_item = gear.get_slot(*inventory_type);
+        if character_item.is_none() {
+            // A two hand weapon occupies the off hand as well
+            if *inventory_type == InventoryType::OffHand && wields_two_hand {
+                item_level_sum += gear.main_hand.as_ref().and_then(|item| data.get_item(expansion_id, item.item_id)).and_then(|item| item.item_level).unwrap_or(0);
+                continue;
+            }
+
+            slots.push(GearAuditSlot {
+                inventory_type: *inventory_type as u8,
+                item_id: None,
+                item_level: None,
+                enchant_id: None,
+                socket_bonus_active: None,
+                findings: vec![GearAuditFinding::MissingItem],
+            });
+            continue;
+        }
+
+        let character_item = character_item.unwrap();
+        let item = data.get_item(expansion_id, character_item.item_id);
+        let item_level = item.as_ref().and_then(|item| item.item_level);
+        item_level_sum += item_level.unwrap_or(0);
+
+        let mut findings = Vec::new();
+        if character_item.enchant_id.is_none() && is_enchantable(*inventory_type, item.as_ref().and_then(|item| item.inventory_type)) {
+            findings.push(GearAuditFinding::MissingEnchant);
+        }
+
+        let mut socket_bonus_active = None;
+        if let Some(item_socket) = data.get_item_socket(expansion_id, character_item.item_id) {
+            let mut bonus_active = true;
+            for (socket_index, socket_color) in item_socket.slots.iter().enumerate() {
+                match character_item.gem_ids.get(socket_index).cloned().flatten() {
+                    Some(gem_id) => {
+                        let gem_flag = data.get_gem(expansion_id, gem_id).map(|gem| gem.flag).unwrap_or(0);
+                        bonus_active = bonus_active && *socket_color == socket_color & gem_flag;
+                        if data.get_item(expansion_id, gem_id).map(|gem_item| gem_item.quality < 3).unwrap_or(false) {
+                            findings.push(GearAuditFinding::LowQualityGem(socket_index as u8, gem_id));
+                        }
+                    },
+                    None => {
+                        bonus_active = false;
+                        findings.push(GearAuditFinding::EmptySocket(socket_index as u8));
+                    },
+                }
+            }
+            if !bonus_active {
+                findings.push(GearAuditFinding::InactiveSocketBonus);
+            }
+            socket_bonus_active = Some(bonus_active);
+        }
+
+        slots.push(GearAuditSlot {
+            inventory_type: *inventory_type as u8,
+            item_id: Some(character_item.item_id),
+            item_level,
+            enchant_id: character_item.enchant_id,
+            socket_bonus_active,
+            findings,
+        });
+    }
+
+    let average_item_level = item_level_sum as f64 / AUDITED_SLOTS.len() as f64;
+    for slot in slots.iter_mut() {
+        if let Some(item_level) = slot.item_level {
+            if ((item_level + LOW_ITEM_LEVEL_MARGIN) as f64) < average_item_level {
+                slot.findings.push(GearAuditFinding::LowItemLevel(item_level));
+            }
+        }
+    }
+
+    ((average_item_level * 100.0).round() / 100.0, slots)
+}
+
+// Off hand frills can not be enchanted, but shields and weapons can
+fn is_enchantable(inventory_type: InventoryType, item_inventory_type: Option<u8>) -> bool {
+    match inventory_type {
+        InventoryType::Head | InventoryType::Shoulder | InventoryType::Back | InventoryType::Chest | InventoryType::Wrist | InventoryType::Hands | InventoryType::Legs | InventoryType::Feet | InventoryType::MainHand => true,
+        InventoryType::OffHand => item_inventory_type != Some(23),
+        _ => false,
+    }
+}
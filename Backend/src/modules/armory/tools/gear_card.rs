@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use image::RgbaImage;
+
+use crate::modules::armory::dto::ArmoryFailure;
+use crate::modules::armory::tools::{audit_gear, CharacterViewer, GetCharacter, GetCharacterHistory};
+use crate::modules::armory::util::gear_card::render_gear_card;
+use crate::modules::armory::Armory;
+use crate::modules::data::tools::{RetrieveItem, RetrieveServer};
+use crate::modules::data::Data;
+use crate::util::database::Select;
+
+pub trait GearCard {
+    fn get_gear_card(&self, db_main: &mut impl Select, data: &Data, character_history_id: u32) -> Result<Vec<u8>, ArmoryFailure>;
+}
+
+impl GearCard for Armory {
+    fn get_gear_card(&self, db_main: &mut impl Select, data: &Data, character_history_id: u32) -> Result<Vec<u8>, ArmoryFailure> {
+        if let Some(gear_card) = self.cache_gear_card.write().unwrap().get(&character_history_id) {
+            return Ok(gear_card.clone());
+        }
+
+        let character_history = self.get_character_history(db_main, character_history_id)?;
+        let character = self.get_character(character_history.character_id).ok_or(ArmoryFailure::InvalidInput)?;
+        let expansion_id = data.get_server(character.server_id).ok_or(ArmoryFailure::InvalidInput)?.expansion_id;
+        let character_viewer = self.get_character_viewer_by_history_id(db_main, data, 1, character_history_id, character.id)?;
+
+        let gear = &character_viewer.gear;
+        let item_levels: HashMap<u32, u16> = [
+            &gear.head,
+            &gear.neck,
+            &gear.shoulder,
+            &gear.back,
+            &gear.chest,
+            &gear.shirt,
+            &gear.tabard,
+            &gear.wrist,
+            &gear.main_hand,
+            &gear.off_hand,
+            &gear.ternary_hand,
+            &gear.glove,
+            &gear.belt,
+            &gear.leg,
+            &gear.boot,
+            &gear.ring1,
+            &gear.ring2,
+            &gear.trinket1,
+            &gear.trinket2,
+        ]
+        .iter()
+        .filter_map(|item| item.as_ref())
+        .filter_map(|item| data.get_item(expansion_id, item.item_id).and_then(|item| item.item_level).map(|item_level| (item.item_id, item_level)))
+        .collect();
+        let (average_item_level, _) = audit_gear(data, expansion_id, &character_history.character_info.gear);
+
+        // The icons are the wow_icon assets of the webclient
+        let icon_path = std::env::var("ICON_PATH").ok().filter(|icon_path| Path::new(icon_path).is_dir());
+        let gear_card = render_gear_card(&character_viewer, &item_levels, average_item_level, |icon| load_icon(icon_path.as_ref()?, icon));
+        // Cards without icons are not cached, so they are complete once the icons are deployed
+        if icon_path.is_some() {
+            self.cache_gear_card.write().unwrap().insert(character_history_id, gear_card.clone());
+        }
+        Ok(gear_card)
+    }
+}
+
+fn load_icon(icon_path: &str, icon: &str) -> Option<RgbaImage> {
+    image::open(format!("{}/{}.jpg", icon_path, icon)).ok().map(|icon| icon.to_rgba8())
+}
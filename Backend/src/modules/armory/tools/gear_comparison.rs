@@ -0,0 +1,248 @@
+use crate::dto::CheckPlausability;
+use crate::modules::armory::domain_value::{CharacterGear, CharacterItem, InventoryType};
+use crate::modules::armory::dto::{ArmoryFailure, CharacterItemDto, CharacterViewerSheetDto, GearComparison, GearSlotReplacement, GearStatDelta, ItemsetBonus, ItemsetBonusChange, SheetStatDelta};
+use crate::modules::armory::tools::{build_character_sheet, get_character_gear_stats, get_itemset_pieces, GetCharacter, GetCharacterHistory};
+use crate::modules::armory::Armory;
+use crate::modules::data::tools::{
+    RetrieveEnchant, RetrieveGem, RetrieveItem, RetrieveItemRandomProperty, RetrieveItemRandomPropertyPoints, RetrieveItemsetEffect, RetrieveItemsetName, RetrieveLocalization, RetrieveServer, RetrieveStatType, SpellDescription,
+};
+use crate::modules::data::{Data, Stat};
+use crate::util::database::Select;
+
+pub trait CompareGear {
+    fn get_gear_comparison(&self, db_main: &mut impl Select, data: &Data, language_id: u8, character_history_id: u32, replacements: Vec<GearSlotReplacement>) -> Result<GearComparison, ArmoryFailure>;
+}
+
+impl CompareGear for Armory {
+    fn get_gear_comparison(&self, db_main: &mut impl Select, data: &Data, language_id: u8, character_history_id: u32, replacements: Vec<GearSlotReplacement>) -> Result<GearComparison, ArmoryFailure> {
+        let character_history = self.get_character_history(db_main, character_history_id)?;
+        let character = self.get_character(character_history.character_id).ok_or(ArmoryFailure::InvalidInput)?;
+        let expansion_id = data.get_server(character.server_id).ok_or(ArmoryFailure::InvalidInput)?.expansion_id;
+        let items = replacements.iter().filter_map(|replacement| replacement.item.as_ref());
+        if !items.clone().all(|item| item.is_plausible()) {
+            return Err(ArmoryFailure::ImplausibleInput);
+        }
+        if !items.clone().all(|item| is_known_item(data, expansion_id, item)) {
+            return Err(ArmoryFailure::InvalidInput);
+        }
+
+        let character_info = &character_history.character_info;
+        let gear_after = replace_gear_slots(data, expansion_id, &character_info.gear, &replacements).ok_or(ArmoryFailure::InvalidInput)?;
+        let stats_before = get_character_gear_stats(data, expansion_id, &character_info.gear);
+        let stats_after = get_character_gear_stats(data, expansion_id, &gear_after);
+        let sheet_before = build_character_sheet(data, expansion_id, character_info.race_id, character_info.hero_class_id, character_info.level, &character_info.talent_specialization, &stats_before);
+        let sheet_after = build_character_sheet(data, expansion_id, character_info.race_id, character_info.hero_class_id, character_info.level, &character_info.talent_specialization, &stats_after);
+
+        let stat_deltas = diff_gear_stats(&stats_before, &stats_after)
+            .into_iter()
+            .map(|(stat_type, before, after)| GearStatDelta {
+                stat_type,
+                name: data
+                    .get_stat_type(stat_type)
+                    .and_then(|stat_type| data.get_localization(language_id, stat_type.localization_id))
+                    .map(|localization| localization.content)
+                    .unwrap_or_default(),
+                before,
+                after,
+                delta: after as i32 - before as i32,
+            })
+            .collect();
+        let itemset_bonus_changes = diff_itemset_pieces(&get_itemset_pieces(data, expansion_id, &character_info.gear), &get_itemset_pieces(data, expansion_id, &gear_after))
+            .into_iter()
+            .filter_map(|(itemset_id, pieces_before, pieces_after)| {
+                let itemset_effects = data.get_itemset_effects(expansion_id, itemset_id).unwrap_or_default();
+                let to_itemset_bonus = |threshold: u8, spell_id: u32| ItemsetBonus {
+                    threshold,
+                    spell_id,
+                    description: data.get_localized_spell_description(expansion_id, language_id, spell_id),
+                };
+                let gained: Vec<ItemsetBonus> = itemset_effects
+                    .iter()
+                    .filter(|itemset_effect| itemset_effect.threshold > pieces_before && itemset_effect.threshold <= pieces_after)
+                    .map(|itemset_effect| to_itemset_bonus(itemset_effect.threshold, itemset_effect.spell_id))
+                    .collect();
+                let lost: Vec<ItemsetBonus> = itemset_effects
+                    .iter()
+                    .filter(|itemset_effect| itemset_effect.threshold > pieces_after && itemset_effect.threshold <= pieces_before)
+                    .map(|itemset_effect| to_itemset_bonus(itemset_effect.threshold, itemset_effect.spell_id))
+                    .collect();
+                if gained.is_empty() && lost.is_empty() {
+                    return None;
+                }
+                Some(ItemsetBonusChange {
+                    itemset_id,
+                    name: data
+                        .get_itemset_name(expansion_id, itemset_id)
+                        .and_then(|itemset_name| data.get_localization(language_id, itemset_name.localization_id))
+                        .map(|localization| localization.content),
+                    pieces_before,
+                    pieces_after,
+                    gained,
+                    lost,
+                })
+            })
+            .collect();
+
+        Ok(GearComparison {
+            character_history_id,
+            sheet_deltas: match (sheet_before.as_ref(), sheet_after.as_ref()) {
+                (Some(sheet_before), Some(sheet_after)) => diff_character_sheets(sheet_before, sheet_after),
+                _ => Vec::new(),
+            },
+            sheet_before,
+            sheet_after,
+            stat_deltas,
+            itemset_bonus_changes,
+        })
+    }
+}
+
+/// Gear with the slots replaced, None if a replacement does not name a gear slot or the item can not be equipped there
+pub fn replace_gear_slots(data: &Data, expansion_id: u8, gear: &CharacterGear, replacements: &[GearSlotReplacement]) -> Option<CharacterGear> {
+    let mut gear = gear.clone();
+    for replacement in replacements {
+        // Anything beyond the tabard is not a gear slot and must not be transmuted
+        if replacement.inventory_type > InventoryType::Tabard as u8 {
+            return None;
+        }
+        let inventory_type = InventoryType::from_u8(replacement.inventory_type);
+        if let Some(item) = replacement.item.as_ref() {
+            let item_inventory_type = data.get_item(expansion_id, item.item_id).and_then(|item| item.inventory_type)?;
+            if !fits_slot(inventory_type, item_inventory_type) {
+                return None;
+            }
+        }
+        let item = replacement.item.as_ref().map(|item| CharacterItem {
+            id: 0,
+            item_id: item.item_id,
+            random_property_id: item.random_property_id,
+            enchant_id: item.enchant_id,
+            gem_ids: item.gem_ids.clone(),
+        });
+        if !gear.set_slot(inventory_type, item) {
+            return None;
+        }
+    }
+
+    // Only checked if the hands are replaced, as Titan's Grip allows two two-handers
+    let replaces_hands = replacements.iter().any(|replacement| replacement.inventory_type == InventoryType::MainHand as u8 || replacement.inventory_type == InventoryType::OffHand as u8);
+    let wields_two_hand = gear.main_hand.as_ref().and_then(|item| data.get_item(expansion_id, item.item_id)).and_then(|item| item.inventory_type) == Some(17);
+    if replaces_hands && wields_two_hand && gear.off_hand.is_some() {
+        return None;
+    }
+    Some(gear)
+}
+
+/// Stat type, value before and value after of every stat that changed, ordered by stat type
+pub fn diff_gear_stats(before: &[Stat], after: &[Stat]) -> Vec<(u8, u16, u16)> {
+    let get_stat_value = |stats: &[Stat], stat_type: u8| stats.iter().filter(|stat| stat.stat_type == stat_type).map(|stat| stat.stat_value).sum::<u16>();
+    let mut stat_types: Vec<u8> = before.iter().chain(after.iter()).map(|stat| stat.stat_type).collect();
+    stat_types.sort_unstable();
+    stat_types.dedup();
+    stat_types
+        .into_iter()
+        .map(|stat_type| (stat_type, get_stat_value(before, stat_type), get_stat_value(after, stat_type)))
+        .filter(|(_, before, after)| before != after)
+        .collect()
+}
+
+/// Itemset, pieces before and pieces after of every itemset whose number of equipped pieces changed
+pub fn diff_itemset_pieces(before: &[(u16, u8)], after: &[(u16, u8)]) -> Vec<(u16, u8, u8)> {
+    let get_pieces = |itemset_pieces: &[(u16, u8)], itemset_id: u16| itemset_pieces.iter().find(|(id, _)| *id == itemset_id).map(|(_, num_pieces)| *num_pieces).unwrap_or(0);
+    let mut itemset_ids: Vec<u16> = before.iter().chain(after.iter()).map(|(itemset_id, _)| *itemset_id).collect();
+    itemset_ids.sort_unstable();
+    itemset_ids.dedup();
+    itemset_ids
+        .into_iter()
+        .map(|itemset_id| (itemset_id, get_pieces(before, itemset_id), get_pieces(after, itemset_id)))
+        .filter(|(_, before, after)| before != after)
+        .collect()
+}
+
+pub fn diff_character_sheets(before: &CharacterViewerSheetDto, after: &CharacterViewerSheetDto) -> Vec<SheetStatDelta> {
+    vec![
+        ("strength", before.strength as f64, after.strength as f64),
+        ("agility", before.agility as f64, after.agility as f64),
+        ("stamina", before.stamina as f64, after.stamina as f64),
+        ("intellect", before.intellect as f64, after.intellect as f64),
+        ("spirit", before.spirit as f64, after.spirit as f64),
+        ("health", before.health as f64, after.health as f64),
+        ("mana", before.mana as f64, after.mana as f64),
+        ("armor", before.armor as f64, after.armor as f64),
+        ("attack_power", before.attack_power as f64, after.attack_power as f64),
+        ("ranged_attack_power", before.ranged_attack_power as f64, after.ranged_attack_power as f64),
+        ("spell_power", before.spell_power as f64, after.spell_power as f64),
+        ("healing_power", before.healing_power as f64, after.healing_power as f64),
+        ("hit_chance", before.hit_chance, after.hit_chance),
+        ("crit_chance", before.crit_chance, after.crit_chance),
+        ("haste", before.haste, after.haste),
+        ("spell_hit_chance", before.spell_hit_chance, after.spell_hit_chance),
+        ("spell_crit_chance", before.spell_crit_chance, after.spell_crit_chance),
+        ("spell_haste", before.spell_haste, after.spell_haste),
+        ("defense", before.defense, after.defense),
+        ("dodge_chance", before.dodge_chance, after.dodge_chance),
+        ("parry_chance", before.parry_chance, after.parry_chance),
+        ("block_chance", before.block_chance, after.block_chance),
+        ("resilience", before.resilience, after.resilience),
+    ]
+    .into_iter()
+    .filter(|(_, before, after)| (after - before).abs() > f64::EPSILON)
+    .map(|(stat, before, after)| SheetStatDelta {
+        stat: stat.to_string(),
+        before,
+        after,
+        delta: after - before,
+    })
+    .collect()
+}
+
+/// The stat calculation expects the item, its enchants and gems to be known
+fn is_known_item(data: &Data, expansion_id: u8, item: &CharacterItemDto) -> bool {
+    let data_item = match data.get_item(expansion_id, item.item_id) {
+        Some(data_item) => data_item,
+        None => return false,
+    };
+    let is_known_enchant = |enchant_id: u32| data.get_enchant(expansion_id, enchant_id).is_some();
+    let is_known_random_property = |random_property_id: i16| {
+        data.get_item_random_property(expansion_id, random_property_id)
+            .map(|random_property| {
+                random_property
+                    .enchant_ids
+                    .iter()
+                    .all(|enchant_id| data.get_enchant(expansion_id, *enchant_id).map(|enchant| data.get_localization(1, enchant.localization_id).is_some()).unwrap_or(false))
+                    && (random_property_id > 0 || !(3..=5).contains(&data_item.quality) || data_item.item_level.and_then(|item_level| data.get_item_random_property_points(expansion_id, item_level)).is_some())
+            })
+            .unwrap_or(false)
+    };
+
+    item.enchant_id.map(is_known_enchant).unwrap_or(true)
+        && item.gem_ids.iter().flatten().all(|gem_id| data.get_gem(expansion_id, *gem_id).map(|gem| is_known_enchant(gem.enchant_id)).unwrap_or(false))
+        && item.random_property_id.map(is_known_random_property).unwrap_or(true)
+}
+
+/// Inventory types of items that can be equipped in the gear slot
+fn fits_slot(inventory_type: InventoryType, item_inventory_type: u8) -> bool {
+    match inventory_type {
+        InventoryType::Head => item_inventory_type == 1,
+        InventoryType::Neck => item_inventory_type == 2,
+        InventoryType::Shoulder => item_inventory_type == 3,
+        InventoryType::Shirt => item_inventory_type == 4,
+        InventoryType::Chest => item_inventory_type == 5 || item_inventory_type == 20,
+        InventoryType::Waist => item_inventory_type == 6,
+        InventoryType::Legs => item_inventory_type == 7,
+        InventoryType::Feet => item_inventory_type == 8,
+        InventoryType::Wrist => item_inventory_type == 9,
+        InventoryType::Hands => item_inventory_type == 10,
+        InventoryType::Finger1 | InventoryType::Finger2 => item_inventory_type == 11,
+        InventoryType::Trinket1 | InventoryType::Trinket2 => item_inventory_type == 12,
+        InventoryType::Back => item_inventory_type == 16,
+        // One-hand, two-hand and main hand weapons
+        InventoryType::MainHand => [13, 17, 21].contains(&item_inventory_type),
+        // One-hand and off hand weapons, shields and off hand frills
+        InventoryType::OffHand => [13, 14, 22, 23].contains(&item_inventory_type),
+        // Bows, thrown weapons, guns, crossbows, wands and relics
+        InventoryType::Ranged => [15, 25, 26, 28].contains(&item_inventory_type),
+        InventoryType::Tabard => item_inventory_type == 19,
+        _ => false,
+    }
+}
@@ -5,7 +5,7 @@ use crate::modules::{
     },
     data::{
         tools::{
-            RetrieveEnchant, RetrieveGem, RetrieveItem, RetrieveItemEffect, RetrieveItemRandomProperty, RetrieveItemRandomPropertyPoints, RetrieveItemSocket, RetrieveItemStat, RetrieveItemsetEffect, RetrieveItemsetName, RetrieveLocalization,
+            RetrieveEnchant, RetrieveGem, RetrieveItem, RetrieveItemEffect, RetrieveItemRandomProperty, RetrieveItemRandomPropertyPoints, RetrieveItemSocket, RetrieveItemStat, RetrieveItemsetEffect, RetrieveLocalization,
             RetrieveStatType, SpellDescription,
         },
         Data, Stat,
@@ -13,6 +13,17 @@ use crate::modules::{
 };
 
 pub fn get_character_stats(data: &Data, language_id: u8, expansion_id: u8, gear: &CharacterGear) -> Vec<CharacterStat> {
+    let mut acc = get_character_gear_stats(data, expansion_id, gear);
+    acc.sort_by(|left, right| left.stat_type.cmp(&right.stat_type));
+    acc.iter()
+        .map(|stat| CharacterStat {
+            stat_type: data.get_localization(language_id, data.get_stat_type(stat.stat_type).unwrap().localization_id).unwrap().content,
+            stat_value: stat.stat_value,
+        })
+        .collect()
+}
+
+pub fn get_character_gear_stats(data: &Data, expansion_id: u8, gear: &CharacterGear) -> Vec<Stat> {
     let mut acc = get_item_stats(data, expansion_id, &gear.head, 0);
     merge_character_stat_vec(&mut acc, get_item_stats(data, expansion_id, &gear.neck, 2));
     merge_character_stat_vec(&mut acc, get_item_stats(data, expansion_id, &gear.shoulder, 1));
@@ -33,32 +44,30 @@ pub fn get_character_stats(data: &Data, language_id: u8, expansion_id: u8, gear:
     merge_character_stat_vec(&mut acc, get_item_stats(data, expansion_id, &gear.trinket1, 1));
     merge_character_stat_vec(&mut acc, get_item_stats(data, expansion_id, &gear.trinket2, 1));
 
-    // Stats from set bonus
-    let gear_to_vec_item_ids = gear_to_item_id_vec(&gear);
-    let mut considered_set_item_ids: Vec<u32> = Vec::new();
-    for item_id in gear_to_vec_item_ids {
-        if considered_set_item_ids.iter().any(|&id| id == item_id) {
-            continue;
-        }
-        if let Some(item) = data.get_item(expansion_id, item_id) {
-            if let Some(itemset_id) = item.itemset {
-                let mut itemset_item_ids = data.get_itemset_item_ids(expansion_id, itemset_id).unwrap();
-                considered_set_item_ids.append(&mut itemset_item_ids);
-                data.get_itemset_effects(expansion_id, itemset_id)
-                    .unwrap()
-                    .iter()
-                    .for_each(|item_effect| merge_character_stat_vec(&mut acc, data.parse_stats(expansion_id, item_effect.spell_id)))
+    // Stats from set bonus, for as many pieces as are equipped
+    for (itemset_id, num_pieces) in get_itemset_pieces(data, expansion_id, gear) {
+        data.get_itemset_effects(expansion_id, itemset_id)
+            .into_iter()
+            .flatten()
+            .filter(|item_effect| item_effect.threshold <= num_pieces)
+            .for_each(|item_effect| merge_character_stat_vec(&mut acc, data.parse_stats(expansion_id, item_effect.spell_id)))
+    }
+
+    acc
+}
+
+/// Equipped pieces of each itemset, in the order the sets appear in the gear
+pub fn get_itemset_pieces(data: &Data, expansion_id: u8, gear: &CharacterGear) -> Vec<(u16, u8)> {
+    let mut itemset_pieces: Vec<(u16, u8)> = Vec::new();
+    for item_id in gear_to_item_id_vec(gear) {
+        if let Some(itemset_id) = data.get_item(expansion_id, item_id).and_then(|item| item.itemset) {
+            match itemset_pieces.iter_mut().find(|(id, _)| *id == itemset_id) {
+                Some((_, num_pieces)) => *num_pieces += 1,
+                None => itemset_pieces.push((itemset_id, 1)),
             }
         }
     }
-
-    acc.sort_by(|left, right| left.stat_type.cmp(&right.stat_type));
-    acc.iter()
-        .map(|stat| CharacterStat {
-            stat_type: data.get_localization(language_id, data.get_stat_type(stat.stat_type).unwrap().localization_id).unwrap().content,
-            stat_value: stat.stat_value,
-        })
-        .collect()
+    itemset_pieces
 }
 
 fn get_item_stats(data: &Data, expansion_id: u8, item: &Option<CharacterItem>, suffix_index: u8) -> Vec<Stat> {
@@ -226,7 +235,7 @@ fn merge_character_stat_vec(acc: &mut Vec<Stat>, input: Vec<Stat>) {
     }
 }
 
-fn gear_to_item_id_vec(gear: &CharacterGear) -> Vec<u32> {
+pub fn gear_to_item_id_vec(gear: &CharacterGear) -> Vec<u32> {
     let mut item_ids = Vec::new();
     if let Some(item) = &gear.head {
         item_ids.push(item.item_id)
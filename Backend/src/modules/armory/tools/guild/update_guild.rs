@@ -20,6 +20,18 @@ impl UpdateGuild for Armory {
             ),
         ) {
             let mut guild = guilds.get_mut(&guild_id).unwrap();
+            if guild.name != guild_name {
+                // Kept for the activity log of the guild
+                db_main.execute_wparams(
+                    "INSERT INTO armory_guild_name_history (`guild_id`, `previous_name`, `name`, `timestamp`) VALUES (:guild_id, :previous_name, :name, :timestamp)",
+                    params!(
+                      "guild_id" => guild_id,
+                      "previous_name" => guild.name.clone(),
+                      "name" => guild_name.clone(),
+                      "timestamp" => time_util::now()
+                    ),
+                );
+            }
             guild.name = guild_name;
             return Ok(());
         }
@@ -0,0 +1,86 @@
+use crate::dto::SearchResult;
+use crate::modules::armory::domain_value::GuildMembership;
+use crate::modules::armory::dto::{ArmoryFailure, GuildActivity, GuildActivityDto};
+use crate::modules::armory::tools::GetGuild;
+use crate::modules::armory::Armory;
+use crate::params;
+use crate::util::database::Select;
+
+pub trait GetGuildActivity {
+    fn get_guild_activity(&self, db_main: &mut impl Select, guild_id: u32, page: u32) -> Result<SearchResult<GuildActivityDto>, ArmoryFailure>;
+}
+
+impl GetGuildActivity for Armory {
+    fn get_guild_activity(&self, db_main: &mut impl Select, guild_id: u32, page: u32) -> Result<SearchResult<GuildActivityDto>, ArmoryFailure> {
+        if self.get_guild(guild_id).is_none() {
+            return Err(ArmoryFailure::InvalidInput);
+        }
+
+        // Every snapshot of every character that has been a member at some point, dated by when it was taken
+        let memberships = db_main.select_wparams(
+            "SELECT A.character_id, A.character_name, A.guild_id, A.guild_rank, A.first_seen FROM armory_character_history A \
+             JOIN (SELECT DISTINCT character_id FROM armory_character_history WHERE guild_id=:guild_id) B ON A.character_id = B.character_id ORDER BY A.character_id, A.first_seen",
+            |mut row| GuildMembership {
+                character_id: row.take(0).unwrap(),
+                character_name: row.take(1).unwrap(),
+                guild_id: row.take_opt(2).unwrap().ok(),
+                rank_index: row.take_opt(3).unwrap().ok(),
+                timestamp: row.take(4).unwrap(),
+            },
+            params!("guild_id" => guild_id),
+        );
+        let renames = db_main.select_wparams(
+            "SELECT previous_name, name, timestamp FROM armory_guild_name_history WHERE guild_id=:guild_id",
+            |mut row| GuildActivityDto {
+                timestamp: row.take(2).unwrap(),
+                character_id: None,
+                character_name: None,
+                activity: GuildActivity::Rename {
+                    previous_name: row.take(0).unwrap(),
+                    name: row.take(1).unwrap(),
+                },
+            },
+            params!("guild_id" => guild_id),
+        );
+
+        let mut result = derive_guild_activity(guild_id, &memberships);
+        result.extend(renames);
+        result.sort_by(|left, right| right.timestamp.cmp(&left.timestamp));
+        let num_items = result.len();
+        Ok(SearchResult {
+            result: result.into_iter().skip((page * 10) as usize).take(10).collect(),
+            num_items,
+        })
+    }
+}
+
+/// Joins, leaves, promotions and demotions, given the memberships ordered by character and timestamp
+pub fn derive_guild_activity(guild_id: u32, memberships: &[GuildMembership]) -> Vec<GuildActivityDto> {
+    let mut result = Vec::new();
+    let mut previous: Option<&GuildMembership> = None;
+    for membership in memberships {
+        let previous_rank_index = previous
+            .filter(|previous| previous.character_id == membership.character_id && previous.guild_id == Some(guild_id))
+            .map(|previous| previous.rank_index.unwrap_or(0));
+        let rank_index = Some(membership.rank_index.unwrap_or(0)).filter(|_| membership.guild_id == Some(guild_id));
+
+        let activity = match (previous_rank_index, rank_index) {
+            (None, Some(rank_index)) => Some(GuildActivity::Join { rank_index }),
+            (Some(previous_rank_index), None) => Some(GuildActivity::Leave { rank_index: previous_rank_index }),
+            // Lower rank indexes are the higher ranks
+            (Some(previous_rank_index), Some(rank_index)) if rank_index < previous_rank_index => Some(GuildActivity::Promotion { previous_rank_index, rank_index }),
+            (Some(previous_rank_index), Some(rank_index)) if rank_index > previous_rank_index => Some(GuildActivity::Demotion { previous_rank_index, rank_index }),
+            _ => None,
+        };
+        if let Some(activity) = activity {
+            result.push(GuildActivityDto {
+                timestamp: membership.timestamp,
+                character_id: Some(membership.character_id),
+                character_name: Some(membership.character_name.clone()),
+                activity,
+            });
+        }
+        previous = Some(membership);
+    }
+    result
+}
@@ -1,22 +1,30 @@
 pub use self::{
-    character::*, character_facial::*, character_gear::*, character_history::*, character_info::*, character_item::*, character_search::PerformCharacterSearch, character_viewer::CharacterViewer, get_character_item_stats::get_character_stats,
-    guild::*, guild_rank::*, guild_viewer::GuildViewer, talent_specialization::*,
+    character::*, character_claim::*, character_facial::*, character_gear::*, character_history::*, character_history_diff::*, character_info::*, character_item::*, character_search::PerformCharacterSearch,
+    character_sheet::{build_character_sheet, calculate_character_sheet, rating_per_percent}, character_viewer::CharacterViewer, gear_audit::*, gear_card::GearCard, gear_comparison::*,
+    get_character_item_stats::{gear_to_item_id_vec, get_character_gear_stats, get_character_stats, get_itemset_pieces}, guild::*, guild_activity::*, guild_rank::*, guild_viewer::GuildViewer, talent_specialization::*,
 };
 
 pub use self::character_arena_team::*;
 
 mod character;
 mod character_arena_team;
+mod character_claim;
 mod character_facial;
 mod character_gear;
 mod character_history;
+mod character_history_diff;
 mod character_info;
 mod character_item;
 mod character_search;
+mod character_sheet;
 mod character_viewer;
 
+mod gear_audit;
+mod gear_card;
+mod gear_comparison;
 mod get_character_item_stats;
 mod guild;
+mod guild_activity;
 mod guild_rank;
 mod guild_viewer;
 mod talent_specialization;
@@ -1,7 +1,13 @@
+use crate::modules::armory::dto::{CharacterViewerTalentDto, CharacterViewerTalentsDto};
+use crate::modules::armory::util::talent_tree::{decode_talent_specialization, get_main_talent_tree, get_talent_points};
+use crate::modules::data::domain_value::{Talent, TalentSpecRule};
+use crate::modules::data::tools::{RetrieveHeroClass, RetrieveIcon, RetrieveLocalization, RetrieveSpell, RetrieveTalent, RetrieveTalentSpecRule};
+use crate::modules::data::Data;
+
 // Check if this breakdown is effectively null, i.e. 000|000|000
 pub fn strip_talent_specialization(spec: &Option<String>) -> Option<String> {
     if let Some(spec_str) = spec {
-        if spec_str.split('|').map(|spec| spec.chars().map(|talent| talent.to_digit(10).unwrap()).sum::<u32>()).sum::<u32>() > 0 {
+        if decode_talent_specialization(spec_str).map(|talent_ranks| get_talent_points(&talent_ranks).iter().sum::<u32>()).unwrap_or(0) > 0 {
             spec.clone()
         } else {
             None
@@ -10,3 +16,80 @@ pub fn strip_talent_specialization(spec: &Option<String>) -> Option<String> {
         None
     }
 }
+
+pub fn build_talent_specialization(data: &Data, expansion_id: u8, language_id: u8, hero_class_id: u8, level: u8, description: String) -> Option<CharacterViewerTalentsDto> {
+    let talent_ranks = decode_talent_specialization(&description)?;
+    let tree_index = get_main_talent_tree(&talent_ranks)?;
+    let hero_class = data.get_hero_class(hero_class_id)?;
+    let tree = hero_class.talents.get(tree_index as usize)?;
+    let talents = data.get_talents(expansion_id, hero_class_id);
+    let points = get_talent_points(&talent_ranks);
+    let points_available = talent_points_available(level);
+
+    let name = detect_talent_spec(&data.get_talent_spec_rules(expansion_id, hero_class_id), &talent_ranks)
+        .map(|talent_spec_rule| talent_spec_rule.name.clone())
+        .or_else(|| data.get_localization(language_id, tree.localization_id).map(|localization| localization.content))?;
+    let talent_dtos = talents
+        .iter()
+        .filter_map(|talent| {
+            let rank = get_rank(&talent_ranks, talent.tree_index, talent.talent_index);
+            if rank == 0 {
+                return None;
+            }
+            let spell = data.get_spell(expansion_id, talent.spell_id)?;
+            Some(CharacterViewerTalentDto {
+                tree_index: talent.tree_index,
+                talent_index: talent.talent_index,
+                rank,
+                max_rank: talent.max_rank,
+                spell_id: talent.spell_id,
+                name: data.get_localization(language_id, spell.localization_id).map(|localization| localization.content).unwrap_or_default(),
+                icon: data.get_icon(spell.icon).map(|icon| icon.name).unwrap_or_default(),
+            })
+        })
+        .collect();
+
+    Some(CharacterViewerTalentsDto {
+        icon: data.get_icon(tree.icon)?.name,
+        name,
+        description,
+        tree_index,
+        is_valid: points.iter().sum::<u32>() <= points_available && has_valid_ranks(&talents, &talent_ranks),
+        points,
+        points_available,
+        talents: talent_dtos,
+    })
+}
+
+/// The first rule of the main tree whose talent requirement is met, otherwise its rule without a requirement
+pub fn detect_talent_spec<'a>(talent_spec_rules: &'a [TalentSpecRule], talent_ranks: &[Vec<u8>]) -> Option<&'a TalentSpecRule> {
+    let tree_index = get_main_talent_tree(talent_ranks)?;
+    let tree_rules: Vec<&TalentSpecRule> = talent_spec_rules.iter().filter(|talent_spec_rule| talent_spec_rule.tree_index == tree_index).collect();
+    tree_rules
+        .iter()
+        .find(|talent_spec_rule| talent_spec_rule.talent_index.map(|talent_index| get_rank(talent_ranks, tree_index, talent_index) >= talent_spec_rule.min_rank).unwrap_or(false))
+        .or_else(|| tree_rules.iter().find(|talent_spec_rule| talent_spec_rule.talent_index.is_none()))
+        .cloned()
+}
+
+/// One talent point per level, starting at level 10
+pub fn talent_points_available(level: u8) -> u32 {
+    (level as u32).saturating_sub(9)
+}
+
+/// Every spent point must belong to a talent of the talent table and stay within its ranks
+pub fn has_valid_ranks(talents: &[Talent], talent_ranks: &[Vec<u8>]) -> bool {
+    talent_ranks.iter().enumerate().all(|(tree_index, tree)| {
+        tree.iter().enumerate().filter(|(_, rank)| **rank > 0).all(|(talent_index, rank)| {
+            talents
+                .iter()
+                .find(|talent| talent.tree_index as usize == tree_index && talent.talent_index as usize == talent_index)
+                .map(|talent| *rank <= talent.max_rank)
+                .unwrap_or(false)
+        })
+    })
+}
+
+fn get_rank(talent_ranks: &[Vec<u8>], tree_index: u8, talent_index: u8) -> u8 {
+    talent_ranks.get(tree_index as usize).and_then(|tree| tree.get(talent_index as usize)).cloned().unwrap_or(0)
+}
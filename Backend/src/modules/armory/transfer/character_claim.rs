@@ -0,0 +1,42 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::{
+    account::guard::Authenticate,
+    armory::{
+        dto::{ArmoryFailure, BasicCharacter},
+        material::CharacterClaim,
+        tools::{CharacterClaims, GetCharacter},
+        Armory,
+    },
+};
+use crate::MainDb;
+
+#[openapi]
+#[post("/character_claim/<character_id>")]
+pub fn request_character_claim(mut db_main: MainDb, me: State<Armory>, auth: Authenticate, character_id: u32) -> Result<Json<CharacterClaim>, ArmoryFailure> {
+    me.request_character_claim(&mut *db_main, auth.0, character_id).map(Json)
+}
+
+#[openapi]
+#[delete("/character_claim/<claim_id>")]
+pub fn revoke_character_claim(mut db_main: MainDb, me: State<Armory>, auth: Authenticate, claim_id: u32) -> Result<(), ArmoryFailure> {
+    me.revoke_character_claim(&mut *db_main, auth.0, claim_id)
+}
+
+#[openapi]
+#[get("/character_claim")]
+pub fn get_character_claims(me: State<Armory>, auth: Authenticate) -> Json<Vec<CharacterClaim>> {
+    Json(me.get_character_claims(auth.0))
+}
+
+#[openapi]
+#[get("/character_claim/alts/<character_id>")]
+pub fn get_character_alts(mut db_main: MainDb, me: State<Armory>, character_id: u32) -> Json<Vec<BasicCharacter>> {
+    Json(
+        me.get_character_alts(character_id)
+            .into_iter()
+            .filter_map(|alt_character_id| me.get_basic_character(&mut *db_main, alt_character_id, u64::MAX))
+            .collect(),
+    )
+}
@@ -2,13 +2,15 @@ use rocket::State;
 use rocket_contrib::json::Json;
 
 use crate::modules::{
-    account::guard::ServerOwner,
+    account::guard::{ServerOwner, Viewer},
     armory::{
-        dto::{ArmoryFailure, CharacterHistoryDto},
+        dto::{ArmoryFailure, CharacterHistoryDiff, CharacterHistoryDto, CharacterTimelineEntry},
         material::CharacterHistory,
-        tools::{DeleteCharacterHistory, GetCharacterHistory, SetCharacterHistory},
+        tools::{link_looted_items, DeleteCharacterHistory, DiffCharacterHistory, GetCharacterHistory, SetCharacterHistory},
         Armory,
     },
+    data::Data,
+    instance::{dto::LootHistoryFilter, tools::LootHistory, Instance},
 };
 use crate::MainDb;
 
@@ -29,3 +31,22 @@ pub fn get_character_history(mut db_main: MainDb, me: State<Armory>, id: u32) ->
 pub fn delete_character_history(mut db_main: MainDb, me: State<Armory>, id: u32) -> Result<(), ArmoryFailure> {
     me.delete_character_history(&mut *db_main, id)
 }
+
+#[openapi]
+#[get("/character_history/diff/<previous_history_id>/<history_id>")]
+pub fn get_character_history_diff(mut db_main: MainDb, me: State<Armory>, previous_history_id: u32, history_id: u32) -> Result<Json<CharacterHistoryDiff>, ArmoryFailure> {
+    me.get_character_history_diff(&mut *db_main, previous_history_id, history_id).map(Json)
+}
+
+#[openapi]
+#[get("/character_timeline/<character_id>")]
+pub fn get_character_timeline(mut db_main: MainDb, me: State<Armory>, instance: State<Instance>, data: State<Data>, viewer: Viewer, character_id: u32) -> Result<Json<Vec<CharacterTimelineEntry>>, ArmoryFailure> {
+    let mut timeline = me.get_character_timeline(&mut *db_main, character_id)?;
+    let loot: Vec<(u32, u32, u64)> = instance
+        .get_character_loot(&mut *db_main, &data, &viewer, character_id, &LootHistoryFilter { start_ts: None, end_ts: None })
+        .into_iter()
+        .map(|entry| (entry.item_id, entry.instance_meta_id, entry.looted_ts))
+        .collect();
+    link_looted_items(&mut timeline, &loot);
+    Ok(Json(timeline))
+}
@@ -4,12 +4,14 @@ use rocket_contrib::json::Json;
 use crate::modules::{
     armory::{
         dto::{ArmoryFailure, CharacterViewerDto},
-        tools::{CharacterViewer, GetCharacter},
+        tools::{CharacterViewer, GearCard, GetCharacter},
         Armory,
     },
     data::{guard::Language, tools::RetrieveServer, Data},
 };
 use crate::MainDb;
+use rocket::http::ContentType;
+use rocket::response::content::Content;
 use rocket::response::Redirect;
 
 #[openapi]
@@ -67,3 +69,9 @@ pub fn get_character_viewer_picture(mut db_main: MainDb, me: State<Armory>, data
     );
     Ok(Redirect::to(uri))
 }
+
+#[openapi(skip)]
+#[get("/character_viewer_card/<character_history_id>")]
+pub fn get_character_viewer_card(mut db_main: MainDb, me: State<Armory>, data: State<Data>, character_history_id: u32) -> Result<Content<Vec<u8>>, ArmoryFailure> {
+    me.get_gear_card(&mut *db_main, &data, character_history_id).map(|gear_card| Content(ContentType::PNG, gear_card))
+}
@@ -0,0 +1,17 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::{
+    armory::{
+        dto::{ArmoryFailure, CharacterGearAudit},
+        tools::GearAudit,
+        Armory,
+    },
+    data::Data,
+};
+
+#[openapi]
+#[get("/gear_audit/<character_id>")]
+pub fn get_character_gear_audit(me: State<Armory>, data: State<Data>, character_id: u32) -> Result<Json<CharacterGearAudit>, ArmoryFailure> {
+    me.get_character_gear_audit(&data, character_id).map(Json)
+}
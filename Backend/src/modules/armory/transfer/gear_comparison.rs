@@ -0,0 +1,18 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::{
+    armory::{
+        dto::{ArmoryFailure, GearComparison, GearSlotReplacement},
+        tools::CompareGear,
+        Armory,
+    },
+    data::{guard::Language, Data},
+};
+use crate::MainDb;
+
+#[openapi]
+#[post("/gear_comparison/<character_history_id>", format = "application/json", data = "<replacements>")]
+pub fn get_gear_comparison(mut db_main: MainDb, me: State<Armory>, data: State<Data>, language: Language, character_history_id: u32, replacements: Json<Vec<GearSlotReplacement>>) -> Result<Json<GearComparison>, ArmoryFailure> {
+    me.get_gear_comparison(&mut *db_main, &data, language.0, character_history_id, replacements.into_inner()).map(Json)
+}
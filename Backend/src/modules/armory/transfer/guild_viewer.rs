@@ -1,15 +1,17 @@
 use rocket::State;
 use rocket_contrib::json::Json;
 
+use crate::dto::SearchResult;
 use crate::modules::armory::dto::GuildViewerMemberDto;
 use crate::modules::{
     armory::{
-        dto::{ArmoryFailure, GuildViewerDto},
-        tools::{GetGuild, GuildViewer},
+        dto::{ArmoryFailure, GuildActivityDto, GuildViewerDto},
+        tools::{GetGuild, GetGuildActivity, GuildViewer},
         Armory,
     },
     data::{tools::RetrieveServer, Data},
 };
+use crate::MainDb;
 
 #[openapi]
 #[get("/guild_view/<server_name>/<guild_name>")]
@@ -24,3 +26,9 @@ pub fn get_guild_view(me: State<Armory>, data: State<Data>, server_name: String,
 pub fn get_guild_roster(me: State<Armory>, data: State<Data>, guild_id: u32) -> Json<Vec<GuildViewerMemberDto>> {
     Json(me.get_guild_roster(&data, guild_id))
 }
+
+#[openapi]
+#[get("/guild_activity/<guild_id>/<page>")]
+pub fn get_guild_activity(mut db_main: MainDb, me: State<Armory>, guild_id: u32, page: u32) -> Result<Json<SearchResult<GuildActivityDto>>, ArmoryFailure> {
+    me.get_guild_activity(&mut *db_main, guild_id, page).map(Json)
+}
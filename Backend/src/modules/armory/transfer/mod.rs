@@ -1,6 +1,9 @@
 pub mod character;
+pub mod character_claim;
 pub mod character_history;
 pub mod character_search;
 pub mod character_viewer;
+pub mod gear_audit;
+pub mod gear_comparison;
 pub mod guild;
 pub mod guild_viewer;
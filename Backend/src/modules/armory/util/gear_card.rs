@@ -0,0 +1,359 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use image::imageops::FilterType;
+use image::{ImageOutputFormat, Rgba, RgbaImage};
+
+use crate::modules::armory::dto::{CharacterViewerDto, CharacterViewerItemDto};
+
+pub static CARD_WIDTH: u32 = 400;
+pub static CARD_HEIGHT: u32 = 422;
+pub static ICON_SIZE: u32 = 32;
+
+static MARGIN: u32 = 12;
+static BORDER: u32 = 2;
+static SLOT_SIZE: u32 = ICON_SIZE + 2 * BORDER;
+static SLOT_SPACING: u32 = SLOT_SIZE + 2;
+static SLOTS_TOP: u32 = 64;
+static GLYPH_WIDTH: u32 = 5;
+static GLYPH_HEIGHT: u32 = 7;
+
+static BACKGROUND: Rgba<u8> = Rgba([21, 25, 30, 255]);
+static EMPTY_SLOT: Rgba<u8> = Rgba([26, 26, 26, 255]);
+static EMPTY_SLOT_BORDER: Rgba<u8> = Rgba([58, 58, 58, 255]);
+static TEXT: Rgba<u8> = Rgba([232, 232, 232, 255]);
+static TEXT_MUTED: Rgba<u8> = Rgba([157, 157, 157, 255]);
+
+/// PNG of the gear card of a character: Name, guild and spec on top, the gear in the layout of the in-game character sheet and the key stats in between.
+/// Item levels are looked up by item id, icons that can not be loaded are left empty.
+pub fn render_gear_card(character_viewer: &CharacterViewerDto, item_levels: &HashMap<u32, u16>, average_item_level: f64, load_icon: impl Fn(&str) -> Option<RgbaImage>) -> Vec<u8> {
+    let mut card = RgbaImage::from_pixel(CARD_WIDTH, CARD_HEIGHT, BACKGROUND);
+    let class_color = get_hero_class_color(character_viewer.hero_class_id);
+
+    // Header
+    draw_text(&mut card, &character_viewer.name, MARGIN, MARGIN, 2, class_color);
+    if let Some(guild) = character_viewer.guild.as_ref() {
+        draw_text(&mut card, &format!("<{}>", guild.name), MARGIN, 32, 1, TEXT_MUTED);
+    }
+    let spec_name = character_viewer.talent_specialization.as_ref().map(|talents| format!(" {}", talents.name)).unwrap_or_default();
+    draw_text(&mut card, &format!("LEVEL {}{} - {}", character_viewer.level, spec_name, character_viewer.server_name), MARGIN, 44, 1, TEXT);
+    fill_rect(&mut card, MARGIN, 55, CARD_WIDTH - 2 * MARGIN, 2, class_color);
+
+    // Gear
+    let gear = &character_viewer.gear;
+    let left_column = [&gear.head, &gear.neck, &gear.shoulder, &gear.back, &gear.chest, &gear.shirt, &gear.tabard, &gear.wrist];
+    let right_column = [&gear.glove, &gear.belt, &gear.leg, &gear.boot, &gear.ring1, &gear.ring2, &gear.trinket1, &gear.trinket2];
+    for (row, item) in left_column.iter().enumerate() {
+        let y = SLOTS_TOP + row as u32 * SLOT_SPACING;
+        draw_slot(&mut card, item.as_ref(), MARGIN, y, &load_icon);
+        if let Some(item_level) = find_item_level(item.as_ref(), item_levels) {
+            draw_text(&mut card, &item_level.to_string(), MARGIN + SLOT_SIZE + 4, y + (SLOT_SIZE - GLYPH_HEIGHT) / 2, 1, TEXT_MUTED);
+        }
+    }
+    for (row, item) in right_column.iter().enumerate() {
+        let x = CARD_WIDTH - MARGIN - SLOT_SIZE;
+        let y = SLOTS_TOP + row as u32 * SLOT_SPACING;
+        draw_slot(&mut card, item.as_ref(), x, y, &load_icon);
+        if let Some(item_level) = find_item_level(item.as_ref(), item_levels) {
+            let item_level = item_level.to_string();
+            draw_text(&mut card, &item_level, x - 4 - text_width(&item_level, 1), y + (SLOT_SIZE - GLYPH_HEIGHT) / 2, 1, TEXT_MUTED);
+        }
+    }
+    for (column, item) in [&gear.main_hand, &gear.off_hand, &gear.ternary_hand].iter().enumerate() {
+        let x = 92 + column as u32 * 80;
+        let y = SLOTS_TOP + 8 * SLOT_SPACING + 4;
+        draw_slot(&mut card, item.as_ref(), x, y, &load_icon);
+        if let Some(item_level) = find_item_level(item.as_ref(), item_levels) {
+            draw_text(&mut card, &item_level.to_string(), x + SLOT_SIZE + 4, y + (SLOT_SIZE - GLYPH_HEIGHT) / 2, 1, TEXT_MUTED);
+        }
+    }
+
+    // Stats
+    let stats_left = MARGIN + SLOT_SIZE + 36;
+    let stats_right = CARD_WIDTH - stats_left;
+    for (row, (label, value)) in get_key_stats(character_viewer, average_item_level).iter().enumerate() {
+        let y = SLOTS_TOP + 8 + row as u32 * 12;
+        draw_text(&mut card, label, stats_left, y, 1, TEXT_MUTED);
+        draw_text(&mut card, value, stats_right - text_width(value, 1), y, 1, TEXT);
+    }
+
+    let mut png = Vec::new();
+    image::DynamicImage::ImageRgba8(card).write_to(&mut Cursor::new(&mut png), ImageOutputFormat::Png).unwrap();
+    png
+}
+
+/// Label and value of the stats shown on the card, mana is omitted for classes without it and everything but the item level without a character sheet
+pub fn get_key_stats(character_viewer: &CharacterViewerDto, average_item_level: f64) -> Vec<(String, String)> {
+    let mut key_stats = vec![(String::from("ITEM LEVEL"), format!("{:.1}", average_item_level))];
+    let sheet = match character_viewer.sheet.as_ref() {
+        Some(sheet) => sheet,
+        None => return key_stats,
+    };
+    key_stats.push((String::from("HEALTH"), sheet.health.to_string()));
+    if sheet.mana > 0 {
+        key_stats.push((String::from("MANA"), sheet.mana.to_string()));
+    }
+    key_stats.extend(
+        vec![
+            ("ARMOR", sheet.armor.to_string()),
+            ("STRENGTH", sheet.strength.to_string()),
+            ("AGILITY", sheet.agility.to_string()),
+            ("STAMINA", sheet.stamina.to_string()),
+            ("INTELLECT", sheet.intellect.to_string()),
+            ("SPIRIT", sheet.spirit.to_string()),
+            ("ATTACK POWER", sheet.attack_power.max(sheet.ranged_attack_power).to_string()),
+            ("SPELL POWER", sheet.spell_power.to_string()),
+            ("HEALING", sheet.healing_power.to_string()),
+            ("CRIT", format!("{:.2}%", sheet.crit_chance)),
+            ("SPELL CRIT", format!("{:.2}%", sheet.spell_crit_chance)),
+            ("HIT", format!("{:.2}%", sheet.hit_chance)),
+        ]
+        .into_iter()
+        .map(|(label, value)| (String::from(label), value)),
+    );
+    key_stats
+}
+
+/// Same colours as the item borders of the webclient
+pub fn get_quality_color(quality: u8) -> Rgba<u8> {
+    match quality {
+        0 => Rgba([255, 209, 0, 255]),
+        1 => Rgba([157, 157, 157, 255]),
+        2 => Rgba([232, 232, 232, 255]),
+        3 => Rgba([30, 255, 0, 255]),
+        4 => Rgba([0, 112, 255, 255]),
+        5 => Rgba([163, 53, 238, 255]),
+        6 => Rgba([255, 128, 0, 255]),
+        7 => Rgba([230, 204, 144, 255]),
+        8 => Rgba([0, 204, 255, 255]),
+        _ => EMPTY_SLOT_BORDER,
+    }
+}
+
+pub fn get_hero_class_color(hero_class_id: u8) -> Rgba<u8> {
+    match hero_class_id {
+        1 => Rgba([199, 156, 110, 255]),
+        2 => Rgba([245, 140, 186, 255]),
+        3 => Rgba([171, 212, 115, 255]),
+        4 => Rgba([255, 245, 105, 255]),
+        6 | 10 => Rgba([196, 31, 59, 255]),
+        7 => Rgba([0, 112, 222, 255]),
+        8 => Rgba([105, 204, 240, 255]),
+        9 => Rgba([148, 130, 201, 255]),
+        11 => Rgba([255, 125, 10, 255]),
+        12 => Rgba([163, 48, 201, 255]),
+        _ => Rgba([255, 255, 255, 255]),
+    }
+}
+
+/// Width in pixels of a text drawn with the card font at the given scale
+pub fn text_width(text: &str, scale: u32) -> u32 {
+    // Upper casing can change the number of characters, e.g. 'ß' is drawn as "SS"
+    (text.chars().flat_map(|chr| chr.to_uppercase()).count() as u32 * (GLYPH_WIDTH + 1)).saturating_sub(1) * scale
+}
+
+fn find_item_level(item: Option<&CharacterViewerItemDto>, item_levels: &HashMap<u32, u16>) -> Option<u16> {
+    item.and_then(|item| item_levels.get(&item.item_id)).cloned()
+}
+
+fn draw_slot(card: &mut RgbaImage, item: Option<&CharacterViewerItemDto>, x: u32, y: u32, load_icon: &impl Fn(&str) -> Option<RgbaImage>) {
+    let border_color = item.map(|item| get_quality_color(item.quality)).unwrap_or(EMPTY_SLOT_BORDER);
+    fill_rect(card, x, y, SLOT_SIZE, SLOT_SIZE, border_color);
+    fill_rect(card, x + BORDER, y + BORDER, ICON_SIZE, ICON_SIZE, EMPTY_SLOT);
+    if let Some(icon) = item.and_then(|item| load_icon(&item.icon)) {
+        let icon = image::imageops::resize(&icon, ICON_SIZE, ICON_SIZE, FilterType::Triangle);
+        image::imageops::overlay(card, &icon, (x + BORDER) as i64, (y + BORDER) as i64);
+    }
+}
+
+fn fill_rect(card: &mut RgbaImage, x: u32, y: u32, width: u32, height: u32, color: Rgba<u8>) {
+    for pixel_y in y..(y + height).min(card.height()) {
+        for pixel_x in x..(x + width).min(card.width()) {
+            card.put_pixel(pixel_x, pixel_y, color);
+        }
+    }
+}
+
+/// Draws upper cased text, characters the font does not know are drawn as '?'. The font covers ASCII, Latin-1 and Cyrillic
+fn draw_text(card: &mut RgbaImage, text: &str, x: u32, y: u32, scale: u32, color: Rgba<u8>) {
+    for (index, chr) in text.chars().flat_map(|chr| chr.to_uppercase()).enumerate() {
+        let glyph_x = x + index as u32 * (GLYPH_WIDTH + 1) * scale;
+        for (row, bits) in get_glyph(chr).iter().enumerate() {
+            for column in 0..GLYPH_WIDTH {
+                if bits & (1 << (GLYPH_WIDTH - 1 - column)) != 0 {
+                    fill_rect(card, glyph_x + column * scale, y + row as u32 * scale, scale, scale, color);
+                }
+            }
+        }
+    }
+}
+
+/// Rows of a 5x7 glyph, the highest of the 5 bits is the leftmost pixel
+pub fn get_glyph(chr: char) -> [u8; 7] {
+    match chr {
+        'A' => [0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001],
+        'B' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110],
+        'C' => [0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110],
+        'D' => [0b11110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b11110],
+        'E' => [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111],
+        'F' => [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000],
+        'G' => [0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111],
+        'H' => [0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001],
+        'I' => [0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        'J' => [0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100],
+        'K' => [0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001],
+        'L' => [0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111],
+        'M' => [0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001],
+        'N' => [0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001],
+        'O' => [0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110],
+        'P' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000],
+        'Q' => [0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101],
+        'R' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001],
+        'S' => [0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110],
+        'T' => [0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100],
+        'U' => [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110],
+        'V' => [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100],
+        'W' => [0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010],
+        'X' => [0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001],
+        'Y' => [0b10001, 0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100],
+        'Z' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111],
+        '0' => [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110],
+        '1' => [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        '2' => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111],
+        '3' => [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110],
+        '4' => [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010],
+        '5' => [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110],
+        '6' => [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110],
+        '7' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000],
+        '8' => [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110],
+        '9' => [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100],
+        ' ' => [0; 7],
+        '.' => [0, 0, 0, 0, 0, 0b01100, 0b01100],
+        ',' => [0, 0, 0, 0, 0b01100, 0b00100, 0b01000],
+        '-' => [0, 0, 0, 0b11111, 0, 0, 0],
+        '+' => [0, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0],
+        ':' => [0, 0b01100, 0b01100, 0, 0b01100, 0b01100, 0],
+        '/' => [0, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0],
+        '\'' => [0b00100, 0b00100, 0b01000, 0, 0, 0, 0],
+        '%' => [0b11000, 0b11001, 0b00010, 0b00100, 0b01000, 0b10011, 0b00011],
+        '<' => [0b00010, 0b00100, 0b01000, 0b10000, 0b01000, 0b00100, 0b00010],
+        '>' => [0b01000, 0b00100, 0b00010, 0b00001, 0b00010, 0b00100, 0b01000],
+        '(' => [0b00010, 0b00100, 0b01000, 0b01000, 0b01000, 0b00100, 0b00010],
+        ')' => [0b01000, 0b00100, 0b00010, 0b00010, 0b00010, 0b00100, 0b01000],
+        '?' => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0, 0b00100],
+        '!' => [0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0, 0b00100],
+        '"' => [0b01010, 0b01010, 0, 0, 0, 0, 0],
+        '#' => [0b01010, 0b01010, 0b11111, 0b01010, 0b11111, 0b01010, 0b01010],
+        '&' => [0b01100, 0b10010, 0b10100, 0b01000, 0b10101, 0b10010, 0b01101],
+        '*' => [0, 0b00100, 0b10101, 0b01110, 0b10101, 0b00100, 0],
+        ';' => [0, 0b01100, 0b01100, 0, 0b01100, 0b00100, 0b01000],
+        '=' => [0, 0, 0b11111, 0, 0b11111, 0, 0],
+        '_' => [0, 0, 0, 0, 0, 0, 0b11111],
+        '[' => [0b01110, 0b01000, 0b01000, 0b01000, 0b01000, 0b01000, 0b01110],
+        ']' => [0b01110, 0b00010, 0b00010, 0b00010, 0b00010, 0b00010, 0b01110],
+        // Latin-1
+        'À' => with_mark(GRAVE, UPPER_A),
+        'Á' => with_mark(ACUTE, UPPER_A),
+        'Â' => with_mark(CIRCUMFLEX, UPPER_A),
+        'Ã' => with_mark(TILDE, UPPER_A),
+        'Ä' => with_mark(DIAERESIS, UPPER_A),
+        'Å' => with_mark(RING, UPPER_A),
+        'Æ' => [0b01111, 0b10100, 0b10100, 0b11110, 0b10100, 0b10100, 0b10111],
+        'Ç' => [0b01110, 0b10001, 0b10000, 0b10001, 0b01110, 0b00100, 0b01000],
+        'È' => with_mark(GRAVE, UPPER_E),
+        'É' => with_mark(ACUTE, UPPER_E),
+        'Ê' => with_mark(CIRCUMFLEX, UPPER_E),
+        'Ë' => with_mark(DIAERESIS, UPPER_E),
+        'Ì' => with_mark(GRAVE, UPPER_I),
+        'Í' => with_mark(ACUTE, UPPER_I),
+        'Î' => with_mark(CIRCUMFLEX, UPPER_I),
+        'Ï' => with_mark(DIAERESIS, UPPER_I),
+        'Ð' => [0b11110, 0b01001, 0b01001, 0b11101, 0b01001, 0b01001, 0b11110],
+        'Ñ' => with_mark(TILDE, UPPER_N),
+        'Ò' => with_mark(GRAVE, UPPER_O),
+        'Ó' => with_mark(ACUTE, UPPER_O),
+        'Ô' => with_mark(CIRCUMFLEX, UPPER_O),
+        'Õ' => with_mark(TILDE, UPPER_O),
+        'Ö' => with_mark(DIAERESIS, UPPER_O),
+        '×' => [0, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0],
+        'Ø' => [0b01111, 0b10011, 0b10101, 0b10101, 0b10101, 0b11001, 0b11110],
+        'Ù' => with_mark(GRAVE, UPPER_U),
+        'Ú' => with_mark(ACUTE, UPPER_U),
+        'Û' => with_mark(CIRCUMFLEX, UPPER_U),
+        'Ü' => with_mark(DIAERESIS, UPPER_U),
+        'Ý' => with_mark(ACUTE, UPPER_Y),
+        'Þ' => [0b10000, 0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000],
+        'Ÿ' => with_mark(DIAERESIS, UPPER_Y),
+        // Cyrillic, letters that look like latin ones share their glyph
+        'А' => get_glyph('A'),
+        'В' => get_glyph('B'),
+        'Е' => get_glyph('E'),
+        'К' => get_glyph('K'),
+        'М' => get_glyph('M'),
+        'Н' => get_glyph('H'),
+        'О' => get_glyph('O'),
+        'Р' => get_glyph('P'),
+        'С' => get_glyph('C'),
+        'Т' => get_glyph('T'),
+        'Х' => get_glyph('X'),
+        'Ѕ' => get_glyph('S'),
+        'І' => get_glyph('I'),
+        'Ј' => get_glyph('J'),
+        'Ѐ' => get_glyph('È'),
+        'Ё' => get_glyph('Ë'),
+        'Ї' => get_glyph('Ï'),
+        'Б' => [0b11111, 0b10000, 0b10000, 0b11110, 0b10001, 0b10001, 0b11110],
+        'Г' => [0b11111, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000],
+        'Ѓ' => with_mark(ACUTE, [0b11111, 0b10000, 0b10000, 0b10000, 0b10000]),
+        'Д' => [0b00110, 0b01010, 0b01010, 0b01010, 0b01010, 0b11111, 0b10001],
+        'Ђ' => [0b11100, 0b01000, 0b01110, 0b01001, 0b01001, 0b01001, 0b01010],
+        'Є' => [0b01110, 0b10001, 0b10000, 0b11100, 0b10000, 0b10001, 0b01110],
+        'Ж' => [0b10101, 0b10101, 0b10101, 0b01110, 0b10101, 0b10101, 0b10101],
+        'З' => [0b01110, 0b10001, 0b00001, 0b00110, 0b00001, 0b10001, 0b01110],
+        'И' => [0b10001, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b10001],
+        'Ѝ' => with_mark(GRAVE, UPPER_I_CYRILLIC),
+        'Й' => with_mark(BREVE, UPPER_I_CYRILLIC),
+        'Ќ' => with_mark(ACUTE, [0b10001, 0b10010, 0b11100, 0b10010, 0b10001]),
+        'Л' => [0b00111, 0b01001, 0b01001, 0b01001, 0b01001, 0b01001, 0b10001],
+        'Љ' => [0b01100, 0b10100, 0b10100, 0b10110, 0b10101, 0b10101, 0b10110],
+        'Њ' => [0b10100, 0b10100, 0b10100, 0b11110, 0b10101, 0b10101, 0b10110],
+        'П' => [0b11111, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001],
+        'Ћ' => [0b11100, 0b01000, 0b01110, 0b01001, 0b01001, 0b01001, 0b01001],
+        'У' => [0b10001, 0b10001, 0b10001, 0b01111, 0b00001, 0b10001, 0b01110],
+        'Ў' => with_mark(BREVE, [0b10001, 0b10001, 0b01111, 0b00001, 0b01110]),
+        'Ф' => [0b00100, 0b01110, 0b10101, 0b10101, 0b10101, 0b01110, 0b00100],
+        'Ц' => [0b10010, 0b10010, 0b10010, 0b10010, 0b10010, 0b11111, 0b00001],
+        'Ч' => [0b10001, 0b10001, 0b10001, 0b01111, 0b00001, 0b00001, 0b00001],
+        'Џ' => [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b11111, 0b00100],
+        'Ш' => [0b10101, 0b10101, 0b10101, 0b10101, 0b10101, 0b10101, 0b11111],
+        'Щ' => [0b10101, 0b10101, 0b10101, 0b10101, 0b10101, 0b11111, 0b00001],
+        'Ъ' => [0b11000, 0b01000, 0b01000, 0b01110, 0b01001, 0b01001, 0b01110],
+        'Ы' => [0b10001, 0b10001, 0b10001, 0b11101, 0b10011, 0b10011, 0b11101],
+        'Ь' => [0b10000, 0b10000, 0b10000, 0b11110, 0b10001, 0b10001, 0b11110],
+        'Э' => [0b01110, 0b10001, 0b00001, 0b00111, 0b00001, 0b10001, 0b01110],
+        'Ю' => [0b10010, 0b10101, 0b10101, 0b11101, 0b10101, 0b10101, 0b10010],
+        'Я' => [0b01111, 0b10001, 0b10001, 0b01111, 0b00101, 0b01001, 0b10001],
+        _ => get_glyph('?'),
+    }
+}
+
+// Diacritics take the upper two rows, the letter below is squeezed into five
+static GRAVE: [u8; 2] = [0b01000, 0b00100];
+static ACUTE: [u8; 2] = [0b00010, 0b00100];
+static CIRCUMFLEX: [u8; 2] = [0b00100, 0b01010];
+static TILDE: [u8; 2] = [0b01101, 0b10010];
+static DIAERESIS: [u8; 2] = [0b01010, 0];
+static RING: [u8; 2] = [0b01110, 0b01010];
+static BREVE: [u8; 2] = [0b10001, 0b01110];
+static UPPER_A: [u8; 5] = [0b01110, 0b10001, 0b11111, 0b10001, 0b10001];
+static UPPER_E: [u8; 5] = [0b11111, 0b10000, 0b11110, 0b10000, 0b11111];
+static UPPER_I: [u8; 5] = [0b01110, 0b00100, 0b00100, 0b00100, 0b01110];
+static UPPER_N: [u8; 5] = [0b10001, 0b11001, 0b10101, 0b10011, 0b10001];
+static UPPER_O: [u8; 5] = [0b01110, 0b10001, 0b10001, 0b10001, 0b01110];
+static UPPER_U: [u8; 5] = [0b10001, 0b10001, 0b10001, 0b10001, 0b01110];
+static UPPER_Y: [u8; 5] = [0b10001, 0b01010, 0b00100, 0b00100, 0b00100];
+static UPPER_I_CYRILLIC: [u8; 5] = [0b10001, 0b10011, 0b10101, 0b11001, 0b10001];
+
+fn with_mark(mark: [u8; 2], letter: [u8; 5]) -> [u8; 7] {
+    [mark[0], mark[1], letter[0], letter[1], letter[2], letter[3], letter[4]]
+}
@@ -1 +1,2 @@
 pub mod talent_tree;
+pub mod gear_card;
@@ -1,11 +1,27 @@
-pub fn get_talent_tree(talent_str: &str) -> u8 {
-    talent_str
-        .split('|')
-        .collect::<Vec<&str>>()
+/// Ranks per talent and tree. Ranks are base 36 digits, as some encodings exceed 9 ranks.
+pub fn decode_talent_specialization(talent_str: &str) -> Option<Vec<Vec<u8>>> {
+    talent_str.split('|').map(|tree_str| tree_str.chars().map(|chr| chr.to_digit(36).map(|rank| rank as u8)).collect()).collect()
+}
+
+pub fn get_talent_points(talent_ranks: &[Vec<u8>]) -> Vec<u32> {
+    talent_ranks.iter().map(|tree| tree.iter().map(|rank| *rank as u32).sum()).collect()
+}
+
+/// Index of the tree with the most points, if any were spent
+pub fn get_main_talent_tree(talent_ranks: &[Vec<u8>]) -> Option<u8> {
+    get_talent_points(talent_ranks)
         .into_iter()
-        .map(|tree_str| tree_str.chars().map(|chr| chr.to_digit(10).unwrap()).sum::<u32>())
         .enumerate()
+        .filter(|(_, points)| *points > 0)
         .max_by(|(_, left), (_, right)| left.cmp(right))
-        .unwrap()
-        .0 as u8
+        .map(|(tree_index, _)| tree_index as u8)
+}
+
+pub fn get_talent_tree(talent_str: &str) -> u8 {
+    decode_talent_specialization(talent_str).and_then(|talent_ranks| get_main_talent_tree(&talent_ranks)).unwrap_or(0)
+}
+
+/// Spec ids start at 1 for the first tree
+pub fn get_talent_spec_id(talent_str: &str) -> Option<u8> {
+    decode_talent_specialization(talent_str).and_then(|talent_ranks| get_main_talent_tree(&talent_ranks)).map(|tree_index| tree_index + 1)
 }
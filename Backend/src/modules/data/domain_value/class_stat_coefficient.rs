@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq)]
+pub struct ClassStatCoefficient {
+    pub expansion_id: u8,
+    pub hero_class_id: u8,
+    pub level: u8,
+    pub agility_per_crit: f64,
+    pub intellect_per_spell_crit: Option<f64>,
+}
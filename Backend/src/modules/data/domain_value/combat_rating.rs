@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq)]
+pub struct CombatRating {
+    pub expansion_id: u8,
+    pub stat_type: u8,
+    // At level 60
+    pub rating_per_percent: f64,
+}
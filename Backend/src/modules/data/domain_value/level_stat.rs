@@ -0,0 +1,14 @@
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq)]
+pub struct LevelStat {
+    pub expansion_id: u8,
+    pub race_id: u8,
+    pub hero_class_id: u8,
+    pub level: u8,
+    pub strength: u16,
+    pub agility: u16,
+    pub stamina: u16,
+    pub intellect: u16,
+    pub spirit: u16,
+    pub health: u32,
+    pub mana: u32,
+}
@@ -0,0 +1,26 @@
+/// Boss of a raid that counts towards a full clear. Entries without a difficulty apply to
+/// every difficulty of the map that does not define entries of its own.
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq)]
+pub struct MapEncounter {
+    pub map_id: u16,
+    pub difficulty_id: Option<u8>,
+    pub encounter_id: u32,
+    pub is_required: bool,
+}
+
+impl MapEncounter {
+    pub fn for_difficulty(map_encounters: &[MapEncounter], difficulty_id: u8) -> Vec<MapEncounter> {
+        let has_difficulty_variant = map_encounters.iter().any(|map_encounter| map_encounter.difficulty_id == Some(difficulty_id));
+        map_encounters
+            .iter()
+            .filter(|map_encounter| {
+                if has_difficulty_variant {
+                    map_encounter.difficulty_id == Some(difficulty_id)
+                } else {
+                    map_encounter.difficulty_id.is_none()
+                }
+            })
+            .cloned()
+            .collect()
+    }
+}
@@ -2,7 +2,8 @@ pub use self::{
     difficulty::Difficulty, dispel_type::DispelType, enchant::Enchant, encounter::Encounter, encounter_npc::EncounterNpc, expansion::Expansion, gem::Gem, hero_class::HeroClass, hero_class_talent::HeroClassTalent, icon::Icon, item::Item,
     item_bonding::ItemBonding, item_class::ItemClass, item_damage::ItemDamage, item_damage_type::ItemDamageType, item_effect::ItemEffect, item_inventory_type::ItemInventoryType, item_quality::ItemQuality, item_random_property::ItemRandomProperty,
     item_random_property_points::ItemRandomPropertyPoints, item_sheath::ItemSheath, item_socket::ItemSocket, item_stat::ItemStat, itemset_effect::ItemsetEffect, itemset_name::ItemsetName, language::Language, localization::Localization,
-    localized::Localized, map::Map, npc::NPC, power_type::PowerType, profession::Profession, race::Race, server::Server, spell::Spell, spell_effect::SpellEffect, stat::Stat, stat_type::StatType, title::Title, addon::Addon
+    localized::Localized, map::Map, map_encounter::MapEncounter, npc::NPC, power_type::PowerType, profession::Profession, race::Race, server::Server, spell::Spell, spell_effect::SpellEffect, stat::Stat, stat_type::StatType, title::Title,
+    addon::Addon, class_stat_coefficient::ClassStatCoefficient, combat_rating::CombatRating, level_stat::LevelStat, talent::Talent, talent_spec_rule::TalentSpecRule, talent_stat_modifier::TalentStatModifier,
 };
 
 mod difficulty;
@@ -34,6 +35,7 @@ mod language;
 mod localization;
 mod localized;
 mod map;
+mod map_encounter;
 mod npc;
 mod power_type;
 mod profession;
@@ -45,3 +47,9 @@ mod stat;
 mod stat_type;
 mod title;
 mod addon;
+mod class_stat_coefficient;
+mod combat_rating;
+mod level_stat;
+mod talent;
+mod talent_spec_rule;
+mod talent_stat_modifier;
@@ -0,0 +1,9 @@
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq)]
+pub struct Talent {
+    pub expansion_id: u8,
+    pub hero_class_id: u8,
+    pub tree_index: u8,
+    pub talent_index: u8,
+    pub spell_id: u32,
+    pub max_rank: u8,
+}
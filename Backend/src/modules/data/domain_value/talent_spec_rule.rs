@@ -0,0 +1,10 @@
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq)]
+pub struct TalentSpecRule {
+    pub id: u32,
+    pub expansion_id: u8,
+    pub hero_class_id: u8,
+    pub tree_index: u8,
+    pub talent_index: Option<u8>,
+    pub min_rank: u8,
+    pub name: String,
+}
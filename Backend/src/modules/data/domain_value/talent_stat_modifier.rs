@@ -0,0 +1,9 @@
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq)]
+pub struct TalentStatModifier {
+    pub expansion_id: u8,
+    pub hero_class_id: u8,
+    pub tree_index: u8,
+    pub talent_index: u8,
+    pub stat_type: u8,
+    pub value_per_rank: f64,
+}
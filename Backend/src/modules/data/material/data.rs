@@ -10,7 +10,7 @@ use crate::modules::data::{
     },
     language::init::Init as DictionaryInit,
 };
-use crate::modules::data::domain_value::{Addon, Difficulty, Encounter, EncounterNpc, Map};
+use crate::modules::data::domain_value::{Addon, ClassStatCoefficient, CombatRating, Difficulty, Encounter, EncounterNpc, LevelStat, Map, MapEncounter, Talent, TalentSpecRule, TalentStatModifier};
 use crate::util::database::*;
 
 #[derive(Debug)]
@@ -52,7 +52,14 @@ pub struct Data {
     pub difficulties: HashMap<u8, Difficulty>,
     pub encounters: HashMap<u32, Encounter>,
     pub encounter_npcs: HashMap<u32, EncounterNpc>,
+    pub map_encounters: HashMap<u16, Vec<MapEncounter>>,
     pub addons: HashMap<u32, Addon>,
+    pub level_stats: HashMap<(u8, u8, u8, u8), LevelStat>,
+    pub combat_ratings: HashMap<(u8, u8), CombatRating>,
+    pub class_stat_coefficients: HashMap<(u8, u8, u8), ClassStatCoefficient>,
+    pub talent_stat_modifiers: HashMap<(u8, u8), Vec<TalentStatModifier>>,
+    pub talents: HashMap<(u8, u8), Vec<Talent>>,
+    pub talent_spec_rules: HashMap<(u8, u8), Vec<TalentSpecRule>>,
 }
 
 impl Default for Data {
@@ -97,7 +104,14 @@ impl Default for Data {
             difficulties: HashMap::new(),
             encounters: HashMap::new(),
             encounter_npcs: HashMap::new(),
+            map_encounters: HashMap::new(),
             addons: HashMap::new(),
+            level_stats: HashMap::new(),
+            combat_ratings: HashMap::new(),
+            class_stat_coefficients: HashMap::new(),
+            talent_stat_modifiers: HashMap::new(),
+            talents: HashMap::new(),
+            talent_spec_rules: HashMap::new(),
         }
     }
 }
@@ -143,7 +157,14 @@ impl Data {
         self.difficulties.init(db_main);
         self.encounters.init(db_main);
         self.encounter_npcs.init(db_main);
+        self.map_encounters.init(db_main);
         self.addons.init(db_main);
+        self.level_stats.init(db_main);
+        self.combat_ratings.init(db_main);
+        self.class_stat_coefficients.init(db_main);
+        self.talent_stat_modifiers.init(db_main);
+        self.talents.init(db_main);
+        self.talent_spec_rules.init(db_main);
         self
     }
 }
@@ -211,7 +232,10 @@ impl Init for Vec<HashMap<u32, Localization>> {
             .into_iter()
             .for_each(|result| {
                 if result.language_id != last_language_id {
-                    self.push(HashMap::new());
+                    // Language ids are not necessarily contiguous
+                    while self.len() < result.language_id as usize {
+                        self.push(HashMap::new());
+                    }
                     last_language_id = result.language_id;
                 }
                 let localizations = self.get_mut(result.language_id as usize - 1).unwrap();
@@ -931,6 +955,22 @@ impl Init for HashMap<u32, Encounter> {
     }
 }
 
+impl Init for HashMap<u16, Vec<MapEncounter>> {
+    fn init(&mut self, db_main: &mut impl Select) {
+        db_main
+            .select("SELECT map_id, difficulty_id, encounter_id, is_required FROM data_map_encounter", |mut row| MapEncounter {
+                map_id: row.take(0).unwrap(),
+                difficulty_id: row.take_opt(1).unwrap().ok(),
+                encounter_id: row.take(2).unwrap(),
+                is_required: row.take(3).unwrap(),
+            })
+            .into_iter()
+            .for_each(|result| {
+                self.entry(result.map_id).or_insert_with(Vec::new).push(result);
+            });
+    }
+}
+
 impl Init for HashMap<u32, EncounterNpc> {
     fn init(&mut self, db_main: &mut impl Select) {
         db_main
@@ -948,3 +988,128 @@ impl Init for HashMap<u32, EncounterNpc> {
             });
     }
 }
+
+impl Init for HashMap<(u8, u8, u8, u8), LevelStat> {
+    fn init(&mut self, db_main: &mut impl Select) {
+        db_main
+            .select(
+                "SELECT expansion_id, race_id, hero_class_id, level, strength, agility, stamina, intellect, spirit, health, mana FROM data_level_stat",
+                |mut row| LevelStat {
+                    expansion_id: row.take(0).unwrap(),
+                    race_id: row.take(1).unwrap(),
+                    hero_class_id: row.take(2).unwrap(),
+                    level: row.take(3).unwrap(),
+                    strength: row.take(4).unwrap(),
+                    agility: row.take(5).unwrap(),
+                    stamina: row.take(6).unwrap(),
+                    intellect: row.take(7).unwrap(),
+                    spirit: row.take(8).unwrap(),
+                    health: row.take(9).unwrap(),
+                    mana: row.take(10).unwrap(),
+                },
+            )
+            .into_iter()
+            .for_each(|result| {
+                self.insert((result.expansion_id, result.race_id, result.hero_class_id, result.level), result);
+            });
+    }
+}
+
+impl Init for HashMap<(u8, u8), CombatRating> {
+    fn init(&mut self, db_main: &mut impl Select) {
+        db_main
+            .select("SELECT expansion_id, stat_type, rating_per_percent FROM data_combat_rating", |mut row| CombatRating {
+                expansion_id: row.take(0).unwrap(),
+                stat_type: row.take(1).unwrap(),
+                rating_per_percent: row.take(2).unwrap(),
+            })
+            .into_iter()
+            .for_each(|result| {
+                self.insert((result.expansion_id, result.stat_type), result);
+            });
+    }
+}
+
+impl Init for HashMap<(u8, u8, u8), ClassStatCoefficient> {
+    fn init(&mut self, db_main: &mut impl Select) {
+        db_main
+            .select(
+                "SELECT expansion_id, hero_class_id, level, agility_per_crit, intellect_per_spell_crit FROM data_class_stat_coefficient",
+                |mut row| ClassStatCoefficient {
+                    expansion_id: row.take(0).unwrap(),
+                    hero_class_id: row.take(1).unwrap(),
+                    level: row.take(2).unwrap(),
+                    agility_per_crit: row.take(3).unwrap(),
+                    intellect_per_spell_crit: row.take_opt(4).unwrap().ok(),
+                },
+            )
+            .into_iter()
+            .for_each(|result| {
+                self.insert((result.expansion_id, result.hero_class_id, result.level), result);
+            });
+    }
+}
+
+impl Init for HashMap<(u8, u8), Vec<TalentStatModifier>> {
+    fn init(&mut self, db_main: &mut impl Select) {
+        db_main
+            .select(
+                "SELECT expansion_id, hero_class_id, tree_index, talent_index, stat_type, value_per_rank FROM data_talent_stat_modifier",
+                |mut row| TalentStatModifier {
+                    expansion_id: row.take(0).unwrap(),
+                    hero_class_id: row.take(1).unwrap(),
+                    tree_index: row.take(2).unwrap(),
+                    talent_index: row.take(3).unwrap(),
+                    stat_type: row.take(4).unwrap(),
+                    value_per_rank: row.take(5).unwrap(),
+                },
+            )
+            .into_iter()
+            .for_each(|result| {
+                self.entry((result.expansion_id, result.hero_class_id)).or_insert_with(Vec::new).push(result);
+            });
+    }
+}
+
+impl Init for HashMap<(u8, u8), Vec<Talent>> {
+    fn init(&mut self, db_main: &mut impl Select) {
+        db_main
+            .select(
+                "SELECT expansion_id, hero_class_id, tree_index, talent_index, spell_id, max_rank FROM data_talent ORDER BY tree_index, talent_index",
+                |mut row| Talent {
+                    expansion_id: row.take(0).unwrap(),
+                    hero_class_id: row.take(1).unwrap(),
+                    tree_index: row.take(2).unwrap(),
+                    talent_index: row.take(3).unwrap(),
+                    spell_id: row.take(4).unwrap(),
+                    max_rank: row.take(5).unwrap(),
+                },
+            )
+            .into_iter()
+            .for_each(|result| {
+                self.entry((result.expansion_id, result.hero_class_id)).or_insert_with(Vec::new).push(result);
+            });
+    }
+}
+
+impl Init for HashMap<(u8, u8), Vec<TalentSpecRule>> {
+    fn init(&mut self, db_main: &mut impl Select) {
+        db_main
+            .select(
+                "SELECT id, expansion_id, hero_class_id, tree_index, talent_index, min_rank, name FROM data_talent_spec_rule ORDER BY id",
+                |mut row| TalentSpecRule {
+                    id: row.take(0).unwrap(),
+                    expansion_id: row.take(1).unwrap(),
+                    hero_class_id: row.take(2).unwrap(),
+                    tree_index: row.take(3).unwrap(),
+                    talent_index: row.take_opt(4).unwrap().ok(),
+                    min_rank: row.take(5).unwrap(),
+                    name: row.take(6).unwrap(),
+                },
+            )
+            .into_iter()
+            .for_each(|result| {
+                self.entry((result.expansion_id, result.hero_class_id)).or_insert_with(Vec::new).push(result);
+            });
+    }
+}
@@ -0,0 +1,21 @@
+use crate::modules::data::domain_value::ClassStatCoefficient;
+use crate::modules::data::{tools::RetrieveClassStatCoefficient, Data};
+
+#[test]
+fn get_class_stat_coefficient() {
+    let mut data = Data::default();
+    let class_stat_coefficient = ClassStatCoefficient {
+        expansion_id: 1,
+        hero_class_id: 4,
+        level: 60,
+        agility_per_crit: 29.0,
+        intellect_per_spell_crit: None,
+    };
+    data.class_stat_coefficients.insert((1, 4, 60), class_stat_coefficient.clone());
+
+    let class_stat_coefficient_res = data.get_class_stat_coefficient(1, 4, 60);
+    assert!(class_stat_coefficient_res.is_some());
+    assert_eq!(class_stat_coefficient_res.unwrap(), class_stat_coefficient);
+    let no_class_stat_coefficient = data.get_class_stat_coefficient(2, 4, 60);
+    assert!(no_class_stat_coefficient.is_none());
+}
@@ -0,0 +1,20 @@
+use crate::modules::data::domain_value::CombatRating;
+use crate::modules::data::{tools::RetrieveCombatRating, Data};
+
+#[test]
+fn get_combat_rating() {
+    let mut data = Data::default();
+    let combat_rating = CombatRating {
+        expansion_id: 2,
+        stat_type: 8,
+        rating_per_percent: 14.0,
+    };
+    data.combat_ratings.insert((2, 8), combat_rating.clone());
+    data.combat_ratings.insert((1, 8), combat_rating.clone());
+
+    let combat_rating_res = data.get_combat_rating(2, 8);
+    assert!(combat_rating_res.is_some());
+    assert_eq!(combat_rating_res.unwrap(), combat_rating);
+    let no_combat_rating = data.get_combat_rating(1, 8);
+    assert!(no_combat_rating.is_none());
+}
@@ -0,0 +1,27 @@
+use crate::modules::data::domain_value::LevelStat;
+use crate::modules::data::{tools::RetrieveLevelStat, Data};
+
+#[test]
+fn get_level_stat() {
+    let mut data = Data::default();
+    let level_stat = LevelStat {
+        expansion_id: 2,
+        race_id: 1,
+        hero_class_id: 1,
+        level: 70,
+        strength: 145,
+        agility: 96,
+        stamina: 132,
+        intellect: 33,
+        spirit: 53,
+        health: 4264,
+        mana: 0,
+    };
+    data.level_stats.insert((2, 1, 1, 70), level_stat.clone());
+
+    let level_stat_res = data.get_level_stat(2, 1, 1, 70);
+    assert!(level_stat_res.is_some());
+    assert_eq!(level_stat_res.unwrap(), level_stat);
+    let no_level_stat = data.get_level_stat(2, 1, 1, 69);
+    assert!(no_level_stat.is_none());
+}
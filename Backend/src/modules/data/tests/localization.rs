@@ -23,3 +23,41 @@ fn get_localization() {
     let no_localization = data.get_localization(0, 0);
     assert!(no_localization.is_none());
 }
+
+#[test]
+fn get_localization_or_english() {
+    let mut data = Data::default();
+    let mut english = HashMap::new();
+    english.insert(
+        1,
+        Localization {
+            id: 1,
+            language_id: 1,
+            content: "Onyxia".to_string(),
+        },
+    );
+    english.insert(
+        2,
+        Localization {
+            id: 2,
+            language_id: 1,
+            content: "Flamestrike".to_string(),
+        },
+    );
+    let mut german = HashMap::new();
+    german.insert(
+        2,
+        Localization {
+            id: 2,
+            language_id: 2,
+            content: "Flammenstoß".to_string(),
+        },
+    );
+    data.localization.push(english);
+    data.localization.push(german);
+
+    assert_eq!(data.get_localization_or_english(2, 1).map(|localization| localization.content), Some("Onyxia".to_string()));
+    assert_eq!(data.get_localization_or_english(2, 2).map(|localization| localization.content), Some("Flammenstoß".to_string()));
+    assert!(data.get_localization(2, 1).is_none());
+    assert!(data.get_localization_or_english(2, 3).is_none());
+}
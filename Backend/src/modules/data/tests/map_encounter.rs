@@ -0,0 +1,31 @@
+use crate::modules::data::domain_value::MapEncounter;
+use crate::modules::data::{tools::RetrieveMapEncounter, Data};
+
+fn map_encounter(difficulty_id: Option<u8>, encounter_id: u32, is_required: bool) -> MapEncounter {
+    MapEncounter {
+        map_id: 603,
+        difficulty_id,
+        encounter_id,
+        is_required,
+    }
+}
+
+#[test]
+fn get_map_encounters() {
+    let mut data = Data::default();
+    data.map_encounters.insert(603, vec![map_encounter(None, 114, true), map_encounter(None, 127, false), map_encounter(Some(4), 146, true)]);
+
+    let default_encounters = data.get_map_encounters(603, 3);
+    assert_eq!(default_encounters, vec![map_encounter(None, 114, true), map_encounter(None, 127, false)]);
+    let variant_encounters = data.get_map_encounters(603, 4);
+    assert_eq!(variant_encounters, vec![map_encounter(Some(4), 146, true)]);
+    let no_encounters = data.get_map_encounters(0, 3);
+    assert!(no_encounters.is_empty());
+}
+
+#[test]
+fn get_all_map_encounters() {
+    let data = Data::default();
+    let map_encounters = data.get_all_map_encounters();
+    assert!(map_encounters.is_empty());
+}
@@ -1,3 +1,5 @@
+mod class_stat_coefficient;
+mod combat_rating;
 mod difficulty;
 mod dispel_type;
 mod enchant;
@@ -23,8 +25,10 @@ mod item_stat;
 mod itemset_effect;
 mod itemset_name;
 mod language;
+mod level_stat;
 mod localization;
 mod map;
+mod map_encounter;
 mod npc;
 mod power_type;
 mod profession;
@@ -34,4 +38,7 @@ mod spell;
 mod spell_description;
 mod spell_effect;
 mod stat_type;
+mod talent;
+mod talent_spec_rule;
+mod talent_stat_modifier;
 mod title;
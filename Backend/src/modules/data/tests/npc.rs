@@ -1,4 +1,4 @@
-use crate::modules::data::domain_value::NPC;
+use crate::modules::data::domain_value::{Localization, NPC};
 use crate::modules::data::{tools::RetrieveNPC, Data};
 use std::collections::HashMap;
 
@@ -26,3 +26,34 @@ fn get_npc() {
     let no_npc = data.get_npc(0, 0);
     assert!(no_npc.is_none());
 }
+
+#[test]
+fn get_npc_by_name_without_translation() {
+    let mut data = Data::default();
+    let npc = NPC {
+        expansion_id: 1,
+        id: 10184,
+        localization_id: 1,
+        is_boss: true,
+        friend: 0,
+        family: 0,
+        map_id: Some(249),
+    };
+    let mut npcs = HashMap::new();
+    npcs.insert(npc.id, npc.clone());
+    data.npcs.push(npcs);
+    let mut english = HashMap::new();
+    english.insert(
+        1,
+        Localization {
+            id: 1,
+            language_id: 1,
+            content: "Onyxia".to_string(),
+        },
+    );
+    data.localization.push(english);
+    data.localization.push(HashMap::new());
+
+    assert_eq!(data.get_npc_by_name(1, 2, &"Onyxia".to_string()), Some(npc));
+    assert!(data.get_npc_by_name(1, 2, &"Ragnaros".to_string()).is_none());
+}
@@ -0,0 +1,21 @@
+use crate::modules::data::domain_value::Talent;
+use crate::modules::data::{tools::RetrieveTalent, Data};
+
+#[test]
+fn get_talents() {
+    let mut data = Data::default();
+    let talent = Talent {
+        expansion_id: 2,
+        hero_class_id: 11,
+        tree_index: 1,
+        talent_index: 4,
+        spell_id: 16929,
+        max_rank: 3,
+    };
+    data.talents.insert((2, 11), vec![talent.clone()]);
+
+    let talents = data.get_talents(2, 11);
+    assert_eq!(talents, vec![talent]);
+    let no_talents = data.get_talents(1, 11);
+    assert!(no_talents.is_empty());
+}
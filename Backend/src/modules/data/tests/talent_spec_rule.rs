@@ -0,0 +1,22 @@
+use crate::modules::data::domain_value::TalentSpecRule;
+use crate::modules::data::{tools::RetrieveTalentSpecRule, Data};
+
+#[test]
+fn get_talent_spec_rules() {
+    let mut data = Data::default();
+    let talent_spec_rule = TalentSpecRule {
+        id: 1,
+        expansion_id: 2,
+        hero_class_id: 11,
+        tree_index: 1,
+        talent_index: Some(4),
+        min_rank: 3,
+        name: "Feral Tank".to_string(),
+    };
+    data.talent_spec_rules.insert((2, 11), vec![talent_spec_rule.clone()]);
+
+    let talent_spec_rules = data.get_talent_spec_rules(2, 11);
+    assert_eq!(talent_spec_rules, vec![talent_spec_rule]);
+    let no_talent_spec_rules = data.get_talent_spec_rules(2, 1);
+    assert!(no_talent_spec_rules.is_empty());
+}
@@ -0,0 +1,21 @@
+use crate::modules::data::domain_value::TalentStatModifier;
+use crate::modules::data::{tools::RetrieveTalentStatModifier, Data};
+
+#[test]
+fn get_talent_stat_modifiers() {
+    let mut data = Data::default();
+    let talent_stat_modifier = TalentStatModifier {
+        expansion_id: 1,
+        hero_class_id: 2,
+        tree_index: 0,
+        talent_index: 0,
+        stat_type: 27,
+        value_per_rank: 2.0,
+    };
+    data.talent_stat_modifiers.insert((1, 2), vec![talent_stat_modifier.clone()]);
+
+    let talent_stat_modifiers = data.get_talent_stat_modifiers(1, 2);
+    assert_eq!(talent_stat_modifiers, vec![talent_stat_modifier]);
+    let no_talent_stat_modifiers = data.get_talent_stat_modifiers(1, 1);
+    assert!(no_talent_stat_modifiers.is_empty());
+}
@@ -0,0 +1,11 @@
+use crate::modules::data::{domain_value::ClassStatCoefficient, Data};
+
+pub trait RetrieveClassStatCoefficient {
+    fn get_class_stat_coefficient(&self, expansion_id: u8, hero_class_id: u8, level: u8) -> Option<ClassStatCoefficient>;
+}
+
+impl RetrieveClassStatCoefficient for Data {
+    fn get_class_stat_coefficient(&self, expansion_id: u8, hero_class_id: u8, level: u8) -> Option<ClassStatCoefficient> {
+        self.class_stat_coefficients.get(&(expansion_id, hero_class_id, level)).cloned()
+    }
+}
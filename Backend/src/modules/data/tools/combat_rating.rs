@@ -0,0 +1,16 @@
+use crate::modules::data::{domain_value::CombatRating, Data};
+
+pub trait RetrieveCombatRating {
+    fn get_combat_rating(&self, expansion_id: u8, stat_type: u8) -> Option<CombatRating>;
+}
+
+impl RetrieveCombatRating for Data {
+    fn get_combat_rating(&self, expansion_id: u8, stat_type: u8) -> Option<CombatRating> {
+        // Combat ratings are introduced with TBC
+        if expansion_id <= 1 {
+            return None;
+        }
+
+        self.combat_ratings.get(&(expansion_id, stat_type)).cloned()
+    }
+}
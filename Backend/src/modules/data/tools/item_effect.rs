@@ -1,7 +1,11 @@
+use std::collections::HashSet;
+
+use crate::modules::data::tools::RetrieveItem;
 use crate::modules::data::{domain_value::ItemEffect, Data};
 
 pub trait RetrieveItemEffect {
     fn get_item_effect(&self, expansion_id: u8, item_id: u32) -> Option<Vec<ItemEffect>>;
+    fn get_consumable_spell_ids(&self, expansion_id: u8) -> HashSet<u32>;
 }
 
 impl RetrieveItemEffect for Data {
@@ -12,4 +16,21 @@ impl RetrieveItemEffect for Data {
 
         self.item_effects.get(expansion_id as usize - 1).and_then(|map| map.get(&item_id).cloned())
     }
+
+    /// Spells triggered by using an item of the item class "Consumable"
+    fn get_consumable_spell_ids(&self, expansion_id: u8) -> HashSet<u32> {
+        if expansion_id == 0 {
+            return HashSet::new();
+        }
+
+        self.item_effects
+            .get(expansion_id as usize - 1)
+            .map(|map| {
+                map.iter()
+                    .filter(|(item_id, _)| self.get_item(expansion_id, **item_id).map(|item| item.class_id == 0).unwrap_or(false))
+                    .flat_map(|(_, item_effects)| item_effects.iter().map(|item_effect| item_effect.spell_id))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 }
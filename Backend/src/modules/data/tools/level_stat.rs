@@ -0,0 +1,11 @@
+use crate::modules::data::{domain_value::LevelStat, Data};
+
+pub trait RetrieveLevelStat {
+    fn get_level_stat(&self, expansion_id: u8, race_id: u8, hero_class_id: u8, level: u8) -> Option<LevelStat>;
+}
+
+impl RetrieveLevelStat for Data {
+    fn get_level_stat(&self, expansion_id: u8, race_id: u8, hero_class_id: u8, level: u8) -> Option<LevelStat> {
+        self.level_stats.get(&(expansion_id, race_id, hero_class_id, level)).cloned()
+    }
+}
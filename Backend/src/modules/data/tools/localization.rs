@@ -2,6 +2,7 @@ use crate::modules::data::{domain_value::Localization, Data};
 
 pub trait RetrieveLocalization {
     fn get_localization(&self, language_id: u8, localization_id: u32) -> Option<Localization>;
+    fn get_localization_or_english(&self, language_id: u8, localization_id: u32) -> Option<Localization>;
 }
 
 impl RetrieveLocalization for Data {
@@ -12,4 +13,9 @@ impl RetrieveLocalization for Data {
 
         self.localization.get(language_id as usize - 1).and_then(|map| map.get(&localization_id).cloned())
     }
+
+    /// Most data is only shipped in English, so names without a translation are matched by their English name
+    fn get_localization_or_english(&self, language_id: u8, localization_id: u32) -> Option<Localization> {
+        self.get_localization(language_id, localization_id).or_else(|| self.get_localization(1, localization_id))
+    }
 }
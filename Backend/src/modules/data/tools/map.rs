@@ -5,7 +5,7 @@ use crate::modules::data::Data;
 pub trait RetrieveMap {
     fn get_map(&self, id: u16) -> Option<Map>;
     fn get_all_maps(&self) -> Vec<Map>;
-    fn get_map_by_name(&self, name: &String) -> Option<Map>;
+    fn get_map_by_name(&self, language_id: u8, name: &String) -> Option<Map>;
 }
 
 impl RetrieveMap for Data {
@@ -17,9 +17,9 @@ impl RetrieveMap for Data {
         self.maps.iter().map(|(_, map)| map.clone()).collect()
     }
 
-    fn get_map_by_name(&self, name: &String) -> Option<Map> {
+    fn get_map_by_name(&self, language_id: u8, name: &String) -> Option<Map> {
         self.maps.iter().find_map(|(_, map)| {
-            if self.get_localization(1, map.localization_id).map(|localization| localization.content).contains(name) {
+            if self.get_localization_or_english(language_id, map.localization_id).map(|localization| localization.content).contains(name) {
                 return Some(map.clone());
             }
             None
@@ -0,0 +1,18 @@
+use std::collections::HashMap;
+
+use crate::modules::data::{domain_value::MapEncounter, Data};
+
+pub trait RetrieveMapEncounter {
+    fn get_map_encounters(&self, map_id: u16, difficulty_id: u8) -> Vec<MapEncounter>;
+    fn get_all_map_encounters(&self) -> HashMap<u16, Vec<MapEncounter>>;
+}
+
+impl RetrieveMapEncounter for Data {
+    fn get_map_encounters(&self, map_id: u16, difficulty_id: u8) -> Vec<MapEncounter> {
+        self.map_encounters.get(&map_id).map(|map_encounters| MapEncounter::for_difficulty(map_encounters, difficulty_id)).unwrap_or_else(Vec::new)
+    }
+
+    fn get_all_map_encounters(&self) -> HashMap<u16, Vec<MapEncounter>> {
+        self.map_encounters.clone()
+    }
+}
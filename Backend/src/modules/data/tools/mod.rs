@@ -2,9 +2,10 @@ pub use self::{
     difficulty::RetrieveDifficulty, dispel_type::RetrieveDispelType, enchant::RetrieveEnchant, encounter::RetrieveEncounter, encounter_npc::RetrieveEncounterNpc, expansion::RetrieveExpansion, gem::RetrieveGem, hero_class::RetrieveHeroClass,
     icon::RetrieveIcon, item::RetrieveItem, item_bonding::RetrieveItemBonding, item_class::RetrieveItemClass, item_damage::RetrieveItemDamage, item_damage_type::RetrieveItemDamageType, item_effect::RetrieveItemEffect,
     item_inventory_type::RetrieveItemInventoryType, item_quality::RetrieveItemQuality, item_random_property::RetrieveItemRandomProperty, item_random_property_points::RetrieveItemRandomPropertyPoints, item_sheath::RetrieveItemSheath,
-    item_socket::RetrieveItemSocket, item_stat::RetrieveItemStat, itemset_effect::RetrieveItemsetEffect, itemset_name::RetrieveItemsetName, language::RetrieveLanguage, localization::RetrieveLocalization, map::RetrieveMap, npc::RetrieveNPC,
+    item_socket::RetrieveItemSocket, item_stat::RetrieveItemStat, itemset_effect::RetrieveItemsetEffect, itemset_name::RetrieveItemsetName, language::RetrieveLanguage, localization::RetrieveLocalization, map::RetrieveMap, map_encounter::RetrieveMapEncounter, npc::RetrieveNPC,
     power_type::RetrievePowerType, profession::RetrieveProfession, race::RetrieveRace, server::RetrieveServer, spell::RetrieveSpell, spell_description::SpellDescription, spell_effect::RetrieveSpellEffect, stat_type::RetrieveStatType,
-    title::RetrieveTitle, addon::RetrieveAddon
+    title::RetrieveTitle, addon::RetrieveAddon, class_stat_coefficient::RetrieveClassStatCoefficient, combat_rating::RetrieveCombatRating, level_stat::RetrieveLevelStat,
+    talent::RetrieveTalent, talent_spec_rule::RetrieveTalentSpecRule, talent_stat_modifier::RetrieveTalentStatModifier,
 };
 
 mod difficulty;
@@ -34,6 +35,7 @@ mod itemset_name;
 mod language;
 mod localization;
 mod map;
+mod map_encounter;
 mod npc;
 mod power_type;
 mod profession;
@@ -45,3 +47,9 @@ mod spell_effect;
 mod stat_type;
 mod title;
 mod addon;
+mod class_stat_coefficient;
+mod combat_rating;
+mod level_stat;
+mod talent;
+mod talent_spec_rule;
+mod talent_stat_modifier;
@@ -0,0 +1,11 @@
+use crate::modules::data::{domain_value::Talent, Data};
+
+pub trait RetrieveTalent {
+    fn get_talents(&self, expansion_id: u8, hero_class_id: u8) -> Vec<Talent>;
+}
+
+impl RetrieveTalent for Data {
+    fn get_talents(&self, expansion_id: u8, hero_class_id: u8) -> Vec<Talent> {
+        self.talents.get(&(expansion_id, hero_class_id)).cloned().unwrap_or_default()
+    }
+}
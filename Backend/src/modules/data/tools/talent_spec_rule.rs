@@ -0,0 +1,11 @@
+use crate::modules::data::{domain_value::TalentSpecRule, Data};
+
+pub trait RetrieveTalentSpecRule {
+    fn get_talent_spec_rules(&self, expansion_id: u8, hero_class_id: u8) -> Vec<TalentSpecRule>;
+}
+
+impl RetrieveTalentSpecRule for Data {
+    fn get_talent_spec_rules(&self, expansion_id: u8, hero_class_id: u8) -> Vec<TalentSpecRule> {
+        self.talent_spec_rules.get(&(expansion_id, hero_class_id)).cloned().unwrap_or_default()
+    }
+}
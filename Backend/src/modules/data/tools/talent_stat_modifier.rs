@@ -0,0 +1,11 @@
+use crate::modules::data::{domain_value::TalentStatModifier, Data};
+
+pub trait RetrieveTalentStatModifier {
+    fn get_talent_stat_modifiers(&self, expansion_id: u8, hero_class_id: u8) -> Vec<TalentStatModifier>;
+}
+
+impl RetrieveTalentStatModifier for Data {
+    fn get_talent_stat_modifiers(&self, expansion_id: u8, hero_class_id: u8) -> Vec<TalentStatModifier> {
+        self.talent_stat_modifiers.get(&(expansion_id, hero_class_id)).cloned().unwrap_or_default()
+    }
+}
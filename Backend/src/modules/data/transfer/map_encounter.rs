@@ -0,0 +1,10 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::data::{domain_value::MapEncounter, tools::RetrieveMapEncounter, Data};
+
+#[openapi]
+#[get("/map_encounter/<map_id>/<difficulty_id>")]
+pub fn get_map_encounters(me: State<Data>, map_id: u16, difficulty_id: u8) -> Json<Vec<MapEncounter>> {
+    Json(me.get_map_encounters(map_id, difficulty_id))
+}
@@ -25,6 +25,7 @@ pub mod itemset_name;
 pub mod language;
 pub mod localization;
 pub mod map;
+pub mod map_encounter;
 pub mod npc;
 pub mod power_type;
 pub mod profession;
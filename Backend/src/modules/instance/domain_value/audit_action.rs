@@ -0,0 +1,51 @@
+use crate::modules::instance::domain_value::PrivacyType;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub enum AuditAction {
+    Delete,
+    Restore,
+    Purge,
+    Unrank { attempt_id: u32 },
+    UpdatePrivacy(PrivacyType),
+}
+
+impl AuditAction {
+    pub fn to_u8(&self) -> u8 {
+        match self {
+            AuditAction::Delete => 0,
+            AuditAction::Restore => 1,
+            AuditAction::Purge => 2,
+            AuditAction::Unrank { .. } => 3,
+            AuditAction::UpdatePrivacy(_) => 4,
+        }
+    }
+
+    pub fn attempt_id(&self) -> Option<u32> {
+        match self {
+            AuditAction::Unrank { attempt_id } => Some(*attempt_id),
+            _ => None,
+        }
+    }
+
+    pub fn privacy_type(&self) -> Option<&PrivacyType> {
+        match self {
+            AuditAction::UpdatePrivacy(privacy_type) => Some(privacy_type),
+            _ => None,
+        }
+    }
+
+    /// Reconstructs the action from the columns of the audit log
+    pub fn from_columns(action: u8, attempt_id: Option<u32>, privacy_type: Option<u8>, privacy_ref: Option<u32>) -> Option<Self> {
+        match action {
+            0 => Some(AuditAction::Delete),
+            1 => Some(AuditAction::Restore),
+            2 => Some(AuditAction::Purge),
+            3 => attempt_id.map(|attempt_id| AuditAction::Unrank { attempt_id }),
+            4 => match privacy_type {
+                Some(privacy_option) if privacy_option <= 2 => Some(AuditAction::UpdatePrivacy(PrivacyType::new(privacy_option, privacy_ref.unwrap_or(0)))),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::modules::instance::dto::InstanceViewerAttempt;
+
+/// One column of an instance comparison: The compared attempts and the exported events of the instance
+#[derive(Debug, Clone)]
+pub struct ComparedInstance {
+    pub instance_meta_id: u32,
+    pub attempt_id: Option<u32>,
+    pub attempts: Vec<InstanceViewerAttempt>,
+    pub spell_casts: Vec<String>,
+    pub deaths: Vec<String>,
+    pub melee_damage: Vec<String>,
+    pub spell_damage: Vec<String>,
+    pub heals: Vec<String>,
+}
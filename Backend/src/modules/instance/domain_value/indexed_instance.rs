@@ -0,0 +1,28 @@
+use std::collections::BTreeSet;
+
+use crate::modules::instance::domain_value::{IndexedParticipant, PrivacyType};
+
+/// Precomputed search attributes of an instance, maintained by the instance thread
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedInstance {
+    pub instance_meta_id: u32,
+    pub meta_type: u8,
+    pub server_id: u32,
+    pub map_id: u16,
+    pub map_difficulty: Option<u8>,
+    pub start_ts: u64,
+    pub end_ts: Option<u64>,
+    pub participants: Vec<u32>,
+    pub guild: Option<(u32, String)>,
+    // Only for rated arenas and battlegrounds
+    pub pvp_participants: Vec<IndexedParticipant>,
+    pub killed_encounter_ids: BTreeSet<u32>,
+    pub uploaded_user: u32,
+    pub privacy_type: PrivacyType,
+}
+
+impl IndexedInstance {
+    pub fn duration(&self) -> Option<u64> {
+        self.end_ts.map(|end_ts| end_ts.saturating_sub(self.start_ts))
+    }
+}
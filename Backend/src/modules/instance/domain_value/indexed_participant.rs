@@ -0,0 +1,8 @@
+/// Class, race and arena teams of a participant at the start of the instance
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedParticipant {
+    pub character_id: u32,
+    pub hero_class_id: u8,
+    pub race_id: u8,
+    pub arena_team_ids: Vec<u32>,
+}
@@ -0,0 +1,49 @@
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub enum MergedRowType {
+    CharacterHistory,
+    CharacterClaim,
+    RankingDamage,
+    RankingHeal,
+    RankingThreat,
+    Loot,
+    Participation,
+}
+
+impl MergedRowType {
+    pub fn to_u8(&self) -> u8 {
+        match self {
+            MergedRowType::CharacterHistory => 0,
+            MergedRowType::CharacterClaim => 1,
+            MergedRowType::RankingDamage => 2,
+            MergedRowType::RankingHeal => 3,
+            MergedRowType::RankingThreat => 4,
+            MergedRowType::Loot => 5,
+            MergedRowType::Participation => 6,
+        }
+    }
+
+    pub fn from_u8(row_type: u8) -> Option<Self> {
+        match row_type {
+            0 => Some(MergedRowType::CharacterHistory),
+            1 => Some(MergedRowType::CharacterClaim),
+            2 => Some(MergedRowType::RankingDamage),
+            3 => Some(MergedRowType::RankingHeal),
+            4 => Some(MergedRowType::RankingThreat),
+            5 => Some(MergedRowType::Loot),
+            6 => Some(MergedRowType::Participation),
+            _ => None,
+        }
+    }
+
+    /// Table and id column of the instance rows, armory rows are reassigned by the armory
+    pub fn instance_table(&self) -> Option<(&'static str, &'static str)> {
+        match self {
+            MergedRowType::RankingDamage => Some(("instance_ranking_damage", "id")),
+            MergedRowType::RankingHeal => Some(("instance_ranking_heal", "id")),
+            MergedRowType::RankingThreat => Some(("instance_ranking_threat", "id")),
+            MergedRowType::Loot => Some(("instance_loot", "id")),
+            MergedRowType::Participation => Some(("instance_participants", "instance_meta_id")),
+            MergedRowType::CharacterHistory | MergedRowType::CharacterClaim => None,
+        }
+    }
+}
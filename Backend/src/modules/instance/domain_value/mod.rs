@@ -1,9 +1,21 @@
+pub use self::audit_action::AuditAction;
+pub use self::compared_instance::ComparedInstance;
+pub use self::indexed_instance::IndexedInstance;
+pub use self::indexed_participant::IndexedParticipant;
 pub use self::instance_meta::InstanceMeta;
+pub use self::merged_row_type::MergedRowType;
 pub use self::meta_type::MetaType;
 pub use self::instance_attempt::InstanceAttempt;
 pub use self::privacy_type::PrivacyType;
+pub use self::season::Season;
 
+mod audit_action;
+mod compared_instance;
+mod indexed_instance;
+mod indexed_participant;
 mod instance_meta;
+mod merged_row_type;
 mod meta_type;
 mod instance_attempt;
-mod privacy_type;
\ No newline at end of file
+mod privacy_type;
+mod season;
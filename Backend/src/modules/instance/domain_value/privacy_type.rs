@@ -1,3 +1,5 @@
+use crate::modules::account::guard::Viewer;
+
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[repr(u8)]
 pub enum PrivacyType {
@@ -23,6 +25,27 @@ impl PrivacyType {
         }
     }
 
+    /// Uploader and moderators can always read, everybody else depends on the privacy
+    pub fn grants_access(&self, uploaded_user: u32, viewer: &Viewer) -> bool {
+        if viewer.is_moderator || viewer.member_id.contains(&uploaded_user) {
+            return true;
+        }
+
+        match self {
+            Self::Public | Self::NotListed => true,
+            Self::OnlyGroups(group) => viewer.groups.contains(group),
+        }
+    }
+
+    /// Whether the instance shows up in searches and listings
+    pub fn is_listed_for(&self, uploaded_user: u32, viewer: &Viewer) -> bool {
+        match self {
+            Self::Public => true,
+            Self::NotListed => false,
+            Self::OnlyGroups(_) => self.grants_access(uploaded_user, viewer),
+        }
+    }
+
     pub fn to_u8(&self) -> u8 {
         match self {
             Self::Public => 0,
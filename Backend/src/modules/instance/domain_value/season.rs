@@ -0,0 +1,12 @@
+/// Named content phase of a server, or of every server of an expansion if `server_id` is not set.
+/// The `season_index` is the position of the phase within its calendar, starting at 1.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct Season {
+    pub id: u32,
+    pub server_id: Option<u32>,
+    pub expansion_id: u8,
+    pub name: String,
+    pub start_ts: u64,
+    pub end_ts: Option<u64>,
+    pub season_index: u8,
+}
@@ -0,0 +1,14 @@
+use crate::modules::instance::dto::SearchArenaTeam;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct ArenaTeamMatch {
+    pub instance_meta_id: u32,
+    pub map_id: u16,
+    pub start_ts: u64,
+    pub end_ts: Option<u64>,
+    pub opponent: SearchArenaTeam,
+    pub won: Option<bool>,
+    pub rating_change: i32,
+    // Sum of all rating changes up to and including this match
+    pub rating_progression: i32,
+}
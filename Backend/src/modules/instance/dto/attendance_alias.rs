@@ -0,0 +1,5 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct AttendanceAlias {
+    pub alt_character_id: u32,
+    pub main_character_id: u32,
+}
@@ -0,0 +1,5 @@
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct AttendanceFilter {
+    pub start_ts: Option<u64>,
+    pub end_ts: Option<u64>,
+}
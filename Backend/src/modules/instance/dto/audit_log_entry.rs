@@ -0,0 +1,12 @@
+use crate::modules::instance::domain_value::AuditAction;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct AuditLogEntry {
+    pub id: u32,
+    pub instance_meta_id: u32,
+    // None for actions of the background jobs or deleted accounts
+    pub member_id: Option<u32>,
+    pub action: AuditAction,
+    // In seconds
+    pub timestamp: u64,
+}
@@ -0,0 +1,9 @@
+use crate::modules::instance::dto::MapWinRate;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct BattlegroundWinRate {
+    pub character_id: u32,
+    pub num_matches: u32,
+    pub num_wins: u32,
+    pub maps: Vec<MapWinRate>,
+}
@@ -0,0 +1,11 @@
+use crate::modules::instance::dto::MapAttendance;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CharacterAttendance {
+    // The main character, raids of its alts are counted towards it
+    pub character_id: u32,
+    pub alt_character_ids: Vec<u32>,
+    pub attended: u32,
+    pub total: u32,
+    pub maps: Vec<MapAttendance>,
+}
@@ -0,0 +1,10 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CharacterMerge {
+    pub id: u32,
+    pub source_character_id: u32,
+    pub target_character_id: u32,
+    pub member_id: Option<u32>,
+    // In seconds
+    pub merged_ts: u64,
+    pub reverted_ts: Option<u64>,
+}
@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct ComparisonColumn {
+    pub instance_meta_id: u32,
+    pub attempt_id: Option<u32>,
+    // Summed duration of the compared attempts in ms
+    pub combat_duration: u64,
+    pub num_deaths: u32,
+}
@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct ComparisonTarget {
+    pub instance_meta_id: u32,
+    // If set, only this attempt of the instance is compared
+    pub attempt_id: Option<u32>,
+}
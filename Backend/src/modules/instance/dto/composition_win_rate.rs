@@ -0,0 +1,9 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CompositionWinRate {
+    pub size_type: u8,
+    pub season_index: u8,
+    // Sorted hero class ids of the team
+    pub hero_class_ids: Vec<u8>,
+    pub num_matches: u32,
+    pub num_wins: u32,
+}
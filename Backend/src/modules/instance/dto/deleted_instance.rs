@@ -0,0 +1,12 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct DeletedInstance {
+    pub instance_meta_id: u32,
+    pub server_id: u32,
+    pub map_id: u16,
+    pub start_ts: u64,
+    pub uploaded_user: u32,
+    pub deleted_by: Option<u32>,
+    // In seconds, the instance is purged for good after purge_ts
+    pub deleted_ts: u64,
+    pub purge_ts: u64,
+}
@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct EncounterKillTimes {
+    pub encounter_id: u32,
+    // Duration of the kill in ms per column, None if the encounter was not killed
+    pub durations: Vec<Option<u64>>,
+}
@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct EncounterProgression {
+    pub encounter_id: u32,
+    pub first_kill_ts: Option<u64>,
+    pub wipes_before_first_kill: u32,
+    pub killed_this_week: bool,
+}
@@ -0,0 +1,25 @@
+use crate::util::event_store::{EventFilter, EventSubject};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EventExportFilter {
+    pub start_ts: Option<u64>,
+    pub end_ts: Option<u64>,
+    pub character_ids: Vec<u32>,
+    pub creature_ids: Vec<u64>,
+}
+
+impl EventExportFilter {
+    pub fn to_event_filter(&self) -> EventFilter {
+        let subjects = self
+            .character_ids
+            .iter()
+            .map(|character_id| EventSubject::Player(*character_id))
+            .chain(self.creature_ids.iter().map(|creature_id| EventSubject::Creature(*creature_id)))
+            .collect();
+        EventFilter {
+            start_ts: self.start_ts,
+            end_ts: self.end_ts,
+            subjects: if self.character_ids.is_empty() && self.creature_ids.is_empty() { None } else { Some(subjects) },
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use crate::modules::instance::dto::CharacterAttendance;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct GuildAttendance {
+    pub guild_id: u32,
+    pub num_raids: u32,
+    pub characters: Vec<CharacterAttendance>,
+}
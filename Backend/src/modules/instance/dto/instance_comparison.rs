@@ -0,0 +1,8 @@
+use crate::modules::instance::dto::{ComparisonColumn, EncounterKillTimes, PlayerComparison};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct InstanceComparison {
+    pub columns: Vec<ComparisonColumn>,
+    pub kill_times: Vec<EncounterKillTimes>,
+    pub players: Vec<PlayerComparison>,
+}
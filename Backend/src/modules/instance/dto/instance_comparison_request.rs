@@ -0,0 +1,6 @@
+use crate::modules::instance::dto::ComparisonTarget;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct InstanceComparisonRequest {
+    pub targets: Vec<ComparisonTarget>,
+}
@@ -7,6 +7,7 @@ use std::io::Cursor;
 #[derive(Debug, JsonSchema)]
 pub enum InstanceFailure {
     InvalidInput,
+    AccessDenied,
     Unknown
 }
 
@@ -18,6 +19,10 @@ impl Responder<'static> for InstanceFailure {
                 body = "Invalid input!".to_owned();
                 Status::new(534, "InvalidInput")
             },
+            Self::AccessDenied => {
+                body = "Access denied!".to_owned();
+                Status::new(535, "AccessDenied")
+            },
             Self::Unknown => {
                 body = "Unknown error!".to_owned();
                 Status::new(599, "Unknown")
@@ -32,6 +37,7 @@ impl OpenApiResponder<'static> for InstanceFailure {
         let mut responses = Responses::default();
         let schema = gen.json_schema::<String>();
         add_schema_response(&mut responses, 534, "text/plain", schema.clone())?;
+        add_schema_response(&mut responses, 535, "text/plain", schema.clone())?;
         add_schema_response(&mut responses, 599, "text/plain", schema)?;
         Ok(responses)
     }
@@ -0,0 +1,6 @@
+/// Position after the last instance of a page, results are ordered by start_ts and instance_meta_id descending
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct InstanceSearchCursor {
+    pub start_ts: u64,
+    pub instance_meta_id: u32,
+}
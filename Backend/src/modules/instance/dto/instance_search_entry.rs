@@ -0,0 +1,18 @@
+use crate::modules::armory::dto::SearchGuildDto;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct InstanceSearchEntry {
+    pub instance_meta_id: u32,
+    pub meta_type: u8,
+    pub server_id: u32,
+    pub map_id: u16,
+    pub map_difficulty: Option<u8>,
+    pub map_icon: String,
+    pub guild: Option<SearchGuildDto>,
+    pub start_ts: u64,
+    pub end_ts: Option<u64>,
+    pub killed_encounter_ids: Vec<u32>,
+    pub can_delete: bool,
+    pub privacy_type: u8,
+    pub privacy_ref: u32,
+}
@@ -0,0 +1,21 @@
+use crate::modules::instance::dto::InstanceSearchCursor;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct InstanceSearchFilter {
+    pub meta_type: Option<u8>,
+    pub server_id: Option<u32>,
+    pub map_id: Option<u16>,
+    pub map_difficulty: Option<u8>,
+    pub character_id: Option<u32>,
+    pub guild_id: Option<u32>,
+    // Case insensitive part of the guild name
+    pub guild_name: Option<String>,
+    // At least one of these encounters was killed
+    pub killed_any_encounter_ids: Vec<u32>,
+    // All of these encounters were killed
+    pub killed_all_encounter_ids: Vec<u32>,
+    pub min_duration: Option<u64>,
+    pub max_duration: Option<u64>,
+    pub page_size: Option<u32>,
+    pub cursor: Option<InstanceSearchCursor>,
+}
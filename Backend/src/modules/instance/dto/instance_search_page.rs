@@ -0,0 +1,9 @@
+use crate::modules::instance::dto::{InstanceSearchCursor, InstanceSearchEntry};
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct InstanceSearchPage {
+    pub result: Vec<InstanceSearchEntry>,
+    pub num_items: usize,
+    // Not set on the last page
+    pub next_cursor: Option<InstanceSearchCursor>,
+}
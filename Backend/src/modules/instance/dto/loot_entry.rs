@@ -0,0 +1,13 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct LootEntry {
+    pub instance_meta_id: u32,
+    pub character_id: u32,
+    pub item_id: u32,
+    pub amount: u32,
+    pub looted_ts: u64,
+    // Last encounter that was killed before the item was looted
+    pub encounter_id: Option<u32>,
+    pub quality: Option<u8>,
+    pub icon: Option<String>,
+    pub inventory_type: Option<u8>,
+}
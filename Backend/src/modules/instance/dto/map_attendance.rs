@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct MapAttendance {
+    pub map_id: u16,
+    pub attended: u32,
+    pub total: u32,
+}
@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct MapWinRate {
+    pub map_id: u16,
+    pub num_matches: u32,
+    pub num_wins: u32,
+}
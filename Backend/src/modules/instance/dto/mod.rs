@@ -1,41 +1,105 @@
+pub use self::arena_team_match::ArenaTeamMatch;
+pub use self::attendance_alias::AttendanceAlias;
+pub use self::attendance_filter::AttendanceFilter;
+pub use self::audit_log_entry::AuditLogEntry;
 pub use self::battleground_search_filter::BattlegroundSearchFilter;
+pub use self::battleground_win_rate::BattlegroundWinRate;
+pub use self::character_attendance::CharacterAttendance;
+pub use self::character_merge::CharacterMerge;
+pub use self::comparison_column::ComparisonColumn;
+pub use self::comparison_target::ComparisonTarget;
+pub use self::composition_win_rate::CompositionWinRate;
+pub use self::deleted_instance::DeletedInstance;
+pub use self::encounter_kill_times::EncounterKillTimes;
+pub use self::encounter_progression::EncounterProgression;
+pub use self::event_export_filter::EventExportFilter;
+pub use self::guild_attendance::GuildAttendance;
+pub use self::instance_comparison::InstanceComparison;
+pub use self::instance_comparison_request::InstanceComparisonRequest;
 pub use self::instance_failure::InstanceFailure;
+pub use self::instance_search_cursor::InstanceSearchCursor;
+pub use self::instance_search_entry::InstanceSearchEntry;
+pub use self::instance_search_filter::InstanceSearchFilter;
+pub use self::instance_search_page::InstanceSearchPage;
 pub use self::instance_viewer_attempt::InstanceViewerAttempt;
 pub use self::instance_viewer_guild::InstanceViewerGuild;
 pub use self::instance_viewer_meta::InstanceViewerMeta;
 pub use self::instance_viewer_participant::InstanceViewerParticipant;
+pub use self::loot_entry::LootEntry;
+pub use self::loot_history_filter::LootHistoryFilter;
+pub use self::map_attendance::MapAttendance;
+pub use self::map_win_rate::MapWinRate;
 pub use self::meta_battleground_search::MetaBattlegroundSearch;
 pub use self::meta_raid_search::MetaRaidSearch;
 pub use self::meta_rated_arena_search::MetaRatedArenaSearch;
 pub use self::meta_skirmish_search::MetaSkirmishSearch;
+pub use self::player_comparison::PlayerComparison;
+pub use self::progression_ranking::ProgressionRanking;
+pub use self::pvp_match_summary::PvpMatchSummary;
+pub use self::pvp_participant_summary::PvpParticipantSummary;
+pub use self::raid_progression::RaidProgression;
 pub use self::raid_search_filter::RaidSearchFilter;
 pub use self::ranking_character_meta::RankingCharacterMeta;
 pub use self::ranking_result::RankingResult;
 pub use self::rated_arena_search_filter::RatedArenaSearchFilter;
 pub use self::responder_raw_json::*;
 pub use self::search_arena_team::SearchArenaTeam;
+pub use self::season_dto::SeasonDto;
 pub use self::skirmish_search_filter::SkirmishSearchFilter;
 pub use self::speed_run::SpeedRun;
+pub use self::speed_run_split::SpeedRunSplit;
 pub use self::speed_kill::SpeedKill;
 pub use self::instance_privacy::InstancePrivacy;
 
+mod arena_team_match;
+mod attendance_alias;
+mod attendance_filter;
+mod audit_log_entry;
 mod battleground_search_filter;
+mod battleground_win_rate;
+mod character_attendance;
+mod character_merge;
+mod comparison_column;
+mod comparison_target;
+mod composition_win_rate;
+mod deleted_instance;
+mod encounter_kill_times;
+mod encounter_progression;
+mod event_export_filter;
+mod guild_attendance;
+mod instance_comparison;
+mod instance_comparison_request;
 mod instance_failure;
+mod instance_search_cursor;
+mod instance_search_entry;
+mod instance_search_filter;
+mod instance_search_page;
 mod instance_viewer_attempt;
 mod instance_viewer_guild;
 mod instance_viewer_meta;
 mod instance_viewer_participant;
+mod loot_entry;
+mod loot_history_filter;
+mod map_attendance;
+mod map_win_rate;
 mod meta_battleground_search;
 mod meta_raid_search;
 mod meta_rated_arena_search;
 mod meta_skirmish_search;
+mod player_comparison;
+mod progression_ranking;
+mod pvp_match_summary;
+mod pvp_participant_summary;
+mod raid_progression;
 mod raid_search_filter;
 mod ranking_character_meta;
 mod ranking_result;
 mod rated_arena_search_filter;
 mod responder_raw_json;
 mod search_arena_team;
+mod season_dto;
 mod skirmish_search_filter;
 mod speed_run;
+mod speed_run_split;
 mod speed_kill;
 mod instance_privacy;
@@ -0,0 +1,12 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct PlayerComparison {
+    pub character_id: u32,
+    // Per column, None if the character did not take part
+    pub dps: Vec<Option<f64>>,
+    pub hps: Vec<Option<f64>>,
+    // Last column compared to the first column
+    pub dps_delta: Option<f64>,
+    pub hps_delta: Option<f64>,
+    pub deaths: Vec<u32>,
+    pub consumables_used: Vec<u32>,
+}
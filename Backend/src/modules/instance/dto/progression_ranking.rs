@@ -0,0 +1,13 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct ProgressionRanking {
+    pub guild_id: u32,
+    pub guild_name: String,
+    pub server_id: u32,
+    pub map_id: u16,
+    pub difficulty_id: u8,
+    pub season_index: u8,
+    pub num_killed: u32,
+    pub num_encounters: u32,
+    // First kill of the encounter that completed the current progress
+    pub progress_ts: u64,
+}
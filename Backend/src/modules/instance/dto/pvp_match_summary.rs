@@ -0,0 +1,7 @@
+use crate::modules::instance::dto::PvpParticipantSummary;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct PvpMatchSummary {
+    pub instance_meta_id: u32,
+    pub participants: Vec<PvpParticipantSummary>,
+}
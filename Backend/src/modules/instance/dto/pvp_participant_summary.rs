@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct PvpParticipantSummary {
+    pub character_id: u32,
+    // Including the damage and healing of the character's pets
+    pub damage_done: u64,
+    pub damage_taken: u64,
+    pub effective_healing_done: u64,
+}
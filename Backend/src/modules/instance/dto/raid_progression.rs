@@ -0,0 +1,11 @@
+use crate::modules::instance::dto::EncounterProgression;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct RaidProgression {
+    pub map_id: u16,
+    pub difficulty_id: u8,
+    pub num_killed: u32,
+    pub num_encounters: u32,
+    pub cleared_this_week: bool,
+    pub encounters: Vec<EncounterProgression>,
+}
@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SeasonDto {
+    pub server_id: Option<u32>,
+    pub expansion_id: u8,
+    pub name: String,
+    pub start_ts: u64,
+    pub end_ts: Option<u64>,
+}
@@ -1,3 +1,5 @@
+use crate::modules::instance::dto::SpeedRunSplit;
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SpeedRun {
     pub instance_meta_id: u32,
@@ -7,5 +9,6 @@ pub struct SpeedRun {
     pub server_id: u32,
     pub duration: u64,
     pub difficulty_id: u8,
-    pub season_index: u8
+    pub season_index: u8,
+    pub splits: Vec<SpeedRunSplit>
 }
\ No newline at end of file
@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct SpeedRunSplit {
+    pub encounter_id: u32,
+    // Time from the start of the run until the kill
+    pub kill_offset: u64,
+    // Time since the previous kill, or the start of the run for the first kill
+    pub segment_duration: u64,
+}
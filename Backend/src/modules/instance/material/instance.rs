@@ -1,16 +1,18 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::sync::{Arc, RwLock};
 
 use crate::material::Cachable;
 use crate::modules::armory::Armory;
 use crate::modules::armory::tools::{GetArenaTeam, GetCharacter};
-use crate::modules::armory::util::talent_tree::get_talent_tree;
-use crate::modules::instance::domain_value::{InstanceAttempt, InstanceMeta, MetaType, PrivacyType};
+use crate::modules::armory::util::talent_tree::get_talent_spec_id;
+use crate::modules::data::domain_value::MapEncounter;
+use crate::modules::instance::domain_value::{AuditAction, IndexedInstance, IndexedParticipant, InstanceAttempt, InstanceMeta, MetaType, PrivacyType};
 use crate::modules::instance::dto::{InstanceViewerAttempt, RankingResult, SpeedKill, SpeedRun};
-use crate::modules::instance::tools::FindInstanceGuild;
+use crate::modules::instance::material::{InstanceSearchIndex, SeasonCalendar};
+use crate::modules::instance::tools::{calculate_speed_run_splits, is_full_clear, log_instance_action, FindInstanceGuild, DELETION_RETENTION_PERIOD};
 use crate::params;
-use crate::util::database::Select;
-use chrono::{NaiveDateTime, Datelike};
+use crate::util::database::{Execute, Select};
+use crate::util::event_store::{event_store_from_env, EventStore};
 
 pub struct Instance {
     pub instance_metas: Arc<RwLock<(u32, HashMap<u32, InstanceMeta>)>>,
@@ -25,6 +27,9 @@ pub struct Instance {
     pub instance_rankings_tps: Arc<RwLock<(u32, HashMap<u32, HashMap<u32, Vec<RankingResult>>>)>>,
     // attempt_id => (instance_meta_id => Vec<Attempt>)
     pub instance_kill_attempts: Arc<RwLock<(u32, HashMap<u32, Vec<InstanceAttempt>>)>>,
+    pub season_calendar: Arc<RwLock<SeasonCalendar>>,
+    pub search_index: Arc<RwLock<InstanceSearchIndex>>,
+    pub event_store: Box<dyn EventStore>,
 }
 
 impl Default for Instance {
@@ -39,12 +44,15 @@ impl Default for Instance {
             instance_kill_attempts: Arc::new(RwLock::new((0, HashMap::new()))),
             speed_runs: Arc::new(RwLock::new(Vec::new())),
             speed_kills: Arc::new(RwLock::new(Vec::new())),
+            season_calendar: Arc::new(RwLock::new(SeasonCalendar::default())),
+            search_index: Arc::new(RwLock::new(InstanceSearchIndex::default())),
+            event_store: event_store_from_env(),
         }
     }
 }
 
 impl Instance {
-    pub fn init(self, mut db_main: (impl Select + Send + 'static)) -> Self {
+    pub fn init(self, mut db_main: (impl Execute + Select + Send + 'static), map_encounters: HashMap<u16, Vec<MapEncounter>>) -> Self {
         let instance_metas_arc_clone = Arc::clone(&self.instance_metas);
         let instance_exports_arc_clone = Arc::clone(&self.instance_exports);
         let instance_attempts_arc_clone = Arc::clone(&self.instance_attempts);
@@ -54,10 +62,14 @@ impl Instance {
         let instance_kill_attempts_clone = Arc::clone(&self.instance_kill_attempts);
         let speed_runs_arc_clone = Arc::clone(&self.speed_runs);
         let speed_kills_arc_clone = Arc::clone(&self.speed_kills);
+        let season_calendar_arc_clone = Arc::clone(&self.season_calendar);
+        let search_index_arc_clone = Arc::clone(&self.search_index);
 
         std::thread::spawn(move || {
             let mut armory_counter = 1;
             let armory = Armory::default().init(&mut db_main);
+            let mut season_calendar = SeasonCalendar::default();
+            let event_store = event_store_from_env();
 
             loop {
                 evict_attempts_cache(Arc::clone(&instance_attempts_arc_clone));
@@ -65,21 +77,41 @@ impl Instance {
                 update_instance_metas(Arc::clone(&instance_metas_arc_clone), &mut db_main, &armory);
 
                 if armory_counter % 6 == 0 {
-                    update_instance_kill_attempts(Arc::clone(&instance_kill_attempts_clone), &mut db_main);
-                    update_instance_rankings_dps(Arc::clone(&instance_rankings_dps_arc_clone), &mut db_main, &armory);
-                    update_instance_rankings_hps(Arc::clone(&instance_rankings_hps_arc_clone), &mut db_main, &armory);
-                    update_instance_rankings_tps(Arc::clone(&instance_rankings_tps_arc_clone), &mut db_main, &armory);
+                    // Everything that carries a season index has to be recalculated if the calendar changes
+                    let current_season_calendar = SeasonCalendar::load(&mut db_main);
+                    if current_season_calendar != season_calendar {
+                        *season_calendar_arc_clone.write().unwrap() = current_season_calendar.clone();
+                        *instance_kill_attempts_clone.write().unwrap() = (0, HashMap::new());
+                        *instance_rankings_dps_arc_clone.write().unwrap() = (0, HashMap::new());
+                        *instance_rankings_hps_arc_clone.write().unwrap() = (0, HashMap::new());
+                        *instance_rankings_tps_arc_clone.write().unwrap() = (0, HashMap::new());
+                        speed_runs_arc_clone.write().unwrap().clear();
+                        speed_kills_arc_clone.write().unwrap().clear();
+                        season_calendar = current_season_calendar;
+                    }
+
+                    update_instance_kill_attempts(Arc::clone(&instance_kill_attempts_clone), &mut db_main, &season_calendar);
+                    update_instance_rankings_dps(Arc::clone(&instance_rankings_dps_arc_clone), &mut db_main, &armory, &season_calendar);
+                    update_instance_rankings_hps(Arc::clone(&instance_rankings_hps_arc_clone), &mut db_main, &armory, &season_calendar);
+                    update_instance_rankings_tps(Arc::clone(&instance_rankings_tps_arc_clone), &mut db_main, &armory, &season_calendar);
                     calculate_speed_runs(Arc::clone(&instance_metas_arc_clone),
                                          Arc::clone(&instance_kill_attempts_clone),
-                                         Arc::clone(&speed_runs_arc_clone), &mut db_main, &armory);
+                                         Arc::clone(&speed_runs_arc_clone), &mut db_main, &armory, &map_encounters);
                     calculate_speed_kills(Arc::clone(&instance_metas_arc_clone),
                                           Arc::clone(&instance_kill_attempts_clone),
                                           Arc::clone(&speed_kills_arc_clone), &mut db_main, &armory);
                 }
 
+                update_instance_search_index(Arc::clone(&search_index_arc_clone),
+                                             Arc::clone(&instance_metas_arc_clone),
+                                             Arc::clone(&instance_kill_attempts_clone), &mut db_main, &armory);
+
                 if armory_counter % 12 == 0 {
                     armory.update(&mut db_main);
                 }
+                if armory_counter % 720 == 0 {
+                    purge_deleted_instances(&mut db_main, &*event_store);
+                }
                 armory_counter += 1;
                 std::thread::sleep(std::time::Duration::from_secs(5));
             }
@@ -88,45 +120,142 @@ impl Instance {
     }
 
     pub fn delete_instance_meta(&self, instance_meta_id: u32) {
-        let mut instance_metas = self.instance_metas.write().unwrap();
-        instance_metas.1.remove(&instance_meta_id);
+        self.instance_metas.write().unwrap().1.remove(&instance_meta_id);
+        self.instance_kill_attempts.write().unwrap().1.remove(&instance_meta_id);
+        self.search_index.write().unwrap().remove(instance_meta_id);
+        self.speed_runs.write().unwrap().retain(|speed_run| speed_run.instance_meta_id != instance_meta_id);
+        self.speed_kills.write().unwrap().retain(|speed_kill| speed_kill.instance_meta_id != instance_meta_id);
+        self.reset_rankings();
+    }
+
+    /// Restored instances are older than the loaded ones, hence everything derived from them is loaded again
+    pub fn restore_instance_meta(&self, instance_meta_id: u32) {
+        {
+            let mut instance_metas = self.instance_metas.write().unwrap();
+            instance_metas.0 = instance_metas.0.min(instance_meta_id.saturating_sub(1));
+        }
+        *self.instance_kill_attempts.write().unwrap() = (0, HashMap::new());
+        self.speed_runs.write().unwrap().clear();
+        self.speed_kills.write().unwrap().clear();
+        self.reset_rankings();
+    }
+
+    /// Participants are loaded by character, hence merged characters are replaced in the loaded instances
+    pub fn replace_participant(&self, instance_meta_ids: &[u32], character_id: u32, replacement_character_id: u32) {
+        {
+            let mut instance_metas = self.instance_metas.write().unwrap();
+            for instance_meta_id in instance_meta_ids {
+                if let Some(instance_meta) = instance_metas.1.get_mut(instance_meta_id) {
+                    instance_meta.participants.iter_mut().filter(|participant| **participant == character_id).for_each(|participant| *participant = replacement_character_id);
+                }
+            }
+        }
+        self.instance_exports.write().unwrap().clear();
+        self.reset_rankings();
+    }
+
+    fn reset_rankings(&self) {
+        *self.instance_rankings_dps.write().unwrap() = (0, HashMap::new());
+        *self.instance_rankings_hps.write().unwrap() = (0, HashMap::new());
+        *self.instance_rankings_tps.write().unwrap() = (0, HashMap::new());
+    }
+}
+
+fn purge_deleted_instances(db_main: &mut (impl Execute + Select), event_store: &dyn EventStore) {
+    let purgeable_instances = db_main.select_wparams("SELECT id, server_id FROM instance_meta WHERE deleted_ts IS NOT NULL AND deleted_ts < :purge_ts",
+                                                     |mut row| (row.take::<u32, usize>(0).unwrap(), row.take::<u32, usize>(1).unwrap()),
+                                                     params!("purge_ts" => time_util::now().saturating_sub(DELETION_RETENTION_PERIOD)));
+    for (instance_meta_id, server_id) in purgeable_instances {
+        if db_main.execute_wparams("DELETE FROM instance_meta WHERE id=:instance_meta_id AND deleted_ts IS NOT NULL", params!("instance_meta_id" => instance_meta_id)) {
+            event_store.delete(server_id, instance_meta_id);
+            log_instance_action(db_main, instance_meta_id, None, &AuditAction::Purge);
+        }
+    }
+}
+
+fn update_instance_search_index(search_index: Arc<RwLock<InstanceSearchIndex>>,
+                                instance_metas: Arc<RwLock<(u32, HashMap<u32, InstanceMeta>)>>,
+                                instance_kill_attempts: Arc<RwLock<(u32, HashMap<u32, Vec<InstanceAttempt>>)>>,
+                                db_main: &mut impl Select, armory: &Armory) {
+    // The metas are copied out, so that no lock is held while the guilds are queried
+    let (instance_meta_ids, outdated_instances): (BTreeSet<u32>, Vec<(InstanceMeta, BTreeSet<u32>, Option<(Option<(u32, String)>, Vec<IndexedParticipant>)>)>) = {
+        let instance_metas = instance_metas.read().unwrap();
+        let kill_attempts = instance_kill_attempts.read().unwrap();
+        let search_index = search_index.read().unwrap();
+        let outdated_instances = instance_metas.1.values()
+            .filter_map(|instance_meta| {
+                let killed_encounter_ids: BTreeSet<u32> = kill_attempts.1.get(&instance_meta.instance_meta_id)
+                    .map(|attempts| attempts.iter().map(|attempt| attempt.encounter_id).collect())
+                    .unwrap_or_default();
+                if search_index.is_up_to_date(instance_meta, &killed_encounter_ids) {
+                    return None;
+                }
+
+                // The guild and the participants only have to be looked up again if the participants changed
+                let indexed_participants = search_index.get(instance_meta.instance_meta_id)
+                    .filter(|indexed| indexed.participants == instance_meta.participants)
+                    .map(|indexed| (indexed.guild.clone(), indexed.pvp_participants.clone()));
+                Some((instance_meta.clone(), killed_encounter_ids, indexed_participants))
+            })
+            .collect();
+        (instance_metas.1.keys().copied().collect(), outdated_instances)
+    };
+
+    let outdated_instances: Vec<IndexedInstance> = outdated_instances.into_iter()
+        .map(|(instance_meta, killed_encounter_ids, indexed_participants)| {
+            let map_difficulty = match instance_meta.instance_specific {
+                MetaType::Raid { map_difficulty } => Some(map_difficulty),
+                _ => None,
+            };
+            let (guild, pvp_participants) = match indexed_participants {
+                Some(indexed_participants) => indexed_participants,
+                None if map_difficulty.is_some() => (instance_meta.participants
+                    .find_instance_guild(db_main, armory, instance_meta.start_ts)
+                    .map(|guild| (guild.id, guild.name)), Vec::new()),
+                None => match instance_meta.instance_specific {
+                    MetaType::RatedArena { .. } | MetaType::Battleground { .. } => (None, instance_meta.participants.iter()
+                        .filter_map(|character_id| armory.get_character_moment(db_main, *character_id, instance_meta.start_ts)
+                            .map(|character_history| IndexedParticipant {
+                                character_id: *character_id,
+                                hero_class_id: character_history.character_info.hero_class_id,
+                                race_id: character_history.character_info.race_id,
+                                arena_team_ids: character_history.arena_teams.iter().map(|arena_team| arena_team.id).collect(),
+                            }))
+                        .collect()),
+                    _ => (None, Vec::new()),
+                },
+            };
+            IndexedInstance {
+                instance_meta_id: instance_meta.instance_meta_id,
+                meta_type: instance_meta.instance_specific.to_u8(),
+                server_id: instance_meta.server_id,
+                map_id: instance_meta.map_id,
+                map_difficulty,
+                start_ts: instance_meta.start_ts,
+                end_ts: instance_meta.end_ts,
+                participants: instance_meta.participants,
+                guild,
+                pvp_participants,
+                killed_encounter_ids,
+                uploaded_user: instance_meta.uploaded_user,
+                privacy_type: instance_meta.privacy_type,
+            }
+        })
+        .collect();
+
+    let mut search_index = search_index.write().unwrap();
+    for instance_meta_id in search_index.instance_meta_ids().into_iter().filter(|instance_meta_id| !instance_meta_ids.contains(instance_meta_id)) {
+        search_index.remove(instance_meta_id);
+    }
+    for instance in outdated_instances {
+        search_index.insert(instance);
     }
 }
 
 fn calculate_speed_runs(instance_metas: Arc<RwLock<(u32, HashMap<u32, InstanceMeta>)>>,
                         instance_kill_attempts: Arc<RwLock<(u32, HashMap<u32, Vec<InstanceAttempt>>)>>,
                         speed_runs: Arc<RwLock<Vec<SpeedRun>>>,
-                        db_main: &mut impl Select, armory: &Armory) {
-    lazy_static! {
-        static ref INSTANCE_ENCOUNTERS: HashMap<u16, Vec<u32>> = {
-            let mut instance_encounters = HashMap::new();
-            instance_encounters.insert(409, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
-            instance_encounters.insert(249, vec![11]);
-            instance_encounters.insert(309, vec![12, 13, 14, 15, 17, 19, 20, 21]);
-            instance_encounters.insert(469, vec![22, 23, 24, 25, 26, 27, 28, 29]);
-            instance_encounters.insert(509, vec![30, 31, 32, 33, 34, 35]);
-            instance_encounters.insert(531, vec![36, 37, 38, 39, 40, 41, 42, 163, 164, 165]);
-            instance_encounters.insert(533, vec![43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57]);
-            instance_encounters.insert(532, vec![58, 59, 60, 61, 63, 64, 65, 66, 67, 68]);
-            instance_encounters.insert(565, vec![69, 70]);
-            instance_encounters.insert(544, vec![71]);
-            instance_encounters.insert(550, vec![72, 73, 74, 75]);
-            instance_encounters.insert(548, vec![76, 78, 79, 80, 81]);
-            instance_encounters.insert(568, vec![82, 83, 84, 85, 86, 87]);
-            instance_encounters.insert(534, vec![88, 89, 90, 91, 92]);
-            instance_encounters.insert(564, vec![93, 94, 95, 96, 97, 98, 99, 100, 101]);
-            instance_encounters.insert(580, vec![103, 104, 105, 106, 107]);
-            instance_encounters.insert(615, vec![108]);
-            instance_encounters.insert(616, vec![109]);
-            instance_encounters.insert(624, vec![110, 111, 112, 113]);
-            instance_encounters.insert(603, vec![114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126]);
-            instance_encounters.insert(649, vec![128, 129, 130, 131, 132]);
-            instance_encounters.insert(631, vec![133, 134, 136, 137, 138, 139, 140, 141, 143, 144]);
-            instance_encounters.insert(724, vec![145]);
-            instance_encounters
-        };
-    }
-
+                        db_main: &mut impl Select, armory: &Armory, map_encounters: &HashMap<u16, Vec<MapEncounter>>) {
     let kill_attempts = instance_kill_attempts.read().unwrap();
     let instance_metas = instance_metas.read().unwrap();
     let mut speed_runs = speed_runs.write().unwrap();
@@ -142,10 +271,15 @@ fn calculate_speed_runs(instance_metas: Arc<RwLock<(u32, HashMap<u32, InstanceMe
             continue;
         }
 
-        let has_killed_all_encounters = INSTANCE_ENCOUNTERS.get(&instance_meta.map_id).unwrap()
-            .iter().all(|encounter_id| attempts.iter().any(|attempt| attempt.encounter_id == *encounter_id && attempt.rankable));
         let all_difficulties_are_same = attempts.iter().all(|attempt| attempt.difficulty_id == attempts[0].difficulty_id);
-        if !has_killed_all_encounters || !all_difficulties_are_same {
+        if !all_difficulties_are_same {
+            continue;
+        }
+
+        let run_encounters = map_encounters.get(&instance_meta.map_id)
+            .map(|map_encounters| MapEncounter::for_difficulty(map_encounters, attempts[0].difficulty_id))
+            .unwrap_or_else(Vec::new);
+        if !is_full_clear(&run_encounters, attempts) {
             continue;
         }
 
@@ -163,7 +297,8 @@ fn calculate_speed_runs(instance_metas: Arc<RwLock<(u32, HashMap<u32, InstanceMe
             server_id: instance_meta.server_id,
             duration: end - start,
             difficulty_id: attempts[0].difficulty_id,
-            season_index: attempts[0].season_index
+            season_index: attempts[0].season_index,
+            splits: calculate_speed_run_splits(&run_encounters, attempts, start)
         });
     }
 }
@@ -206,24 +341,12 @@ fn calculate_speed_kills(instance_metas: Arc<RwLock<(u32, HashMap<u32, InstanceM
     }
 }
 
-fn calculate_season_index(ts: u64) -> u8 {
-    static FIRST_SEASON_YEAR: i32 = 2020;
-    static SEASON_DURATION: i32 = 3;
-    let today = NaiveDateTime::from_timestamp((ts / 1000) as i64, 0);
-    let year = today.year();
-    let month = today.month() as i32;
-    let months_since = (year - FIRST_SEASON_YEAR) * 12 + month;
-    if (months_since % SEASON_DURATION) == 0 {
-        return (months_since / SEASON_DURATION) as u8;
-    }
-    return (months_since / SEASON_DURATION) as u8 + 1;
-}
-
-fn update_instance_kill_attempts(instance_kill_attempts: Arc<RwLock<(u32, HashMap<u32, Vec<InstanceAttempt>>)>>, db_main: &mut impl Select) {
+fn update_instance_kill_attempts(instance_kill_attempts: Arc<RwLock<(u32, HashMap<u32, Vec<InstanceAttempt>>)>>, db_main: &mut impl Select, season_calendar: &SeasonCalendar) {
     let mut kill_attempts = instance_kill_attempts.write().unwrap();
-    db_main.select_wparams("SELECT A.instance_meta_id, A.id, A.encounter_id, A.start_ts, A.end_ts, B.map_difficulty, A.rankable FROM instance_attempt A \
+    db_main.select_wparams("SELECT A.instance_meta_id, A.id, A.encounter_id, A.start_ts, A.end_ts, B.map_difficulty, A.rankable, C.server_id FROM instance_attempt A \
     JOIN instance_raid B ON A.instance_meta_id = B.instance_meta_id \
-    WHERE A.is_kill = 1 AND A.id > :saved_attempt_id ORDER BY A.id",
+    JOIN instance_meta C ON A.instance_meta_id = C.id \
+    WHERE A.is_kill = 1 AND A.id > :saved_attempt_id AND C.deleted_ts IS NULL ORDER BY A.id",
                            |mut row|
                                {
                                    let start_ts = row.take(3).unwrap();
@@ -235,7 +358,7 @@ fn update_instance_kill_attempts(instance_kill_attempts: Arc<RwLock<(u32, HashMa
                                        is_kill: true,
                                        difficulty_id: row.take(5).unwrap(),
                                        rankable: row.take(6).unwrap(),
-                                       season_index: calculate_season_index(start_ts)
+                                       season_index: season_calendar.season_index(row.take(7).unwrap(), start_ts)
                                    })
                                }, params!("saved_attempt_id" => kill_attempts.0))
         .into_iter()
@@ -246,15 +369,16 @@ fn update_instance_kill_attempts(instance_kill_attempts: Arc<RwLock<(u32, HashMa
         });
 }
 
-fn update_instance_rankings_dps(instance_rankings_dps: Arc<RwLock<(u32, HashMap<u32, HashMap<u32, Vec<RankingResult>>>)>>, db_main: &mut impl Select, armory: &Armory) {
+fn update_instance_rankings_dps(instance_rankings_dps: Arc<RwLock<(u32, HashMap<u32, HashMap<u32, Vec<RankingResult>>>)>>, db_main: &mut impl Select, armory: &Armory, season_calendar: &SeasonCalendar) {
     let mut rankings_dps = instance_rankings_dps.write().unwrap();
     db_main
         .select_wparams(
             "SELECT A.id, A.character_id, B.encounter_id, A.attempt_id, A.damage, \
-            (B.end_ts - B.start_ts) as duration, B.instance_meta_id, C.map_difficulty, B.start_ts FROM instance_ranking_damage A \
+            (B.end_ts - B.start_ts) as duration, B.instance_meta_id, C.map_difficulty, B.start_ts, D.server_id FROM instance_ranking_damage A \
             JOIN instance_attempt B ON A.attempt_id = B.id \
             JOIN instance_raid C ON B.instance_meta_id = C.instance_meta_id \
-            WHERE A.id > :last_queried_id AND B.rankable = 1 ORDER BY A.id",
+            JOIN instance_meta D ON B.instance_meta_id = D.id \
+            WHERE A.id > :last_queried_id AND B.rankable = 1 AND D.deleted_ts IS NULL ORDER BY A.id",
             |mut row| {
                 let id: u32 = row.take(0).unwrap();
                 let character_id: u32 = row.take(1).unwrap();
@@ -265,6 +389,7 @@ fn update_instance_rankings_dps(instance_rankings_dps: Arc<RwLock<(u32, HashMap<
                 let instance_meta_id: u32 = row.take(6).unwrap();
                 let difficulty_id: u8 = row.take(7).unwrap();
                 let start_ts: u64 = row.take(8).unwrap();
+                let server_id: u32 = row.take(9).unwrap();
                 (
                     id,
                     character_id,
@@ -274,13 +399,14 @@ fn update_instance_rankings_dps(instance_rankings_dps: Arc<RwLock<(u32, HashMap<
                     duration,
                     instance_meta_id,
                     difficulty_id,
-                    start_ts
+                    start_ts,
+                    server_id
                 )
             },
             params!("last_queried_id" => rankings_dps.0),
         )
         .into_iter()
-        .for_each(|(id, character_id, encounter_id, attempt_id, amount, duration, instance_meta_id, difficulty_id, start_ts)| {
+        .for_each(|(id, character_id, encounter_id, attempt_id, amount, duration, instance_meta_id, difficulty_id, start_ts, server_id)| {
             rankings_dps.0 = id;
             let characters_rankings = rankings_dps.1.entry(encounter_id).or_insert_with(HashMap::new);
             let rankings = characters_rankings.entry(character_id).or_insert_with(|| Vec::with_capacity(1));
@@ -291,22 +417,23 @@ fn update_instance_rankings_dps(instance_rankings_dps: Arc<RwLock<(u32, HashMap<
                 instance_meta_id,
                 difficulty_id,
                 character_spec: armory.get_character_moment(db_main, character_id, start_ts)
-                    .and_then(|char_history| char_history.character_info.talent_specialization.as_ref().map(|talents| get_talent_tree(&talents) + 1))
+                    .and_then(|char_history| char_history.character_info.talent_specialization.as_ref().and_then(|talents| get_talent_spec_id(talents)))
                     .unwrap_or(0),
-                season_index: calculate_season_index(start_ts)
+                season_index: season_calendar.season_index(server_id, start_ts)
             });
         });
 }
 
-fn update_instance_rankings_hps(instance_rankings_hps: Arc<RwLock<(u32, HashMap<u32, HashMap<u32, Vec<RankingResult>>>)>>, db_main: &mut impl Select, armory: &Armory) {
+fn update_instance_rankings_hps(instance_rankings_hps: Arc<RwLock<(u32, HashMap<u32, HashMap<u32, Vec<RankingResult>>>)>>, db_main: &mut impl Select, armory: &Armory, season_calendar: &SeasonCalendar) {
     let mut rankings_hps = instance_rankings_hps.write().unwrap();
     db_main
         .select_wparams(
             "SELECT A.id, A.character_id, B.encounter_id, A.attempt_id, A.heal, \
-            (B.end_ts - B.start_ts) as duration, B.instance_meta_id, C.map_difficulty, B.start_ts FROM instance_ranking_heal A \
+            (B.end_ts - B.start_ts) as duration, B.instance_meta_id, C.map_difficulty, B.start_ts, D.server_id FROM instance_ranking_heal A \
             JOIN instance_attempt B ON A.attempt_id = B.id \
             JOIN instance_raid C ON B.instance_meta_id = C.instance_meta_id \
-            WHERE A.id > :last_queried_id AND B.rankable = 1 ORDER BY A.id",
+            JOIN instance_meta D ON B.instance_meta_id = D.id \
+            WHERE A.id > :last_queried_id AND B.rankable = 1 AND D.deleted_ts IS NULL ORDER BY A.id",
             |mut row| {
                 let id: u32 = row.take(0).unwrap();
                 let character_id: u32 = row.take(1).unwrap();
@@ -317,6 +444,7 @@ fn update_instance_rankings_hps(instance_rankings_hps: Arc<RwLock<(u32, HashMap<
                 let instance_meta_id: u32 = row.take(6).unwrap();
                 let difficulty_id: u8 = row.take(7).unwrap();
                 let start_ts: u64 = row.take(8).unwrap();
+                let server_id: u32 = row.take(9).unwrap();
                 (
                     id,
                     character_id,
@@ -326,13 +454,14 @@ fn update_instance_rankings_hps(instance_rankings_hps: Arc<RwLock<(u32, HashMap<
                     duration,
                     instance_meta_id,
                     difficulty_id,
-                    start_ts
+                    start_ts,
+                    server_id
                 )
             },
             params!("last_queried_id" => rankings_hps.0),
         )
         .into_iter()
-        .for_each(|(id, character_id, encounter_id, attempt_id, amount, duration, instance_meta_id, difficulty_id, start_ts)| {
+        .for_each(|(id, character_id, encounter_id, attempt_id, amount, duration, instance_meta_id, difficulty_id, start_ts, server_id)| {
             rankings_hps.0 = id;
             let characters_rankings = rankings_hps.1.entry(encounter_id).or_insert_with(HashMap::new);
             let rankings = characters_rankings.entry(character_id).or_insert_with(|| Vec::with_capacity(1));
@@ -343,22 +472,23 @@ fn update_instance_rankings_hps(instance_rankings_hps: Arc<RwLock<(u32, HashMap<
                 instance_meta_id,
                 difficulty_id,
                 character_spec: armory.get_character_moment(db_main, character_id, start_ts)
-                    .and_then(|char_history| char_history.character_info.talent_specialization.as_ref().map(|talents| get_talent_tree(&talents) + 1))
+                    .and_then(|char_history| char_history.character_info.talent_specialization.as_ref().and_then(|talents| get_talent_spec_id(talents)))
                     .unwrap_or(0),
-                season_index: calculate_season_index(start_ts)
+                season_index: season_calendar.season_index(server_id, start_ts)
             });
         });
 }
 
-fn update_instance_rankings_tps(instance_rankings_tps: Arc<RwLock<(u32, HashMap<u32, HashMap<u32, Vec<RankingResult>>>)>>, db_main: &mut impl Select, armory: &Armory) {
+fn update_instance_rankings_tps(instance_rankings_tps: Arc<RwLock<(u32, HashMap<u32, HashMap<u32, Vec<RankingResult>>>)>>, db_main: &mut impl Select, armory: &Armory, season_calendar: &SeasonCalendar) {
     let mut rankings_tps = instance_rankings_tps.write().unwrap();
     db_main
         .select_wparams(
             "SELECT A.id, A.character_id, B.encounter_id, A.attempt_id, A.threat, \
-            (B.end_ts - B.start_ts) as duration, B.instance_meta_id, C.map_difficulty, B.start_ts FROM instance_ranking_threat A \
+            (B.end_ts - B.start_ts) as duration, B.instance_meta_id, C.map_difficulty, B.start_ts, D.server_id FROM instance_ranking_threat A \
             JOIN instance_attempt B ON A.attempt_id = B.id \
             JOIN instance_raid C ON B.instance_meta_id = C.instance_meta_id \
-            WHERE A.id > :last_queried_id AND B.rankable = 1 ORDER BY A.id",
+            JOIN instance_meta D ON B.instance_meta_id = D.id \
+            WHERE A.id > :last_queried_id AND B.rankable = 1 AND D.deleted_ts IS NULL ORDER BY A.id",
             |mut row| {
                 let id: u32 = row.take(0).unwrap();
                 let character_id: u32 = row.take(1).unwrap();
@@ -369,6 +499,7 @@ fn update_instance_rankings_tps(instance_rankings_tps: Arc<RwLock<(u32, HashMap<
                 let instance_meta_id: u32 = row.take(6).unwrap();
                 let difficulty_id: u8 = row.take(7).unwrap();
                 let start_ts: u64 = row.take(8).unwrap();
+                let server_id: u32 = row.take(9).unwrap();
                 (
                     id,
                     character_id,
@@ -378,13 +509,14 @@ fn update_instance_rankings_tps(instance_rankings_tps: Arc<RwLock<(u32, HashMap<
                     duration,
                     instance_meta_id,
                     difficulty_id,
-                    start_ts
+                    start_ts,
+                    server_id
                 )
             },
             params!("last_queried_id" => rankings_tps.0),
         )
         .into_iter()
-        .for_each(|(id, character_id, encounter_id, attempt_id, amount, duration, instance_meta_id, difficulty_id, start_ts)| {
+        .for_each(|(id, character_id, encounter_id, attempt_id, amount, duration, instance_meta_id, difficulty_id, start_ts, server_id)| {
             rankings_tps.0 = id;
             let characters_rankings = rankings_tps.1.entry(encounter_id).or_insert_with(HashMap::new);
             let rankings = characters_rankings.entry(character_id).or_insert_with(|| Vec::with_capacity(1));
@@ -395,9 +527,9 @@ fn update_instance_rankings_tps(instance_rankings_tps: Arc<RwLock<(u32, HashMap<
                 instance_meta_id,
                 difficulty_id,
                 character_spec: armory.get_character_moment(db_main, character_id, start_ts)
-                    .and_then(|char_history| char_history.character_info.talent_specialization.as_ref().map(|talents| get_talent_tree(&talents) + 1))
+                    .and_then(|char_history| char_history.character_info.talent_specialization.as_ref().and_then(|talents| get_talent_spec_id(talents)))
                     .unwrap_or(0),
-                season_index: calculate_season_index(start_ts)
+                season_index: season_calendar.season_index(server_id, start_ts)
             });
         });
 }
@@ -438,7 +570,7 @@ fn update_instance_metas(instance_metas: Arc<RwLock<(u32, HashMap<u32, InstanceM
             "SELECT A.id, A.server_id, A.start_ts, A.end_ts, A.expired, A.map_id, B.map_difficulty, C.member_id, A.upload_id, A.privacy_type, A.privacy_ref FROM instance_meta A \
             JOIN instance_raid B ON A.id = B.instance_meta_id \
             JOIN instance_uploads C ON A.upload_id = C.id \
-            WHERE A.id > :saved_instance_meta_id ORDER BY A.id",
+            WHERE A.id > :saved_instance_meta_id AND A.deleted_ts IS NULL ORDER BY A.id",
             |mut row| InstanceMeta {
                 instance_meta_id: row.take(0).unwrap(),
                 server_id: row.take(1).unwrap(),
@@ -469,7 +601,7 @@ fn update_instance_metas(instance_metas: Arc<RwLock<(u32, HashMap<u32, InstanceM
             B.team_id1, B.team_id2, B.team_change1, B.team_change2, C.member_id, A.upload_id, A.privacy_type, A.privacy_ref FROM instance_meta A \
             JOIN instance_rated_arena B ON A.id = B.instance_meta_id \
             JOIN instance_uploads C ON A.upload_id = C.id \
-            WHERE A.id > :saved_instance_meta_id ORDER BY A.id",
+            WHERE A.id > :saved_instance_meta_id AND A.deleted_ts IS NULL ORDER BY A.id",
             |mut row| {
                 (
                     row.take::<u32, usize>(0).unwrap(),
@@ -522,7 +654,7 @@ fn update_instance_metas(instance_metas: Arc<RwLock<(u32, HashMap<u32, InstanceM
             "SELECT A.id, A.server_id, A.start_ts, A.end_ts, A.expired, A.map_id, B.winner, C.member_id, A.upload_id, A.privacy_type, A.privacy_ref FROM instance_meta A \
             JOIN instance_skirmish B ON A.id = B.instance_meta_id \
             JOIN instance_uploads C ON A.upload_id = C.id \
-            WHERE A.id > :saved_instance_meta_id ORDER BY A.id",
+            WHERE A.id > :saved_instance_meta_id AND A.deleted_ts IS NULL ORDER BY A.id",
             |mut row| InstanceMeta {
                 instance_meta_id: row.take(0).unwrap(),
                 server_id: row.take(1).unwrap(),
@@ -551,7 +683,7 @@ fn update_instance_metas(instance_metas: Arc<RwLock<(u32, HashMap<u32, InstanceM
             B.score_alliance, B.score_horde, C.member_id, A.upload_id, A.privacy_type, A.privacy_ref FROM instance_meta A \
             JOIN instance_battleground B ON A.id = B.instance_meta_id \
             JOIN instance_uploads C ON A.upload_id = C.id \
-            WHERE A.id > :saved_instance_meta_id ORDER BY A.id",
+            WHERE A.id > :saved_instance_meta_id AND A.deleted_ts IS NULL ORDER BY A.id",
             |mut row| InstanceMeta {
                 instance_meta_id: row.take(0).unwrap(),
                 server_id: row.take(1).unwrap(),
@@ -579,7 +711,7 @@ fn update_instance_metas(instance_metas: Arc<RwLock<(u32, HashMap<u32, InstanceM
     db_main
         .select_wparams("SELECT A.id, B.character_id FROM instance_meta A \
         JOIN instance_participants B ON A.id = B.instance_meta_id \
-        WHERE A.id > :saved_instance_meta_id ORDER BY A.id", |mut row| {
+        WHERE A.id > :saved_instance_meta_id AND A.deleted_ts IS NULL ORDER BY A.id", |mut row| {
             (row.take::<u32, usize>(0).unwrap(), row.take::<u32, usize>(1).unwrap())
         }, params)
         .into_iter()
@@ -0,0 +1,161 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::modules::instance::domain_value::{IndexedInstance, InstanceMeta};
+use crate::modules::instance::dto::{InstanceSearchCursor, InstanceSearchFilter};
+
+#[derive(Debug, Default)]
+pub struct InstanceSearchIndex {
+    instances: HashMap<u32, IndexedInstance>,
+    // (start_ts, instance_meta_id)
+    by_start: BTreeSet<(u64, u32)>,
+    // character_id => instance_meta_ids
+    by_character: HashMap<u32, BTreeSet<u32>>,
+    // guild_id => instance_meta_ids
+    by_guild: HashMap<u32, BTreeSet<u32>>,
+    // encounter_id => instance_meta_ids
+    by_killed_encounter: HashMap<u32, BTreeSet<u32>>,
+}
+
+impl InstanceSearchIndex {
+    pub fn get(&self, instance_meta_id: u32) -> Option<&IndexedInstance> {
+        self.instances.get(&instance_meta_id)
+    }
+
+    pub fn instance_meta_ids(&self) -> Vec<u32> {
+        self.instances.keys().copied().collect()
+    }
+
+    pub fn instances(&self) -> impl Iterator<Item = &IndexedInstance> {
+        self.instances.values()
+    }
+
+    pub fn guild_instances(&self, guild_id: u32) -> Vec<&IndexedInstance> {
+        self.by_guild.get(&guild_id).map(|instance_meta_ids| instance_meta_ids.iter().filter_map(|instance_meta_id| self.instances.get(instance_meta_id)).collect()).unwrap_or_default()
+    }
+
+    /// Whether the indexed state still matches the instance meta and its kills
+    pub fn is_up_to_date(&self, instance_meta: &InstanceMeta, killed_encounter_ids: &BTreeSet<u32>) -> bool {
+        self.instances
+            .get(&instance_meta.instance_meta_id)
+            .map(|indexed| indexed.participants == instance_meta.participants && indexed.end_ts == instance_meta.end_ts && indexed.privacy_type == instance_meta.privacy_type && indexed.killed_encounter_ids == *killed_encounter_ids)
+            .unwrap_or(false)
+    }
+
+    pub fn insert(&mut self, instance: IndexedInstance) {
+        self.remove(instance.instance_meta_id);
+
+        let instance_meta_id = instance.instance_meta_id;
+        self.by_start.insert((instance.start_ts, instance_meta_id));
+        for character_id in instance.participants.iter() {
+            self.by_character.entry(*character_id).or_default().insert(instance_meta_id);
+        }
+        if let Some((guild_id, _)) = &instance.guild {
+            self.by_guild.entry(*guild_id).or_default().insert(instance_meta_id);
+        }
+        for encounter_id in instance.killed_encounter_ids.iter() {
+            self.by_killed_encounter.entry(*encounter_id).or_default().insert(instance_meta_id);
+        }
+        self.instances.insert(instance_meta_id, instance);
+    }
+
+    pub fn remove(&mut self, instance_meta_id: u32) {
+        if let Some(instance) = self.instances.remove(&instance_meta_id) {
+            self.by_start.remove(&(instance.start_ts, instance_meta_id));
+            for character_id in instance.participants.iter() {
+                remove_posting(&mut self.by_character, *character_id, instance_meta_id);
+            }
+            if let Some((guild_id, _)) = instance.guild {
+                remove_posting(&mut self.by_guild, guild_id, instance_meta_id);
+            }
+            for encounter_id in instance.killed_encounter_ids.iter() {
+                remove_posting(&mut self.by_killed_encounter, *encounter_id, instance_meta_id);
+            }
+        }
+    }
+
+    /// Instances of the page after the cursor, ordered by start_ts descending, the number of all matching instances and the cursor of the next page
+    pub fn search(&self, filter: &InstanceSearchFilter, page_size: usize, is_visible: impl Fn(&IndexedInstance) -> bool) -> (Vec<&IndexedInstance>, usize, Option<InstanceSearchCursor>) {
+        let guild_name = filter.guild_name.as_ref().map(|guild_name| guild_name.to_lowercase());
+        let matches: Vec<&IndexedInstance> = self
+            .candidates(filter)
+            .into_iter()
+            .filter_map(|instance_meta_id| self.instances.get(&instance_meta_id))
+            .filter(|instance| filter.meta_type.map(|meta_type| instance.meta_type == meta_type).unwrap_or(true))
+            .filter(|instance| filter.server_id.map(|server_id| instance.server_id == server_id).unwrap_or(true))
+            .filter(|instance| filter.map_id.map(|map_id| instance.map_id == map_id).unwrap_or(true))
+            .filter(|instance| filter.map_difficulty.map(|map_difficulty| instance.map_difficulty == Some(map_difficulty)).unwrap_or(true))
+            .filter(|instance| {
+                guild_name
+                    .as_ref()
+                    .map(|guild_name| instance.guild.as_ref().map(|(_, name)| name.to_lowercase().contains(guild_name)).unwrap_or(false))
+                    .unwrap_or(true)
+            })
+            .filter(|instance| filter.min_duration.map(|min_duration| instance.duration().map(|duration| duration >= min_duration).unwrap_or(false)).unwrap_or(true))
+            .filter(|instance| filter.max_duration.map(|max_duration| instance.duration().map(|duration| duration <= max_duration).unwrap_or(false)).unwrap_or(true))
+            .filter(|instance| is_visible(instance))
+            .collect();
+        let num_items = matches.len();
+
+        let mut page: Vec<&IndexedInstance> = matches
+            .into_iter()
+            .skip_while(|instance| filter.cursor.as_ref().map(|cursor| (instance.start_ts, instance.instance_meta_id) >= (cursor.start_ts, cursor.instance_meta_id)).unwrap_or(false))
+            .take(page_size + 1)
+            .collect();
+        let next_cursor = if page.len() > page_size {
+            page.truncate(page_size);
+            page.last().map(|instance| InstanceSearchCursor {
+                start_ts: instance.start_ts,
+                instance_meta_id: instance.instance_meta_id,
+            })
+        } else {
+            None
+        };
+        (page, num_items, next_cursor)
+    }
+
+    /// Instance meta ids narrowed down by the posting lists, ordered by start_ts descending
+    fn candidates(&self, filter: &InstanceSearchFilter) -> Vec<u32> {
+        let mut candidates: Option<BTreeSet<u32>> = None;
+        let mut restrict = |postings: BTreeSet<u32>| {
+            candidates = Some(match candidates.take() {
+                Some(current) => current.intersection(&postings).copied().collect(),
+                None => postings,
+            });
+        };
+
+        if let Some(character_id) = filter.character_id {
+            restrict(self.by_character.get(&character_id).cloned().unwrap_or_default());
+        }
+        if let Some(guild_id) = filter.guild_id {
+            restrict(self.by_guild.get(&guild_id).cloned().unwrap_or_default());
+        }
+        for encounter_id in filter.killed_all_encounter_ids.iter() {
+            restrict(self.by_killed_encounter.get(encounter_id).cloned().unwrap_or_default());
+        }
+        if !filter.killed_any_encounter_ids.is_empty() {
+            restrict(filter.killed_any_encounter_ids.iter().filter_map(|encounter_id| self.by_killed_encounter.get(encounter_id)).flatten().copied().collect());
+        }
+
+        match candidates {
+            Some(candidates) => {
+                let mut ordered: Vec<(u64, u32)> = candidates
+                    .into_iter()
+                    .filter_map(|instance_meta_id| self.instances.get(&instance_meta_id))
+                    .map(|instance| (instance.start_ts, instance.instance_meta_id))
+                    .collect();
+                ordered.sort_unstable_by(|left, right| right.cmp(left));
+                ordered.into_iter().map(|(_, instance_meta_id)| instance_meta_id).collect()
+            },
+            None => self.by_start.iter().rev().map(|(_, instance_meta_id)| *instance_meta_id).collect(),
+        }
+    }
+}
+
+fn remove_posting(postings: &mut HashMap<u32, BTreeSet<u32>>, key: u32, instance_meta_id: u32) {
+    if let Some(instance_meta_ids) = postings.get_mut(&key) {
+        instance_meta_ids.remove(&instance_meta_id);
+        if instance_meta_ids.is_empty() {
+            postings.remove(&key);
+        }
+    }
+}
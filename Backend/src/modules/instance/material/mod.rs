@@ -1,5 +1,9 @@
 pub use self::instance::Instance;
+pub use self::instance_search_index::InstanceSearchIndex;
 pub use self::role::Role;
+pub use self::season_calendar::SeasonCalendar;
 
 mod instance;
+mod instance_search_index;
 mod role;
+mod season_calendar;
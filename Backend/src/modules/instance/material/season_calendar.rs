@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use chrono::{Datelike, NaiveDateTime};
+
+use crate::modules::instance::domain_value::Season;
+use crate::util::database::Select;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SeasonCalendar {
+    seasons: Vec<Season>,
+    // server_id => expansion_id
+    server_expansions: HashMap<u32, u8>,
+}
+
+impl SeasonCalendar {
+    pub fn new(mut seasons: Vec<Season>, server_expansions: HashMap<u32, u8>) -> Self {
+        seasons.sort_by_key(|season| (season.server_id, season.expansion_id, season.start_ts, season.id));
+        let mut season_index: u8 = 0;
+        let mut last_scope = None;
+        for season in seasons.iter_mut() {
+            let scope = Some((season.server_id, season.expansion_id));
+            if scope != last_scope {
+                season_index = 0;
+                last_scope = scope;
+            }
+            season_index = season_index.saturating_add(1);
+            season.season_index = season_index;
+        }
+
+        SeasonCalendar { seasons, server_expansions }
+    }
+
+    pub fn load(db_main: &mut impl Select) -> Self {
+        let seasons = db_main.select("SELECT id, server_id, expansion_id, season_name, start_ts, end_ts FROM instance_season", |mut row| Season {
+            id: row.take(0).unwrap(),
+            server_id: row.take_opt(1).unwrap().ok(),
+            expansion_id: row.take(2).unwrap(),
+            name: row.take(3).unwrap(),
+            start_ts: row.take(4).unwrap(),
+            end_ts: row.take_opt(5).unwrap().ok(),
+            season_index: 0,
+        });
+        let server_expansions = db_main
+            .select("SELECT id, expansion_id FROM data_server", |mut row| (row.take::<u32, usize>(0).unwrap(), row.take::<u8, usize>(1).unwrap()))
+            .into_iter()
+            .collect();
+        SeasonCalendar::new(seasons, server_expansions)
+    }
+
+    pub fn get_seasons(&self) -> &Vec<Season> {
+        &self.seasons
+    }
+
+    pub fn get_season(&self, season_id: u32) -> Option<&Season> {
+        self.seasons.iter().find(|season| season.id == season_id)
+    }
+
+    pub fn get_server_expansion(&self, server_id: u32) -> Option<u8> {
+        self.server_expansions.get(&server_id).copied()
+    }
+
+    /// The calendar a server follows: Its own phases if it has any, otherwise the phases of its expansion
+    pub fn get_server_seasons(&self, server_id: u32) -> Vec<&Season> {
+        let server_seasons: Vec<&Season> = self.seasons.iter().filter(|season| season.server_id == Some(server_id)).collect();
+        if !server_seasons.is_empty() {
+            return server_seasons;
+        }
+
+        match self.get_server_expansion(server_id) {
+            Some(expansion_id) => self.seasons.iter().filter(|season| season.server_id.is_none() && season.expansion_id == expansion_id).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Rankings, speed runs and speed kills refer to the season index, hence new seasons can only be appended to their scope
+    pub fn may_append_season(&self, server_id: Option<u32>, expansion_id: u8, start_ts: u64) -> bool {
+        self.seasons.iter().filter(|season| season.server_id == server_id && season.expansion_id == expansion_id).all(|season| season.start_ts < start_ts)
+    }
+
+    /// Whether the other seasons keep their index if the season is moved to the given (server_id, expansion_id, start_ts) or deleted (None)
+    pub fn keeps_season_indexes(&self, season_id: u32, moved_to: Option<(Option<u32>, u8, u64)>) -> bool {
+        let season = match self.get_season(season_id) {
+            Some(season) => season,
+            None => return false,
+        };
+        let scope: Vec<&Season> = self
+            .seasons
+            .iter()
+            .filter(|other| other.id != season_id && other.server_id == season.server_id && other.expansion_id == season.expansion_id)
+            .collect();
+        let is_last = scope.iter().all(|other| other.season_index < season.season_index);
+        match moved_to {
+            // Within its scope the season may only move between its neighbours
+            Some((server_id, expansion_id, start_ts)) if server_id == season.server_id && expansion_id == season.expansion_id => {
+                scope.iter().all(|other| if other.season_index < season.season_index { other.start_ts < start_ts } else { start_ts < other.start_ts })
+            },
+            Some((server_id, expansion_id, start_ts)) => is_last && self.may_append_season(server_id, expansion_id, start_ts),
+            None => is_last,
+        }
+    }
+
+    /// Season index of a timestamp (ms) on a server. 0 if the server has a calendar but the timestamp is not within any phase.
+    /// Servers without a calendar fall back to the quarterly seasons.
+    pub fn season_index(&self, server_id: u32, ts: u64) -> u8 {
+        let server_seasons = self.get_server_seasons(server_id);
+        if server_seasons.is_empty() {
+            return quarterly_season_index(ts);
+        }
+
+        server_seasons
+            .into_iter()
+            .filter(|season| season.start_ts <= ts && season.end_ts.map(|end_ts| ts < end_ts).unwrap_or(true))
+            .max_by_key(|season| season.start_ts)
+            .map(|season| season.season_index)
+            .unwrap_or(0)
+    }
+}
+
+fn quarterly_season_index(ts: u64) -> u8 {
+    static FIRST_SEASON_YEAR: i32 = 2020;
+    static SEASON_DURATION: i32 = 3;
+    let today = NaiveDateTime::from_timestamp((ts / 1000) as i64, 0);
+    let year = today.year();
+    let month = today.month() as i32;
+    let months_since = (year - FIRST_SEASON_YEAR) * 12 + month;
+    if (months_since % SEASON_DURATION) == 0 {
+        return (months_since / SEASON_DURATION) as u8;
+    }
+    (months_since / SEASON_DURATION) as u8 + 1
+}
@@ -1,9 +1,9 @@
 pub use self::material::Instance;
 
 mod domain_value;
-mod dto;
+pub mod dto;
 mod material;
-mod tools;
+pub mod tools;
 pub mod transfer;
 
 #[cfg(test)]
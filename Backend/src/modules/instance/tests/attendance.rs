@@ -0,0 +1,168 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::modules::account::guard::Viewer;
+use crate::modules::armory::material::{Character, CharacterClaim};
+use crate::modules::armory::Armory;
+use crate::modules::instance::domain_value::{IndexedInstance, InstanceMeta, MetaType, PrivacyType};
+use crate::modules::instance::dto::{AttendanceAlias, AttendanceFilter, CharacterAttendance, InstanceFailure, MapAttendance};
+use crate::modules::instance::tools::{calculate_attendance, may_alias_characters, Attendance};
+use crate::modules::instance::Instance;
+use crate::util::database::MockDatabase;
+
+#[test]
+fn attendance_is_split_by_map() {
+    // Arrange
+    let raids = vec![(409, vec![1, 2]), (409, vec![1]), (469, vec![2])];
+
+    // Act
+    let attendance = calculate_attendance(&raids, &HashMap::new());
+
+    // Assert
+    assert_eq!(
+        attendance,
+        vec![
+            CharacterAttendance {
+                character_id: 1,
+                alt_character_ids: vec![],
+                attended: 2,
+                total: 3,
+                maps: vec![MapAttendance { map_id: 409, attended: 2, total: 2 }, MapAttendance { map_id: 469, attended: 0, total: 1 }],
+            },
+            CharacterAttendance {
+                character_id: 2,
+                alt_character_ids: vec![],
+                attended: 2,
+                total: 3,
+                maps: vec![MapAttendance { map_id: 409, attended: 1, total: 2 }, MapAttendance { map_id: 469, attended: 1, total: 1 }],
+            },
+        ]
+    );
+}
+
+#[test]
+fn alts_count_towards_their_main_once_per_raid() {
+    // Arrange
+    let raids = vec![(409, vec![1, 3]), (409, vec![3]), (409, vec![2])];
+    let mut aliases = HashMap::new();
+    aliases.insert(3, 1);
+
+    // Act
+    let attendance = calculate_attendance(&raids, &aliases);
+
+    // Assert
+    assert_eq!(attendance.len(), 2);
+    assert_eq!(attendance[0].character_id, 1);
+    assert_eq!(attendance[0].alt_character_ids, vec![3]);
+    assert_eq!(attendance[0].attended, 2);
+    assert_eq!(attendance[1].character_id, 2);
+    assert_eq!(attendance[1].attended, 1);
+}
+
+#[test]
+fn alias_requires_claims_of_both_characters() {
+    // Arrange
+    let claimed_character_ids = vec![1, 2];
+
+    // Act + Assert
+    assert!(may_alias_characters(&claimed_character_ids, 2, 1));
+    assert!(!may_alias_characters(&claimed_character_ids, 3, 1));
+    assert!(!may_alias_characters(&claimed_character_ids, 2, 3));
+    assert!(!may_alias_characters(&[], 2, 1));
+}
+
+#[test]
+fn set_attendance_alias_rejects_unowned_characters() {
+    // Arrange
+    let armory = Armory::default();
+    {
+        let mut characters = armory.characters.write().unwrap();
+        for character_id in 1..=3 {
+            characters.insert(
+                character_id,
+                Character {
+                    id: character_id,
+                    server_id: 1,
+                    server_uid: character_id as u64,
+                    last_update: None,
+                    history_moments: Vec::new(),
+                },
+            );
+        }
+        let mut character_claims = armory.character_claims.write().unwrap();
+        for (claim_id, member_id, character_id, confirmed_ts) in [(1, 10, 1, Some(2000)), (2, 10, 2, None), (3, 20, 3, Some(2000))].iter() {
+            character_claims.insert(
+                *claim_id,
+                CharacterClaim {
+                    id: *claim_id,
+                    member_id: *member_id,
+                    character_id: *character_id,
+                    token: "LPAB12CD34".to_string(),
+                    requested_ts: 1000,
+                    confirmed_ts: *confirmed_ts,
+                },
+            );
+        }
+    }
+    let instance = Instance::default();
+    let alias = |alt_character_id: u32, main_character_id: u32| AttendanceAlias { alt_character_id, main_character_id };
+    // Without expectations any query fails the test, the alias has to be rejected before
+    let mut db_main = MockDatabase::new();
+
+    // Act
+    let unconfirmed_alt = instance.set_attendance_alias(&mut db_main, &armory, 10, alias(2, 1));
+    let foreign_alt = instance.set_attendance_alias(&mut db_main, &armory, 10, alias(3, 1));
+    let foreign_main = instance.set_attendance_alias(&mut db_main, &armory, 20, alias(3, 1));
+
+    // Assert
+    assert!(matches!(unconfirmed_alt, Err(InstanceFailure::AccessDenied)));
+    assert!(matches!(foreign_alt, Err(InstanceFailure::AccessDenied)));
+    assert!(matches!(foreign_main, Err(InstanceFailure::AccessDenied)));
+}
+
+#[test]
+fn guild_attendance_counts_raids_of_the_guild() {
+    // Arrange
+    let instance = Instance::default();
+    for (instance_meta_id, guild_id, participants) in [(1, 5, vec![1, 2]), (2, 6, vec![3])].iter() {
+        instance.instance_metas.write().unwrap().1.insert(
+            *instance_meta_id,
+            InstanceMeta {
+                instance_meta_id: *instance_meta_id,
+                server_id: 1,
+                start_ts: 1000,
+                end_ts: None,
+                map_id: 409,
+                expired: None,
+                participants: participants.clone(),
+                instance_specific: MetaType::Raid { map_difficulty: 9 },
+                uploaded_user: 1,
+                upload_id: *instance_meta_id,
+                privacy_type: PrivacyType::Public,
+            },
+        );
+        instance.search_index.write().unwrap().insert(IndexedInstance {
+            instance_meta_id: *instance_meta_id,
+            meta_type: 0,
+            server_id: 1,
+            map_id: 409,
+            map_difficulty: Some(9),
+            start_ts: 1000,
+            end_ts: None,
+            participants: participants.clone(),
+            guild: Some((*guild_id, format!("Guild {}", guild_id))),
+            pvp_participants: Vec::new(),
+            killed_encounter_ids: BTreeSet::new(),
+            uploaded_user: 1,
+            privacy_type: PrivacyType::Public,
+        });
+    }
+    let mut db_main = MockDatabase::new();
+    db_main.expect_select::<(u32, u32, u32)>().times(1).return_const(Vec::new());
+
+    // Act
+    let attendance = instance.get_guild_attendance(&mut db_main, &Armory::default(), &Viewer::default(), 5, &AttendanceFilter::default());
+
+    // Assert
+    assert_eq!(attendance.num_raids, 1);
+    assert_eq!(attendance.characters.iter().map(|character| character.character_id).collect::<Vec<u32>>(), vec![1, 2]);
+}
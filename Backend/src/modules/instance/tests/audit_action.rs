@@ -0,0 +1,65 @@
+use crate::modules::instance::domain_value::{AuditAction, PrivacyType};
+use crate::modules::instance::tools::may_restore;
+
+#[test]
+fn audit_action_round_trip() {
+    // Arrange
+    let actions = vec![
+        AuditAction::Delete,
+        AuditAction::Restore,
+        AuditAction::Purge,
+        AuditAction::Unrank { attempt_id: 42 },
+        AuditAction::UpdatePrivacy(PrivacyType::NotListed),
+        AuditAction::UpdatePrivacy(PrivacyType::OnlyGroups(7)),
+    ];
+
+    for action in actions {
+        // Act
+        let restored = AuditAction::from_columns(
+            action.to_u8(),
+            action.attempt_id(),
+            action.privacy_type().map(|privacy_type| privacy_type.to_u8()),
+            action.privacy_type().map(|privacy_type| privacy_type.get_group()),
+        );
+
+        // Assert
+        assert_eq!(restored, Some(action));
+    }
+}
+
+#[test]
+fn audit_action_rejects_incomplete_columns() {
+    // Arrange
+    let columns = vec![(3, None, None, None), (4, None, None, None), (4, None, Some(9), None), (99, None, None, None)];
+
+    // Act
+    let actions: Vec<Option<AuditAction>> = columns
+        .into_iter()
+        .map(|(action, attempt_id, privacy_type, privacy_ref)| AuditAction::from_columns(action, attempt_id, privacy_type, privacy_ref))
+        .collect();
+
+    // Assert
+    assert!(actions.iter().all(Option::is_none));
+}
+
+#[test]
+fn only_moderators_and_deleting_uploaders_may_restore() {
+    // Arrange
+    let uploader = 1;
+    let moderator = 2;
+    let other_member = 3;
+
+    // Act
+    let own_deletion = may_restore(uploader, Some(uploader), uploader, false);
+    let moderator_deletion = may_restore(uploader, Some(moderator), uploader, false);
+    let deleted_account = may_restore(uploader, None, uploader, false);
+    let by_moderator = may_restore(uploader, Some(uploader), moderator, true);
+    let by_other_member = may_restore(uploader, Some(uploader), other_member, false);
+
+    // Assert
+    assert!(own_deletion);
+    assert!(!moderator_deletion);
+    assert!(!deleted_account);
+    assert!(by_moderator);
+    assert!(!by_other_member);
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use mockall::Sequence;
+
+use crate::modules::armory::domain_value::{CharacterGear, CharacterInfo, HistoryMoment};
+use crate::modules::armory::material::{Character, CharacterClaim, CharacterHistory};
+use crate::modules::armory::tools::ReassignCharacter;
+use crate::modules::armory::Armory;
+use crate::modules::instance::domain_value::MergedRowType;
+use crate::modules::instance::dto::InstanceFailure;
+use crate::modules::instance::tools::{in_transaction, may_merge_characters};
+use crate::util::database::{Execute, MockExecute, MockSelect};
+
+/// Expects the statements in this order, every statement but the failing one succeeds
+fn expect_statements(statements: &[&'static str], failing_statement: Option<&'static str>) -> MockExecute {
+    let mut db_main = MockExecute::new();
+    let mut sequence = Sequence::new();
+    for statement in statements.iter().copied() {
+        let succeeds = failing_statement != Some(statement);
+        if ["START TRANSACTION", "COMMIT", "ROLLBACK"].contains(&statement) {
+            db_main.expect_execute_one().withf(move |query_str| &query_str[..] == statement).times(1).in_sequence(&mut sequence).return_const(succeeds);
+        } else {
+            db_main.expect_execute_wparams().withf(move |query_str, _| &query_str[..] == statement).times(1).in_sequence(&mut sequence).return_const(succeeds);
+        }
+    }
+    db_main
+}
+
+fn get_character_history(id: u32, character_id: u32) -> CharacterHistory {
+    CharacterHistory {
+        id,
+        character_id,
+        character_info: CharacterInfo {
+            id,
+            gear: CharacterGear {
+                id,
+                head: None,
+                neck: None,
+                shoulder: None,
+                back: None,
+                chest: None,
+                shirt: None,
+                tabard: None,
+                wrist: None,
+                main_hand: None,
+                off_hand: None,
+                ternary_hand: None,
+                glove: None,
+                belt: None,
+                leg: None,
+                boot: None,
+                ring1: None,
+                ring2: None,
+                trinket1: None,
+                trinket2: None,
+            },
+            hero_class_id: 1,
+            level: 60,
+            gender: false,
+            profession1: None,
+            profession2: None,
+            talent_specialization: None,
+            race_id: 1,
+        },
+        character_name: format!("Character{}", character_id),
+        character_guild: None,
+        character_title: None,
+        profession_skill_points1: None,
+        profession_skill_points2: None,
+        facial: None,
+        arena_teams: Vec::new(),
+        timestamp: id as u64,
+        first_seen: id as u64,
+    }
+}
+
+/// Character 1 with the snapshots 1 and 2, character 2 with the snapshot 3 and character 3 with the snapshot 4. Every snapshot is cached
+fn get_armory() -> Armory {
+    let armory = Armory::default();
+    {
+        let mut characters = armory.characters.write().unwrap();
+        let mut cache_char_history = armory.cache_char_history.write().unwrap();
+        for (character_id, history_ids) in [(1, vec![1, 2]), (2, vec![3]), (3, vec![4])].iter() {
+            for history_id in history_ids {
+                cache_char_history.insert(*history_id, get_character_history(*history_id, *character_id));
+            }
+            characters.insert(
+                *character_id,
+                Character {
+                    id: *character_id,
+                    server_id: 1,
+                    server_uid: *character_id as u64,
+                    last_update: history_ids.last().map(|history_id| get_character_history(*history_id, *character_id)),
+                    history_moments: history_ids.iter().map(|history_id| HistoryMoment { id: *history_id, timestamp: *history_id as u64 }).collect(),
+                },
+            );
+        }
+        let mut character_claims = armory.character_claims.write().unwrap();
+        for (claim_id, character_id) in [(1, 1), (2, 3)].iter() {
+            character_claims.insert(
+                *claim_id,
+                CharacterClaim {
+                    id: *claim_id,
+                    member_id: 1,
+                    character_id: *character_id,
+                    token: "LPAB12CD34".to_string(),
+                    requested_ts: 1000,
+                    confirmed_ts: Some(2000),
+                },
+            );
+        }
+    }
+    armory
+}
+
+fn get_history_ids(armory: &Armory, character_id: u32) -> Vec<u32> {
+    armory.characters.read().unwrap().get(&character_id).unwrap().history_moments.iter().map(|history_moment| history_moment.id).collect()
+}
+
+#[test]
+fn merged_row_type_round_trip() {
+    for row_type in 0..7 {
+        // Act
+        let merged_row_type = MergedRowType::from_u8(row_type);
+
+        // Assert
+        assert_eq!(merged_row_type.map(|merged_row_type| merged_row_type.to_u8()), Some(row_type));
+    }
+    assert!(MergedRowType::from_u8(7).is_none());
+}
+
+#[test]
+fn armory_rows_have_no_instance_table() {
+    // Act + Assert
+    assert!(MergedRowType::CharacterHistory.instance_table().is_none());
+    assert!(MergedRowType::CharacterClaim.instance_table().is_none());
+    assert_eq!(MergedRowType::Loot.instance_table(), Some(("instance_loot", "id")));
+    assert_eq!(MergedRowType::Participation.instance_table(), Some(("instance_participants", "instance_meta_id")));
+}
+
+#[test]
+fn merge_requires_both_claims_or_moderator() {
+    // Arrange
+    let claimed_character_ids = vec![1, 2];
+
+    // Act + Assert
+    assert!(may_merge_characters(&claimed_character_ids, 1, 2, false));
+    assert!(!may_merge_characters(&claimed_character_ids, 1, 3, false));
+    assert!(!may_merge_characters(&[], 3, 4, false));
+    assert!(may_merge_characters(&[], 3, 4, true));
+}
+
+#[test]
+fn merge_statements_are_committed_together() {
+    // Arrange
+    let mut db_main = expect_statements(&["START TRANSACTION", "UPDATE instance_loot", "COMMIT"], None);
+
+    // Act
+    let result = in_transaction(&mut db_main, |db_main| {
+        db_main.execute_wparams("UPDATE instance_loot", Vec::new());
+        Ok(1)
+    });
+
+    // Assert
+    assert!(matches!(result, Ok(1)));
+    db_main.checkpoint();
+}
+
+#[test]
+fn failed_merge_statements_are_rolled_back() {
+    // Arrange
+    let mut db_main = expect_statements(&["START TRANSACTION", "UPDATE instance_loot", "ROLLBACK"], None);
+    let mut failing_commit_db_main = expect_statements(&["START TRANSACTION", "COMMIT", "ROLLBACK"], Some("COMMIT"));
+
+    // Act
+    let result = in_transaction(&mut db_main, |db_main| -> Result<(), InstanceFailure> {
+        db_main.execute_wparams("UPDATE instance_loot", Vec::new());
+        Err(InstanceFailure::InvalidInput)
+    });
+    let failing_commit_result = in_transaction(&mut failing_commit_db_main, |_| Ok(()));
+
+    // Assert
+    assert!(matches!(result, Err(InstanceFailure::InvalidInput)));
+    db_main.checkpoint();
+    assert!(matches!(failing_commit_result, Err(InstanceFailure::Unknown)));
+    failing_commit_db_main.checkpoint();
+}
+
+#[test]
+fn merge_and_undo_move_only_rows_of_the_previous_owner() {
+    // Arrange
+    let armory = get_armory();
+    let mut db_execute = MockExecute::new();
+    db_execute
+        .expect_execute_batch_wparams::<u32>()
+        .withf(|query_str, history_ids, _| query_str.ends_with("WHERE id=:id AND character_id=:previous_character_id") && *history_ids == vec![1, 2, 4])
+        .times(1)
+        .return_const(true);
+    // Every snapshot is cached, nothing is selected
+    let mut db_select = MockSelect::new();
+
+    // Act + Assert
+    // Snapshot 4 and claim 2 belong to character 3 and stay there
+    assert!(armory.reassign_character_history(&mut db_execute, &[1, 2, 4], 1, 2).is_ok());
+    assert!(armory.reload_reassigned_characters(&mut db_select, &[1, 2, 4], &[1, 2], 1, 2).is_ok());
+    assert!(get_history_ids(&armory, 1).is_empty());
+    assert_eq!(get_history_ids(&armory, 2), vec![1, 2, 3]);
+    assert_eq!(get_history_ids(&armory, 3), vec![4]);
+    assert_eq!(armory.characters.read().unwrap().get(&2).unwrap().last_update.as_ref().map(|last_update| last_update.id), Some(3));
+    assert!(armory.characters.read().unwrap().get(&1).unwrap().last_update.is_none());
+    assert_eq!(armory.cache_char_history.read().unwrap().get(&1).map(|character_history| character_history.character_id), Some(2));
+    let claimed_characters = |armory: &Armory| armory.character_claims.read().unwrap().values().map(|claim| (claim.id, claim.character_id)).collect::<HashMap<u32, u32>>();
+    assert_eq!(claimed_characters(&armory), [(1, 2), (2, 3)].iter().cloned().collect());
+
+    // Undo
+    assert!(armory.reload_reassigned_characters(&mut db_select, &[1, 2, 4], &[1, 2], 2, 1).is_ok());
+    assert_eq!(get_history_ids(&armory, 1), vec![1, 2]);
+    assert_eq!(get_history_ids(&armory, 2), vec![3]);
+    assert_eq!(get_history_ids(&armory, 3), vec![4]);
+    assert_eq!(armory.characters.read().unwrap().get(&1).unwrap().last_update.as_ref().map(|last_update| last_update.id), Some(2));
+    assert_eq!(claimed_characters(&armory), [(1, 1), (2, 3)].iter().cloned().collect());
+}
@@ -0,0 +1,112 @@
+use crate::modules::data::domain_value::MapEncounter;
+use crate::modules::instance::dto::{InstanceViewerAttempt, ProgressionRanking};
+use crate::modules::instance::tools::{calculate_raid_progression, current_lockout_start, sort_progression_rankings, LOCKOUT_DURATION};
+
+fn map_encounter(encounter_id: u32, is_required: bool) -> MapEncounter {
+    MapEncounter {
+        map_id: 533,
+        difficulty_id: Some(4),
+        encounter_id,
+        is_required,
+    }
+}
+
+fn attempt(id: u32, encounter_id: u32, end_ts: u64, is_kill: bool) -> InstanceViewerAttempt {
+    InstanceViewerAttempt {
+        id,
+        is_kill,
+        encounter_id,
+        start_ts: end_ts - 10,
+        end_ts,
+    }
+}
+
+fn ranking(guild_id: u32, map_id: u16, num_killed: u32, progress_ts: u64) -> ProgressionRanking {
+    ProgressionRanking {
+        guild_id,
+        guild_name: format!("Guild {}", guild_id),
+        server_id: 1,
+        map_id,
+        difficulty_id: 4,
+        season_index: 1,
+        num_killed,
+        num_encounters: 15,
+        progress_ts,
+    }
+}
+
+#[test]
+fn raid_progression_counts_wipes_before_first_kill() {
+    // Arrange
+    let map_encounters = vec![map_encounter(1, true), map_encounter(2, true), map_encounter(3, false)];
+    let attempts = vec![attempt(1, 1, 100, false), attempt(2, 1, 200, false), attempt(3, 1, 300, true), attempt(4, 1, 400, false), attempt(5, 2, 500, false)];
+
+    // Act
+    let progression = calculate_raid_progression(533, 4, &map_encounters, &attempts, 1000);
+
+    // Assert
+    assert_eq!(progression.num_killed, 1);
+    assert_eq!(progression.num_encounters, 3);
+    assert_eq!(progression.encounters[0].first_kill_ts, Some(300));
+    assert_eq!(progression.encounters[0].wipes_before_first_kill, 2);
+    assert_eq!(progression.encounters[1].first_kill_ts, None);
+    assert_eq!(progression.encounters[1].wipes_before_first_kill, 1);
+    assert_eq!(progression.encounters[2].wipes_before_first_kill, 0);
+}
+
+#[test]
+fn raid_progression_is_cleared_when_required_encounters_died_this_week() {
+    // Arrange
+    let map_encounters = vec![map_encounter(1, true), map_encounter(2, true), map_encounter(3, false)];
+    let attempts = vec![attempt(1, 1, 100, true), attempt(2, 1, 1100, true), attempt(3, 2, 1200, true)];
+
+    // Act
+    let cleared = calculate_raid_progression(533, 4, &map_encounters, &attempts, 1000);
+    let not_cleared = calculate_raid_progression(533, 4, &map_encounters, &attempts[..1], 1000);
+
+    // Assert
+    assert!(cleared.cleared_this_week);
+    assert!(cleared.encounters[0].killed_this_week);
+    assert!(!cleared.encounters[2].killed_this_week);
+    assert!(!not_cleared.cleared_this_week);
+    assert!(!not_cleared.encounters[0].killed_this_week);
+}
+
+#[test]
+fn raid_progression_without_encounter_data_uses_attempted_encounters() {
+    // Arrange
+    let attempts = vec![attempt(1, 7, 100, true), attempt(2, 5, 200, false)];
+
+    // Act
+    let progression = calculate_raid_progression(533, 4, &[], &attempts, 0);
+
+    // Assert
+    assert_eq!(progression.encounters.iter().map(|encounter| encounter.encounter_id).collect::<Vec<u32>>(), vec![5, 7]);
+    assert_eq!(progression.num_killed, 1);
+    assert!(!progression.cleared_this_week);
+}
+
+#[test]
+fn lockout_start_follows_weekly_resets() {
+    // Arrange
+    let reset_time = 10 * LOCKOUT_DURATION;
+
+    // Act + Assert
+    assert_eq!(current_lockout_start(Some(reset_time), reset_time), reset_time);
+    assert_eq!(current_lockout_start(Some(reset_time), reset_time + 3 * LOCKOUT_DURATION + 5), reset_time + 3 * LOCKOUT_DURATION);
+    assert_eq!(current_lockout_start(Some(reset_time), reset_time - 5), reset_time - LOCKOUT_DURATION);
+    assert_eq!(current_lockout_start(Some(reset_time), reset_time - LOCKOUT_DURATION), reset_time - LOCKOUT_DURATION);
+    assert_eq!(current_lockout_start(None, reset_time), reset_time - LOCKOUT_DURATION);
+}
+
+#[test]
+fn progression_rankings_prefer_further_then_earlier_progress() {
+    // Arrange
+    let mut rankings = vec![ranking(1, 533, 10, 500), ranking(2, 533, 12, 900), ranking(3, 533, 10, 300), ranking(4, 249, 1, 1000)];
+
+    // Act
+    sort_progression_rankings(&mut rankings);
+
+    // Assert
+    assert_eq!(rankings.iter().map(|ranking| ranking.guild_id).collect::<Vec<u32>>(), vec![4, 2, 3, 1]);
+}
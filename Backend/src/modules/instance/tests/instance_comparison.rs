@@ -0,0 +1,76 @@
+use std::collections::HashSet;
+
+use crate::modules::instance::domain_value::ComparedInstance;
+use crate::modules::instance::dto::InstanceViewerAttempt;
+use crate::modules::instance::tools::build_instance_comparison;
+
+fn attempt(id: u32, encounter_id: u32, start_ts: u64, end_ts: u64, is_kill: bool) -> InstanceViewerAttempt {
+    InstanceViewerAttempt { id, is_kill, encounter_id, start_ts, end_ts }
+}
+
+fn compared_instance(instance_meta_id: u32, attempt_id: Option<u32>, attempts: Vec<InstanceViewerAttempt>) -> ComparedInstance {
+    ComparedInstance {
+        instance_meta_id,
+        attempt_id,
+        attempts,
+        spell_casts: Vec::new(),
+        deaths: Vec::new(),
+        melee_damage: Vec::new(),
+        spell_damage: Vec::new(),
+        heals: Vec::new(),
+    }
+}
+
+#[test]
+fn compare_kill_times_and_deaths() {
+    // Arrange
+    let mut last_week = compared_instance(1, None, vec![attempt(1, 10, 1000, 61000, true), attempt(2, 11, 100000, 130000, false), attempt(3, 11, 200000, 290000, true)]);
+    last_week.deaths = vec!["[1,50000,[1,5]]".to_string(), "[2,120000,[1,6]]".to_string(), "[3,150000,[1,6]]".to_string(), "[4,60000,[0,7,100]]".to_string()];
+    let this_week = compared_instance(2, None, vec![attempt(4, 10, 1000, 51000, true)]);
+
+    // Act
+    let comparison = build_instance_comparison(&[last_week, this_week], &HashSet::new());
+
+    // Assert
+    assert_eq!(comparison.columns.len(), 2);
+    assert_eq!(comparison.columns[0].combat_duration, 180000);
+    assert_eq!(comparison.columns[0].num_deaths, 2);
+    assert_eq!(comparison.columns[1].combat_duration, 50000);
+    assert_eq!(comparison.kill_times.len(), 2);
+    assert_eq!(comparison.kill_times[0].encounter_id, 10);
+    assert_eq!(comparison.kill_times[0].durations, vec![Some(60000), Some(50000)]);
+    assert_eq!(comparison.kill_times[1].encounter_id, 11);
+    assert_eq!(comparison.kill_times[1].durations, vec![Some(90000), None]);
+    assert_eq!(comparison.players.len(), 2);
+    assert_eq!(comparison.players[0].character_id, 5);
+    assert_eq!(comparison.players[0].deaths, vec![1, 0]);
+    assert_eq!(comparison.players[1].character_id, 6);
+    assert_eq!(comparison.players[1].deaths, vec![1, 0]);
+}
+
+#[test]
+fn compare_dps_hps_and_consumables_of_attempts() {
+    // Arrange
+    let mut first = compared_instance(1, Some(1), vec![attempt(1, 10, 0, 10000, false)]);
+    first.melee_damage = vec!["[1,1000,[1,5],[0,7,100],1,[[5000,1,0,0,0]]]".to_string(), "[2,20000,[1,5],[0,7,100],1,[[9999,1,0,0,0]]]".to_string()];
+    first.spell_damage = vec!["[3,2000,0,[0,8,200,[1,5]],[0,7,100],133,1,[[5000,4,0,0,0]]]".to_string()];
+    first.heals = vec!["[4,3000,0,[1,6],[1,5],2061,1,2,3000,2000,0,0,0]".to_string()];
+    first.spell_casts = vec!["[5,4000,[1,5],null,17528,1,1]".to_string(), "[6,5000,[1,5],null,133,1,4]".to_string()];
+    let mut second = compared_instance(2, Some(2), vec![attempt(2, 10, 0, 5000, true)]);
+    second.melee_damage = vec!["[1,1000,[1,5],[0,7,100],1,[[15000,1,0,0,0]]]".to_string()];
+    let consumable_spell_ids: HashSet<u32> = vec![17528].into_iter().collect();
+
+    // Act
+    let comparison = build_instance_comparison(&[first, second], &consumable_spell_ids);
+
+    // Assert
+    assert_eq!(comparison.columns[0].attempt_id, Some(1));
+    assert_eq!(comparison.kill_times[0].durations, vec![None, Some(5000)]);
+    let player = comparison.players.iter().find(|player| player.character_id == 5).unwrap();
+    assert_eq!(player.dps, vec![Some(1000.0), Some(3000.0)]);
+    assert_eq!(player.dps_delta, Some(2000.0));
+    assert_eq!(player.consumables_used, vec![1, 0]);
+    let healer = comparison.players.iter().find(|player| player.character_id == 6).unwrap();
+    assert_eq!(healer.hps, vec![Some(200.0), None]);
+    assert_eq!(healer.hps_delta, None);
+}
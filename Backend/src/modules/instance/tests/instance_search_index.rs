@@ -0,0 +1,142 @@
+use std::collections::BTreeSet;
+
+use crate::modules::instance::domain_value::{IndexedInstance, PrivacyType};
+use crate::modules::instance::dto::{InstanceSearchCursor, InstanceSearchFilter};
+use crate::modules::instance::material::InstanceSearchIndex;
+
+fn raid(instance_meta_id: u32, start_ts: u64, duration: u64, participants: &[u32], guild_id: Option<u32>, killed_encounter_ids: &[u32]) -> IndexedInstance {
+    IndexedInstance {
+        instance_meta_id,
+        meta_type: 0,
+        server_id: 1,
+        map_id: 409,
+        map_difficulty: Some(9),
+        start_ts,
+        end_ts: Some(start_ts + duration),
+        participants: participants.to_vec(),
+        guild: guild_id.map(|guild_id| (guild_id, format!("Guild {}", guild_id))),
+        pvp_participants: Vec::new(),
+        killed_encounter_ids: killed_encounter_ids.iter().copied().collect::<BTreeSet<u32>>(),
+        uploaded_user: 1,
+        privacy_type: PrivacyType::Public,
+    }
+}
+
+fn search_index() -> InstanceSearchIndex {
+    let mut search_index = InstanceSearchIndex::default();
+    search_index.insert(raid(1, 100, 50, &[10, 11], Some(1), &[1, 2]));
+    search_index.insert(raid(2, 200, 80, &[10], Some(2), &[1]));
+    search_index.insert(raid(3, 300, 30, &[11], Some(1), &[2]));
+    search_index.insert(raid(4, 400, 60, &[10, 11], None, &[]));
+    search_index
+}
+
+fn instance_meta_ids(search_index: &InstanceSearchIndex, filter: &InstanceSearchFilter) -> Vec<u32> {
+    search_index.search(filter, 10, |_| true).0.into_iter().map(|instance| instance.instance_meta_id).collect()
+}
+
+#[test]
+fn compound_filters_are_intersected() {
+    // Arrange
+    let search_index = search_index();
+
+    // Act + Assert
+    assert_eq!(instance_meta_ids(&search_index, &InstanceSearchFilter::default()), vec![4, 3, 2, 1]);
+    assert_eq!(
+        instance_meta_ids(
+            &search_index,
+            &InstanceSearchFilter {
+                character_id: Some(10),
+                guild_id: Some(1),
+                ..Default::default()
+            }
+        ),
+        vec![1]
+    );
+    assert_eq!(
+        instance_meta_ids(
+            &search_index,
+            &InstanceSearchFilter {
+                killed_all_encounter_ids: vec![1, 2],
+                ..Default::default()
+            }
+        ),
+        vec![1]
+    );
+    assert_eq!(
+        instance_meta_ids(
+            &search_index,
+            &InstanceSearchFilter {
+                killed_any_encounter_ids: vec![1, 2],
+                min_duration: Some(40),
+                ..Default::default()
+            }
+        ),
+        vec![2, 1]
+    );
+    assert_eq!(
+        instance_meta_ids(
+            &search_index,
+            &InstanceSearchFilter {
+                guild_name: Some("gUiLd 1".to_owned()),
+                max_duration: Some(40),
+                ..Default::default()
+            }
+        ),
+        vec![3]
+    );
+}
+
+#[test]
+fn cursor_continues_after_the_last_page() {
+    // Arrange
+    let search_index = search_index();
+    let mut filter = InstanceSearchFilter::default();
+
+    // Act
+    let (first_page, num_items, next_cursor) = search_index.search(&filter, 3, |_| true);
+    let first_page: Vec<u32> = first_page.into_iter().map(|instance| instance.instance_meta_id).collect();
+    filter.cursor = next_cursor.clone();
+    let (second_page, _, last_cursor) = search_index.search(&filter, 3, |_| true);
+
+    // Assert
+    assert_eq!(num_items, 4);
+    assert_eq!(first_page, vec![4, 3, 2]);
+    assert_eq!(next_cursor, Some(InstanceSearchCursor { start_ts: 200, instance_meta_id: 2 }));
+    assert_eq!(second_page.into_iter().map(|instance| instance.instance_meta_id).collect::<Vec<u32>>(), vec![1]);
+    assert_eq!(last_cursor, None);
+}
+
+#[test]
+fn reindexing_replaces_postings() {
+    // Arrange
+    let mut search_index = search_index();
+
+    // Act
+    search_index.insert(raid(1, 100, 50, &[12], Some(3), &[]));
+    search_index.remove(2);
+
+    // Assert
+    let by_character = |character_id| {
+        instance_meta_ids(
+            &search_index,
+            &InstanceSearchFilter {
+                character_id: Some(character_id),
+                ..Default::default()
+            },
+        )
+    };
+    assert_eq!(by_character(10), vec![4]);
+    assert_eq!(by_character(12), vec![1]);
+    assert!(search_index.get(2).is_none());
+    assert_eq!(
+        instance_meta_ids(
+            &search_index,
+            &InstanceSearchFilter {
+                killed_any_encounter_ids: vec![1],
+                ..Default::default()
+            }
+        ),
+        Vec::<u32>::new()
+    );
+}
@@ -0,0 +1,128 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::modules::account::guard::Viewer;
+use crate::modules::armory::domain_value::HistoryMoment;
+use crate::modules::data::domain_value::{Icon, Item, Server};
+use crate::modules::data::Data;
+use crate::modules::instance::domain_value::{InstanceMeta, MetaType, PrivacyType};
+use crate::modules::instance::dto::LootEntry;
+use crate::modules::instance::tools::filter_guild_loot;
+use crate::modules::instance::Instance;
+
+fn loot_entry(instance_meta_id: u32, character_id: u32, looted_ts: u64) -> LootEntry {
+    LootEntry {
+        instance_meta_id,
+        character_id,
+        item_id: 19019,
+        amount: 1,
+        looted_ts,
+        encounter_id: Some(12),
+        quality: None,
+        icon: None,
+        inventory_type: None,
+    }
+}
+
+fn instance_meta(instance_meta_id: u32, privacy_type: PrivacyType) -> InstanceMeta {
+    InstanceMeta {
+        instance_meta_id,
+        server_id: 1,
+        start_ts: 0,
+        end_ts: None,
+        map_id: 409,
+        expired: None,
+        participants: Vec::new(),
+        instance_specific: MetaType::Raid { map_difficulty: 9 },
+        uploaded_user: 1,
+        upload_id: 1,
+        privacy_type,
+    }
+}
+
+fn data_with_item() -> Data {
+    let mut data = Data::default();
+    data.servers.write().unwrap().insert(
+        1,
+        Server {
+            id: 1,
+            expansion_id: 1,
+            name: "Test".to_string(),
+            owner: None,
+            patch: "1.12.1".to_string(),
+            retail_id: None,
+            archived: false,
+        },
+    );
+    data.icons.insert(7, Icon { id: 7, name: "inv_sword_39".to_string() });
+    let mut items = HashMap::new();
+    items.insert(
+        19019,
+        Item {
+            expansion_id: 1,
+            id: 19019,
+            localization_id: 0,
+            icon: 7,
+            quality: 5,
+            inventory_type: Some(13),
+            class_id: 2,
+            required_level: Some(60),
+            bonding: Some(1),
+            sheath: None,
+            itemset: None,
+            max_durability: None,
+            item_level: Some(80),
+            delay: Some(1900),
+            display_info: None,
+        },
+    );
+    data.items.push(items);
+    data
+}
+
+#[test]
+fn visible_loot_hides_private_instances_and_adds_item_data() {
+    // Arrange
+    let instance = Instance::default();
+    {
+        let mut instance_metas = instance.instance_metas.write().unwrap();
+        instance_metas.1.insert(1, instance_meta(1, PrivacyType::Public));
+        instance_metas.1.insert(2, instance_meta(2, PrivacyType::OnlyGroups(7)));
+    }
+    let data = data_with_item();
+    let loot = vec![loot_entry(1, 5, 1000), loot_entry(2, 5, 2000), loot_entry(3, 5, 3000)];
+    let group_member = Viewer {
+        member_id: Some(2),
+        is_moderator: false,
+        groups: vec![7].into_iter().collect::<BTreeSet<u32>>(),
+    };
+
+    // Act
+    let anonymous_loot = instance.visible_loot(&data, &Viewer::default(), loot.clone());
+    let group_member_loot = instance.visible_loot(&data, &group_member, loot);
+
+    // Assert
+    assert_eq!(anonymous_loot.len(), 1);
+    assert_eq!(anonymous_loot[0].instance_meta_id, 1);
+    assert_eq!(anonymous_loot[0].quality, Some(5));
+    assert_eq!(anonymous_loot[0].icon, Some("inv_sword_39".to_string()));
+    assert_eq!(anonymous_loot[0].inventory_type, Some(13));
+    // Instance 3 is unknown
+    assert_eq!(group_member_loot.iter().map(|entry| entry.instance_meta_id).collect::<Vec<u32>>(), vec![1, 2]);
+}
+
+#[test]
+fn guild_loot_uses_the_guild_at_the_time_of_looting() {
+    // Arrange
+    let mut history_moments = HashMap::new();
+    // Character 5 joined the guild with history moment 2, character 6 left it with moment 4
+    history_moments.insert(5, vec![HistoryMoment { id: 1, timestamp: 100 }, HistoryMoment { id: 2, timestamp: 200 }]);
+    history_moments.insert(6, vec![HistoryMoment { id: 3, timestamp: 100 }, HistoryMoment { id: 4, timestamp: 200 }]);
+    let guild_history_ids: HashSet<u32> = vec![2, 3].into_iter().collect();
+    let loot = vec![loot_entry(1, 5, 110_000), loot_entry(1, 5, 190_000), loot_entry(1, 6, 110_000), loot_entry(1, 6, 190_000), loot_entry(1, 7, 190_000)];
+
+    // Act
+    let guild_loot = filter_guild_loot(loot, &history_moments, &guild_history_ids);
+
+    // Assert
+    assert_eq!(guild_loot, vec![loot_entry(1, 5, 190_000), loot_entry(1, 6, 110_000)]);
+}
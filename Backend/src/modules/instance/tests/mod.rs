@@ -1 +1,11 @@
-
+mod attendance;
+mod audit_action;
+mod character_merge;
+mod guild_progression;
+mod instance_comparison;
+mod instance_search_index;
+mod loot;
+mod privacy_type;
+mod pvp;
+mod season_calendar;
+mod speed_run;
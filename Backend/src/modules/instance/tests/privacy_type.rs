@@ -0,0 +1,174 @@
+use crate::modules::account::guard::Viewer;
+use crate::modules::account::{get_guild_group_id, Account, Groups};
+use crate::modules::instance::domain_value::{InstanceMeta, MetaType, PrivacyType};
+use crate::modules::instance::dto::{InstanceFailure, InstancePrivacy};
+use crate::modules::instance::tools::{ExportMeta, InstanceAccess, UpdateMeta};
+use crate::modules::instance::Instance;
+use crate::util::database::MockExecute;
+use std::collections::BTreeSet;
+
+static UPLOADER: u32 = 1;
+
+fn anonymous() -> Viewer {
+    Viewer::default()
+}
+
+fn member(member_id: u32, groups: &[u32]) -> Viewer {
+    Viewer {
+        member_id: Some(member_id),
+        is_moderator: false,
+        groups: groups.iter().copied().collect::<BTreeSet<u32>>(),
+    }
+}
+
+fn moderator() -> Viewer {
+    Viewer {
+        member_id: Some(99),
+        is_moderator: true,
+        groups: BTreeSet::new(),
+    }
+}
+
+fn instance_with_raid(instance_meta_id: u32, privacy_type: PrivacyType) -> Instance {
+    let instance = Instance::default();
+    {
+        let mut instance_metas = instance.instance_metas.write().unwrap();
+        instance_metas.1.insert(
+            instance_meta_id,
+            InstanceMeta {
+                instance_meta_id,
+                server_id: 1,
+                start_ts: 0,
+                end_ts: None,
+                map_id: 409,
+                expired: None,
+                participants: Vec::new(),
+                instance_specific: MetaType::Raid { map_difficulty: 9 },
+                uploaded_user: UPLOADER,
+                upload_id: 1,
+                privacy_type,
+            },
+        );
+    }
+    instance
+}
+
+#[test]
+fn public_is_accessible_and_listed_for_everyone() {
+    // Arrange
+    let privacy_type = PrivacyType::Public;
+
+    // Act
+    let viewers = vec![anonymous(), member(2, &[]), member(UPLOADER, &[]), moderator()];
+
+    // Assert
+    for viewer in viewers.iter() {
+        assert!(privacy_type.grants_access(UPLOADER, viewer));
+        assert!(privacy_type.is_listed_for(UPLOADER, viewer));
+    }
+}
+
+#[test]
+fn not_listed_is_accessible_but_never_listed() {
+    // Arrange
+    let privacy_type = PrivacyType::NotListed;
+
+    // Act
+    let viewers = vec![anonymous(), member(2, &[]), member(UPLOADER, &[]), moderator()];
+
+    // Assert
+    for viewer in viewers.iter() {
+        assert!(privacy_type.grants_access(UPLOADER, viewer));
+        assert!(!privacy_type.is_listed_for(UPLOADER, viewer));
+    }
+}
+
+#[test]
+fn only_groups_is_restricted_to_group_uploader_and_moderators() {
+    // Arrange
+    let privacy_type = PrivacyType::OnlyGroups(7);
+
+    // Act
+    let granted = vec![member(2, &[7]), member(UPLOADER, &[]), moderator()];
+    let denied = vec![anonymous(), member(2, &[]), member(3, &[8])];
+
+    // Assert
+    for viewer in granted.iter() {
+        assert!(privacy_type.grants_access(UPLOADER, viewer));
+        assert!(privacy_type.is_listed_for(UPLOADER, viewer));
+    }
+    for viewer in denied.iter() {
+        assert!(!privacy_type.grants_access(UPLOADER, viewer));
+        assert!(!privacy_type.is_listed_for(UPLOADER, viewer));
+    }
+}
+
+#[test]
+fn only_groups_of_guild_is_accessible_for_guild_members() {
+    // Arrange
+    let account = Account::default();
+    let privacy_type = PrivacyType::OnlyGroups(get_guild_group_id(5));
+
+    // Act
+    let guild_member = member(2, &account.get_member_and_guild_groups(2, &[5]).into_iter().collect::<Vec<u32>>());
+    let other_guild_member = member(3, &account.get_member_and_guild_groups(3, &[6]).into_iter().collect::<Vec<u32>>());
+    let without_guild = member(4, &account.get_member_and_guild_groups(4, &[]).into_iter().collect::<Vec<u32>>());
+
+    // Assert
+    assert!(privacy_type.grants_access(UPLOADER, &guild_member));
+    assert!(privacy_type.is_listed_for(UPLOADER, &guild_member));
+    assert!(!privacy_type.grants_access(UPLOADER, &other_guild_member));
+    assert!(!privacy_type.grants_access(UPLOADER, &without_guild));
+    // The guild does not grant access to the explicit group of the same id
+    assert!(!PrivacyType::OnlyGroups(5).grants_access(UPLOADER, &guild_member));
+}
+
+#[test]
+fn check_access_of_instance() {
+    // Arrange
+    let instance = instance_with_raid(42, PrivacyType::OnlyGroups(7));
+
+    // Act
+    let denied = instance.check_access(42, &anonymous());
+    let granted = instance.check_access(42, &member(2, &[7]));
+    let unknown = instance.check_access(43, &moderator());
+
+    // Assert
+    assert!(matches!(denied, Err(InstanceFailure::AccessDenied)));
+    assert!(granted.is_ok());
+    assert!(matches!(unknown, Err(InstanceFailure::InvalidInput)));
+}
+
+#[test]
+fn export_meta_hides_inaccessible_instances() {
+    // Arrange
+    let instance = instance_with_raid(42, PrivacyType::OnlyGroups(7));
+
+    // Act
+    let anonymous_metas = instance.export_meta(0, &anonymous());
+    let member_metas = instance.export_meta(0, &member(2, &[7]));
+
+    // Assert
+    assert!(anonymous_metas.is_empty());
+    assert_eq!(member_metas.len(), 1);
+}
+
+#[test]
+fn only_uploader_may_update_privacy() {
+    // Arrange
+    let instance = instance_with_raid(42, PrivacyType::Public);
+    let mut db_main = MockExecute::new();
+    db_main.expect_execute_wparams().never();
+    let instance_privacy = InstancePrivacy {
+        instance_meta_id: 42,
+        privacy_option: 1,
+        privacy_group: 0,
+    };
+
+    // Act
+    let result = instance.update_privacy(&mut db_main, instance_privacy, 2, &BTreeSet::new());
+
+    // Assert
+    assert!(matches!(result, Err(InstanceFailure::AccessDenied)));
+    assert_eq!(instance.instance_metas.read().unwrap().1.get(&42).unwrap().privacy_type, PrivacyType::Public);
+}
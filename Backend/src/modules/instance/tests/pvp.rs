@@ -0,0 +1,143 @@
+use std::collections::BTreeSet;
+
+use crate::modules::account::guard::Viewer;
+use crate::modules::armory::domain_value::{ArenaTeam, ArenaTeamSizeType};
+use crate::modules::instance::domain_value::{IndexedInstance, IndexedParticipant, InstanceMeta, MetaType, PrivacyType};
+use crate::modules::instance::dto::PvpParticipantSummary;
+use crate::modules::instance::tools::{arena_team_history, summarize_pvp_events, team_won, PvpStatistics};
+use crate::modules::instance::Instance;
+
+fn arena_team(id: u32) -> ArenaTeam {
+    ArenaTeam {
+        id,
+        server_uid: id as u64,
+        server_id: 1,
+        team_name: format!("Team {}", id),
+        size_type: ArenaTeamSizeType::Size2v2,
+    }
+}
+
+fn rated_arena(instance_meta_id: u32, start_ts: u64, winner: Option<bool>, team1: u32, team2: u32, team1_change: i32, team2_change: i32) -> InstanceMeta {
+    InstanceMeta {
+        instance_meta_id,
+        server_id: 1,
+        start_ts,
+        end_ts: Some(start_ts + 1000),
+        map_id: 559,
+        expired: None,
+        participants: Vec::new(),
+        instance_specific: MetaType::RatedArena {
+            winner,
+            team1: arena_team(team1),
+            team2: arena_team(team2),
+            team1_change,
+            team2_change,
+        },
+        uploaded_user: 1,
+        upload_id: 1,
+        privacy_type: PrivacyType::Public,
+    }
+}
+
+#[test]
+fn horde_side_is_team2() {
+    assert_eq!(team_won(Some(true), false), Some(true));
+    assert_eq!(team_won(Some(true), true), Some(false));
+    assert_eq!(team_won(Some(false), true), Some(true));
+    assert_eq!(team_won(None, true), None);
+}
+
+#[test]
+fn arena_team_history_accumulates_rating_changes() {
+    // Arrange
+    let rated_arenas = vec![rated_arena(3, 300, Some(true), 1, 2, -12, 12), rated_arena(1, 100, Some(false), 1, 3, 15, -15), rated_arena(2, 200, Some(true), 4, 5, -10, 10)];
+
+    // Act
+    let history = arena_team_history(1, rated_arenas);
+
+    // Assert
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].instance_meta_id, 1);
+    assert_eq!(history[0].opponent.team_id, 3);
+    assert_eq!(history[0].won, Some(true));
+    assert_eq!(history[0].rating_progression, 15);
+    assert_eq!(history[1].instance_meta_id, 3);
+    assert_eq!(history[1].opponent.team_id, 2);
+    assert_eq!(history[1].won, Some(false));
+    assert_eq!(history[1].rating_progression, 3);
+}
+
+#[test]
+fn pvp_events_are_summarized_per_character() {
+    // Arrange
+    let melee_damage = vec!["[1,100,[1,10],[1,20],[1],[[500,1,0,0,0],[100,4,0,0,0]]]".to_owned()];
+    // The second hit is done by a pet of character 20
+    let spell_damage = vec!["[3,200,2,[1,20],[1,10],133,1,[[300,4,0,0,0]]]".to_owned(), "[5,300,4,[0,55,416,[1,20]],[1,10],3110,1,[[50,4,0,0,0]]]".to_owned()];
+    let heals = vec!["[7,400,6,[1,10],[1,10],2061,1,2,900,700,0,0,0]".to_owned()];
+
+    // Act
+    let summaries = summarize_pvp_events(&melee_damage, &spell_damage, &heals);
+
+    // Assert
+    assert_eq!(
+        summaries,
+        vec![
+            PvpParticipantSummary {
+                character_id: 10,
+                damage_done: 600,
+                damage_taken: 350,
+                effective_healing_done: 700,
+            },
+            PvpParticipantSummary {
+                character_id: 20,
+                damage_done: 350,
+                damage_taken: 600,
+                effective_healing_done: 0,
+            },
+        ]
+    );
+}
+
+#[test]
+fn composition_win_rates_use_the_indexed_participants() {
+    // Arrange
+    let instance = Instance::default();
+    let mut arena = rated_arena(1, 100, Some(true), 1, 2, -12, 12);
+    arena.participants = vec![10, 11, 20, 21];
+    // Character 21 is not known to the armory
+    let pvp_participants = vec![(10, 1, 1), (11, 5, 1), (20, 8, 2)]
+        .into_iter()
+        .map(|(character_id, hero_class_id, arena_team_id)| IndexedParticipant {
+            character_id,
+            hero_class_id,
+            race_id: 1,
+            arena_team_ids: vec![arena_team_id],
+        })
+        .collect();
+    instance.search_index.write().unwrap().insert(IndexedInstance {
+        instance_meta_id: 1,
+        meta_type: 1,
+        server_id: 1,
+        map_id: arena.map_id,
+        map_difficulty: None,
+        start_ts: arena.start_ts,
+        end_ts: arena.end_ts,
+        participants: arena.participants.clone(),
+        guild: None,
+        pvp_participants,
+        killed_encounter_ids: BTreeSet::new(),
+        uploaded_user: 1,
+        privacy_type: PrivacyType::Public,
+    });
+    instance.instance_metas.write().unwrap().1.insert(1, arena);
+
+    // Act
+    let composition_win_rates = instance.get_composition_win_rates(&Viewer::default(), 1);
+
+    // Assert
+    assert_eq!(composition_win_rates.len(), 2);
+    assert_eq!(composition_win_rates[0].hero_class_ids, vec![1, 5]);
+    assert_eq!(composition_win_rates[0].num_wins, 0);
+    assert_eq!(composition_win_rates[1].hero_class_ids, vec![8]);
+    assert_eq!(composition_win_rates[1].num_wins, 1);
+}
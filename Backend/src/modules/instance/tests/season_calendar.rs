@@ -0,0 +1,128 @@
+use crate::modules::instance::domain_value::Season;
+use crate::modules::instance::material::SeasonCalendar;
+use crate::modules::instance::tools::may_manage_season;
+use std::collections::HashMap;
+
+static VANILLA_SERVER: u32 = 1;
+static TBC_SERVER: u32 = 2;
+static UNCONFIGURED_SERVER: u32 = 3;
+
+fn season(id: u32, server_id: Option<u32>, expansion_id: u8, start_ts: u64, end_ts: Option<u64>) -> Season {
+    Season {
+        id,
+        server_id,
+        expansion_id,
+        name: format!("Phase {}", id),
+        start_ts,
+        end_ts,
+        season_index: 0,
+    }
+}
+
+fn calendar() -> SeasonCalendar {
+    let mut server_expansions = HashMap::new();
+    server_expansions.insert(VANILLA_SERVER, 1);
+    server_expansions.insert(TBC_SERVER, 2);
+    server_expansions.insert(UNCONFIGURED_SERVER, 3);
+
+    SeasonCalendar::new(
+        vec![
+            season(2, Some(VANILLA_SERVER), 1, 2000, Some(3000)),
+            season(1, Some(VANILLA_SERVER), 1, 1000, Some(2000)),
+            season(3, Some(VANILLA_SERVER), 1, 4000, None),
+            season(4, None, 2, 1000, None),
+            season(5, None, 2, 5000, None),
+        ],
+        server_expansions,
+    )
+}
+
+#[test]
+fn season_index_follows_start_order() {
+    // Arrange
+    let season_calendar = calendar();
+
+    // Act
+    let indexes: Vec<(u32, u8)> = season_calendar.get_seasons().iter().map(|season| (season.id, season.season_index)).collect();
+
+    // Assert
+    assert!(indexes.contains(&(1, 1)));
+    assert!(indexes.contains(&(2, 2)));
+    assert!(indexes.contains(&(3, 3)));
+    assert!(indexes.contains(&(4, 1)));
+    assert!(indexes.contains(&(5, 2)));
+}
+
+#[test]
+fn server_calendar() {
+    // Arrange
+    let season_calendar = calendar();
+
+    // Act + Assert
+    assert_eq!(season_calendar.season_index(VANILLA_SERVER, 1500), 1);
+    assert_eq!(season_calendar.season_index(VANILLA_SERVER, 2000), 2);
+    assert_eq!(season_calendar.season_index(VANILLA_SERVER, 3500), 0);
+    assert_eq!(season_calendar.season_index(VANILLA_SERVER, 100_000), 3);
+    assert_eq!(season_calendar.get_server_seasons(VANILLA_SERVER).len(), 3);
+}
+
+#[test]
+fn expansion_calendar() {
+    // Arrange
+    let season_calendar = calendar();
+
+    // Act + Assert
+    assert_eq!(season_calendar.season_index(TBC_SERVER, 500), 0);
+    assert_eq!(season_calendar.season_index(TBC_SERVER, 4000), 1);
+    assert_eq!(season_calendar.season_index(TBC_SERVER, 6000), 2);
+    assert_eq!(season_calendar.get_server_seasons(TBC_SERVER).len(), 2);
+}
+
+#[test]
+fn quarterly_fallback() {
+    // Arrange
+    let season_calendar = calendar();
+
+    // Act + Assert
+    // 15th of January 2021
+    assert_eq!(season_calendar.season_index(UNCONFIGURED_SERVER, 1_610_668_800_000), 5);
+    assert!(season_calendar.get_server_seasons(UNCONFIGURED_SERVER).is_empty());
+}
+
+#[test]
+fn seasons_can_only_be_appended() {
+    // Arrange
+    let season_calendar = calendar();
+
+    // Act + Assert
+    assert!(season_calendar.may_append_season(Some(VANILLA_SERVER), 1, 5000));
+    assert!(!season_calendar.may_append_season(Some(VANILLA_SERVER), 1, 1500));
+    assert!(season_calendar.may_append_season(None, 2, 6000));
+    assert!(!season_calendar.may_append_season(None, 2, 3000));
+    assert!(season_calendar.may_append_season(Some(TBC_SERVER), 2, 0));
+}
+
+#[test]
+fn changed_seasons_keep_the_indexes() {
+    // Arrange
+    let season_calendar = calendar();
+
+    // Act + Assert
+    assert!(season_calendar.keeps_season_indexes(2, Some((Some(VANILLA_SERVER), 1, 2500))));
+    assert!(!season_calendar.keeps_season_indexes(2, Some((Some(VANILLA_SERVER), 1, 4500))));
+    assert!(!season_calendar.keeps_season_indexes(2, Some((Some(VANILLA_SERVER), 1, 500))));
+    // Only the last season of a scope may be deleted or moved to another scope
+    assert!(season_calendar.keeps_season_indexes(3, None));
+    assert!(!season_calendar.keeps_season_indexes(2, None));
+    assert!(season_calendar.keeps_season_indexes(3, Some((None, 2, 6000))));
+    assert!(!season_calendar.keeps_season_indexes(1, Some((None, 2, 6000))));
+    assert!(!season_calendar.keeps_season_indexes(42, None));
+}
+
+#[test]
+fn manage_rights() {
+    assert!(may_manage_season(None, true, None));
+    assert!(may_manage_season(Some(VANILLA_SERVER), false, Some(VANILLA_SERVER)));
+    assert!(!may_manage_season(Some(TBC_SERVER), false, Some(VANILLA_SERVER)));
+    assert!(!may_manage_season(None, false, Some(VANILLA_SERVER)));
+}
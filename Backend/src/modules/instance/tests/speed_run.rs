@@ -0,0 +1,70 @@
+use crate::modules::data::domain_value::MapEncounter;
+use crate::modules::instance::domain_value::InstanceAttempt;
+use crate::modules::instance::dto::SpeedRunSplit;
+use crate::modules::instance::tools::{calculate_speed_run_splits, is_full_clear};
+
+fn map_encounter(encounter_id: u32, is_required: bool) -> MapEncounter {
+    MapEncounter {
+        map_id: 309,
+        difficulty_id: None,
+        encounter_id,
+        is_required,
+    }
+}
+
+fn kill(attempt_id: u32, encounter_id: u32, start_ts: u64, end_ts: u64) -> InstanceAttempt {
+    InstanceAttempt {
+        attempt_id,
+        encounter_id,
+        start_ts,
+        end_ts,
+        is_kill: true,
+        difficulty_id: 0,
+        rankable: true,
+        season_index: 1,
+    }
+}
+
+#[test]
+fn full_clear_ignores_optional_bosses() {
+    // Arrange
+    let map_encounters = vec![map_encounter(12, true), map_encounter(13, true), map_encounter(16, false)];
+    let attempts = vec![kill(1, 12, 0, 100), kill(2, 13, 200, 300)];
+
+    // Act + Assert
+    assert!(is_full_clear(&map_encounters, &attempts));
+    assert!(!is_full_clear(&map_encounters, &attempts[..1]));
+    assert!(!is_full_clear(&[map_encounter(16, false)], &attempts));
+    assert!(!is_full_clear(&[], &attempts));
+}
+
+#[test]
+fn full_clear_requires_rankable_kills() {
+    // Arrange
+    let map_encounters = vec![map_encounter(12, true)];
+    let mut attempt = kill(1, 12, 0, 100);
+    attempt.rankable = false;
+
+    // Act + Assert
+    assert!(!is_full_clear(&map_encounters, &[attempt]));
+}
+
+#[test]
+fn splits_are_ordered_by_kill() {
+    // Arrange
+    let map_encounters = vec![map_encounter(12, true), map_encounter(13, true), map_encounter(16, false)];
+    let attempts = vec![kill(3, 13, 600, 900), kill(1, 12, 100, 300), kill(2, 16, 350, 500), kill(4, 99, 950, 1000)];
+
+    // Act
+    let splits = calculate_speed_run_splits(&map_encounters, &attempts, 100);
+
+    // Assert
+    assert_eq!(
+        splits,
+        vec![
+            SpeedRunSplit { encounter_id: 12, kill_offset: 200, segment_duration: 200 },
+            SpeedRunSplit { encounter_id: 16, kill_offset: 400, segment_duration: 200 },
+            SpeedRunSplit { encounter_id: 13, kill_offset: 800, segment_duration: 400 },
+        ]
+    );
+}
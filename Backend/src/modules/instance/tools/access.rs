@@ -0,0 +1,18 @@
+use crate::modules::account::guard::Viewer;
+use crate::modules::instance::dto::InstanceFailure;
+use crate::modules::instance::Instance;
+
+pub trait InstanceAccess {
+    fn check_access(&self, instance_meta_id: u32, viewer: &Viewer) -> Result<(), InstanceFailure>;
+}
+
+impl InstanceAccess for Instance {
+    fn check_access(&self, instance_meta_id: u32, viewer: &Viewer) -> Result<(), InstanceFailure> {
+        let instance_metas = self.instance_metas.read().unwrap();
+        let instance_meta = instance_metas.1.get(&instance_meta_id).ok_or(InstanceFailure::InvalidInput)?;
+        if instance_meta.privacy_type.grants_access(instance_meta.uploaded_user, viewer) {
+            return Ok(());
+        }
+        Err(InstanceFailure::AccessDenied)
+    }
+}
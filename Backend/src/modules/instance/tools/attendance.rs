@@ -0,0 +1,185 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::modules::account::guard::Viewer;
+use crate::modules::armory::tools::{CharacterClaims, GetCharacter};
+use crate::modules::armory::Armory;
+use crate::modules::instance::dto::{AttendanceAlias, AttendanceFilter, CharacterAttendance, GuildAttendance, InstanceFailure, MapAttendance};
+use crate::modules::instance::Instance;
+use crate::params;
+use crate::util::database::{Execute, Select};
+
+pub trait Attendance {
+    fn get_guild_attendance(&self, db_main: &mut impl Select, armory: &Armory, viewer: &Viewer, guild_id: u32, filter: &AttendanceFilter) -> GuildAttendance;
+    fn get_attendance_aliases(&self, db_main: &mut impl Select, member_id: u32) -> Vec<AttendanceAlias>;
+    fn set_attendance_alias(&self, db_main: &mut (impl Execute + Select), armory: &Armory, member_id: u32, alias: AttendanceAlias) -> Result<(), InstanceFailure>;
+    fn delete_attendance_alias(&self, db_main: &mut (impl Execute + Select), member_id: u32, alt_character_id: u32) -> Result<(), InstanceFailure>;
+}
+
+impl Attendance for Instance {
+    fn get_guild_attendance(&self, db_main: &mut impl Select, armory: &Armory, viewer: &Viewer, guild_id: u32, filter: &AttendanceFilter) -> GuildAttendance {
+        let raids: Vec<(u16, Vec<u32>)> = {
+            let instance_metas = self.instance_metas.read().unwrap();
+            let search_index = self.search_index.read().unwrap();
+            search_index
+                .guild_instances(guild_id)
+                .into_iter()
+                .filter(|instance| instance.meta_type == 0)
+                .filter(|instance| filter.start_ts.map(|start_ts| instance.start_ts >= start_ts).unwrap_or(true))
+                .filter(|instance| filter.end_ts.map(|end_ts| instance.start_ts <= end_ts).unwrap_or(true))
+                .filter(|instance| {
+                    instance_metas
+                        .1
+                        .get(&instance.instance_meta_id)
+                        .map(|instance_meta| instance_meta.privacy_type.grants_access(instance_meta.uploaded_user, viewer))
+                        .unwrap_or(false)
+                })
+                .map(|instance| (instance.map_id, instance.participants.clone()))
+                .collect()
+        };
+
+        // Only aliases of characters that took part are relevant, they only count as long as their member still owns both characters
+        let participants: BTreeSet<u32> = raids.iter().flat_map(|(_, participants)| participants.iter().copied()).collect();
+        let mut claimed_character_ids: HashMap<u32, Vec<u32>> = HashMap::new();
+        let aliases: HashMap<u32, u32> = if participants.is_empty() {
+            HashMap::new()
+        } else {
+            let alt_character_ids = participants.iter().map(|character_id| character_id.to_string()).collect::<Vec<String>>().join(",");
+            db_main
+                .select(
+                    &format!("SELECT alt_character_id, main_character_id, member_id FROM instance_attendance_alias WHERE alt_character_id IN ({})", alt_character_ids),
+                    |mut row| (row.take::<u32, usize>(0).unwrap(), row.take::<u32, usize>(1).unwrap(), row.take::<u32, usize>(2).unwrap()),
+                )
+                .into_iter()
+                .filter(|(alt_character_id, main_character_id, member_id)| {
+                    let claimed_character_ids = claimed_character_ids.entry(*member_id).or_insert_with(|| armory.get_claimed_character_ids(*member_id));
+                    may_alias_characters(claimed_character_ids, *alt_character_id, *main_character_id)
+                })
+                .map(|(alt_character_id, main_character_id, _)| (alt_character_id, main_character_id))
+                .collect()
+        };
+
+        GuildAttendance {
+            guild_id,
+            num_raids: raids.len() as u32,
+            characters: calculate_attendance(&raids, &aliases),
+        }
+    }
+
+    fn get_attendance_aliases(&self, db_main: &mut impl Select, member_id: u32) -> Vec<AttendanceAlias> {
+        db_main.select_wparams(
+            "SELECT alt_character_id, main_character_id FROM instance_attendance_alias WHERE member_id=:member_id",
+            |mut row| AttendanceAlias {
+                alt_character_id: row.take(0).unwrap(),
+                main_character_id: row.take(1).unwrap(),
+            },
+            params!("member_id" => member_id),
+        )
+    }
+
+    fn set_attendance_alias(&self, db_main: &mut (impl Execute + Select), armory: &Armory, member_id: u32, alias: AttendanceAlias) -> Result<(), InstanceFailure> {
+        if alias.alt_character_id == alias.main_character_id {
+            return Err(InstanceFailure::InvalidInput);
+        }
+
+        if !may_alias_characters(&armory.get_claimed_character_ids(member_id), alias.alt_character_id, alias.main_character_id) {
+            return Err(InstanceFailure::AccessDenied);
+        }
+
+        let alt_character = armory.get_character(alias.alt_character_id).ok_or(InstanceFailure::InvalidInput)?;
+        let main_character = armory.get_character(alias.main_character_id).ok_or(InstanceFailure::InvalidInput)?;
+        if alt_character.server_id != main_character.server_id {
+            return Err(InstanceFailure::InvalidInput);
+        }
+
+        let existing_aliases = db_main.select_wparams(
+            "SELECT alt_character_id, main_character_id, member_id FROM instance_attendance_alias WHERE alt_character_id IN (:alt_character_id, :main_character_id) OR main_character_id=:alt_character_id",
+            |mut row| (row.take::<u32, usize>(0).unwrap(), row.take::<u32, usize>(1).unwrap(), row.take::<u32, usize>(2).unwrap()),
+            params!("alt_character_id" => alias.alt_character_id, "main_character_id" => alias.main_character_id),
+        );
+        // Aliases are not chained: A main can not be an alt itself and an alt can not have alts
+        if existing_aliases
+            .iter()
+            .any(|(alt_character_id, main_character_id, _)| *alt_character_id == alias.main_character_id || *main_character_id == alias.alt_character_id)
+        {
+            return Err(InstanceFailure::InvalidInput);
+        }
+        if existing_aliases.iter().any(|(alt_character_id, _, owner_id)| *alt_character_id == alias.alt_character_id && *owner_id != member_id) {
+            return Err(InstanceFailure::AccessDenied);
+        }
+
+        if !db_main.execute_wparams(
+            "REPLACE INTO instance_attendance_alias (alt_character_id, main_character_id, member_id) VALUES (:alt_character_id, :main_character_id, :member_id)",
+            params!("alt_character_id" => alias.alt_character_id, "main_character_id" => alias.main_character_id, "member_id" => member_id),
+        ) {
+            return Err(InstanceFailure::Unknown);
+        }
+        Ok(())
+    }
+
+    fn delete_attendance_alias(&self, db_main: &mut (impl Execute + Select), member_id: u32, alt_character_id: u32) -> Result<(), InstanceFailure> {
+        let owner_id = db_main
+            .select_wparams_value(
+                "SELECT member_id FROM instance_attendance_alias WHERE alt_character_id=:alt_character_id",
+                |mut row| row.take::<u32, usize>(0).unwrap(),
+                params!("alt_character_id" => alt_character_id),
+            )
+            .ok_or(InstanceFailure::InvalidInput)?;
+        if owner_id != member_id {
+            return Err(InstanceFailure::AccessDenied);
+        }
+
+        if !db_main.execute_wparams("DELETE FROM instance_attendance_alias WHERE alt_character_id=:alt_character_id", params!("alt_character_id" => alt_character_id)) {
+            return Err(InstanceFailure::Unknown);
+        }
+        Ok(())
+    }
+}
+
+/// Members can only alias characters they have claimed both of
+pub fn may_alias_characters(claimed_character_ids: &[u32], alt_character_id: u32, main_character_id: u32) -> bool {
+    claimed_character_ids.contains(&alt_character_id) && claimed_character_ids.contains(&main_character_id)
+}
+
+/// Attendance of every character in the given raids (map_id, participants), alts are counted towards their main.
+/// A raid is counted once per main, even if several of its characters took part.
+pub fn calculate_attendance(raids: &[(u16, Vec<u32>)], aliases: &HashMap<u32, u32>) -> Vec<CharacterAttendance> {
+    let mut map_totals: BTreeMap<u16, u32> = BTreeMap::new();
+    // main_character_id => (alt_character_ids, map_id => attended)
+    let mut attendance: BTreeMap<u32, (BTreeSet<u32>, BTreeMap<u16, u32>)> = BTreeMap::new();
+    for (map_id, participants) in raids.iter() {
+        *map_totals.entry(*map_id).or_insert(0) += 1;
+
+        let mut counted_mains = BTreeSet::new();
+        for character_id in participants.iter() {
+            let main_character_id = aliases.get(character_id).copied().unwrap_or(*character_id);
+            let (alt_character_ids, maps) = attendance.entry(main_character_id).or_insert_with(|| (BTreeSet::new(), BTreeMap::new()));
+            if main_character_id != *character_id {
+                alt_character_ids.insert(*character_id);
+            }
+            if counted_mains.insert(main_character_id) {
+                *maps.entry(*map_id).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let total = raids.len() as u32;
+    let mut result: Vec<CharacterAttendance> = attendance
+        .into_iter()
+        .map(|(character_id, (alt_character_ids, maps))| CharacterAttendance {
+            character_id,
+            alt_character_ids: alt_character_ids.into_iter().collect(),
+            attended: maps.values().sum(),
+            total,
+            maps: map_totals
+                .iter()
+                .map(|(map_id, map_total)| MapAttendance {
+                    map_id: *map_id,
+                    attended: maps.get(map_id).copied().unwrap_or(0),
+                    total: *map_total,
+                })
+                .collect(),
+        })
+        .collect();
+    result.sort_by(|left, right| right.attended.cmp(&left.attended).then_with(|| left.character_id.cmp(&right.character_id)));
+    result
+}
@@ -0,0 +1,63 @@
+use crate::modules::instance::domain_value::AuditAction;
+use crate::modules::instance::dto::{AuditLogEntry, InstanceFailure};
+use crate::modules::instance::Instance;
+use crate::params;
+use crate::util::database::{Execute, Select};
+
+pub trait InstanceAuditLog {
+    fn get_audit_log(&self, db_main: &mut impl Select, instance_meta_id: u32, member_id: u32, is_moderator: bool) -> Result<Vec<AuditLogEntry>, InstanceFailure>;
+}
+
+impl InstanceAuditLog for Instance {
+    fn get_audit_log(&self, db_main: &mut impl Select, instance_meta_id: u32, member_id: u32, is_moderator: bool) -> Result<Vec<AuditLogEntry>, InstanceFailure> {
+        if !is_moderator {
+            // Purged instances are only visible to moderators
+            let uploaded_user = db_main
+                .select_wparams_value(
+                    "SELECT B.member_id FROM instance_meta A JOIN instance_uploads B ON A.upload_id = B.id WHERE A.id=:instance_meta_id",
+                    |mut row| row.take::<u32, usize>(0).unwrap(),
+                    params!("instance_meta_id" => instance_meta_id),
+                )
+                .ok_or(InstanceFailure::InvalidInput)?;
+            if uploaded_user != member_id {
+                return Err(InstanceFailure::AccessDenied);
+            }
+        }
+
+        Ok(db_main
+            .select_wparams(
+                "SELECT id, instance_meta_id, member_id, action, attempt_id, privacy_type, privacy_ref, `timestamp` FROM instance_audit_log WHERE instance_meta_id=:instance_meta_id ORDER BY id",
+                |mut row| {
+                    let action = AuditAction::from_columns(row.take(3).unwrap(), row.take_opt(4).unwrap().ok(), row.take_opt(5).unwrap().ok(), row.take_opt(6).unwrap().ok());
+                    action.map(|action| AuditLogEntry {
+                        id: row.take(0).unwrap(),
+                        instance_meta_id: row.take(1).unwrap(),
+                        member_id: row.take_opt(2).unwrap().ok(),
+                        action,
+                        timestamp: row.take(7).unwrap(),
+                    })
+                },
+                params!("instance_meta_id" => instance_meta_id),
+            )
+            .into_iter()
+            .flatten()
+            .collect())
+    }
+}
+
+/// Records who did what to an instance. Actions of background jobs have no member.
+pub fn log_instance_action(db_main: &mut impl Execute, instance_meta_id: u32, member_id: Option<u32>, action: &AuditAction) -> bool {
+    db_main.execute_wparams(
+        "INSERT INTO instance_audit_log (instance_meta_id, member_id, action, attempt_id, privacy_type, privacy_ref, `timestamp`) \
+        VALUES (:instance_meta_id, :member_id, :action, :attempt_id, :privacy_type, :privacy_ref, :timestamp)",
+        params!(
+            "instance_meta_id" => instance_meta_id,
+            "member_id" => member_id,
+            "action" => action.to_u8(),
+            "attempt_id" => action.attempt_id(),
+            "privacy_type" => action.privacy_type().map(|privacy_type| privacy_type.to_u8()),
+            "privacy_ref" => action.privacy_type().map(|privacy_type| privacy_type.get_group()),
+            "timestamp" => time_util::now()
+        ),
+    )
+}
@@ -0,0 +1,230 @@
+use crate::modules::armory::tools::{CharacterClaims, GetCharacter, ReassignCharacter};
+use crate::modules::armory::Armory;
+use crate::modules::instance::domain_value::MergedRowType;
+use crate::modules::instance::dto::{CharacterMerge, InstanceFailure};
+use crate::modules::instance::Instance;
+use crate::mysql::Value;
+use crate::params;
+use crate::util::database::{Execute, Select};
+
+static INSTANCE_ROW_TYPES: [MergedRowType; 5] = [MergedRowType::RankingDamage, MergedRowType::RankingHeal, MergedRowType::RankingThreat, MergedRowType::Loot, MergedRowType::Participation];
+
+pub trait MergeCharacter {
+    fn merge_character(&self, db_main: &mut (impl Execute + Select), armory: &Armory, source_character_id: u32, target_character_id: u32, member_id: u32, is_moderator: bool) -> Result<CharacterMerge, InstanceFailure>;
+    fn undo_character_merge(&self, db_main: &mut (impl Execute + Select), armory: &Armory, merge_id: u32, member_id: u32, is_moderator: bool) -> Result<CharacterMerge, InstanceFailure>;
+    fn get_character_merges(&self, db_main: &mut impl Select, character_id: u32) -> Vec<CharacterMerge>;
+}
+
+impl MergeCharacter for Instance {
+    fn merge_character(&self, db_main: &mut (impl Execute + Select), armory: &Armory, source_character_id: u32, target_character_id: u32, member_id: u32, is_moderator: bool) -> Result<CharacterMerge, InstanceFailure> {
+        if source_character_id == target_character_id {
+            return Err(InstanceFailure::InvalidInput);
+        }
+        let source_character = armory.get_character(source_character_id).ok_or(InstanceFailure::InvalidInput)?;
+        let target_character = armory.get_character(target_character_id).ok_or(InstanceFailure::InvalidInput)?;
+        if !may_merge_characters(&armory.get_claimed_character_ids(member_id), source_character_id, target_character_id, is_moderator) {
+            return Err(InstanceFailure::AccessDenied);
+        }
+
+        // A transfer or faction change keeps the class, and two characters of one raid are not the same character
+        if let (Some(source_update), Some(target_update)) = (source_character.last_update.as_ref(), target_character.last_update.as_ref()) {
+            if source_update.character_info.hero_class_id != target_update.character_info.hero_class_id {
+                return Err(InstanceFailure::InvalidInput);
+            }
+        }
+        let num_shared_instances = db_main
+            .select_wparams_value(
+                "SELECT COUNT(*) FROM instance_participants A JOIN instance_participants B ON A.instance_meta_id = B.instance_meta_id WHERE A.character_id=:source_character_id AND B.character_id=:target_character_id",
+                |mut row| row.take::<u32, usize>(0).unwrap(),
+                params!("source_character_id" => source_character_id, "target_character_id" => target_character_id),
+            )
+            .unwrap_or(0);
+        if num_shared_instances > 0 {
+            return Err(InstanceFailure::InvalidInput);
+        }
+
+        // The rows are recorded before they are moved, so the merge can be undone
+        let history_ids: Vec<u32> = source_character.history_moments.iter().map(|history_moment| history_moment.id).collect();
+        let target_claim_members: Vec<u32> = armory.character_claims.read().unwrap().values().filter(|claim| claim.character_id == target_character_id).map(|claim| claim.member_id).collect();
+        let claim_ids: Vec<u32> = armory
+            .character_claims
+            .read()
+            .unwrap()
+            .values()
+            .filter(|claim| claim.character_id == source_character_id && !target_claim_members.contains(&claim.member_id))
+            .map(|claim| claim.id)
+            .collect();
+
+        let merged_ts = time_util::now();
+        let merge_id = in_transaction(db_main, |db_main| {
+            let params = params!(
+                "source_character_id" => source_character_id,
+                "target_character_id" => target_character_id,
+                "member_id" => member_id,
+                "merged_ts" => merged_ts
+            );
+            if !db_main.execute_wparams(
+                "INSERT INTO instance_character_merge (`source_character_id`, `target_character_id`, `member_id`, `merged_ts`) VALUES (:source_character_id, :target_character_id, :member_id, :merged_ts)",
+                params.clone(),
+            ) {
+                return Err(InstanceFailure::Unknown);
+            }
+            let merge_id = db_main
+                .select_wparams_value(
+                    "SELECT MAX(id) FROM instance_character_merge WHERE source_character_id=:source_character_id AND target_character_id=:target_character_id AND member_id=:member_id AND merged_ts=:merged_ts",
+                    |mut row| row.take::<u32, usize>(0).unwrap(),
+                    params,
+                )
+                .ok_or(InstanceFailure::Unknown)?;
+
+            let mut success = record_merged_rows(db_main, merge_id, MergedRowType::CharacterHistory, &history_ids) && record_merged_rows(db_main, merge_id, MergedRowType::CharacterClaim, &claim_ids);
+            for row_type in INSTANCE_ROW_TYPES.iter() {
+                success = success && record_merged_instance_rows(db_main, merge_id, *row_type, source_character_id);
+            }
+            if !success {
+                return Err(InstanceFailure::Unknown);
+            }
+            move_merged_rows(db_main, armory, merge_id, source_character_id, target_character_id)?;
+            Ok(merge_id)
+        })?;
+
+        reload_merged_characters(self, db_main, armory, merge_id, source_character_id, target_character_id)?;
+        Ok(CharacterMerge {
+            id: merge_id,
+            source_character_id,
+            target_character_id,
+            member_id: Some(member_id),
+            merged_ts,
+            reverted_ts: None,
+        })
+    }
+
+    fn undo_character_merge(&self, db_main: &mut (impl Execute + Select), armory: &Armory, merge_id: u32, member_id: u32, is_moderator: bool) -> Result<CharacterMerge, InstanceFailure> {
+        let mut character_merge = query_character_merges(db_main, "id=:id", params!("id" => merge_id)).pop().ok_or(InstanceFailure::InvalidInput)?;
+        if character_merge.reverted_ts.is_some() {
+            return Err(InstanceFailure::InvalidInput);
+        }
+        if !is_moderator && character_merge.member_id != Some(member_id) {
+            return Err(InstanceFailure::AccessDenied);
+        }
+
+        let reverted_ts = time_util::now();
+        in_transaction(db_main, |db_main| {
+            move_merged_rows(db_main, armory, merge_id, character_merge.target_character_id, character_merge.source_character_id)?;
+            if !db_main.execute_wparams("UPDATE instance_character_merge SET reverted_ts=:reverted_ts WHERE id=:id", params!("reverted_ts" => reverted_ts, "id" => merge_id)) {
+                return Err(InstanceFailure::Unknown);
+            }
+            Ok(())
+        })?;
+
+        reload_merged_characters(self, db_main, armory, merge_id, character_merge.target_character_id, character_merge.source_character_id)?;
+        character_merge.reverted_ts = Some(reverted_ts);
+        Ok(character_merge)
+    }
+
+    fn get_character_merges(&self, db_main: &mut impl Select, character_id: u32) -> Vec<CharacterMerge> {
+        query_character_merges(db_main, "source_character_id=:character_id OR target_character_id=:character_id", params!("character_id" => character_id))
+    }
+}
+
+/// Moderators can merge any characters, members only those they have claimed both of
+pub fn may_merge_characters(claimed_character_ids: &[u32], source_character_id: u32, target_character_id: u32, is_moderator: bool) -> bool {
+    is_moderator || (claimed_character_ids.contains(&source_character_id) && claimed_character_ids.contains(&target_character_id))
+}
+
+fn record_merged_rows(db_main: &mut impl Execute, merge_id: u32, row_type: MergedRowType, row_ids: &[u32]) -> bool {
+    let row_type = row_type.to_u8();
+    db_main.execute_batch_wparams(
+        "INSERT INTO instance_character_merge_row (`merge_id`, `row_type`, `row_id`) VALUES (:merge_id, :row_type, :row_id)",
+        row_ids.to_vec(),
+        move |row_id| params!("merge_id" => merge_id, "row_type" => row_type, "row_id" => row_id),
+    )
+}
+
+fn record_merged_instance_rows(db_main: &mut impl Execute, merge_id: u32, row_type: MergedRowType, character_id: u32) -> bool {
+    let (table, id_column) = row_type.instance_table().unwrap();
+    db_main.execute_wparams(
+        &format!(
+            "INSERT INTO instance_character_merge_row (`merge_id`, `row_type`, `row_id`) SELECT :merge_id, :row_type, {} FROM {} WHERE character_id=:character_id",
+            id_column, table
+        ),
+        params!("merge_id" => merge_id, "row_type" => row_type.to_u8(), "character_id" => character_id),
+    )
+}
+
+fn get_merged_row_ids(db_main: &mut impl Select, merge_id: u32, row_type: MergedRowType) -> Vec<u32> {
+    db_main.select_wparams(
+        "SELECT row_id FROM instance_character_merge_row WHERE merge_id=:merge_id AND row_type=:row_type",
+        |mut row| row.take::<u32, usize>(0).unwrap(),
+        params!("merge_id" => merge_id, "row_type" => row_type.to_u8()),
+    )
+}
+
+fn query_character_merges(db_main: &mut impl Select, condition: &str, params: Vec<(String, Value)>) -> Vec<CharacterMerge> {
+    db_main.select_wparams(
+        &format!(
+            "SELECT id, source_character_id, target_character_id, member_id, merged_ts, reverted_ts FROM instance_character_merge WHERE {} ORDER BY id DESC",
+            condition
+        ),
+        |mut row| CharacterMerge {
+            id: row.take(0).unwrap(),
+            source_character_id: row.take(1).unwrap(),
+            target_character_id: row.take(2).unwrap(),
+            member_id: row.take_opt(3).unwrap().ok(),
+            merged_ts: row.take(4).unwrap(),
+            reverted_ts: row.take_opt(5).unwrap().ok(),
+        },
+        params,
+    )
+}
+
+/// Either all statements of the closure are committed or none of them
+pub fn in_transaction<D: Execute, T>(db_main: &mut D, statements: impl FnOnce(&mut D) -> Result<T, InstanceFailure>) -> Result<T, InstanceFailure> {
+    if !db_main.execute_one("START TRANSACTION") {
+        return Err(InstanceFailure::Unknown);
+    }
+    let result = statements(db_main);
+    if result.is_ok() && db_main.execute_one("COMMIT") {
+        return result;
+    }
+    db_main.execute_one("ROLLBACK");
+    result.and(Err(InstanceFailure::Unknown))
+}
+
+fn move_merged_rows(db_main: &mut (impl Execute + Select), armory: &Armory, merge_id: u32, character_id: u32, replacement_character_id: u32) -> Result<(), InstanceFailure> {
+    for row_type in INSTANCE_ROW_TYPES.iter() {
+        let (table, id_column) = row_type.instance_table().unwrap();
+        if !db_main.execute_wparams(
+            &format!(
+                "UPDATE {} SET character_id=:replacement_character_id WHERE character_id=:character_id AND {} IN (SELECT row_id FROM instance_character_merge_row WHERE merge_id=:merge_id AND row_type=:row_type)",
+                table, id_column
+            ),
+            params!(
+                "replacement_character_id" => replacement_character_id,
+                "character_id" => character_id,
+                "merge_id" => merge_id,
+                "row_type" => row_type.to_u8()
+            ),
+        ) {
+            return Err(InstanceFailure::Unknown);
+        }
+    }
+
+    let history_ids = get_merged_row_ids(db_main, merge_id, MergedRowType::CharacterHistory);
+    let claim_ids = get_merged_row_ids(db_main, merge_id, MergedRowType::CharacterClaim);
+    armory
+        .reassign_character_history(db_main, &history_ids, character_id, replacement_character_id)
+        .and_then(|_| armory.reassign_character_claims(db_main, &claim_ids, character_id, replacement_character_id))
+        .map_err(|_| InstanceFailure::Unknown)
+}
+
+// Only once the moved rows are committed
+fn reload_merged_characters(instance: &Instance, db_main: &mut impl Select, armory: &Armory, merge_id: u32, character_id: u32, replacement_character_id: u32) -> Result<(), InstanceFailure> {
+    let history_ids = get_merged_row_ids(db_main, merge_id, MergedRowType::CharacterHistory);
+    let claim_ids = get_merged_row_ids(db_main, merge_id, MergedRowType::CharacterClaim);
+    armory
+        .reload_reassigned_characters(db_main, &history_ids, &claim_ids, character_id, replacement_character_id)
+        .map_err(|_| InstanceFailure::Unknown)?;
+    instance.replace_participant(&get_merged_row_ids(db_main, merge_id, MergedRowType::Participation), character_id, replacement_character_id);
+    Ok(())
+}
@@ -1,39 +1,90 @@
-use crate::modules::instance::dto::InstanceFailure;
+use crate::modules::instance::domain_value::AuditAction;
+use crate::modules::instance::dto::{DeletedInstance, InstanceFailure};
+use crate::modules::instance::tools::log_instance_action;
 use crate::modules::instance::Instance;
 use crate::params;
 use crate::util::database::{Execute, Select};
-use std::fs;
+
+/// Soft deleted instances can be restored for 30 days before they are purged for good
+pub static DELETION_RETENTION_PERIOD: u64 = 30 * 24 * 60 * 60;
 
 pub trait DeleteInstance {
-    fn delete_instance(&self, db_main: &mut (impl Execute + Select), instance_meta_id: u32, member_id: u32) -> Result<(), InstanceFailure>;
+    fn delete_instance(&self, db_main: &mut (impl Execute + Select), instance_meta_id: u32, member_id: u32, is_moderator: bool) -> Result<(), InstanceFailure>;
+    fn restore_instance(&self, db_main: &mut (impl Execute + Select), instance_meta_id: u32, member_id: u32, is_moderator: bool) -> Result<(), InstanceFailure>;
+    fn get_deleted_instances(&self, db_main: &mut impl Select, member_id: u32, is_moderator: bool) -> Vec<DeletedInstance>;
 }
 
 impl DeleteInstance for Instance {
-    fn delete_instance(&self, db_main: &mut (impl Execute + Select), instance_meta_id: u32, member_id: u32) -> Result<(), InstanceFailure> {
-        let storage_path = std::env::var("INSTANCE_STORAGE_PATH").expect("storage path must be set");
-        let server_id: Option<u32> = db_main.select_wparams_value(
-            "SELECT server_id FROM instance_meta WHERE id=:instance_meta_id",
-            |mut row| row.take::<u32, usize>(0).unwrap(),
-            params!("instance_meta_id" => instance_meta_id),
-        );
-
-        if let Some(server_id) = server_id {
-            if db_main.execute_wparams(
-                "DELETE A FROM instance_meta A \
-                JOIN instance_uploads B ON A.upload_id = B.id \
-                JOIN account_member C ON (B.member_id = C.id OR (C.access_rights & 1) = 1) \
-                WHERE A.id=:instance_meta_id AND C.id=:member_id",
-                params!(
-                    "instance_meta_id" => instance_meta_id,
-                    "member_id" => member_id
-                ),
-            ) {
-                self.delete_instance_meta(instance_meta_id);
-                let _ = fs::remove_dir_all(&format!("{}/{}/{}", storage_path, server_id, instance_meta_id));
-                let _ = fs::remove_file(&format!("{}/{}/{}.zip", storage_path, server_id, instance_meta_id));
-                return Ok(());
-            }
+    fn delete_instance(&self, db_main: &mut (impl Execute + Select), instance_meta_id: u32, member_id: u32, is_moderator: bool) -> Result<(), InstanceFailure> {
+        let (uploaded_user, deleted_ts, _) = query_deletion_state(db_main, instance_meta_id).ok_or(InstanceFailure::InvalidInput)?;
+        if deleted_ts.is_some() {
+            return Err(InstanceFailure::InvalidInput);
+        }
+        if uploaded_user != member_id && !is_moderator {
+            return Err(InstanceFailure::AccessDenied);
+        }
+
+        if !db_main.execute_wparams(
+            "UPDATE instance_meta SET deleted_ts=:deleted_ts, deleted_by=:member_id WHERE id=:instance_meta_id AND deleted_ts IS NULL",
+            params!("deleted_ts" => time_util::now(), "member_id" => member_id, "instance_meta_id" => instance_meta_id),
+        ) {
+            return Err(InstanceFailure::Unknown);
+        }
+        log_instance_action(db_main, instance_meta_id, Some(member_id), &AuditAction::Delete);
+        self.delete_instance_meta(instance_meta_id);
+        Ok(())
+    }
+
+    fn restore_instance(&self, db_main: &mut (impl Execute + Select), instance_meta_id: u32, member_id: u32, is_moderator: bool) -> Result<(), InstanceFailure> {
+        let (uploaded_user, deleted_ts, deleted_by) = query_deletion_state(db_main, instance_meta_id).ok_or(InstanceFailure::InvalidInput)?;
+        if deleted_ts.is_none() {
+            return Err(InstanceFailure::InvalidInput);
+        }
+        if !may_restore(uploaded_user, deleted_by, member_id, is_moderator) {
+            return Err(InstanceFailure::AccessDenied);
         }
-        Err(InstanceFailure::InvalidInput)
+
+        if !db_main.execute_wparams("UPDATE instance_meta SET deleted_ts=NULL, deleted_by=NULL WHERE id=:instance_meta_id", params!("instance_meta_id" => instance_meta_id)) {
+            return Err(InstanceFailure::Unknown);
+        }
+        log_instance_action(db_main, instance_meta_id, Some(member_id), &AuditAction::Restore);
+        self.restore_instance_meta(instance_meta_id);
+        Ok(())
+    }
+
+    fn get_deleted_instances(&self, db_main: &mut impl Select, member_id: u32, is_moderator: bool) -> Vec<DeletedInstance> {
+        db_main.select_wparams(
+            "SELECT A.id, A.server_id, A.map_id, A.start_ts, B.member_id, A.deleted_by, A.deleted_ts FROM instance_meta A \
+            JOIN instance_uploads B ON A.upload_id = B.id \
+            WHERE A.deleted_ts IS NOT NULL AND (B.member_id=:member_id OR :is_moderator = 1) ORDER BY A.deleted_ts DESC",
+            |mut row| {
+                let deleted_ts: u64 = row.take(6).unwrap();
+                DeletedInstance {
+                    instance_meta_id: row.take(0).unwrap(),
+                    server_id: row.take(1).unwrap(),
+                    map_id: row.take(2).unwrap(),
+                    start_ts: row.take(3).unwrap(),
+                    uploaded_user: row.take(4).unwrap(),
+                    deleted_by: row.take_opt(5).unwrap().ok(),
+                    deleted_ts,
+                    purge_ts: deleted_ts + DELETION_RETENTION_PERIOD,
+                }
+            },
+            params!("member_id" => member_id, "is_moderator" => is_moderator as u8),
+        )
     }
 }
+
+/// Moderators can restore any instance, uploaders only those they deleted themselves
+pub fn may_restore(uploaded_user: u32, deleted_by: Option<u32>, member_id: u32, is_moderator: bool) -> bool {
+    is_moderator || (uploaded_user == member_id && deleted_by == Some(member_id))
+}
+
+// (uploaded_user, deleted_ts, deleted_by)
+fn query_deletion_state(db_main: &mut impl Select, instance_meta_id: u32) -> Option<(u32, Option<u64>, Option<u32>)> {
+    db_main.select_wparams_value(
+        "SELECT B.member_id, A.deleted_ts, A.deleted_by FROM instance_meta A JOIN instance_uploads B ON A.upload_id = B.id WHERE A.id=:instance_meta_id",
+        |mut row| (row.take(0).unwrap(), row.take_opt(1).unwrap().ok(), row.take_opt(2).unwrap().ok()),
+        params!("instance_meta_id" => instance_meta_id),
+    )
+}
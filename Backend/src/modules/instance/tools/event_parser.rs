@@ -0,0 +1,23 @@
+use serde_json::Value;
+
+/// Exported events are JSON arrays starting with [id, timestamp, ...], see the deserializers of the live data processor
+pub fn parse_event(line: &str) -> Option<Value> {
+    serde_json::from_str::<Value>(line).ok()
+}
+
+pub fn event_timestamp(event: &Value) -> Option<u64> {
+    event.get(1).and_then(Value::as_u64)
+}
+
+/// [1, character_id] for players, [0, creature_id, entry, owner?] for creatures. Pets count for their owner.
+pub fn character_of_unit(unit: &Value) -> Option<u32> {
+    match unit.get(0).and_then(Value::as_u64)? {
+        1 => unit.get(1).and_then(Value::as_u64).map(|character_id| character_id as u32),
+        _ => unit.get(3).and_then(character_of_unit),
+    }
+}
+
+/// [[amount, school_mask, absorb, resist, block], ...]
+pub fn damage_of_components(components: &Value) -> u64 {
+    components.as_array().map(|components| components.iter().filter_map(|component| component.get(0).and_then(Value::as_u64)).sum()).unwrap_or(0)
+}
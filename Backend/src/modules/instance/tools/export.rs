@@ -10,12 +10,11 @@ use crate::modules::instance::tools::FindInstanceGuild;
 use crate::modules::instance::Instance;
 use crate::params;
 use crate::util::database::Select;
-use std::fs::File;
-use std::io::Read;
-use std::path::Path;
+use crate::util::event_store::EventFilter;
 
 pub trait ExportInstance {
     fn export_instance_event_type(&self, instance_meta_id: u32, event_type: u8) -> Result<Vec<String>, InstanceFailure>;
+    fn export_instance_event_type_filtered(&self, instance_meta_id: u32, event_type: u8, filter: &EventFilter) -> Result<Vec<String>, InstanceFailure>;
     fn get_instance_meta(&self, db_main: &mut impl Select, data: &Data, armory: &Armory, instance_meta_id: u32) -> Result<InstanceViewerMeta, InstanceFailure>;
     fn get_instance_participants(&self, db_main: &mut impl Select, armory: &Armory, instance_meta_id: u32) -> Result<Vec<InstanceViewerParticipant>, InstanceFailure>;
     fn get_instance_attempts(&self, db_main: &mut impl Select, instance_meta_id: u32) -> Result<Vec<InstanceViewerAttempt>, InstanceFailure>;
@@ -40,58 +39,18 @@ impl ExportInstance for Instance {
             }
         }
 
-        let storage_path = std::env::var("INSTANCE_STORAGE_PATH").expect("storage path must be set");
-        if Path::new(&format!("{}/{}/{}.zip", storage_path, server_id, instance_meta_id)).exists() {
-            let mut instance_exports = self.instance_exports.write().unwrap();
-            // In Case of parallel accesses
-            if let Some(cached) = instance_exports.get(&(instance_meta_id, event_type)) {
-                let now = time_util::now() * 1000;
-                let last_updated = cached.get_last_updated() * 1000;
-                if (expired.is_some() && expired.unwrap() < last_updated) || (last_updated + 300000 > now) {
-                    return Ok(cached.get_cached());
-                }
-            }
-
-            let reader = File::open(format!("{}/{}/{}.zip", storage_path, server_id, instance_meta_id)).unwrap();
-            let zip = zip::ZipArchive::new(reader);
-            if zip.is_err() {
-                // Can happen if the zip file wants to be read before its ready
-                return Err(InstanceFailure::Unknown);
-            }
-            let mut zip = zip.unwrap();
-
-            for i in 0..zip.len() {
-                let mut file = zip.by_index(i).unwrap();
-                let evt_type = u8::from_str_radix(file.name(), 10).unwrap();
-                let mut content = String::with_capacity(file.size() as usize);
-                let read_result = file.read_to_string(&mut content);
-                if read_result.is_ok() {
-                    let events = content.lines().into_iter().map(|cont| cont.to_owned()).collect::<Vec<String>>();
-                    instance_exports.insert((instance_meta_id, evt_type), Cachable::new(events));
-                } else {
-                    instance_exports.insert((instance_meta_id, evt_type), Cachable::new(Vec::new()));
-                }
-            }
-
-            for i in 0..20 {
-                if !instance_exports.contains_key(&(instance_meta_id, i)) {
-                    instance_exports.insert((instance_meta_id, i), Cachable::new(Vec::new()));
-                }
-            }
+        let events = self.event_store.read(server_id, instance_meta_id, event_type, &EventFilter::default()).map_err(|_| InstanceFailure::Unknown)?;
+        let mut instance_exports = self.instance_exports.write().unwrap();
+        instance_exports.insert((instance_meta_id, event_type), Cachable::new(events.clone()));
+        Ok(events)
+    }
 
-            if let Some(cached) = instance_exports.get(&(instance_meta_id, event_type)) {
-                return Ok(cached.get_cached());
-            }
-        } else {
-            let event_path = format!("{}/{}/{}/{}", storage_path, server_id, instance_meta_id, event_type);
-            if let Ok(file_content) = std::fs::read_to_string(event_path) {
-                let events = file_content.lines().into_iter().map(|cont| cont.to_owned()).collect::<Vec<String>>();
-                let mut instance_exports = self.instance_exports.write().unwrap();
-                instance_exports.insert((instance_meta_id, event_type), Cachable::new(events.clone()));
-                return Ok(events);
-            }
-        }
-        Ok(vec![])
+    fn export_instance_event_type_filtered(&self, instance_meta_id: u32, event_type: u8, filter: &EventFilter) -> Result<Vec<String>, InstanceFailure> {
+        let server_id = {
+            let instance_metas = self.instance_metas.read().unwrap();
+            instance_metas.1.get(&instance_meta_id).ok_or(InstanceFailure::InvalidInput)?.server_id
+        };
+        self.event_store.read(server_id, instance_meta_id, event_type, filter).map_err(|_| InstanceFailure::Unknown)
     }
 
     fn get_instance_meta(&self, db_main: &mut impl Select, data: &Data, armory: &Armory, instance_meta_id: u32) -> Result<InstanceViewerMeta, InstanceFailure> {
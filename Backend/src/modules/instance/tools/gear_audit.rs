@@ -0,0 +1,36 @@
+use crate::modules::armory::dto::CharacterGearAudit;
+use crate::modules::armory::tools::{GearAudit, GetCharacter};
+use crate::modules::armory::Armory;
+use crate::modules::data::Data;
+use crate::modules::instance::dto::InstanceFailure;
+use crate::modules::instance::Instance;
+use crate::util::database::Select;
+
+pub trait InstanceGearAudit {
+    fn get_instance_gear_audit(&self, db_main: &mut impl Select, armory: &Armory, data: &Data, instance_meta_id: u32) -> Result<Vec<CharacterGearAudit>, InstanceFailure>;
+}
+
+impl InstanceGearAudit for Instance {
+    fn get_instance_gear_audit(&self, db_main: &mut impl Select, armory: &Armory, data: &Data, instance_meta_id: u32) -> Result<Vec<CharacterGearAudit>, InstanceFailure> {
+        let (participants, start_ts) = {
+            let instance_metas = self.instance_metas.read().unwrap();
+            let instance_meta = instance_metas.1.get(&instance_meta_id).ok_or(InstanceFailure::InvalidInput)?;
+            (instance_meta.participants.clone(), instance_meta.start_ts)
+        };
+
+        // The gear as it was at the time of the raid, participants with the most findings first
+        let mut gear_audits: Vec<CharacterGearAudit> = participants
+            .into_iter()
+            .filter(|character_id| {
+                armory
+                    .get_character(*character_id)
+                    .and_then(|character| character.last_update)
+                    .map(|character_history| character_history.character_info.hero_class_id != 12)
+                    .unwrap_or(false)
+            })
+            .filter_map(|character_id| armory.get_character_gear_audit_moment(db_main, data, character_id, start_ts))
+            .collect();
+        gear_audits.sort_by(|left, right| right.num_findings.cmp(&left.num_findings));
+        Ok(gear_audits)
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::modules::account::guard::Viewer;
+use crate::modules::data::domain_value::MapEncounter;
+use crate::modules::data::tools::RetrieveMapEncounter;
+use crate::modules::data::Data;
+use crate::modules::instance::domain_value::PrivacyType;
+use crate::modules::instance::dto::{EncounterProgression, InstanceViewerAttempt, ProgressionRanking, RaidProgression};
+use crate::modules::instance::tools::ExportInstance;
+use crate::modules::instance::Instance;
+use crate::params;
+use crate::util::database::Select;
+
+/// Raid lockouts last a week, in ms
+pub static LOCKOUT_DURATION: u64 = 7 * 24 * 60 * 60 * 1000;
+
+pub trait GuildProgression {
+    fn get_guild_progression(&self, db_main: &mut impl Select, data: &Data, viewer: &Viewer, guild_id: u32) -> Vec<RaidProgression>;
+    fn get_progression_rankings(&self, data: &Data, season_index: u8) -> Vec<ProgressionRanking>;
+}
+
+impl GuildProgression for Instance {
+    fn get_guild_progression(&self, db_main: &mut impl Select, data: &Data, viewer: &Viewer, guild_id: u32) -> Vec<RaidProgression> {
+        // (instance_meta_id, server_id, map_id, difficulty_id)
+        let raids: Vec<(u32, u32, u16, u8)> = {
+            let instance_metas = self.instance_metas.read().unwrap();
+            let search_index = self.search_index.read().unwrap();
+            search_index
+                .guild_instances(guild_id)
+                .into_iter()
+                .filter(|instance| {
+                    instance_metas
+                        .1
+                        .get(&instance.instance_meta_id)
+                        .map(|instance_meta| instance_meta.privacy_type.is_listed_for(instance_meta.uploaded_user, viewer))
+                        .unwrap_or(false)
+                })
+                .filter_map(|instance| instance.map_difficulty.map(|map_difficulty| (instance.instance_meta_id, instance.server_id, instance.map_id, map_difficulty)))
+                .collect()
+        };
+
+        // (map_id, difficulty_id) => (server_id, attempts)
+        let mut raid_attempts: BTreeMap<(u16, u8), (u32, Vec<InstanceViewerAttempt>)> = BTreeMap::new();
+        for (instance_meta_id, server_id, map_id, difficulty_id) in raids {
+            if let Ok(attempts) = self.get_instance_attempts(db_main, instance_meta_id) {
+                raid_attempts.entry((map_id, difficulty_id)).or_insert_with(|| (server_id, Vec::new())).1.extend(attempts);
+            }
+        }
+
+        let now = time_util::now() * 1000;
+        raid_attempts
+            .into_iter()
+            .map(|((map_id, difficulty_id), (server_id, attempts))| {
+                let reset_time = db_main
+                    .select_wparams_value(
+                        "SELECT MAX(reset_time) FROM armory_instance_resets WHERE server_id=:server_id AND map_id=:map_id AND difficulty=:difficulty",
+                        |mut row| row.take_opt::<u64, usize>(0).unwrap().ok(),
+                        params!("server_id" => server_id, "map_id" => map_id, "difficulty" => difficulty_id),
+                    )
+                    .flatten();
+                calculate_raid_progression(map_id, difficulty_id, &data.get_map_encounters(map_id, difficulty_id), &attempts, current_lockout_start(reset_time, now))
+            })
+            .collect()
+    }
+
+    fn get_progression_rankings(&self, data: &Data, season_index: u8) -> Vec<ProgressionRanking> {
+        let instance_metas = self.instance_metas.read().unwrap();
+        let kill_attempts = self.instance_kill_attempts.read().unwrap();
+        let search_index = self.search_index.read().unwrap();
+
+        // (guild_id, map_id, difficulty_id) => (guild_name, server_id, encounter_id => first kill ts)
+        let mut progressions: HashMap<(u32, u16, u8), (String, u32, HashMap<u32, u64>)> = HashMap::new();
+        for instance in search_index.instances() {
+            // Like the speed run boards, only public raids are ranked
+            let is_public = instance_metas.1.get(&instance.instance_meta_id).map(|instance_meta| instance_meta.privacy_type == PrivacyType::Public).unwrap_or(false);
+            let (guild_id, guild_name) = match (&instance.guild, instance.map_difficulty) {
+                (Some(guild), Some(_)) if is_public => guild,
+                _ => continue,
+            };
+
+            for attempt in kill_attempts.1.get(&instance.instance_meta_id).into_iter().flatten().filter(|attempt| attempt.rankable && attempt.season_index == season_index) {
+                let (_, _, first_kills) = progressions.entry((*guild_id, instance.map_id, attempt.difficulty_id)).or_insert_with(|| (guild_name.clone(), instance.server_id, HashMap::new()));
+                let first_kill_ts = first_kills.entry(attempt.encounter_id).or_insert(attempt.end_ts);
+                *first_kill_ts = (*first_kill_ts).min(attempt.end_ts);
+            }
+        }
+
+        let mut rankings: Vec<ProgressionRanking> = progressions
+            .into_iter()
+            .filter_map(|((guild_id, map_id, difficulty_id), (guild_name, server_id, first_kills))| {
+                let map_encounters = data.get_map_encounters(map_id, difficulty_id);
+                let kill_timestamps: Vec<u64> = first_kills
+                    .iter()
+                    .filter(|(encounter_id, _)| map_encounters.is_empty() || map_encounters.iter().any(|map_encounter| map_encounter.encounter_id == **encounter_id))
+                    .map(|(_, first_kill_ts)| *first_kill_ts)
+                    .collect();
+                Some(ProgressionRanking {
+                    guild_id,
+                    guild_name,
+                    server_id,
+                    map_id,
+                    difficulty_id,
+                    season_index,
+                    num_killed: kill_timestamps.len() as u32,
+                    num_encounters: if map_encounters.is_empty() { first_kills.len() } else { map_encounters.len() } as u32,
+                    progress_ts: *kill_timestamps.iter().max()?,
+                })
+            })
+            .collect();
+        sort_progression_rankings(&mut rankings);
+        rankings
+    }
+}
+
+/// Progress of a guild in a raid, given all attempts of the guild in it. Kills since the lockout start count for the current week.
+pub fn calculate_raid_progression(map_id: u16, difficulty_id: u8, map_encounters: &[MapEncounter], attempts: &[InstanceViewerAttempt], lockout_start: u64) -> RaidProgression {
+    // Without encounter data, every encounter that was attempted is listed
+    let encounter_ids: Vec<u32> = if map_encounters.is_empty() {
+        attempts.iter().map(|attempt| attempt.encounter_id).collect::<BTreeSet<u32>>().into_iter().collect()
+    } else {
+        map_encounters.iter().map(|map_encounter| map_encounter.encounter_id).collect()
+    };
+
+    let encounters: Vec<EncounterProgression> = encounter_ids
+        .iter()
+        .map(|encounter_id| {
+            let encounter_attempts: Vec<&InstanceViewerAttempt> = attempts.iter().filter(|attempt| attempt.encounter_id == *encounter_id).collect();
+            let first_kill_ts = encounter_attempts.iter().filter(|attempt| attempt.is_kill).map(|attempt| attempt.end_ts).min();
+            EncounterProgression {
+                encounter_id: *encounter_id,
+                first_kill_ts,
+                wipes_before_first_kill: encounter_attempts
+                    .iter()
+                    .filter(|attempt| !attempt.is_kill && first_kill_ts.map(|first_kill_ts| attempt.end_ts < first_kill_ts).unwrap_or(true))
+                    .count() as u32,
+                killed_this_week: encounter_attempts.iter().any(|attempt| attempt.is_kill && attempt.end_ts >= lockout_start),
+            }
+        })
+        .collect();
+
+    let mut required_encounters = map_encounters.iter().filter(|map_encounter| map_encounter.is_required).peekable();
+    let cleared_this_week = required_encounters.peek().is_some() && required_encounters.all(|map_encounter| encounters.iter().any(|encounter| encounter.encounter_id == map_encounter.encounter_id && encounter.killed_this_week));
+    RaidProgression {
+        map_id,
+        difficulty_id,
+        num_killed: encounters.iter().filter(|encounter| encounter.first_kill_ts.is_some()).count() as u32,
+        num_encounters: encounters.len() as u32,
+        cleared_this_week,
+        encounters,
+    }
+}
+
+/// Start of the lockout that contains now, given any known reset time of the raid. Without one, the last seven days are the current week.
+pub fn current_lockout_start(reset_time: Option<u64>, now: u64) -> u64 {
+    match reset_time {
+        Some(reset_time) if reset_time <= now => reset_time + (now - reset_time) / LOCKOUT_DURATION * LOCKOUT_DURATION,
+        Some(reset_time) => reset_time.saturating_sub(((reset_time - now - 1) / LOCKOUT_DURATION + 1) * LOCKOUT_DURATION),
+        None => now.saturating_sub(LOCKOUT_DURATION),
+    }
+}
+
+/// Grouped by raid and difficulty, the furthest progress first and ties going to whoever got there first
+pub fn sort_progression_rankings(rankings: &mut [ProgressionRanking]) {
+    rankings.sort_by(|left, right| {
+        (left.map_id, left.difficulty_id)
+            .cmp(&(right.map_id, right.difficulty_id))
+            .then_with(|| right.num_killed.cmp(&left.num_killed))
+            .then_with(|| left.progress_ts.cmp(&right.progress_ts))
+    });
+}
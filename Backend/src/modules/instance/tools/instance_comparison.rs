@@ -0,0 +1,211 @@
+use std::collections::{BTreeMap, HashSet};
+
+use serde_json::Value;
+
+use crate::modules::data::tools::{RetrieveItemEffect, RetrieveServer};
+use crate::modules::data::Data;
+use crate::modules::instance::domain_value::ComparedInstance;
+use crate::modules::instance::dto::{ComparisonColumn, ComparisonTarget, EncounterKillTimes, InstanceComparison, InstanceFailure, PlayerComparison};
+use crate::modules::instance::tools::{character_of_unit, damage_of_components, event_timestamp, parse_event, ExportInstance};
+use crate::modules::instance::Instance;
+use crate::util::database::Select;
+
+static SPELL_CAST_EVENT_TYPE: u8 = 0;
+static DEATH_EVENT_TYPE: u8 = 1;
+static MELEE_DAMAGE_EVENT_TYPE: u8 = 12;
+static SPELL_DAMAGE_EVENT_TYPE: u8 = 13;
+static HEAL_EVENT_TYPE: u8 = 14;
+static MAX_COMPARISON_TARGETS: usize = 5;
+
+pub trait CompareInstances {
+    fn compare_instances(&self, db_main: &mut impl Select, data: &Data, targets: &[ComparisonTarget]) -> Result<InstanceComparison, InstanceFailure>;
+}
+
+impl CompareInstances for Instance {
+    fn compare_instances(&self, db_main: &mut impl Select, data: &Data, targets: &[ComparisonTarget]) -> Result<InstanceComparison, InstanceFailure> {
+        if targets.len() < 2 || targets.len() > MAX_COMPARISON_TARGETS {
+            return Err(InstanceFailure::InvalidInput);
+        }
+        // Either whole instances or single attempts are compared, never a mix of both
+        let compare_attempts = targets[0].attempt_id.is_some();
+        if targets.iter().any(|target| target.attempt_id.is_some() != compare_attempts) {
+            return Err(InstanceFailure::InvalidInput);
+        }
+        if targets.iter().enumerate().any(|(index, target)| targets[..index].contains(target)) {
+            return Err(InstanceFailure::InvalidInput);
+        }
+
+        let (map_ids, server_ids): (Vec<u16>, Vec<u32>) = {
+            let instance_metas = self.instance_metas.read().unwrap();
+            targets
+                .iter()
+                .map(|target| instance_metas.1.get(&target.instance_meta_id).map(|instance_meta| (instance_meta.map_id, instance_meta.server_id)))
+                .collect::<Option<Vec<(u16, u32)>>>()
+                .ok_or(InstanceFailure::InvalidInput)?
+                .into_iter()
+                .unzip()
+        };
+        if map_ids.iter().any(|map_id| *map_id != map_ids[0]) {
+            return Err(InstanceFailure::InvalidInput);
+        }
+
+        let mut compared_instances = Vec::with_capacity(targets.len());
+        for target in targets.iter() {
+            let mut attempts = self.get_instance_attempts(db_main, target.instance_meta_id)?;
+            if let Some(attempt_id) = target.attempt_id {
+                attempts.retain(|attempt| attempt.id == attempt_id);
+                if attempts.is_empty() {
+                    return Err(InstanceFailure::InvalidInput);
+                }
+            }
+            compared_instances.push(ComparedInstance {
+                instance_meta_id: target.instance_meta_id,
+                attempt_id: target.attempt_id,
+                attempts,
+                spell_casts: self.export_instance_event_type(target.instance_meta_id, SPELL_CAST_EVENT_TYPE)?,
+                deaths: self.export_instance_event_type(target.instance_meta_id, DEATH_EVENT_TYPE)?,
+                melee_damage: self.export_instance_event_type(target.instance_meta_id, MELEE_DAMAGE_EVENT_TYPE)?,
+                spell_damage: self.export_instance_event_type(target.instance_meta_id, SPELL_DAMAGE_EVENT_TYPE)?,
+                heals: self.export_instance_event_type(target.instance_meta_id, HEAL_EVENT_TYPE)?,
+            });
+        }
+        if compare_attempts && compared_instances.iter().any(|compared_instance| compared_instance.attempts[0].encounter_id != compared_instances[0].attempts[0].encounter_id) {
+            return Err(InstanceFailure::InvalidInput);
+        }
+
+        let consumable_spell_ids = server_ids
+            .iter()
+            .filter_map(|server_id| data.get_server(*server_id).map(|server| server.expansion_id))
+            .collect::<HashSet<u8>>()
+            .into_iter()
+            .flat_map(|expansion_id| data.get_consumable_spell_ids(expansion_id))
+            .collect();
+        Ok(build_instance_comparison(&compared_instances, &consumable_spell_ids))
+    }
+}
+
+#[derive(Default)]
+struct PlayerColumn {
+    damage_done: u64,
+    healing_done: u64,
+    deaths: u32,
+    consumables_used: u32,
+}
+
+/// Compares the instances column by column. Only events within the compared attempts are considered,
+/// DPS and HPS are relative to the summed duration of these attempts.
+pub fn build_instance_comparison(compared_instances: &[ComparedInstance], consumable_spell_ids: &HashSet<u32>) -> InstanceComparison {
+    let num_columns = compared_instances.len();
+    let mut columns = Vec::with_capacity(num_columns);
+    // encounter_id => duration of the first kill per column
+    let mut kill_times: BTreeMap<u32, Vec<Option<u64>>> = BTreeMap::new();
+    // character_id => player per column, None if the character did not take part
+    let mut players: BTreeMap<u32, Vec<Option<PlayerColumn>>> = BTreeMap::new();
+
+    for (column, compared_instance) in compared_instances.iter().enumerate() {
+        let mut kills: Vec<_> = compared_instance.attempts.iter().filter(|attempt| attempt.is_kill).collect();
+        kills.sort_by_key(|attempt| attempt.start_ts);
+        for attempt in kills {
+            let durations = kill_times.entry(attempt.encounter_id).or_insert_with(|| vec![None; num_columns]);
+            if durations[column].is_none() {
+                durations[column] = Some(attempt.end_ts.saturating_sub(attempt.start_ts));
+            }
+        }
+
+        let in_attempt = |event: &Value| {
+            event_timestamp(event)
+                .map(|timestamp| compared_instance.attempts.iter().any(|attempt| attempt.start_ts <= timestamp && timestamp <= attempt.end_ts))
+                .unwrap_or(false)
+        };
+        let events = |lines: &[String]| lines.iter().filter_map(|line| parse_event(line)).filter(|event| in_attempt(event)).collect::<Vec<Value>>();
+
+        // [id, timestamp, attacker, victim, hit_mask, components]
+        for event in events(&compared_instance.melee_damage) {
+            if let Some(attacker) = event.get(2).and_then(character_of_unit) {
+                player_column(&mut players, attacker, column, num_columns).damage_done += event.get(5).map(damage_of_components).unwrap_or(0);
+            }
+        }
+        // [id, timestamp, cause_id, attacker, victim, spell_id, hit_mask, components]
+        for event in events(&compared_instance.spell_damage) {
+            if let Some(attacker) = event.get(3).and_then(character_of_unit) {
+                player_column(&mut players, attacker, column, num_columns).damage_done += event.get(7).map(damage_of_components).unwrap_or(0);
+            }
+        }
+        // [id, timestamp, cause_id, caster, target, spell_id, hit_mask, school_mask, total, effective, absorb, resist, block]
+        for event in events(&compared_instance.heals) {
+            if let Some(caster) = event.get(3).and_then(character_of_unit) {
+                player_column(&mut players, caster, column, num_columns).healing_done += event.get(9).and_then(Value::as_u64).unwrap_or(0);
+            }
+        }
+        // [id, timestamp, subject, victim, spell_id, hit_mask, school_mask]
+        for event in events(&compared_instance.spell_casts) {
+            let spell_id = event.get(4).and_then(Value::as_u64).map(|spell_id| spell_id as u32);
+            if let (Some(caster), Some(spell_id)) = (event.get(2).and_then(player_of_unit), spell_id) {
+                if consumable_spell_ids.contains(&spell_id) {
+                    player_column(&mut players, caster, column, num_columns).consumables_used += 1;
+                }
+            }
+        }
+        // [id, timestamp, subject, murder]
+        let mut num_deaths = 0;
+        for event in events(&compared_instance.deaths) {
+            if let Some(character_id) = event.get(2).and_then(player_of_unit) {
+                player_column(&mut players, character_id, column, num_columns).deaths += 1;
+                num_deaths += 1;
+            }
+        }
+
+        columns.push(ComparisonColumn {
+            instance_meta_id: compared_instance.instance_meta_id,
+            attempt_id: compared_instance.attempt_id,
+            combat_duration: compared_instance.attempts.iter().map(|attempt| attempt.end_ts.saturating_sub(attempt.start_ts)).sum(),
+            num_deaths,
+        });
+    }
+
+    let per_second = |amount: u64, combat_duration: u64| if combat_duration == 0 { 0.0 } else { amount as f64 * 1000.0 / combat_duration as f64 };
+    let delta = |values: &[Option<f64>]| match (values.first(), values.last()) {
+        (Some(Some(first)), Some(Some(last))) => Some(last - first),
+        _ => None,
+    };
+    InstanceComparison {
+        kill_times: kill_times.into_iter().map(|(encounter_id, durations)| EncounterKillTimes { encounter_id, durations }).collect(),
+        players: players
+            .into_iter()
+            .map(|(character_id, player_columns)| {
+                let dps: Vec<Option<f64>> = player_columns
+                    .iter()
+                    .zip(columns.iter())
+                    .map(|(player, column)| player.as_ref().map(|player| per_second(player.damage_done, column.combat_duration)))
+                    .collect();
+                let hps: Vec<Option<f64>> = player_columns
+                    .iter()
+                    .zip(columns.iter())
+                    .map(|(player, column)| player.as_ref().map(|player| per_second(player.healing_done, column.combat_duration)))
+                    .collect();
+                PlayerComparison {
+                    character_id,
+                    dps_delta: delta(&dps),
+                    hps_delta: delta(&hps),
+                    dps,
+                    hps,
+                    deaths: player_columns.iter().map(|player| player.as_ref().map(|player| player.deaths).unwrap_or(0)).collect(),
+                    consumables_used: player_columns.iter().map(|player| player.as_ref().map(|player| player.consumables_used).unwrap_or(0)).collect(),
+                }
+            })
+            .collect(),
+        columns,
+    }
+}
+
+fn player_column(players: &mut BTreeMap<u32, Vec<Option<PlayerColumn>>>, character_id: u32, column: usize, num_columns: usize) -> &mut PlayerColumn {
+    players.entry(character_id).or_insert_with(|| (0..num_columns).map(|_| None).collect())[column].get_or_insert_with(PlayerColumn::default)
+}
+
+/// Deaths and consumables are only attributed to the player itself, not to the owner of a pet
+fn player_of_unit(unit: &Value) -> Option<u32> {
+    if unit.get(0).and_then(Value::as_u64) == Some(1) {
+        return character_of_unit(unit);
+    }
+    None
+}
@@ -0,0 +1,63 @@
+use crate::modules::account::guard::Viewer;
+use crate::modules::armory::dto::SearchGuildDto;
+use crate::modules::data::tools::RetrieveMap;
+use crate::modules::data::Data;
+use crate::modules::instance::dto::{InstanceFailure, InstanceSearchEntry, InstanceSearchFilter, InstanceSearchPage};
+use crate::modules::instance::Instance;
+
+static DEFAULT_PAGE_SIZE: u32 = 10;
+static MAX_PAGE_SIZE: u32 = 100;
+
+pub trait InstanceSearch {
+    fn search_instances(&self, data: &Data, viewer: &Viewer, filter: InstanceSearchFilter) -> Result<InstanceSearchPage, InstanceFailure>;
+}
+
+impl InstanceSearch for Instance {
+    fn search_instances(&self, data: &Data, viewer: &Viewer, filter: InstanceSearchFilter) -> Result<InstanceSearchPage, InstanceFailure> {
+        let page_size = filter.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+        if page_size == 0 || page_size > MAX_PAGE_SIZE {
+            return Err(InstanceFailure::InvalidInput);
+        }
+
+        // The privacy is checked against the current metas, as the index is only refreshed by the instance thread
+        let instance_metas = self.instance_metas.read().unwrap();
+        let search_index = self.search_index.read().unwrap();
+        let (instances, num_items, next_cursor) = search_index.search(&filter, page_size as usize, |instance| {
+            instance_metas
+                .1
+                .get(&instance.instance_meta_id)
+                .map(|instance_meta| instance_meta.privacy_type.is_listed_for(instance_meta.uploaded_user, viewer))
+                .unwrap_or(false)
+        });
+
+        Ok(InstanceSearchPage {
+            result: instances
+                .into_iter()
+                .map(|instance| {
+                    let privacy_type = instance_metas
+                        .1
+                        .get(&instance.instance_meta_id)
+                        .map(|instance_meta| instance_meta.privacy_type.clone())
+                        .unwrap_or_else(|| instance.privacy_type.clone());
+                    InstanceSearchEntry {
+                        instance_meta_id: instance.instance_meta_id,
+                        meta_type: instance.meta_type,
+                        server_id: instance.server_id,
+                        map_id: instance.map_id,
+                        map_difficulty: instance.map_difficulty,
+                        map_icon: data.get_map(instance.map_id).map(|map| map.icon).unwrap_or_default(),
+                        guild: instance.guild.clone().map(|(guild_id, name)| SearchGuildDto { guild_id, name }),
+                        start_ts: instance.start_ts,
+                        end_ts: instance.end_ts,
+                        killed_encounter_ids: instance.killed_encounter_ids.iter().copied().collect(),
+                        can_delete: viewer.member_id == Some(instance.uploaded_user),
+                        privacy_type: privacy_type.to_u8(),
+                        privacy_ref: privacy_type.get_group(),
+                    }
+                })
+                .collect(),
+            num_items,
+            next_cursor,
+        })
+    }
+}
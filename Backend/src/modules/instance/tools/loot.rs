@@ -0,0 +1,121 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::modules::account::guard::Viewer;
+use crate::modules::armory::domain_value::HistoryMoment;
+use crate::modules::armory::tools::get_closest_history_moment;
+use crate::modules::armory::Armory;
+use crate::modules::data::tools::{RetrieveIcon, RetrieveItem, RetrieveServer};
+use crate::modules::data::Data;
+use crate::modules::instance::dto::{InstanceFailure, LootEntry, LootHistoryFilter};
+use crate::modules::instance::Instance;
+use crate::mysql::Value;
+use crate::params;
+use crate::util::database::Select;
+
+pub trait LootHistory {
+    fn get_instance_loot(&self, db_main: &mut impl Select, data: &Data, instance_meta_id: u32) -> Result<Vec<LootEntry>, InstanceFailure>;
+    fn get_character_loot(&self, db_main: &mut impl Select, data: &Data, viewer: &Viewer, character_id: u32, filter: &LootHistoryFilter) -> Vec<LootEntry>;
+    fn get_guild_loot(&self, db_main: &mut impl Select, data: &Data, armory: &Armory, viewer: &Viewer, guild_id: u32, filter: &LootHistoryFilter) -> Vec<LootEntry>;
+}
+
+impl LootHistory for Instance {
+    fn get_instance_loot(&self, db_main: &mut impl Select, data: &Data, instance_meta_id: u32) -> Result<Vec<LootEntry>, InstanceFailure> {
+        let loot = query_loot(db_main, "A.instance_meta_id = :instance_meta_id", params!("instance_meta_id" => instance_meta_id));
+        let instance_metas = self.instance_metas.read().unwrap();
+        let instance_meta = instance_metas.1.get(&instance_meta_id).ok_or(InstanceFailure::InvalidInput)?;
+        Ok(loot.into_iter().map(|entry| with_item_data(data, instance_meta.server_id, entry)).collect())
+    }
+
+    fn get_character_loot(&self, db_main: &mut impl Select, data: &Data, viewer: &Viewer, character_id: u32, filter: &LootHistoryFilter) -> Vec<LootEntry> {
+        let loot = query_loot(
+            db_main,
+            "A.character_id = :character_id AND A.looted_ts BETWEEN :start_ts AND :end_ts",
+            params!("character_id" => character_id, "start_ts" => filter.start_ts.unwrap_or(0), "end_ts" => filter.end_ts.unwrap_or(u64::MAX)),
+        );
+        self.visible_loot(data, viewer, loot)
+    }
+
+    fn get_guild_loot(&self, db_main: &mut impl Select, data: &Data, armory: &Armory, viewer: &Viewer, guild_id: u32, filter: &LootHistoryFilter) -> Vec<LootEntry> {
+        // Characters that have been in the guild at some point, the guild at the time of the loot is checked below
+        let loot = query_loot(
+            db_main,
+            "A.character_id IN (SELECT DISTINCT character_id FROM armory_character_history WHERE guild_id = :guild_id) AND A.looted_ts BETWEEN :start_ts AND :end_ts",
+            params!("guild_id" => guild_id, "start_ts" => filter.start_ts.unwrap_or(0), "end_ts" => filter.end_ts.unwrap_or(u64::MAX)),
+        );
+        // One query for the guild of every history moment instead of one per loot entry
+        let guild_history_ids: HashSet<u32> = db_main
+            .select_wparams("SELECT id FROM armory_character_history WHERE guild_id = :guild_id", |mut row| row.take::<u32, usize>(0).unwrap(), params!("guild_id" => guild_id))
+            .into_iter()
+            .collect();
+        let history_moments: HashMap<u32, Vec<HistoryMoment>> = {
+            let characters = armory.characters.read().unwrap();
+            loot.iter()
+                .filter_map(|entry| characters.get(&entry.character_id))
+                .map(|character| (character.id, character.history_moments.clone()))
+                .collect()
+        };
+        let loot = filter_guild_loot(loot, &history_moments, &guild_history_ids);
+        self.visible_loot(data, viewer, loot)
+    }
+}
+
+impl Instance {
+    /// Drops the loot of instances the viewer may not read and adds the item data
+    pub fn visible_loot(&self, data: &Data, viewer: &Viewer, loot: Vec<LootEntry>) -> Vec<LootEntry> {
+        let instance_metas = self.instance_metas.read().unwrap();
+        loot.into_iter()
+            .filter_map(|entry| {
+                let instance_meta = instance_metas.1.get(&entry.instance_meta_id)?;
+                if !instance_meta.privacy_type.grants_access(instance_meta.uploaded_user, viewer) {
+                    return None;
+                }
+                Some(with_item_data(data, instance_meta.server_id, entry))
+            })
+            .collect()
+    }
+}
+
+/// Loot of characters whose closest history moment at the time of looting is in the guild
+pub fn filter_guild_loot(loot: Vec<LootEntry>, history_moments: &HashMap<u32, Vec<HistoryMoment>>, guild_history_ids: &HashSet<u32>) -> Vec<LootEntry> {
+    loot.into_iter()
+        .filter(|entry| {
+            history_moments
+                .get(&entry.character_id)
+                .and_then(|history_moments| get_closest_history_moment(history_moments, entry.looted_ts / 1000))
+                .map(|history_moment| guild_history_ids.contains(&history_moment.id))
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
+fn query_loot(db_main: &mut impl Select, condition: &str, params: Vec<(String, Value)>) -> Vec<LootEntry> {
+    db_main.select_wparams(
+        &format!(
+            "SELECT A.instance_meta_id, A.character_id, A.item_id, A.amount, A.looted_ts, \
+            (SELECT B.encounter_id FROM instance_attempt B WHERE B.instance_meta_id = A.instance_meta_id AND B.is_kill = 1 AND B.end_ts <= A.looted_ts ORDER BY B.end_ts DESC LIMIT 1) \
+            FROM instance_loot A WHERE {} ORDER BY A.looted_ts",
+            condition
+        ),
+        |mut row| LootEntry {
+            instance_meta_id: row.take(0).unwrap(),
+            character_id: row.take(1).unwrap(),
+            item_id: row.take(2).unwrap(),
+            amount: row.take(3).unwrap(),
+            looted_ts: row.take(4).unwrap(),
+            encounter_id: row.take_opt(5).unwrap().ok(),
+            quality: None,
+            icon: None,
+            inventory_type: None,
+        },
+        params,
+    )
+}
+
+fn with_item_data(data: &Data, server_id: u32, mut entry: LootEntry) -> LootEntry {
+    if let Some(item) = data.get_server(server_id).and_then(|server| data.get_item(server.expansion_id, entry.item_id)) {
+        entry.quality = Some(item.quality);
+        entry.icon = data.get_icon(item.icon).map(|icon| icon.name);
+        entry.inventory_type = item.inventory_type;
+    }
+    entry
+}
@@ -1,9 +1,11 @@
 use crate::dto::{ApplyFilter, ApplyFilterTs, SearchResult};
 use crate::modules::armory::dto::SearchGuildDto;
+use crate::modules::armory::tools::CharacterClaims;
 use crate::modules::armory::Armory;
 use crate::modules::data::tools::RetrieveMap;
 use crate::modules::data::Data;
-use crate::modules::instance::domain_value::{InstanceMeta, MetaType, PrivacyType};
+use crate::modules::account::guard::Viewer;
+use crate::modules::instance::domain_value::{InstanceMeta, MetaType};
 use crate::modules::instance::dto::{BattlegroundSearchFilter, MetaBattlegroundSearch, MetaRaidSearch, MetaRatedArenaSearch, MetaSkirmishSearch, RaidSearchFilter, RatedArenaSearchFilter, SearchArenaTeam, SkirmishSearchFilter};
 use crate::modules::instance::tools::{ExportMeta, FindInstanceGuild};
 use crate::modules::instance::Instance;
@@ -13,21 +15,21 @@ use crate::util::ordering::NegateOrdExt;
 use std::cmp::Ordering;
 
 pub trait MetaSearch {
-    fn search_meta_raids(&self, db_main: &mut impl Select, armory: &Armory, data: &Data, current_user: Option<u32>, filter: RaidSearchFilter) -> SearchResult<MetaRaidSearch>;
-    fn search_meta_raids_by_member(&self, db_main: &mut impl Select, armory: &Armory, data: &Data, current_user: Option<u32>, filter: RaidSearchFilter) -> SearchResult<MetaRaidSearch>;
-    fn search_meta_raids_by_character(&self, db_main: &mut impl Select, armory: &Armory, data: &Data, character_id: u32, filter: RaidSearchFilter) -> SearchResult<MetaRaidSearch>;
-    fn search_meta_rated_arenas(&self, current_user: Option<u32>, filter: RatedArenaSearchFilter) -> SearchResult<MetaRatedArenaSearch>;
-    fn search_meta_skirmishes(&self, current_user: Option<u32>, filter: SkirmishSearchFilter) -> SearchResult<MetaSkirmishSearch>;
-    fn search_meta_battlegrounds(&self, current_user: Option<u32>, filter: BattlegroundSearchFilter) -> SearchResult<MetaBattlegroundSearch>;
+    fn search_meta_raids(&self, db_main: &mut impl Select, armory: &Armory, data: &Data, viewer: &Viewer, filter: RaidSearchFilter) -> SearchResult<MetaRaidSearch>;
+    fn search_meta_raids_by_member(&self, db_main: &mut impl Select, armory: &Armory, data: &Data, viewer: &Viewer, filter: RaidSearchFilter) -> SearchResult<MetaRaidSearch>;
+    fn search_meta_raids_by_character(&self, db_main: &mut impl Select, armory: &Armory, data: &Data, viewer: &Viewer, character_id: u32, filter: RaidSearchFilter) -> SearchResult<MetaRaidSearch>;
+    fn search_meta_rated_arenas(&self, viewer: &Viewer, filter: RatedArenaSearchFilter) -> SearchResult<MetaRatedArenaSearch>;
+    fn search_meta_skirmishes(&self, viewer: &Viewer, filter: SkirmishSearchFilter) -> SearchResult<MetaSkirmishSearch>;
+    fn search_meta_battlegrounds(&self, viewer: &Viewer, filter: BattlegroundSearchFilter) -> SearchResult<MetaBattlegroundSearch>;
 }
 
 impl MetaSearch for Instance {
-    fn search_meta_raids(&self, db_main: &mut impl Select, armory: &Armory, data: &Data, current_user: Option<u32>, mut filter: RaidSearchFilter) -> SearchResult<MetaRaidSearch> {
+    fn search_meta_raids(&self, db_main: &mut impl Select, armory: &Armory, data: &Data, viewer: &Viewer, mut filter: RaidSearchFilter) -> SearchResult<MetaRaidSearch> {
         filter.guild.convert_to_lowercase();
         let mut result = self
-            .export_meta(0)
+            .export_meta(0, viewer)
             .into_iter()
-            .filter(|raid| raid.privacy_type == PrivacyType::Public)
+            .filter(|raid| raid.privacy_type.is_listed_for(raid.uploaded_user, viewer))
             .filter(|raid| filter.map_id.apply_filter(raid.map_id))
             .filter(|raid| filter.server_id.apply_filter(raid.server_id))
             .filter(|raid| filter.start_ts.apply_filter_ts(raid.start_ts))
@@ -51,7 +53,7 @@ impl MetaSearch for Instance {
                                 server_id: raid.server_id,
                                 start_ts: raid.start_ts,
                                 end_ts: raid.end_ts,
-                                can_delete: current_user.contains(&raid.uploaded_user),
+                                can_delete: viewer.member_id.contains(&raid.uploaded_user),
                                 privacy_type: raid.privacy_type.to_u8(),
                                 privacy_ref: raid.privacy_type.get_group()
                             });
@@ -80,15 +82,20 @@ impl MetaSearch for Instance {
         }
     }
 
-    fn search_meta_raids_by_member(&self, db_main: &mut impl Select, armory: &Armory, data: &Data, current_user: Option<u32>, mut filter: RaidSearchFilter) -> SearchResult<MetaRaidSearch> {
+    fn search_meta_raids_by_member(&self, db_main: &mut impl Select, armory: &Armory, data: &Data, viewer: &Viewer, mut filter: RaidSearchFilter) -> SearchResult<MetaRaidSearch> {
         filter.guild.convert_to_lowercase();
+        // Raids uploaded by the member as well as those their claimed characters participated in
+        let claimed_character_ids = viewer.member_id.map(|member_id| armory.get_claimed_character_ids(member_id)).unwrap_or_default();
         let mut result = self
-            .export_meta(0)
+            .export_meta(0, viewer)
             .into_iter()
             .filter(|raid| filter.map_id.apply_filter(raid.map_id))
             .filter(|raid| filter.start_ts.apply_filter_ts(raid.start_ts))
             .filter(|raid| filter.end_ts.apply_filter_ts(raid.end_ts))
-            .filter(|raid| current_user.contains(&raid.uploaded_user))
+            .filter(|raid| {
+                viewer.member_id.contains(&raid.uploaded_user)
+                    || (raid.privacy_type.is_listed_for(raid.uploaded_user, viewer) && raid.participants.iter().any(|character_id| claimed_character_ids.contains(character_id)))
+            })
             .filter(|raid| filter.privacy.apply_filter(raid.privacy_type.to_u8()))
             .filter_map(|raid| {
                 if let InstanceMeta {
@@ -106,7 +113,7 @@ impl MetaSearch for Instance {
                         server_id: raid.server_id,
                         start_ts: raid.start_ts,
                         end_ts: raid.end_ts,
-                        can_delete: true,
+                        can_delete: viewer.member_id.contains(&raid.uploaded_user),
                         privacy_type: raid.privacy_type.to_u8(),
                         privacy_ref: raid.privacy_type.get_group()
                     });
@@ -131,12 +138,12 @@ impl MetaSearch for Instance {
         }
     }
 
-    fn search_meta_raids_by_character(&self, db_main: &mut impl Select, armory: &Armory, data: &Data, character_id: u32, mut filter: RaidSearchFilter) -> SearchResult<MetaRaidSearch> {
+    fn search_meta_raids_by_character(&self, db_main: &mut impl Select, armory: &Armory, data: &Data, viewer: &Viewer, character_id: u32, mut filter: RaidSearchFilter) -> SearchResult<MetaRaidSearch> {
         filter.guild.convert_to_lowercase();
         let mut result = self
-            .export_meta(0)
+            .export_meta(0, viewer)
             .into_iter()
-            .filter(|raid| raid.privacy_type == PrivacyType::Public)
+            .filter(|raid| raid.privacy_type.is_listed_for(raid.uploaded_user, viewer))
             .filter(|raid| filter.map_id.apply_filter(raid.map_id))
             .filter(|raid| filter.start_ts.apply_filter_ts(raid.start_ts))
             .filter(|raid| filter.end_ts.apply_filter_ts(raid.end_ts))
@@ -184,12 +191,13 @@ impl MetaSearch for Instance {
         }
     }
 
-    fn search_meta_rated_arenas(&self, current_user: Option<u32>, mut filter: RatedArenaSearchFilter) -> SearchResult<MetaRatedArenaSearch> {
+    fn search_meta_rated_arenas(&self, viewer: &Viewer, mut filter: RatedArenaSearchFilter) -> SearchResult<MetaRatedArenaSearch> {
         filter.team1.convert_to_lowercase();
         filter.team2.convert_to_lowercase();
         let mut result = self
-            .export_meta(1)
+            .export_meta(1, viewer)
             .into_iter()
+            .filter(|rated_arena| rated_arena.privacy_type.is_listed_for(rated_arena.uploaded_user, viewer))
             .filter(|rated_arena| filter.map_id.apply_filter(rated_arena.map_id))
             .filter(|rated_arena| filter.server_id.apply_filter(rated_arena.server_id))
             .filter(|rated_arena| filter.start_ts.apply_filter_ts(rated_arena.start_ts))
@@ -218,7 +226,7 @@ impl MetaSearch for Instance {
                             team2_change,
                             start_ts: rated_arena.start_ts,
                             end_ts: rated_arena.end_ts,
-                            can_delete: current_user.contains(&rated_arena.uploaded_user),
+                            can_delete: viewer.member_id.contains(&rated_arena.uploaded_user),
                         });
                     }
                 }
@@ -246,10 +254,11 @@ impl MetaSearch for Instance {
         }
     }
 
-    fn search_meta_skirmishes(&self, current_user: Option<u32>, filter: SkirmishSearchFilter) -> SearchResult<MetaSkirmishSearch> {
+    fn search_meta_skirmishes(&self, viewer: &Viewer, filter: SkirmishSearchFilter) -> SearchResult<MetaSkirmishSearch> {
         let mut result = self
-            .export_meta(2)
+            .export_meta(2, viewer)
             .into_iter()
+            .filter(|skirmish| skirmish.privacy_type.is_listed_for(skirmish.uploaded_user, viewer))
             .filter(|skirmish| filter.map_id.apply_filter(skirmish.map_id))
             .filter(|skirmish| filter.server_id.apply_filter(skirmish.server_id))
             .filter(|skirmish| filter.start_ts.apply_filter_ts(skirmish.start_ts))
@@ -266,7 +275,7 @@ impl MetaSearch for Instance {
                         winner,
                         start_ts: skirmish.start_ts,
                         end_ts: skirmish.end_ts,
-                        can_delete: current_user.contains(&skirmish.uploaded_user),
+                        can_delete: viewer.member_id.contains(&skirmish.uploaded_user),
                     });
                 }
                 None
@@ -289,10 +298,11 @@ impl MetaSearch for Instance {
         }
     }
 
-    fn search_meta_battlegrounds(&self, current_user: Option<u32>, filter: BattlegroundSearchFilter) -> SearchResult<MetaBattlegroundSearch> {
+    fn search_meta_battlegrounds(&self, viewer: &Viewer, filter: BattlegroundSearchFilter) -> SearchResult<MetaBattlegroundSearch> {
         let mut result = self
-            .export_meta(3)
+            .export_meta(3, viewer)
             .into_iter()
+            .filter(|skirmish| skirmish.privacy_type.is_listed_for(skirmish.uploaded_user, viewer))
             .filter(|skirmish| filter.map_id.apply_filter(skirmish.map_id))
             .filter(|skirmish| filter.server_id.apply_filter(skirmish.server_id))
             .filter(|skirmish| filter.start_ts.apply_filter_ts(skirmish.start_ts))
@@ -312,7 +322,7 @@ impl MetaSearch for Instance {
                             score_horde,
                             start_ts: skirmish.start_ts,
                             end_ts: skirmish.end_ts,
-                            can_delete: current_user.contains(&skirmish.uploaded_user),
+                            can_delete: viewer.member_id.contains(&skirmish.uploaded_user),
                         });
                     }
                 }
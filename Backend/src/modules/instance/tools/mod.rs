@@ -1,15 +1,41 @@
-pub use self::delete::DeleteInstance;
+pub use self::access::InstanceAccess;
+pub use self::attendance::*;
+pub use self::audit::*;
+pub use self::character_merge::*;
+pub use self::delete::*;
+pub use self::event_parser::*;
 pub use self::export::ExportInstance;
+pub use self::gear_audit::InstanceGearAudit;
+pub use self::guild_progression::*;
+pub use self::instance_comparison::*;
 pub use self::instance_guild::FindInstanceGuild;
+pub use self::instance_search::InstanceSearch;
+pub use self::loot::{filter_guild_loot, LootHistory};
 pub use self::meta::*;
 pub use self::meta_search::MetaSearch;
+pub use self::pvp::*;
 pub use self::ranking::*;
+pub use self::season::*;
+pub use self::speed_run::*;
 pub use self::unrank::*;
 
+mod access;
+mod attendance;
+mod audit;
+mod character_merge;
 mod delete;
+mod event_parser;
 mod export;
+mod gear_audit;
+mod guild_progression;
+mod instance_comparison;
 mod instance_guild;
+mod instance_search;
+mod loot;
 mod meta;
 mod meta_search;
+mod pvp;
 mod ranking;
+mod season;
+mod speed_run;
 mod unrank;
\ No newline at end of file
@@ -0,0 +1,207 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::modules::account::guard::Viewer;
+use crate::modules::data::tools::RetrieveRace;
+use crate::modules::data::Data;
+use crate::modules::instance::domain_value::{InstanceMeta, MetaType};
+use crate::modules::instance::dto::{ArenaTeamMatch, BattlegroundWinRate, CompositionWinRate, InstanceFailure, MapWinRate, PvpMatchSummary, PvpParticipantSummary, SearchArenaTeam};
+use crate::modules::instance::tools::{character_of_unit, damage_of_components, parse_event, ExportInstance, ExportMeta};
+use crate::modules::instance::Instance;
+
+static MELEE_DAMAGE_EVENT_TYPE: u8 = 12;
+static SPELL_DAMAGE_EVENT_TYPE: u8 = 13;
+static HEAL_EVENT_TYPE: u8 = 14;
+
+pub trait PvpStatistics {
+    fn get_arena_team_history(&self, viewer: &Viewer, team_id: u32) -> Vec<ArenaTeamMatch>;
+    fn get_composition_win_rates(&self, viewer: &Viewer, server_id: u32) -> Vec<CompositionWinRate> {
+        let rated_arenas = self.export_meta(1, viewer);
+        let season_calendar = self.season_calendar.read().unwrap();
+        let search_index = self.search_index.read().unwrap();
+        let mut compositions: BTreeMap<(u8, u8, Vec<u8>), (u32, u32)> = BTreeMap::new();
+        for rated_arena in rated_arenas.into_iter().filter(|rated_arena| rated_arena.server_id == server_id) {
+            if let (MetaType::RatedArena { winner: Some(winner), team1, team2, .. }, Some(indexed)) = (&rated_arena.instance_specific, search_index.get(rated_arena.instance_meta_id)) {
+                let season_index = season_calendar.season_index(rated_arena.server_id, rated_arena.start_ts);
+                for (team, is_team1) in [(team1, true), (team2, false)].iter() {
+                    let mut hero_class_ids: Vec<u8> = indexed
+                        .pvp_participants
+                        .iter()
+                        .filter(|participant| participant.arena_team_ids.contains(&team.id))
+                        .map(|participant| participant.hero_class_id)
+                        .collect();
+                    if hero_class_ids.is_empty() {
+                        continue;
+                    }
+                    hero_class_ids.sort_unstable();
+
+                    let (num_matches, num_wins) = compositions.entry((team.size_type.to_u8(), season_index, hero_class_ids)).or_insert((0, 0));
+                    *num_matches += 1;
+                    if team_won(Some(*winner), *is_team1) == Some(true) {
+                        *num_wins += 1;
+                    }
+                }
+            }
+        }
+
+        compositions
+            .into_iter()
+            .map(|((size_type, season_index, hero_class_ids), (num_matches, num_wins))| CompositionWinRate {
+                size_type,
+                season_index,
+                hero_class_ids,
+                num_matches,
+                num_wins,
+            })
+            .collect()
+    }
+
+    fn get_battleground_win_rate(&self, data: &Data, viewer: &Viewer, character_id: u32) -> BattlegroundWinRate {
+        let battlegrounds = self.export_meta(3, viewer);
+        let search_index = self.search_index.read().unwrap();
+        // map_id => (num_matches, num_wins)
+        let mut maps: BTreeMap<u16, (u32, u32)> = BTreeMap::new();
+        for battleground in battlegrounds.into_iter().filter(|battleground| battleground.participants.contains(&character_id)) {
+            if let MetaType::Battleground { winner: Some(winner), .. } = battleground.instance_specific {
+                let faction = search_index
+                    .get(battleground.instance_meta_id)
+                    .and_then(|indexed| indexed.pvp_participants.iter().find(|participant| participant.character_id == character_id))
+                    .and_then(|participant| data.get_race(participant.race_id))
+                    .map(|race| race.faction);
+                if let Some(faction) = faction {
+                    let (num_matches, num_wins) = maps.entry(battleground.map_id).or_insert((0, 0));
+                    *num_matches += 1;
+                    // The winner is true if the horde won, the faction is true for the horde
+                    if winner == faction {
+                        *num_wins += 1;
+                    }
+                }
+            }
+        }
+
+        BattlegroundWinRate {
+            character_id,
+            num_matches: maps.values().map(|(num_matches, _)| num_matches).sum(),
+            num_wins: maps.values().map(|(_, num_wins)| num_wins).sum(),
+            maps: maps.into_iter().map(|(map_id, (num_matches, num_wins))| MapWinRate { map_id, num_matches, num_wins }).collect(),
+        }
+    }
+
+    fn get_pvp_match_summary(&self, instance_meta_id: u32) -> Result<PvpMatchSummary, InstanceFailure> {
+        let is_pvp = self
+            .instance_metas
+            .read()
+            .unwrap()
+            .1
+            .get(&instance_meta_id)
+            .map(|instance_meta| instance_meta.instance_specific.to_u8() != 0)
+            .ok_or(InstanceFailure::InvalidInput)?;
+        if !is_pvp {
+            return Err(InstanceFailure::InvalidInput);
+        }
+
+        let melee_damage = self.export_instance_event_type(instance_meta_id, MELEE_DAMAGE_EVENT_TYPE)?;
+        let spell_damage = self.export_instance_event_type(instance_meta_id, SPELL_DAMAGE_EVENT_TYPE)?;
+        let heals = self.export_instance_event_type(instance_meta_id, HEAL_EVENT_TYPE)?;
+        Ok(PvpMatchSummary {
+            instance_meta_id,
+            participants: summarize_pvp_events(&melee_damage, &spell_damage, &heals),
+        })
+    }
+}
+
+/// Whether the given team of a rated arena won. The winner is true if the horde side, i.e. team 2, won.
+pub fn team_won(winner: Option<bool>, is_team1: bool) -> Option<bool> {
+    winner.map(|horde_won| horde_won != is_team1)
+}
+
+/// Matches of the team ordered by time, with the rating progression relative to the first tracked match
+pub fn arena_team_history(team_id: u32, rated_arenas: Vec<InstanceMeta>) -> Vec<ArenaTeamMatch> {
+    let mut matches: Vec<ArenaTeamMatch> = rated_arenas
+        .into_iter()
+        .filter_map(|rated_arena| {
+            if let MetaType::RatedArena {
+                winner,
+                team1,
+                team2,
+                team1_change,
+                team2_change,
+            } = rated_arena.instance_specific
+            {
+                let (is_team1, opponent, rating_change) = if team1.id == team_id {
+                    (true, team2, team1_change)
+                } else if team2.id == team_id {
+                    (false, team1, team2_change)
+                } else {
+                    return None;
+                };
+                return Some(ArenaTeamMatch {
+                    instance_meta_id: rated_arena.instance_meta_id,
+                    map_id: rated_arena.map_id,
+                    start_ts: rated_arena.start_ts,
+                    end_ts: rated_arena.end_ts,
+                    opponent: SearchArenaTeam {
+                        team_id: opponent.id,
+                        name: opponent.team_name,
+                    },
+                    won: team_won(winner, is_team1),
+                    rating_change,
+                    rating_progression: 0,
+                });
+            }
+            None
+        })
+        .collect();
+    matches.sort_by_key(|arena_match| (arena_match.start_ts, arena_match.instance_meta_id));
+
+    let mut rating_progression = 0;
+    for arena_match in matches.iter_mut() {
+        rating_progression += arena_match.rating_change;
+        arena_match.rating_progression = rating_progression;
+    }
+    matches
+}
+
+/// Damage and healing per character from the exported melee damage, spell damage and heal events
+pub fn summarize_pvp_events(melee_damage: &[String], spell_damage: &[String], heals: &[String]) -> Vec<PvpParticipantSummary> {
+    let mut damage_events: Vec<(Option<u32>, Option<u32>, u64)> = Vec::new();
+    // [id, timestamp, attacker, victim, hit_mask, components]
+    for event in melee_damage.iter().filter_map(|line| parse_event(line)) {
+        damage_events.push((event.get(2).and_then(character_of_unit), event.get(3).and_then(character_of_unit), event.get(5).map(damage_of_components).unwrap_or(0)));
+    }
+    // [id, timestamp, cause_id, attacker, victim, spell_id, hit_mask, components]
+    for event in spell_damage.iter().filter_map(|line| parse_event(line)) {
+        damage_events.push((event.get(3).and_then(character_of_unit), event.get(4).and_then(character_of_unit), event.get(7).map(damage_of_components).unwrap_or(0)));
+    }
+
+    let mut result: BTreeMap<u32, PvpParticipantSummary> = BTreeMap::new();
+    let mut add = |character_id: u32, damage_done: u64, damage_taken: u64, effective_healing_done: u64| {
+        let summary = result.entry(character_id).or_insert_with(|| PvpParticipantSummary {
+            character_id,
+            damage_done: 0,
+            damage_taken: 0,
+            effective_healing_done: 0,
+        });
+        summary.damage_done += damage_done;
+        summary.damage_taken += damage_taken;
+        summary.effective_healing_done += effective_healing_done;
+    };
+
+    for (attacker, victim, amount) in damage_events {
+        if let Some(attacker) = attacker {
+            add(attacker, amount, 0, 0);
+        }
+        if let Some(victim) = victim {
+            add(victim, 0, amount, 0);
+        }
+    }
+    // [id, timestamp, cause_id, caster, target, spell_id, hit_mask, school_mask, total, effective, absorb, resist, block]
+    for event in heals.iter().filter_map(|line| parse_event(line)) {
+        if let Some(caster) = event.get(3).and_then(character_of_unit) {
+            add(caster, 0, 0, event.get(9).and_then(Value::as_u64).unwrap_or(0));
+        }
+    }
+
+    result.into_values().collect()
+}
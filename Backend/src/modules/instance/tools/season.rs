@@ -0,0 +1,114 @@
+use std::sync::RwLock;
+
+use crate::modules::instance::domain_value::Season;
+use crate::modules::instance::dto::{InstanceFailure, SeasonDto};
+use crate::modules::instance::material::SeasonCalendar;
+use crate::modules::instance::Instance;
+use crate::params;
+use crate::util::database::{Execute, Select};
+
+pub trait Seasons {
+    fn get_seasons(&self, server_id: Option<u32>) -> Vec<Season>;
+    fn create_season(&self, db_main: &mut (impl Execute + Select), season: SeasonDto, is_moderator: bool, owned_server_id: Option<u32>) -> Result<Season, InstanceFailure>;
+    fn update_season(&self, db_main: &mut (impl Execute + Select), season_id: u32, season: SeasonDto, is_moderator: bool, owned_server_id: Option<u32>) -> Result<Season, InstanceFailure>;
+    fn delete_season(&self, db_main: &mut (impl Execute + Select), season_id: u32, is_moderator: bool, owned_server_id: Option<u32>) -> Result<(), InstanceFailure>;
+}
+
+impl Seasons for Instance {
+    fn get_seasons(&self, server_id: Option<u32>) -> Vec<Season> {
+        let season_calendar = self.season_calendar.read().unwrap();
+        match server_id {
+            Some(server_id) => season_calendar.get_server_seasons(server_id).into_iter().cloned().collect(),
+            None => season_calendar.get_seasons().clone(),
+        }
+    }
+
+    fn create_season(&self, db_main: &mut (impl Execute + Select), season: SeasonDto, is_moderator: bool, owned_server_id: Option<u32>) -> Result<Season, InstanceFailure> {
+        let season = validate_season(&self.season_calendar, None, season, is_moderator, owned_server_id)?;
+        if !db_main.execute_wparams(
+            "INSERT INTO instance_season (server_id, expansion_id, season_name, start_ts, end_ts) VALUES (:server_id, :expansion_id, :season_name, :start_ts, :end_ts)",
+            params!("server_id" => season.server_id, "expansion_id" => season.expansion_id, "season_name" => season.name.clone(), "start_ts" => season.start_ts, "end_ts" => season.end_ts),
+        ) {
+            return Err(InstanceFailure::Unknown);
+        }
+
+        // The id of the insert of this connection, even if seasons are created concurrently
+        let season_id = db_main.select_value("SELECT LAST_INSERT_ID()", |mut row| row.take::<u32, usize>(0).unwrap()).ok_or(InstanceFailure::Unknown)?;
+        reload_season_calendar(&self.season_calendar, db_main).get_season(season_id).cloned().ok_or(InstanceFailure::Unknown)
+    }
+
+    fn update_season(&self, db_main: &mut (impl Execute + Select), season_id: u32, season: SeasonDto, is_moderator: bool, owned_server_id: Option<u32>) -> Result<Season, InstanceFailure> {
+        let current_server_id = self.season_calendar.read().unwrap().get_season(season_id).map(|season| season.server_id).ok_or(InstanceFailure::InvalidInput)?;
+        if !may_manage_season(current_server_id, is_moderator, owned_server_id) {
+            return Err(InstanceFailure::AccessDenied);
+        }
+
+        let season = validate_season(&self.season_calendar, Some(season_id), season, is_moderator, owned_server_id)?;
+        if !db_main.execute_wparams(
+            "UPDATE instance_season SET server_id=:server_id, expansion_id=:expansion_id, season_name=:season_name, start_ts=:start_ts, end_ts=:end_ts WHERE id=:id",
+            params!("server_id" => season.server_id, "expansion_id" => season.expansion_id, "season_name" => season.name.clone(),
+            "start_ts" => season.start_ts, "end_ts" => season.end_ts, "id" => season_id),
+        ) {
+            return Err(InstanceFailure::Unknown);
+        }
+
+        reload_season_calendar(&self.season_calendar, db_main).get_season(season_id).cloned().ok_or(InstanceFailure::Unknown)
+    }
+
+    fn delete_season(&self, db_main: &mut (impl Execute + Select), season_id: u32, is_moderator: bool, owned_server_id: Option<u32>) -> Result<(), InstanceFailure> {
+        let current_server_id = self.season_calendar.read().unwrap().get_season(season_id).map(|season| season.server_id).ok_or(InstanceFailure::InvalidInput)?;
+        if !may_manage_season(current_server_id, is_moderator, owned_server_id) {
+            return Err(InstanceFailure::AccessDenied);
+        }
+        if !self.season_calendar.read().unwrap().keeps_season_indexes(season_id, None) {
+            return Err(InstanceFailure::InvalidInput);
+        }
+
+        if !db_main.execute_wparams("DELETE FROM instance_season WHERE id=:id", params!("id" => season_id)) {
+            return Err(InstanceFailure::Unknown);
+        }
+
+        reload_season_calendar(&self.season_calendar, db_main);
+        Ok(())
+    }
+}
+
+fn validate_season(season_calendar: &RwLock<SeasonCalendar>, season_id: Option<u32>, mut season: SeasonDto, is_moderator: bool, owned_server_id: Option<u32>) -> Result<SeasonDto, InstanceFailure> {
+    if !may_manage_season(season.server_id, is_moderator, owned_server_id) {
+        return Err(InstanceFailure::AccessDenied);
+    }
+
+    season.name = season.name.trim().to_owned();
+    if season.name.is_empty() || season.name.chars().count() > 64 || season.end_ts.map(|end_ts| end_ts <= season.start_ts).unwrap_or(false) {
+        return Err(InstanceFailure::InvalidInput);
+    }
+
+    // A server specific phase has to belong to the expansion of the server
+    if let Some(server_id) = season.server_id {
+        if season_calendar.read().unwrap().get_server_expansion(server_id) != Some(season.expansion_id) {
+            return Err(InstanceFailure::InvalidInput);
+        }
+    }
+
+    let season_calendar = season_calendar.read().unwrap();
+    let keeps_season_indexes = match season_id {
+        Some(season_id) => season_calendar.keeps_season_indexes(season_id, Some((season.server_id, season.expansion_id, season.start_ts))),
+        None => season_calendar.may_append_season(season.server_id, season.expansion_id, season.start_ts),
+    };
+    if !keeps_season_indexes {
+        return Err(InstanceFailure::InvalidInput);
+    }
+    Ok(season)
+}
+
+/// Rankings, speed runs and speed kills are recalculated by the instance thread once it notices the change
+fn reload_season_calendar(season_calendar: &RwLock<SeasonCalendar>, db_main: &mut impl Select) -> SeasonCalendar {
+    let reloaded_season_calendar = SeasonCalendar::load(db_main);
+    *season_calendar.write().unwrap() = reloaded_season_calendar.clone();
+    reloaded_season_calendar
+}
+
+/// Moderators manage every calendar, server owners only the one of their server
+pub fn may_manage_season(server_id: Option<u32>, is_moderator: bool, owned_server_id: Option<u32>) -> bool {
+    is_moderator || (server_id.is_some() && server_id == owned_server_id)
+}
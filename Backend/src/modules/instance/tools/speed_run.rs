@@ -0,0 +1,37 @@
+use std::collections::BTreeSet;
+
+use crate::modules::data::domain_value::MapEncounter;
+use crate::modules::instance::domain_value::InstanceAttempt;
+use crate::modules::instance::dto::SpeedRunSplit;
+
+/// A run is complete if every required boss has a rankable kill. Maps without required bosses can not be completed.
+pub fn is_full_clear(map_encounters: &[MapEncounter], attempts: &[InstanceAttempt]) -> bool {
+    let mut required_encounters = map_encounters.iter().filter(|map_encounter| map_encounter.is_required).peekable();
+    required_encounters.peek().is_some()
+        && required_encounters.all(|map_encounter| attempts.iter().any(|attempt| attempt.encounter_id == map_encounter.encounter_id && attempt.rankable))
+}
+
+/// Split per killed boss of the map, ordered by the time of the kill
+pub fn calculate_speed_run_splits(map_encounters: &[MapEncounter], attempts: &[InstanceAttempt], start_ts: u64) -> Vec<SpeedRunSplit> {
+    let mut kills: Vec<&InstanceAttempt> = attempts
+        .iter()
+        .filter(|attempt| attempt.rankable && map_encounters.iter().any(|map_encounter| map_encounter.encounter_id == attempt.encounter_id))
+        .collect();
+    kills.sort_by_key(|attempt| attempt.end_ts);
+
+    let mut killed_encounters = BTreeSet::new();
+    let mut previous_kill_ts = start_ts;
+    let mut splits = Vec::with_capacity(kills.len());
+    for kill in kills {
+        if !killed_encounters.insert(kill.encounter_id) {
+            continue;
+        }
+        splits.push(SpeedRunSplit {
+            encounter_id: kill.encounter_id,
+            kill_offset: kill.end_ts.saturating_sub(start_ts),
+            segment_duration: kill.end_ts.saturating_sub(previous_kill_ts),
+        });
+        previous_kill_ts = kill.end_ts;
+    }
+    splits
+}
@@ -1,15 +1,17 @@
+use crate::modules::instance::domain_value::AuditAction;
 use crate::modules::instance::dto::InstanceFailure;
+use crate::modules::instance::tools::log_instance_action;
 use crate::modules::instance::Instance;
 use crate::params;
 use crate::util::database::Execute;
 use std::collections::HashMap;
 
 pub trait UnrankAttempt {
-    fn unrank_attempt(&self, db_main: &mut impl Execute, attempt_id: u32) -> Result<(), InstanceFailure>;
+    fn unrank_attempt(&self, db_main: &mut impl Execute, attempt_id: u32, member_id: u32) -> Result<(), InstanceFailure>;
 }
 
 impl UnrankAttempt for Instance {
-    fn unrank_attempt(&self, db_main: &mut impl Execute, attempt_id: u32) -> Result<(), InstanceFailure> {
+    fn unrank_attempt(&self, db_main: &mut impl Execute, attempt_id: u32, member_id: u32) -> Result<(), InstanceFailure> {
         let i_m_i;
         {
             let mut attempts = self.instance_kill_attempts.write().unwrap();
@@ -21,6 +23,7 @@ impl UnrankAttempt for Instance {
             let _ = db_main.execute_wparams("UPDATE `main`.`instance_attempt` SET rankable = 0 WHERE id=:attempt_id", params!("attempt_id" => attempt_id));
             i_m_i = *instance_meta_id;
         }
+        log_instance_action(db_main, i_m_i, Some(member_id), &AuditAction::Unrank { attempt_id });
 
         {
             let mut speed_runs = self.speed_runs.write().unwrap();
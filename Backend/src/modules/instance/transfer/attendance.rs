@@ -0,0 +1,33 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::account::guard::{Authenticate, Viewer};
+use crate::modules::armory::Armory;
+use crate::modules::instance::dto::{AttendanceAlias, AttendanceFilter, GuildAttendance, InstanceFailure};
+use crate::modules::instance::tools::Attendance;
+use crate::modules::instance::Instance;
+use crate::MainDb;
+
+#[openapi]
+#[post("/attendance/by_guild/<guild_id>", format = "application/json", data = "<filter>")]
+pub fn get_guild_attendance(mut db_main: MainDb, me: State<Instance>, armory: State<Armory>, viewer: Viewer, guild_id: u32, filter: Json<AttendanceFilter>) -> Json<GuildAttendance> {
+    Json(me.get_guild_attendance(&mut *db_main, &armory, &viewer, guild_id, &filter))
+}
+
+#[openapi]
+#[get("/attendance/alias")]
+pub fn get_attendance_aliases(mut db_main: MainDb, me: State<Instance>, auth: Authenticate) -> Json<Vec<AttendanceAlias>> {
+    Json(me.get_attendance_aliases(&mut *db_main, auth.0))
+}
+
+#[openapi]
+#[post("/attendance/alias", format = "application/json", data = "<alias>")]
+pub fn set_attendance_alias(mut db_main: MainDb, me: State<Instance>, armory: State<Armory>, auth: Authenticate, alias: Json<AttendanceAlias>) -> Result<(), InstanceFailure> {
+    me.set_attendance_alias(&mut *db_main, &armory, auth.0, alias.into_inner())
+}
+
+#[openapi]
+#[delete("/attendance/alias/<alt_character_id>")]
+pub fn delete_attendance_alias(mut db_main: MainDb, me: State<Instance>, auth: Authenticate, alt_character_id: u32) -> Result<(), InstanceFailure> {
+    me.delete_attendance_alias(&mut *db_main, auth.0, alt_character_id)
+}
@@ -0,0 +1,27 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::account::guard::{Authenticate, IsModerator};
+use crate::modules::armory::Armory;
+use crate::modules::instance::dto::{CharacterMerge, InstanceFailure};
+use crate::modules::instance::tools::MergeCharacter;
+use crate::modules::instance::Instance;
+use crate::MainDb;
+
+#[openapi]
+#[post("/character_merge/<source_character_id>/<target_character_id>")]
+pub fn merge_character(mut db_main: MainDb, me: State<Instance>, armory: State<Armory>, auth: Authenticate, moderator: Option<IsModerator>, source_character_id: u32, target_character_id: u32) -> Result<Json<CharacterMerge>, InstanceFailure> {
+    me.merge_character(&mut *db_main, &armory, source_character_id, target_character_id, auth.0, moderator.is_some()).map(Json)
+}
+
+#[openapi]
+#[delete("/character_merge/<merge_id>")]
+pub fn undo_character_merge(mut db_main: MainDb, me: State<Instance>, armory: State<Armory>, auth: Authenticate, moderator: Option<IsModerator>, merge_id: u32) -> Result<Json<CharacterMerge>, InstanceFailure> {
+    me.undo_character_merge(&mut *db_main, &armory, merge_id, auth.0, moderator.is_some()).map(Json)
+}
+
+#[openapi]
+#[get("/character_merge/<character_id>")]
+pub fn get_character_merges(mut db_main: MainDb, me: State<Instance>, _auth: Authenticate, character_id: u32) -> Json<Vec<CharacterMerge>> {
+    Json(me.get_character_merges(&mut *db_main, character_id))
+}
@@ -1,6 +1,6 @@
-use crate::modules::account::guard::Authenticate;
-use crate::modules::instance::dto::InstanceFailure;
-use crate::modules::instance::tools::DeleteInstance;
+use crate::modules::account::guard::{Authenticate, IsModerator};
+use crate::modules::instance::dto::{AuditLogEntry, DeletedInstance, InstanceFailure};
+use crate::modules::instance::tools::{DeleteInstance, InstanceAuditLog};
 use crate::modules::instance::Instance;
 use crate::MainDb;
 use rocket::State;
@@ -8,6 +8,24 @@ use rocket_contrib::json::Json;
 
 #[openapi]
 #[delete("/delete", data = "<data>")]
-pub fn delete_instance(mut db_main: MainDb, me: State<Instance>, data: Json<u32>, auth: Authenticate) -> Result<(), InstanceFailure> {
-    me.delete_instance(&mut *db_main, data.into_inner(), auth.0)
+pub fn delete_instance(mut db_main: MainDb, me: State<Instance>, data: Json<u32>, auth: Authenticate, moderator: Option<IsModerator>) -> Result<(), InstanceFailure> {
+    me.delete_instance(&mut *db_main, data.into_inner(), auth.0, moderator.is_some())
+}
+
+#[openapi]
+#[post("/restore", data = "<data>")]
+pub fn restore_instance(mut db_main: MainDb, me: State<Instance>, data: Json<u32>, auth: Authenticate, moderator: Option<IsModerator>) -> Result<(), InstanceFailure> {
+    me.restore_instance(&mut *db_main, data.into_inner(), auth.0, moderator.is_some())
+}
+
+#[openapi]
+#[get("/deleted")]
+pub fn get_deleted_instances(mut db_main: MainDb, me: State<Instance>, auth: Authenticate, moderator: Option<IsModerator>) -> Json<Vec<DeletedInstance>> {
+    Json(me.get_deleted_instances(&mut *db_main, auth.0, moderator.is_some()))
+}
+
+#[openapi]
+#[get("/audit_log/<instance_meta_id>")]
+pub fn get_audit_log(mut db_main: MainDb, me: State<Instance>, auth: Authenticate, moderator: Option<IsModerator>, instance_meta_id: u32) -> Result<Json<Vec<AuditLogEntry>>, InstanceFailure> {
+    me.get_audit_log(&mut *db_main, instance_meta_id, auth.0, moderator.is_some()).map(Json)
 }
@@ -4,31 +4,44 @@ use rocket_contrib::json::Json;
 use crate::MainDb;
 use crate::modules::armory::Armory;
 use crate::modules::data::Data;
-use crate::modules::instance::dto::{InstanceFailure, InstanceViewerAttempt, InstanceViewerMeta, InstanceViewerParticipant, RawJson};
+use crate::modules::instance::dto::{EventExportFilter, InstanceFailure, InstanceViewerAttempt, InstanceViewerMeta, InstanceViewerParticipant, RawJson};
 use crate::modules::instance::Instance;
-use crate::modules::instance::tools::ExportInstance;
+use crate::modules::instance::tools::{ExportInstance, InstanceAccess};
+use crate::modules::account::guard::Viewer;
 
 #[openapi(skip)]
 #[get("/export/<instance_meta_id>/<event_type>/<_last_event_id>")]
-pub fn get_instance_event_type(me: State<Instance>, instance_meta_id: u32, event_type: u8, _last_event_id: u32) -> Result<RawJson, InstanceFailure> {
+pub fn get_instance_event_type(me: State<Instance>, viewer: Viewer, instance_meta_id: u32, event_type: u8, _last_event_id: u32) -> Result<RawJson, InstanceFailure> {
+    me.check_access(instance_meta_id, &viewer)?;
     me.export_instance_event_type(instance_meta_id, event_type)
         .map(|events| RawJson("[".to_owned() + &events.join(",") + "]"))
 }
 
+#[openapi(skip)]
+#[post("/export/<instance_meta_id>/<event_type>", format = "application/json", data = "<filter>")]
+pub fn get_instance_event_type_filtered(me: State<Instance>, viewer: Viewer, instance_meta_id: u32, event_type: u8, filter: Json<EventExportFilter>) -> Result<RawJson, InstanceFailure> {
+    me.check_access(instance_meta_id, &viewer)?;
+    me.export_instance_event_type_filtered(instance_meta_id, event_type, &filter.to_event_filter())
+        .map(|events| RawJson("[".to_owned() + &events.join(",") + "]"))
+}
+
 #[openapi]
 #[get("/export/<instance_meta_id>")]
-pub fn get_instance_meta(mut db_main: MainDb, me: State<Instance>, data: State<Data>, armory: State<Armory>, instance_meta_id: u32) -> Result<Json<InstanceViewerMeta>, InstanceFailure> {
+pub fn get_instance_meta(mut db_main: MainDb, me: State<Instance>, data: State<Data>, armory: State<Armory>, viewer: Viewer, instance_meta_id: u32) -> Result<Json<InstanceViewerMeta>, InstanceFailure> {
+    me.check_access(instance_meta_id, &viewer)?;
     me.get_instance_meta(&mut *db_main, &data, &armory, instance_meta_id).map(Json)
 }
 
 #[openapi]
 #[get("/export/participants/<instance_meta_id>")]
-pub fn get_instance_participants(mut db_main: MainDb, me: State<Instance>, armory: State<Armory>, instance_meta_id: u32) -> Result<Json<Vec<InstanceViewerParticipant>>, InstanceFailure> {
+pub fn get_instance_participants(mut db_main: MainDb, me: State<Instance>, armory: State<Armory>, viewer: Viewer, instance_meta_id: u32) -> Result<Json<Vec<InstanceViewerParticipant>>, InstanceFailure> {
+    me.check_access(instance_meta_id, &viewer)?;
     me.get_instance_participants(&mut *db_main, &armory, instance_meta_id).map(Json)
 }
 
 #[openapi]
 #[get("/export/attempts/<instance_meta_id>")]
-pub fn get_instance_attempts(me: State<Instance>, mut db_main: MainDb, instance_meta_id: u32) -> Result<Json<Vec<InstanceViewerAttempt>>, InstanceFailure> {
+pub fn get_instance_attempts(me: State<Instance>, mut db_main: MainDb, viewer: Viewer, instance_meta_id: u32) -> Result<Json<Vec<InstanceViewerAttempt>>, InstanceFailure> {
+    me.check_access(instance_meta_id, &viewer)?;
     me.get_instance_attempts(&mut (*db_main), instance_meta_id).map(Json)
 }
@@ -0,0 +1,18 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::account::guard::Viewer;
+use crate::modules::armory::dto::CharacterGearAudit;
+use crate::modules::armory::Armory;
+use crate::modules::data::Data;
+use crate::modules::instance::dto::InstanceFailure;
+use crate::modules::instance::tools::{InstanceAccess, InstanceGearAudit};
+use crate::modules::instance::Instance;
+use crate::MainDb;
+
+#[openapi]
+#[get("/gear_audit/<instance_meta_id>")]
+pub fn get_instance_gear_audit(mut db_main: MainDb, me: State<Instance>, armory: State<Armory>, data: State<Data>, viewer: Viewer, instance_meta_id: u32) -> Result<Json<Vec<CharacterGearAudit>>, InstanceFailure> {
+    me.check_access(instance_meta_id, &viewer)?;
+    me.get_instance_gear_audit(&mut *db_main, &armory, &data, instance_meta_id).map(Json)
+}
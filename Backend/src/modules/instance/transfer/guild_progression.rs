@@ -0,0 +1,21 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::account::guard::Viewer;
+use crate::modules::data::Data;
+use crate::modules::instance::dto::{ProgressionRanking, RaidProgression};
+use crate::modules::instance::tools::GuildProgression;
+use crate::modules::instance::Instance;
+use crate::MainDb;
+
+#[openapi]
+#[get("/guild_progression/<guild_id>")]
+pub fn get_guild_progression(mut db_main: MainDb, me: State<Instance>, data: State<Data>, viewer: Viewer, guild_id: u32) -> Json<Vec<RaidProgression>> {
+    Json(me.get_guild_progression(&mut *db_main, &data, &viewer, guild_id))
+}
+
+#[openapi]
+#[get("/guild_progression/by_season/<season>")]
+pub fn get_progression_rankings_by_season(me: State<Instance>, data: State<Data>, season: u8) -> Json<Vec<ProgressionRanking>> {
+    Json(me.get_progression_rankings(&data, season))
+}
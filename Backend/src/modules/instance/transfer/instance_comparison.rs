@@ -0,0 +1,18 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::account::guard::Viewer;
+use crate::modules::data::Data;
+use crate::modules::instance::dto::{InstanceComparison, InstanceComparisonRequest, InstanceFailure};
+use crate::modules::instance::tools::{CompareInstances, InstanceAccess};
+use crate::modules::instance::Instance;
+use crate::MainDb;
+
+#[openapi]
+#[post("/compare", format = "application/json", data = "<request>")]
+pub fn compare_instances(mut db_main: MainDb, me: State<Instance>, data: State<Data>, viewer: Viewer, request: Json<InstanceComparisonRequest>) -> Result<Json<InstanceComparison>, InstanceFailure> {
+    for target in request.targets.iter() {
+        me.check_access(target.instance_meta_id, &viewer)?;
+    }
+    me.compare_instances(&mut *db_main, &data, &request.targets).map(Json)
+}
@@ -0,0 +1,14 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::account::guard::Viewer;
+use crate::modules::data::Data;
+use crate::modules::instance::dto::{InstanceFailure, InstanceSearchFilter, InstanceSearchPage};
+use crate::modules::instance::tools::InstanceSearch;
+use crate::modules::instance::Instance;
+
+#[openapi]
+#[post("/search", format = "application/json", data = "<filter>")]
+pub fn search_instances(me: State<Instance>, data: State<Data>, viewer: Viewer, filter: Json<InstanceSearchFilter>) -> Result<Json<InstanceSearchPage>, InstanceFailure> {
+    me.search_instances(&data, &viewer, filter.into_inner()).map(Json)
+}
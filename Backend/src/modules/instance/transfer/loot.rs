@@ -0,0 +1,29 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::account::guard::Viewer;
+use crate::modules::armory::Armory;
+use crate::modules::data::Data;
+use crate::modules::instance::dto::{InstanceFailure, LootEntry, LootHistoryFilter};
+use crate::modules::instance::tools::{InstanceAccess, LootHistory};
+use crate::modules::instance::Instance;
+use crate::MainDb;
+
+#[openapi]
+#[get("/loot/<instance_meta_id>")]
+pub fn get_instance_loot(mut db_main: MainDb, me: State<Instance>, data: State<Data>, viewer: Viewer, instance_meta_id: u32) -> Result<Json<Vec<LootEntry>>, InstanceFailure> {
+    me.check_access(instance_meta_id, &viewer)?;
+    me.get_instance_loot(&mut *db_main, &data, instance_meta_id).map(Json)
+}
+
+#[openapi]
+#[post("/loot/by_character/<character_id>", format = "application/json", data = "<filter>")]
+pub fn get_character_loot(mut db_main: MainDb, me: State<Instance>, data: State<Data>, viewer: Viewer, character_id: u32, filter: Json<LootHistoryFilter>) -> Json<Vec<LootEntry>> {
+    Json(me.get_character_loot(&mut *db_main, &data, &viewer, character_id, &filter))
+}
+
+#[openapi]
+#[post("/loot/by_guild/<guild_id>", format = "application/json", data = "<filter>")]
+pub fn get_guild_loot(mut db_main: MainDb, me: State<Instance>, data: State<Data>, armory: State<Armory>, viewer: Viewer, guild_id: u32, filter: Json<LootHistoryFilter>) -> Json<Vec<LootEntry>> {
+    Json(me.get_guild_loot(&mut *db_main, &data, &armory, &viewer, guild_id, &filter))
+}
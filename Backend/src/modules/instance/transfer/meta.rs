@@ -5,34 +5,34 @@ use rocket::State;
 use rocket_contrib::json::Json;
 use crate::modules::instance::dto::{InstanceFailure, InstancePrivacy};
 use crate::MainDb;
-use crate::modules::account::guard::CanAdjustLogPrivacy;
+use crate::modules::account::guard::{CanAdjustLogPrivacy, Viewer};
 
 #[openapi]
 #[get("/meta/raids")]
-pub fn export_raids(me: State<Instance>) -> Json<Vec<InstanceMeta>> {
-    Json(me.export_meta(0))
+pub fn export_raids(me: State<Instance>, viewer: Viewer) -> Json<Vec<InstanceMeta>> {
+    Json(me.export_meta(0, &viewer))
 }
 
 #[openapi]
 #[get("/meta/rated_arenas")]
-pub fn export_rated_arenas(me: State<Instance>) -> Json<Vec<InstanceMeta>> {
-    Json(me.export_meta(1))
+pub fn export_rated_arenas(me: State<Instance>, viewer: Viewer) -> Json<Vec<InstanceMeta>> {
+    Json(me.export_meta(1, &viewer))
 }
 
 #[openapi]
 #[get("/meta/skirmishes")]
-pub fn export_skirmishes(me: State<Instance>) -> Json<Vec<InstanceMeta>> {
-    Json(me.export_meta(2))
+pub fn export_skirmishes(me: State<Instance>, viewer: Viewer) -> Json<Vec<InstanceMeta>> {
+    Json(me.export_meta(2, &viewer))
 }
 
 #[openapi]
 #[get("/meta/battlegrounds")]
-pub fn export_battlegrounds(me: State<Instance>) -> Json<Vec<InstanceMeta>> {
-    Json(me.export_meta(3))
+pub fn export_battlegrounds(me: State<Instance>, viewer: Viewer) -> Json<Vec<InstanceMeta>> {
+    Json(me.export_meta(3, &viewer))
 }
 
 #[openapi]
 #[post("/meta/update_privacy", format = "application/json", data = "<privacy>")]
 pub fn update_privacy(mut db_main: MainDb, me: State<Instance>, privacy: Json<InstancePrivacy>, auth: CanAdjustLogPrivacy) -> Result<(), InstanceFailure> {
-    me.update_privacy(&mut (*db_main), privacy.into_inner(), auth.0)
+    me.update_privacy(&mut (*db_main), privacy.into_inner(), auth.0, &auth.1)
 }
\ No newline at end of file
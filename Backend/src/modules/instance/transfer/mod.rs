@@ -1,7 +1,16 @@
+pub mod attendance;
+pub mod character_merge;
 pub mod delete;
 pub mod export;
+pub mod gear_audit;
+pub mod guild_progression;
+pub mod instance_comparison;
+pub mod instance_search;
+pub mod loot;
 pub mod meta;
 pub mod meta_search;
+pub mod pvp;
 pub mod ranking;
+pub mod season;
 pub mod speed_run;
 pub mod speed_kill;
\ No newline at end of file
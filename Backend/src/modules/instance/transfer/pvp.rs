@@ -0,0 +1,33 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::account::guard::Viewer;
+use crate::modules::data::Data;
+use crate::modules::instance::dto::{ArenaTeamMatch, BattlegroundWinRate, CompositionWinRate, InstanceFailure, PvpMatchSummary};
+use crate::modules::instance::tools::{InstanceAccess, PvpStatistics};
+use crate::modules::instance::Instance;
+
+#[openapi]
+#[get("/pvp/arena_team/<team_id>")]
+pub fn get_arena_team_history(me: State<Instance>, viewer: Viewer, team_id: u32) -> Json<Vec<ArenaTeamMatch>> {
+    Json(me.get_arena_team_history(&viewer, team_id))
+}
+
+#[openapi]
+#[get("/pvp/compositions/<server_id>")]
+pub fn get_composition_win_rates(me: State<Instance>, viewer: Viewer, server_id: u32) -> Json<Vec<CompositionWinRate>> {
+    Json(me.get_composition_win_rates(&viewer, server_id))
+}
+
+#[openapi]
+#[get("/pvp/battleground/<character_id>")]
+pub fn get_battleground_win_rate(me: State<Instance>, data: State<Data>, viewer: Viewer, character_id: u32) -> Json<BattlegroundWinRate> {
+    Json(me.get_battleground_win_rate(&data, &viewer, character_id))
+}
+
+#[openapi]
+#[get("/pvp/summary/<instance_meta_id>")]
+pub fn get_pvp_match_summary(me: State<Instance>, viewer: Viewer, instance_meta_id: u32) -> Result<Json<PvpMatchSummary>, InstanceFailure> {
+    me.check_access(instance_meta_id, &viewer)?;
+    me.get_pvp_match_summary(instance_meta_id).map(Json)
+}
@@ -82,6 +82,6 @@ pub fn get_instance_ranking_tps_by_server_and_season(me: State<Instance>, armory
 
 #[openapi]
 #[delete("/ranking/unrank", data = "<data>")]
-pub fn unrank_attempt(mut db_main: MainDb, me: State<Instance>, data: Json<u32>, _auth: IsModerator) -> Result<(), InstanceFailure> {
-    me.unrank_attempt(&mut *db_main, data.into_inner())
+pub fn unrank_attempt(mut db_main: MainDb, me: State<Instance>, data: Json<u32>, auth: IsModerator) -> Result<(), InstanceFailure> {
+    me.unrank_attempt(&mut *db_main, data.into_inner(), auth.0)
 }
\ No newline at end of file
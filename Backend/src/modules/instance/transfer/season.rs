@@ -0,0 +1,39 @@
+use rocket::State;
+use rocket_contrib::json::Json;
+
+use crate::modules::account::guard::{Authenticate, IsModerator, ServerOwner};
+use crate::modules::instance::domain_value::Season;
+use crate::modules::instance::dto::{InstanceFailure, SeasonDto};
+use crate::modules::instance::tools::Seasons;
+use crate::modules::instance::Instance;
+use crate::MainDb;
+
+#[openapi]
+#[get("/season")]
+pub fn get_seasons(me: State<Instance>) -> Json<Vec<Season>> {
+    Json(me.get_seasons(None))
+}
+
+#[openapi]
+#[get("/season/by_server/<server_id>")]
+pub fn get_seasons_by_server(me: State<Instance>, server_id: u32) -> Json<Vec<Season>> {
+    Json(me.get_seasons(Some(server_id)))
+}
+
+#[openapi]
+#[post("/season", format = "application/json", data = "<season>")]
+pub fn create_season(mut db_main: MainDb, me: State<Instance>, _auth: Authenticate, moderator: Option<IsModerator>, owner: Option<ServerOwner>, season: Json<SeasonDto>) -> Result<Json<Season>, InstanceFailure> {
+    me.create_season(&mut *db_main, season.into_inner(), moderator.is_some(), owner.map(|owner| owner.0)).map(Json)
+}
+
+#[openapi]
+#[post("/season/<season_id>", format = "application/json", data = "<season>")]
+pub fn update_season(mut db_main: MainDb, me: State<Instance>, _auth: Authenticate, moderator: Option<IsModerator>, owner: Option<ServerOwner>, season_id: u32, season: Json<SeasonDto>) -> Result<Json<Season>, InstanceFailure> {
+    me.update_season(&mut *db_main, season_id, season.into_inner(), moderator.is_some(), owner.map(|owner| owner.0)).map(Json)
+}
+
+#[openapi]
+#[delete("/season/<season_id>")]
+pub fn delete_season(mut db_main: MainDb, me: State<Instance>, _auth: Authenticate, moderator: Option<IsModerator>, owner: Option<ServerOwner>, season_id: u32) -> Result<(), InstanceFailure> {
+    me.delete_season(&mut *db_main, season_id, moderator.is_some(), owner.map(|owner| owner.0))
+}
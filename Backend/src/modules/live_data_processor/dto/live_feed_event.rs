@@ -0,0 +1,34 @@
+use crate::modules::live_data_processor::domain_value::Unit;
+
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq)]
+pub enum LiveFeedEvent {
+    Attempt {
+        encounter_id: u32,
+        start_ts: u64,
+        end_ts: u64,
+        is_kill: bool,
+    },
+    Death {
+        timestamp: u64,
+        character_id: u32,
+        murder: Option<Unit>,
+    },
+    Meters {
+        encounter_id: u32,
+        timestamp: u64,
+        // (character_id, amount), highest first
+        damage: Vec<(u32, u32)>,
+        heal: Vec<(u32, u32)>,
+        threat: Vec<(u32, i32)>,
+    },
+}
+
+impl LiveFeedEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LiveFeedEvent::Attempt { .. } => "attempt",
+            LiveFeedEvent::Death { .. } => "death",
+            LiveFeedEvent::Meters { .. } => "meters",
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use crate::modules::live_data_processor::dto::LiveFeedEvent;
+
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+pub struct LiveFeedPage {
+    // Poll with it next time
+    pub cursor: u64,
+    pub events: Vec<LiveFeedEvent>,
+}
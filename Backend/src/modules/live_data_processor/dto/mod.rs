@@ -14,9 +14,13 @@ pub use self::instance_start_rated_arena::InstanceStartRatedArena;
 pub use self::instance_unrated_arena::InstanceUnratedArena;
 pub use self::interrupt::Interrupt;
 pub use self::live_data_processor_failure::LiveDataProcessorFailure;
+pub use self::live_feed_event::LiveFeedEvent;
+pub use self::live_feed_page::LiveFeedPage;
 pub use self::loot::Loot;
 pub use self::message::Message;
 pub use self::message_type::*;
+pub use self::package::Package;
+pub use self::package_acknowledgement::PackageAcknowledgement;
 pub use self::position::Position;
 pub use self::power::Power;
 pub use self::spell_cast::SpellCast;
@@ -41,9 +45,13 @@ mod instance_start_rated_arena;
 mod instance_unrated_arena;
 mod interrupt;
 mod live_data_processor_failure;
+mod live_feed_event;
+mod live_feed_page;
 mod loot;
 mod message;
 mod message_type;
+mod package;
+mod package_acknowledgement;
 mod position;
 mod power;
 mod spell_cast;
@@ -0,0 +1,5 @@
+pub struct Package {
+    pub api_version: u8,
+    pub sequence: Option<u32>,
+    pub messages: Vec<Vec<u8>>,
+}
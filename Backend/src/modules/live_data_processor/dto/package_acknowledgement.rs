@@ -0,0 +1,7 @@
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+pub struct PackageAcknowledgement {
+    pub sequence: Option<u32>,
+    pub accepted_messages: u32,
+    pub rejected_messages: u32,
+    pub lost_packages: u32,
+}
@@ -0,0 +1,145 @@
+use language::{domain_value::Language, material::Dictionary, tools::Register};
+
+pub fn init(dictionary: &Dictionary) {
+    dictionary.register("vanilla.possessive", Language::English, r"^[^\s]+\s?'s\s");
+
+    dictionary.register(
+        "vanilla.grammar.damage_hit_or_crit",
+        Language::English,
+        r"(?P<attacker>.+[^\s]) (?P<kind>cr|h)its (?P<victim>.+[^\s]) for (?P<amount>\d+)\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_hit_or_crit_school",
+        Language::English,
+        r"(?P<attacker>.+[^\s]) (?P<kind>cr|h)its (?P<victim>.+[^\s]) for (?P<amount>\d+) (?P<school>[a-zA-Z]+) damage\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register("vanilla.grammar.damage_miss", Language::English, r"(?P<attacker>.+[^\s]) misses (?P<victim>.+[^\s])\.");
+    dictionary.register(
+        "vanilla.grammar.damage_avoid",
+        Language::English,
+        r"(?P<attacker>.+[^\s]) attacks\. (?P<victim>.+[^\s]) (?P<avoid>blocks|parries|evades|dodges|deflects)\.",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_absorb_resist",
+        Language::English,
+        r"(?P<attacker>.+[^\s]) attacks\. (?P<victim>.+[^\s]) (?P<avoid>absorbs|resists) all the damage\.",
+    );
+    dictionary.register("vanilla.grammar.damage_immune", Language::English, r"(?P<attacker>.+[^\s]) attacks but (?P<victim>.+[^\s]) is immune\.");
+
+    dictionary.register(
+        "vanilla.grammar.damage_spell_hit_or_crit",
+        Language::English,
+        r"(?P<attacker>.+[^\s])\s's (?P<spell>.+[^\s]) (?P<kind>cr|h)its (?P<victim>.+[^\s]) for (?P<amount>\d+)\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_spell_hit_or_crit_school",
+        Language::English,
+        r"(?P<attacker>.+[^\s])\s's (?P<spell>.+[^\s]) (?P<kind>cr|h)its (?P<victim>.+[^\s]) for (?P<amount>\d+) (?P<school>[a-zA-Z]+) damage\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_periodic",
+        Language::English,
+        r"(?P<victim>.+[^\s]) suffers (?P<amount>\d+) (?P<school>[a-zA-Z]+) damage from (?P<attacker>.+[^\s])\s's (?P<spell>.+[^\s])\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_spell_split",
+        Language::English,
+        r"(?P<attacker>.+[^\s])\s's (?P<spell>.+[^\s]) causes (?P<victim>.+[^\s]) (?P<amount>\d+) damage\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register("vanilla.grammar.damage_spell_miss", Language::English, r"(?P<attacker>.+[^\s])\s's (?P<spell>.+[^\s]) misse(?:s|d) (?P<victim>.+[^\s])\.");
+    dictionary.register(
+        "vanilla.grammar.damage_spell_avoid",
+        Language::English,
+        r"(?P<attacker>.+[^\s])\s's (?P<spell>.+[^\s]) was (?P<avoid>blocked|parried|evaded|dodged|resisted|deflected) by (?P<victim>.+[^\s])\.",
+    );
+    dictionary.register("vanilla.grammar.damage_spell_absorb", Language::English, r"(?P<attacker>.+[^\s])\s's (?P<spell>.+[^\s]) is absorbed by (?P<victim>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_spell_absorb_self", Language::English, r"(?P<victim>.+[^\s]) absorbs (?P<attacker>.+[^\s])\s's (?P<spell>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_reflect", Language::English, r"(?P<attacker>.+[^\s])\s's (?P<spell>.+[^\s]) is reflected back by (?P<victim>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_proc_resist", Language::English, r"(?P<victim>.+[^\s]) resists (?P<attacker>.+[^\s])\s's (?P<spell>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_spell_immune", Language::English, r"(?P<attacker>.+[^\s])\s's (?P<spell>.+[^\s]) fails\. (?P<victim>.+[^\s]) is immune\.");
+    dictionary.register(
+        "vanilla.grammar.damage_shield",
+        Language::English,
+        r"(?P<attacker>.+[^\s]) reflects (?P<amount>\d+) (?P<school>[a-zA-Z]+) damage to (?P<victim>.+[^\s])\.",
+    );
+    dictionary.register("vanilla.grammar.spell_cast_attempt", Language::English, r"(?P<caster>.+[^\s]) begins to cast (?P<spell>.+[^\s])\.");
+
+    dictionary.register("vanilla.grammar.heal_hit", Language::English, r"(?P<caster>.+[^\s])\s's (?P<spell>.+[^\s]) heals (?P<target>.+[^\s]) for (?P<amount>\d+)\.");
+    dictionary.register(
+        "vanilla.grammar.heal_crit",
+        Language::English,
+        r"(?P<caster>.+[^\s])\s's (?P<spell>.+[^\s]) critically heals (?P<target>.+[^\s]) for (?P<amount>\d+)\.",
+    );
+    dictionary.register(
+        "vanilla.grammar.gain",
+        Language::English,
+        r"(?P<target>.+[^\s]) gains (?P<amount>\d+) (?P<power>Health|health|Mana|Rage|Energy|Happiness|Focus) from (?P<caster>.+[^\s])\s's (?P<spell>.+[^\s])\.",
+    );
+
+    dictionary.register("vanilla.grammar.aura_gain", Language::English, r"(?P<target>.+[^\s]) (?:is afflicted by|gains) (?P<spell>.+[^\s]) \((?P<stacks>\d+)\)\.");
+    dictionary.register("vanilla.grammar.aura_fade", Language::English, r"(?P<spell>.+[^\s]) fades from (?P<target>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.aura_dispel", Language::English, r"(?P<target>.+[^\s])\s's (?P<spell>.+[^\s]) is removed\.");
+    dictionary.register("vanilla.grammar.aura_interrupt", Language::English, r"(?P<caster>.+[^\s]) interrupts (?P<target>.+[^\s])\s's (?P<spell>.+[^\s])\.");
+
+    dictionary.register(
+        "vanilla.grammar.spell_cast_perform_durability",
+        Language::English,
+        r"(?P<caster>.+[^\s]) (?:casts|performs) (?P<spell>.+[^\s]) on (?P<target>.+[^\s]): (?P<durability>.+)\.",
+    );
+    dictionary.register("vanilla.grammar.spell_cast_perform", Language::English, r"(?P<caster>.+[^\s]) (?:casts|performs) (?P<spell>.+[^\s]) on (?P<target>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.spell_cast_perform_unknown", Language::English, r"(?P<caster>.+[^\s]) (?:casts|performs) (?P<spell>.+[^\s])\.");
+
+    dictionary.register("vanilla.grammar.unit_die_destroyed", Language::English, r"(?P<victim>.+[^\s]) (?:dies|is destroyed)\.");
+    dictionary.register("vanilla.grammar.unit_slay", Language::English, r"(?P<victim>.+[^\s]) is slain by (?P<attacker>.+[^\s])(?:!|\.)");
+
+    dictionary.register(
+        "vanilla.grammar.bug_damage_spell_hit_or_crit",
+        Language::English,
+        r"(?P<attacker>.+[^\s])\s's (?P<kind>cr|h)its (?P<victim>.+[^\s]) for (?P<amount>\d+)\.\s?(?P<trailer>.*)",
+    );
+
+    dictionary.register("vanilla.keyword.crit", Language::English, "cr");
+    dictionary.register("vanilla.keyword.hit", Language::English, "h");
+
+    dictionary.register("vanilla.keyword.physical", Language::English, "Physical");
+    dictionary.register("vanilla.keyword.arcane", Language::English, "Arcane");
+    dictionary.register("vanilla.keyword.fire", Language::English, "Fire");
+    dictionary.register("vanilla.keyword.frost", Language::English, "Frost");
+    dictionary.register("vanilla.keyword.shadow", Language::English, "Shadow");
+    dictionary.register("vanilla.keyword.nature", Language::English, "Nature");
+    dictionary.register("vanilla.keyword.holy", Language::English, "Holy");
+
+    dictionary.register("vanilla.keyword.block", Language::English, "blocks|blocked");
+    dictionary.register("vanilla.keyword.parry", Language::English, "parries|parried");
+    dictionary.register("vanilla.keyword.evade", Language::English, "evades|evaded");
+    dictionary.register("vanilla.keyword.dodge", Language::English, "dodges|dodged");
+    dictionary.register("vanilla.keyword.deflect", Language::English, "deflects|deflected");
+    dictionary.register("vanilla.keyword.absorb", Language::English, "absorbs|absorbed");
+    dictionary.register("vanilla.keyword.resist", Language::English, "resists|resisted");
+
+    dictionary.register("vanilla.keyword.glancing", Language::English, "glancing");
+    dictionary.register("vanilla.keyword.crushing", Language::English, "crushing");
+    dictionary.register("vanilla.keyword.vulnerability", Language::English, "vulnerability");
+
+    dictionary.register("vanilla.keyword.health", Language::English, "Health");
+    dictionary.register("vanilla.keyword.unknown", Language::English, "Unknown");
+
+    dictionary.register("vanilla.keyword.warrior", Language::English, "warrior");
+    dictionary.register("vanilla.keyword.paladin", Language::English, "paladin");
+    dictionary.register("vanilla.keyword.hunter", Language::English, "hunter");
+    dictionary.register("vanilla.keyword.rogue", Language::English, "rogue");
+    dictionary.register("vanilla.keyword.priest", Language::English, "priest");
+    dictionary.register("vanilla.keyword.shaman", Language::English, "shaman");
+    dictionary.register("vanilla.keyword.mage", Language::English, "mage");
+    dictionary.register("vanilla.keyword.warlock", Language::English, "warlock");
+    dictionary.register("vanilla.keyword.druid", Language::English, "druid");
+
+    dictionary.register("vanilla.keyword.human", Language::English, "human");
+    dictionary.register("vanilla.keyword.orc", Language::English, "orc");
+    dictionary.register("vanilla.keyword.dwarf", Language::English, "dwarf");
+    dictionary.register("vanilla.keyword.night_elf", Language::English, "night elf|nightelf");
+    dictionary.register("vanilla.keyword.undead", Language::English, "undead|scourge");
+    dictionary.register("vanilla.keyword.tauren", Language::English, "tauren");
+    dictionary.register("vanilla.keyword.gnome", Language::English, "gnome");
+    dictionary.register("vanilla.keyword.troll", Language::English, "troll");
+}
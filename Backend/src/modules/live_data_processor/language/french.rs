@@ -0,0 +1,145 @@
+use language::{domain_value::Language, material::Dictionary, tools::Register};
+
+pub fn init(dictionary: &Dictionary) {
+    dictionary.register("vanilla.possessive", Language::French, r"\sde\s[^\s]+$");
+
+    dictionary.register(
+        "vanilla.grammar.damage_hit_or_crit",
+        Language::French,
+        "(?P<attacker>.+[^\\s]) inflige un (?P<kind>coup critique) à (?P<victim>.+[^\\s]) \\((?P<amount>\\d+) points de dégâts\\)\\.\\s?(?P<trailer>.*)\n(?P<attacker>.+[^\\s]) (?P<kind>touche) (?P<victim>.+[^\\s]) et inflige (?P<amount>\\d+) points de dégâts\\.\\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_hit_or_crit_school",
+        Language::French,
+        "(?P<attacker>.+[^\\s]) inflige un (?P<kind>coup critique) à (?P<victim>.+[^\\s]) \\((?P<amount>\\d+) points de dégâts \\((?P<school>\\w+)\\)\\)\\.\\s?(?P<trailer>.*)\n(?P<attacker>.+[^\\s]) (?P<kind>touche) (?P<victim>.+[^\\s]) et inflige (?P<amount>\\d+) points de dégâts \\((?P<school>\\w+)\\)\\.\\s?(?P<trailer>.*)",
+    );
+    dictionary.register("vanilla.grammar.damage_miss", Language::French, r"(?P<attacker>.+[^\s]) rate (?P<victim>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_avoid", Language::French, r"(?P<attacker>.+[^\s]) attaque\. (?P<victim>.+[^\s]) (?P<avoid>bloque|pare|évite|esquive|dévie)\.");
+    dictionary.register(
+        "vanilla.grammar.damage_absorb_resist",
+        Language::French,
+        r"(?P<attacker>.+[^\s]) attaque\. (?P<victim>.+[^\s]) (?P<avoid>absorbe|résiste à) tous les dégâts\.",
+    );
+    dictionary.register("vanilla.grammar.damage_immune", Language::French, r"(?P<attacker>.+[^\s]) attaque mais (?P<victim>.+[^\s]) est insensible\.");
+
+    dictionary.register(
+        "vanilla.grammar.damage_spell_hit_or_crit",
+        Language::French,
+        "(?P<spell>.+[^\\s]) de (?P<attacker>.+[^\\s]) inflige un (?P<kind>coup critique) à (?P<victim>.+[^\\s]) \\((?P<amount>\\d+) points de dégâts\\)\\.\\s?(?P<trailer>.*)\n(?P<spell>.+[^\\s]) de (?P<attacker>.+[^\\s]) (?P<kind>touche) (?P<victim>.+[^\\s]) et inflige (?P<amount>\\d+) points de dégâts\\.\\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_spell_hit_or_crit_school",
+        Language::French,
+        "(?P<spell>.+[^\\s]) de (?P<attacker>.+[^\\s]) inflige un (?P<kind>coup critique) à (?P<victim>.+[^\\s]) \\((?P<amount>\\d+) points de dégâts \\((?P<school>\\w+)\\)\\)\\.\\s?(?P<trailer>.*)\n(?P<spell>.+[^\\s]) de (?P<attacker>.+[^\\s]) (?P<kind>touche) (?P<victim>.+[^\\s]) et inflige (?P<amount>\\d+) points de dégâts \\((?P<school>\\w+)\\)\\.\\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_periodic",
+        Language::French,
+        r"(?P<victim>.+[^\s]) subit (?P<amount>\d+) points de dégâts \((?P<school>\w+)\) infligés par (?P<spell>.+[^\s]) de (?P<attacker>.+[^\s])\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_spell_split",
+        Language::French,
+        r"(?P<spell>.+[^\s]) de (?P<attacker>.+[^\s]) inflige (?P<amount>\d+) points de dégâts à (?P<victim>.+[^\s])\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register("vanilla.grammar.damage_spell_miss", Language::French, r"(?P<spell>.+[^\s]) de (?P<attacker>.+[^\s]) rate (?P<victim>.+[^\s])\.");
+    dictionary.register(
+        "vanilla.grammar.damage_spell_avoid",
+        Language::French,
+        r"(?P<spell>.+[^\s]) de (?P<attacker>.+[^\s]) a été (?P<avoid>bloqué|paré|évité|esquivé|résisté|dévié) par (?P<victim>.+[^\s])\.",
+    );
+    dictionary.register("vanilla.grammar.damage_spell_absorb", Language::French, r"(?P<spell>.+[^\s]) de (?P<attacker>.+[^\s]) est absorbé par (?P<victim>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_spell_absorb_self", Language::French, r"(?P<victim>.+[^\s]) absorbe (?P<spell>.+[^\s]) de (?P<attacker>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_reflect", Language::French, r"(?P<spell>.+[^\s]) de (?P<attacker>.+[^\s]) est renvoyé par (?P<victim>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_proc_resist", Language::French, r"(?P<victim>.+[^\s]) résiste à (?P<spell>.+[^\s]) de (?P<attacker>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_spell_immune", Language::French, r"(?P<spell>.+[^\s]) de (?P<attacker>.+[^\s]) échoue\. (?P<victim>.+[^\s]) est insensible\.");
+    dictionary.register(
+        "vanilla.grammar.damage_shield",
+        Language::French,
+        r"(?P<attacker>.+[^\s]) renvoie (?P<amount>\d+) points de dégâts \((?P<school>\w+)\) à (?P<victim>.+[^\s])\.",
+    );
+    dictionary.register("vanilla.grammar.spell_cast_attempt", Language::French, r"(?P<caster>.+[^\s]) commence à lancer (?P<spell>.+[^\s])\.");
+
+    dictionary.register(
+        "vanilla.grammar.heal_hit",
+        Language::French,
+        r"(?P<spell>.+[^\s]) de (?P<caster>.+[^\s]) guérit (?P<target>.+[^\s]) de (?P<amount>\d+) points de vie\.",
+    );
+    dictionary.register(
+        "vanilla.grammar.heal_crit",
+        Language::French,
+        r"(?P<spell>.+[^\s]) de (?P<caster>.+[^\s]) soigne (?P<target>.+[^\s]) avec un effet critique et lui rend (?P<amount>\d+) points de vie\.",
+    );
+    dictionary.register(
+        "vanilla.grammar.gain",
+        Language::French,
+        r"(?P<target>.+[^\s]) gagne (?P<amount>\d+) points de (?P<power>vie|mana|rage|énergie|bonheur|focalisation) grâce à (?P<spell>.+[^\s]) de (?P<caster>.+[^\s])\.",
+    );
+
+    dictionary.register("vanilla.grammar.aura_gain", Language::French, r"(?P<target>.+[^\s]) (?:subit les effets de|gagne) (?P<spell>.+[^\s]) \((?P<stacks>\d+)\)\.");
+    dictionary.register("vanilla.grammar.aura_fade", Language::French, r"(?P<spell>.+[^\s]) sur (?P<target>.+[^\s]) vient de se dissiper\.");
+    dictionary.register("vanilla.grammar.aura_dispel", Language::French, r"(?P<spell>.+[^\s]) de (?P<target>.+[^\s]) est dissipé\.");
+    dictionary.register("vanilla.grammar.aura_interrupt", Language::French, r"(?P<caster>.+[^\s]) interrompt (?P<spell>.+[^\s]) de (?P<target>.+[^\s])\.");
+
+    dictionary.register(
+        "vanilla.grammar.spell_cast_perform_durability",
+        Language::French,
+        r"(?P<caster>.+[^\s]) (?:lance|exécute) (?P<spell>.+[^\s]) sur (?P<target>.+[^\s]) : (?P<durability>.+)\.",
+    );
+    dictionary.register("vanilla.grammar.spell_cast_perform", Language::French, r"(?P<caster>.+[^\s]) (?:lance|exécute) (?P<spell>.+[^\s]) sur (?P<target>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.spell_cast_perform_unknown", Language::French, r"(?P<caster>.+[^\s]) (?:lance|exécute) (?P<spell>.+[^\s])\.");
+
+    dictionary.register("vanilla.grammar.unit_die_destroyed", Language::French, r"(?P<victim>.+[^\s]) (?:meurt|est détruit)\.");
+    dictionary.register("vanilla.grammar.unit_slay", Language::French, r"(?P<victim>.+[^\s]) a été tué par (?P<attacker>.+[^\s])\s?(?:!|\.)");
+
+    dictionary.register(
+        "vanilla.grammar.bug_damage_spell_hit_or_crit",
+        Language::French,
+        r"de (?P<attacker>.+[^\s]) (?P<kind>touche) (?P<victim>.+[^\s]) et inflige (?P<amount>\d+) points de dégâts\.\s?(?P<trailer>.*)",
+    );
+
+    dictionary.register("vanilla.keyword.crit", Language::French, "coup critique");
+    dictionary.register("vanilla.keyword.hit", Language::French, "touche");
+
+    dictionary.register("vanilla.keyword.physical", Language::French, "Physique");
+    dictionary.register("vanilla.keyword.arcane", Language::French, "Arcanes");
+    dictionary.register("vanilla.keyword.fire", Language::French, "Feu");
+    dictionary.register("vanilla.keyword.frost", Language::French, "Givre");
+    dictionary.register("vanilla.keyword.shadow", Language::French, "Ombre");
+    dictionary.register("vanilla.keyword.nature", Language::French, "Nature");
+    dictionary.register("vanilla.keyword.holy", Language::French, "Sacré");
+
+    dictionary.register("vanilla.keyword.block", Language::French, "bloque|bloqué");
+    dictionary.register("vanilla.keyword.parry", Language::French, "pare|paré");
+    dictionary.register("vanilla.keyword.evade", Language::French, "évite|évité");
+    dictionary.register("vanilla.keyword.dodge", Language::French, "esquive|esquivé");
+    dictionary.register("vanilla.keyword.deflect", Language::French, "dévie|dévié");
+    dictionary.register("vanilla.keyword.absorb", Language::French, "absorbe|absorbé|absorbés");
+    dictionary.register("vanilla.keyword.resist", Language::French, "résiste à|résisté|résistés");
+
+    dictionary.register("vanilla.keyword.glancing", Language::French, "érafle");
+    dictionary.register("vanilla.keyword.crushing", Language::French, "écrasement");
+    dictionary.register("vanilla.keyword.vulnerability", Language::French, "vulnérabilité");
+
+    dictionary.register("vanilla.keyword.health", Language::French, "vie");
+    dictionary.register("vanilla.keyword.unknown", Language::French, "Inconnu");
+
+    dictionary.register("vanilla.keyword.warrior", Language::French, "guerrier|guerrière");
+    dictionary.register("vanilla.keyword.paladin", Language::French, "paladin");
+    dictionary.register("vanilla.keyword.hunter", Language::French, "chasseur|chasseresse");
+    dictionary.register("vanilla.keyword.rogue", Language::French, "voleur|voleuse");
+    dictionary.register("vanilla.keyword.priest", Language::French, "prêtre|prêtresse");
+    dictionary.register("vanilla.keyword.shaman", Language::French, "chaman|chamane");
+    dictionary.register("vanilla.keyword.mage", Language::French, "mage");
+    dictionary.register("vanilla.keyword.warlock", Language::French, "démoniste");
+    dictionary.register("vanilla.keyword.druid", Language::French, "druide|druidesse");
+
+    dictionary.register("vanilla.keyword.human", Language::French, "humain|humaine");
+    dictionary.register("vanilla.keyword.orc", Language::French, "orc|orque");
+    dictionary.register("vanilla.keyword.dwarf", Language::French, "nain|naine");
+    dictionary.register("vanilla.keyword.night_elf", Language::French, "elfe de la nuit");
+    dictionary.register("vanilla.keyword.undead", Language::French, "mort-vivant|morte-vivante");
+    dictionary.register("vanilla.keyword.tauren", Language::French, "tauren");
+    dictionary.register("vanilla.keyword.gnome", Language::French, "gnome");
+    dictionary.register("vanilla.keyword.troll", Language::French, "troll|trollesse");
+}
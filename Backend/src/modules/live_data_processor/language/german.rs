@@ -0,0 +1,149 @@
+use language::{domain_value::Language, material::Dictionary, tools::Register};
+
+pub fn init(dictionary: &Dictionary) {
+    dictionary.register("vanilla.possessive", Language::German, r"^[^\s]+s\s");
+
+    dictionary.register(
+        "vanilla.grammar.damage_hit_or_crit",
+        Language::German,
+        "(?P<attacker>.+[^\\s]) trifft (?P<victim>.+[^\\s]) (?P<kind>kritisch) für (?P<amount>\\d+) Schaden\\.\\s?(?P<trailer>.*)\n(?P<attacker>.+[^\\s]) (?P<kind>trifft) (?P<victim>.+[^\\s]) für (?P<amount>\\d+) Schaden\\.\\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_hit_or_crit_school",
+        Language::German,
+        "(?P<attacker>.+[^\\s]) trifft (?P<victim>.+[^\\s]) (?P<kind>kritisch) für (?P<amount>\\d+) (?P<school>\\w+)schaden\\.\\s?(?P<trailer>.*)\n(?P<attacker>.+[^\\s]) (?P<kind>trifft) (?P<victim>.+[^\\s]) für (?P<amount>\\d+) (?P<school>\\w+)schaden\\.\\s?(?P<trailer>.*)",
+    );
+    dictionary.register("vanilla.grammar.damage_miss", Language::German, r"(?P<attacker>.+[^\s]) verfehlt (?P<victim>.+[^\s])\.");
+    dictionary.register(
+        "vanilla.grammar.damage_avoid",
+        Language::German,
+        r"(?P<attacker>.+[^\s]) greift an\. (?P<victim>.+[^\s]) (?P<avoid>blockt ab|pariert|entkommt|weicht aus|lenkt ab)\.",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_absorb_resist",
+        Language::German,
+        r"(?P<attacker>.+[^\s]) greift an\. (?P<victim>.+[^\s]) (?P<avoid>absorbiert|widersteht) allen? Schaden\.",
+    );
+    dictionary.register("vanilla.grammar.damage_immune", Language::German, r"(?P<attacker>.+[^\s]) greift an, aber (?P<victim>.+[^\s]) ist immun\.");
+
+    dictionary.register(
+        "vanilla.grammar.damage_spell_hit_or_crit",
+        Language::German,
+        "(?P<attacker>.+[^\\s])s (?P<spell>.+[^\\s]) trifft (?P<victim>.+[^\\s]) (?P<kind>kritisch) für (?P<amount>\\d+) Schaden\\.\\s?(?P<trailer>.*)\n(?P<attacker>.+[^\\s])s (?P<spell>.+[^\\s]) (?P<kind>trifft) (?P<victim>.+[^\\s]) für (?P<amount>\\d+) Schaden\\.\\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_spell_hit_or_crit_school",
+        Language::German,
+        "(?P<attacker>.+[^\\s])s (?P<spell>.+[^\\s]) trifft (?P<victim>.+[^\\s]) (?P<kind>kritisch) für (?P<amount>\\d+) (?P<school>\\w+)schaden\\.\\s?(?P<trailer>.*)\n(?P<attacker>.+[^\\s])s (?P<spell>.+[^\\s]) (?P<kind>trifft) (?P<victim>.+[^\\s]) für (?P<amount>\\d+) (?P<school>\\w+)schaden\\.\\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_periodic",
+        Language::German,
+        r"(?P<victim>.+[^\s]) erleidet (?P<amount>\d+) (?P<school>\w+)schaden von (?P<attacker>.+[^\s]) \(durch (?P<spell>.+[^\s])\)\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_spell_split",
+        Language::German,
+        r"(?P<attacker>.+[^\s])s (?P<spell>.+[^\s]) verursacht bei (?P<victim>.+[^\s]) (?P<amount>\d+) Schaden\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register("vanilla.grammar.damage_spell_miss", Language::German, r"(?P<attacker>.+[^\s])s (?P<spell>.+[^\s]) verfehlt (?P<victim>.+[^\s])\.");
+    dictionary.register(
+        "vanilla.grammar.damage_spell_avoid",
+        Language::German,
+        r"(?P<attacker>.+[^\s])s (?P<spell>.+[^\s]) wurde von (?P<victim>.+[^\s]) (?P<avoid>geblockt|pariert|entkommen|ausgewichen|widerstanden|abgelenkt)\.",
+    );
+    dictionary.register("vanilla.grammar.damage_spell_absorb", Language::German, r"(?P<attacker>.+[^\s])s (?P<spell>.+[^\s]) wird von (?P<victim>.+[^\s]) absorbiert\.");
+    dictionary.register("vanilla.grammar.damage_spell_absorb_self", Language::German, r"(?P<victim>.+[^\s]) absorbiert (?P<attacker>.+[^\s])s (?P<spell>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_reflect", Language::German, r"(?P<attacker>.+[^\s])s (?P<spell>.+[^\s]) wird von (?P<victim>.+[^\s]) reflektiert\.");
+    dictionary.register("vanilla.grammar.damage_proc_resist", Language::German, r"(?P<victim>.+[^\s]) widersteht (?P<attacker>.+[^\s])s (?P<spell>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_spell_immune", Language::German, r"(?P<attacker>.+[^\s])s (?P<spell>.+[^\s]) schlägt fehl\. (?P<victim>.+[^\s]) ist immun\.");
+    dictionary.register(
+        "vanilla.grammar.damage_shield",
+        Language::German,
+        r"(?P<attacker>.+[^\s]) reflektiert (?P<amount>\d+) (?P<school>\w+)schaden auf (?P<victim>.+[^\s])\.",
+    );
+    dictionary.register("vanilla.grammar.spell_cast_attempt", Language::German, r"(?P<caster>.+[^\s]) beginnt (?P<spell>.+[^\s]) zu (?:wirken|benutzen)\.");
+
+    dictionary.register("vanilla.grammar.heal_hit", Language::German, r"(?P<caster>.+[^\s])s (?P<spell>.+[^\s]) heilt (?P<target>.+[^\s]) um (?P<amount>\d+) Punkte\.");
+    dictionary.register(
+        "vanilla.grammar.heal_crit",
+        Language::German,
+        r"(?P<caster>.+[^\s])s (?P<spell>.+[^\s]) heilt (?P<target>.+[^\s]) kritisch um (?P<amount>\d+) Punkte\.",
+    );
+    dictionary.register(
+        "vanilla.grammar.gain",
+        Language::German,
+        r"(?P<target>.+[^\s]) erhält (?P<amount>\d+) (?P<power>Gesundheit|Mana|Wut|Energie|Glück|Fokus) durch (?P<caster>.+[^\s])s (?P<spell>.+[^\s])\.",
+    );
+
+    dictionary.register(
+        "vanilla.grammar.aura_gain",
+        Language::German,
+        "(?P<target>.+[^\\s]) ist von (?P<spell>.+[^\\s]) betroffen \\((?P<stacks>\\d+)\\)\\.\n(?P<target>.+[^\\s]) bekommt (?P<spell>.+[^\\s]) \\((?P<stacks>\\d+)\\)\\.",
+    );
+    dictionary.register("vanilla.grammar.aura_fade", Language::German, r"(?P<spell>.+[^\s]) schwindet von (?P<target>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.aura_dispel", Language::German, r"(?P<target>.+[^\s])s (?P<spell>.+[^\s]) wurde entfernt\.");
+    dictionary.register("vanilla.grammar.aura_interrupt", Language::German, r"(?P<caster>.+[^\s]) unterbricht (?P<target>.+[^\s])s (?P<spell>.+[^\s])\.");
+
+    dictionary.register(
+        "vanilla.grammar.spell_cast_perform_durability",
+        Language::German,
+        r"(?P<caster>.+[^\s]) (?:wirkt|führt) (?P<spell>.+[^\s]) auf (?P<target>.+[^\s])(?: aus)?: (?P<durability>.+)\.",
+    );
+    dictionary.register("vanilla.grammar.spell_cast_perform", Language::German, r"(?P<caster>.+[^\s]) (?:wirkt|führt) (?P<spell>.+[^\s]) auf (?P<target>.+[^\s])(?: aus)?\.");
+    dictionary.register("vanilla.grammar.spell_cast_perform_unknown", Language::German, r"(?P<caster>.+[^\s]) (?:wirkt|führt) (?P<spell>.+[^\s])(?: aus)?\.");
+
+    dictionary.register("vanilla.grammar.unit_die_destroyed", Language::German, r"(?P<victim>.+[^\s]) (?:stirbt|wurde zerstört)\.");
+    dictionary.register("vanilla.grammar.unit_slay", Language::German, r"(?P<victim>.+[^\s]) wurde von (?P<attacker>.+[^\s]) getötet(?:!|\.)");
+
+    dictionary.register(
+        "vanilla.grammar.bug_damage_spell_hit_or_crit",
+        Language::German,
+        r"(?P<attacker>.+[^\s])s (?P<kind>trifft) (?P<victim>.+[^\s]) für (?P<amount>\d+) Schaden\.\s?(?P<trailer>.*)",
+    );
+
+    dictionary.register("vanilla.keyword.crit", Language::German, "kritisch");
+    dictionary.register("vanilla.keyword.hit", Language::German, "trifft");
+
+    dictionary.register("vanilla.keyword.physical", Language::German, "Körperlich|Körperlicher");
+    dictionary.register("vanilla.keyword.arcane", Language::German, "Arkan");
+    dictionary.register("vanilla.keyword.fire", Language::German, "Feuer");
+    dictionary.register("vanilla.keyword.frost", Language::German, "Frost");
+    dictionary.register("vanilla.keyword.shadow", Language::German, "Schatten");
+    dictionary.register("vanilla.keyword.nature", Language::German, "Natur");
+    dictionary.register("vanilla.keyword.holy", Language::German, "Heilig");
+
+    dictionary.register("vanilla.keyword.block", Language::German, "blockt ab|geblockt");
+    dictionary.register("vanilla.keyword.parry", Language::German, "pariert");
+    dictionary.register("vanilla.keyword.evade", Language::German, "entkommt|entkommen");
+    dictionary.register("vanilla.keyword.dodge", Language::German, "weicht aus|ausgewichen");
+    dictionary.register("vanilla.keyword.deflect", Language::German, "lenkt ab|abgelenkt");
+    dictionary.register("vanilla.keyword.absorb", Language::German, "absorbiert");
+    dictionary.register("vanilla.keyword.resist", Language::German, "widersteht|widerstanden");
+
+    dictionary.register("vanilla.keyword.glancing", Language::German, "gestreift");
+    dictionary.register("vanilla.keyword.crushing", Language::German, "schmetternd");
+    dictionary.register("vanilla.keyword.vulnerability", Language::German, "Verwundbarkeit");
+
+    dictionary.register("vanilla.keyword.health", Language::German, "Gesundheit");
+    dictionary.register("vanilla.keyword.unknown", Language::German, "Unbekannt");
+
+    dictionary.register("vanilla.keyword.warrior", Language::German, "krieger|kriegerin");
+    dictionary.register("vanilla.keyword.paladin", Language::German, "paladin");
+    dictionary.register("vanilla.keyword.hunter", Language::German, "jäger|jägerin");
+    dictionary.register("vanilla.keyword.rogue", Language::German, "schurke|schurkin");
+    dictionary.register("vanilla.keyword.priest", Language::German, "priester|priesterin");
+    dictionary.register("vanilla.keyword.shaman", Language::German, "schamane|schamanin");
+    dictionary.register("vanilla.keyword.mage", Language::German, "magier|magierin");
+    dictionary.register("vanilla.keyword.warlock", Language::German, "hexenmeister|hexenmeisterin");
+    dictionary.register("vanilla.keyword.druid", Language::German, "druide|druidin");
+
+    dictionary.register("vanilla.keyword.human", Language::German, "mensch");
+    dictionary.register("vanilla.keyword.orc", Language::German, "orc");
+    dictionary.register("vanilla.keyword.dwarf", Language::German, "zwerg|zwergin");
+    dictionary.register("vanilla.keyword.night_elf", Language::German, "nachtelf|nachtelfe");
+    dictionary.register("vanilla.keyword.undead", Language::German, "untoter|untote");
+    dictionary.register("vanilla.keyword.tauren", Language::German, "tauren");
+    dictionary.register("vanilla.keyword.gnome", Language::German, "gnom|gnomin");
+    dictionary.register("vanilla.keyword.troll", Language::German, "troll|trollin");
+}
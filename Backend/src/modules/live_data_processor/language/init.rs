@@ -0,0 +1,16 @@
+use language::material::Dictionary;
+
+use crate::modules::live_data_processor::language::{english, french, german, spanish};
+
+pub trait Init {
+    fn init(&self);
+}
+
+impl Init for Dictionary {
+    fn init(&self) {
+        english::init(self);
+        german::init(self);
+        french::init(self);
+        spanish::init(self);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod english;
+pub mod french;
+pub mod german;
+pub mod init;
+pub mod spanish;
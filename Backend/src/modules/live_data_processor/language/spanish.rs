@@ -0,0 +1,137 @@
+use language::{domain_value::Language, material::Dictionary, tools::Register};
+
+pub fn init(dictionary: &Dictionary) {
+    dictionary.register("vanilla.possessive", Language::Spanish, r"\sde\s[^\s]+$");
+
+    dictionary.register(
+        "vanilla.grammar.damage_hit_or_crit",
+        Language::Spanish,
+        r"(?P<attacker>.+[^\s]) (?P<kind>golpea|golpea críticamente) a (?P<victim>.+[^\s]) por (?P<amount>\d+)\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_hit_or_crit_school",
+        Language::Spanish,
+        r"(?P<attacker>.+[^\s]) (?P<kind>golpea|golpea críticamente) a (?P<victim>.+[^\s]) por (?P<amount>\d+) de daño de (?P<school>\w+)\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register("vanilla.grammar.damage_miss", Language::Spanish, r"(?P<attacker>.+[^\s]) falla a (?P<victim>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_avoid", Language::Spanish, r"(?P<attacker>.+[^\s]) ataca\. (?P<victim>.+[^\s]) (?P<avoid>bloquea|para|evade|esquiva|desvía)\.");
+    dictionary.register(
+        "vanilla.grammar.damage_absorb_resist",
+        Language::Spanish,
+        r"(?P<attacker>.+[^\s]) ataca\. (?P<victim>.+[^\s]) (?P<avoid>absorbe|resiste) todo el daño\.",
+    );
+    dictionary.register("vanilla.grammar.damage_immune", Language::Spanish, r"(?P<attacker>.+[^\s]) ataca pero (?P<victim>.+[^\s]) es inmune\.");
+
+    dictionary.register(
+        "vanilla.grammar.damage_spell_hit_or_crit",
+        Language::Spanish,
+        r"(?P<spell>.+[^\s]) de (?P<attacker>.+[^\s]) (?P<kind>golpea|golpea críticamente) a (?P<victim>.+[^\s]) por (?P<amount>\d+)\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_spell_hit_or_crit_school",
+        Language::Spanish,
+        r"(?P<spell>.+[^\s]) de (?P<attacker>.+[^\s]) (?P<kind>golpea|golpea críticamente) a (?P<victim>.+[^\s]) por (?P<amount>\d+) de daño de (?P<school>\w+)\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_periodic",
+        Language::Spanish,
+        r"(?P<victim>.+[^\s]) sufre (?P<amount>\d+) de daño de (?P<school>\w+) de (?P<spell>.+[^\s]) de (?P<attacker>.+[^\s])\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register(
+        "vanilla.grammar.damage_spell_split",
+        Language::Spanish,
+        r"(?P<spell>.+[^\s]) de (?P<attacker>.+[^\s]) causa (?P<amount>\d+) de daño a (?P<victim>.+[^\s])\.\s?(?P<trailer>.*)",
+    );
+    dictionary.register("vanilla.grammar.damage_spell_miss", Language::Spanish, r"(?P<spell>.+[^\s]) de (?P<attacker>.+[^\s]) falla a (?P<victim>.+[^\s])\.");
+    dictionary.register(
+        "vanilla.grammar.damage_spell_avoid",
+        Language::Spanish,
+        r"(?P<spell>.+[^\s]) de (?P<attacker>.+[^\s]) ha sido (?P<avoid>bloqueado|parado|evadido|esquivado|resistido|desviado) por (?P<victim>.+[^\s])\.",
+    );
+    dictionary.register("vanilla.grammar.damage_spell_absorb", Language::Spanish, r"(?P<spell>.+[^\s]) de (?P<attacker>.+[^\s]) ha sido absorbido por (?P<victim>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_spell_absorb_self", Language::Spanish, r"(?P<victim>.+[^\s]) absorbe (?P<spell>.+[^\s]) de (?P<attacker>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_reflect", Language::Spanish, r"(?P<spell>.+[^\s]) de (?P<attacker>.+[^\s]) se refleja en (?P<victim>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_proc_resist", Language::Spanish, r"(?P<victim>.+[^\s]) resiste (?P<spell>.+[^\s]) de (?P<attacker>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.damage_spell_immune", Language::Spanish, r"(?P<spell>.+[^\s]) de (?P<attacker>.+[^\s]) falla\. (?P<victim>.+[^\s]) es inmune\.");
+    dictionary.register(
+        "vanilla.grammar.damage_shield",
+        Language::Spanish,
+        r"(?P<attacker>.+[^\s]) refleja (?P<amount>\d+) de daño de (?P<school>\w+) a (?P<victim>.+[^\s])\.",
+    );
+    dictionary.register("vanilla.grammar.spell_cast_attempt", Language::Spanish, r"(?P<caster>.+[^\s]) comienza a lanzar (?P<spell>.+[^\s])\.");
+
+    dictionary.register("vanilla.grammar.heal_hit", Language::Spanish, r"(?P<spell>.+[^\s]) de (?P<caster>.+[^\s]) cura a (?P<target>.+[^\s]) (?P<amount>\d+)\.");
+    dictionary.register("vanilla.grammar.heal_crit", Language::Spanish, r"(?P<spell>.+[^\s]) de (?P<caster>.+[^\s]) cura críticamente a (?P<target>.+[^\s]) (?P<amount>\d+)\.");
+    dictionary.register(
+        "vanilla.grammar.gain",
+        Language::Spanish,
+        r"(?P<target>.+[^\s]) gana (?P<amount>\d+) de (?P<power>salud|maná|ira|energía|felicidad|concentración) por (?P<spell>.+[^\s]) de (?P<caster>.+[^\s])\.",
+    );
+
+    dictionary.register("vanilla.grammar.aura_gain", Language::Spanish, r"(?P<target>.+[^\s]) (?:sufre|gana) (?P<spell>.+[^\s]) \((?P<stacks>\d+)\)\.");
+    dictionary.register("vanilla.grammar.aura_fade", Language::Spanish, r"(?P<spell>.+[^\s]) desaparece de (?P<target>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.aura_dispel", Language::Spanish, r"(?P<spell>.+[^\s]) de (?P<target>.+[^\s]) se ha disipado\.");
+    dictionary.register("vanilla.grammar.aura_interrupt", Language::Spanish, r"(?P<caster>.+[^\s]) interrumpe (?P<spell>.+[^\s]) de (?P<target>.+[^\s])\.");
+
+    dictionary.register(
+        "vanilla.grammar.spell_cast_perform_durability",
+        Language::Spanish,
+        r"(?P<caster>.+[^\s]) (?:lanza|realiza) (?P<spell>.+[^\s]) a (?P<target>.+[^\s]): (?P<durability>.+)\.",
+    );
+    dictionary.register("vanilla.grammar.spell_cast_perform", Language::Spanish, r"(?P<caster>.+[^\s]) (?:lanza|realiza) (?P<spell>.+[^\s]) a (?P<target>.+[^\s])\.");
+    dictionary.register("vanilla.grammar.spell_cast_perform_unknown", Language::Spanish, r"(?P<caster>.+[^\s]) (?:lanza|realiza) (?P<spell>.+[^\s])\.");
+
+    dictionary.register("vanilla.grammar.unit_die_destroyed", Language::Spanish, r"(?P<victim>.+[^\s]) (?:muere|ha sido destruido)\.");
+    dictionary.register("vanilla.grammar.unit_slay", Language::Spanish, r"¡?(?P<victim>.+[^\s]) ha sido asesinado por (?P<attacker>.+[^\s])(?:!|\.)");
+
+    dictionary.register(
+        "vanilla.grammar.bug_damage_spell_hit_or_crit",
+        Language::Spanish,
+        r"de (?P<attacker>.+[^\s]) (?P<kind>golpea|golpea críticamente) a (?P<victim>.+[^\s]) por (?P<amount>\d+)\.\s?(?P<trailer>.*)",
+    );
+
+    dictionary.register("vanilla.keyword.crit", Language::Spanish, "golpea críticamente");
+    dictionary.register("vanilla.keyword.hit", Language::Spanish, "golpea");
+
+    dictionary.register("vanilla.keyword.physical", Language::Spanish, "Física|Físico");
+    dictionary.register("vanilla.keyword.arcane", Language::Spanish, "Arcano");
+    dictionary.register("vanilla.keyword.fire", Language::Spanish, "Fuego");
+    dictionary.register("vanilla.keyword.frost", Language::Spanish, "Escarcha");
+    dictionary.register("vanilla.keyword.shadow", Language::Spanish, "Sombras");
+    dictionary.register("vanilla.keyword.nature", Language::Spanish, "Naturaleza");
+    dictionary.register("vanilla.keyword.holy", Language::Spanish, "Sagrado");
+
+    dictionary.register("vanilla.keyword.block", Language::Spanish, "bloquea|bloqueado");
+    dictionary.register("vanilla.keyword.parry", Language::Spanish, "para|parado");
+    dictionary.register("vanilla.keyword.evade", Language::Spanish, "evade|evadido");
+    dictionary.register("vanilla.keyword.dodge", Language::Spanish, "esquiva|esquivado");
+    dictionary.register("vanilla.keyword.deflect", Language::Spanish, "desvía|desviado");
+    dictionary.register("vanilla.keyword.absorb", Language::Spanish, "absorbe|absorbido");
+    dictionary.register("vanilla.keyword.resist", Language::Spanish, "resiste|resistido");
+
+    dictionary.register("vanilla.keyword.glancing", Language::Spanish, "de refilón");
+    dictionary.register("vanilla.keyword.crushing", Language::Spanish, "aplastante");
+    dictionary.register("vanilla.keyword.vulnerability", Language::Spanish, "vulnerabilidad");
+
+    dictionary.register("vanilla.keyword.health", Language::Spanish, "salud");
+    dictionary.register("vanilla.keyword.unknown", Language::Spanish, "Desconocido");
+
+    dictionary.register("vanilla.keyword.warrior", Language::Spanish, "guerrero|guerrera");
+    dictionary.register("vanilla.keyword.paladin", Language::Spanish, "paladín");
+    dictionary.register("vanilla.keyword.hunter", Language::Spanish, "cazador|cazadora");
+    dictionary.register("vanilla.keyword.rogue", Language::Spanish, "pícaro|pícara");
+    dictionary.register("vanilla.keyword.priest", Language::Spanish, "sacerdote|sacerdotisa");
+    dictionary.register("vanilla.keyword.shaman", Language::Spanish, "chamán");
+    dictionary.register("vanilla.keyword.mage", Language::Spanish, "mago|maga");
+    dictionary.register("vanilla.keyword.warlock", Language::Spanish, "brujo|bruja");
+    dictionary.register("vanilla.keyword.druid", Language::Spanish, "druida");
+
+    dictionary.register("vanilla.keyword.human", Language::Spanish, "humano|humana");
+    dictionary.register("vanilla.keyword.orc", Language::Spanish, "orco");
+    dictionary.register("vanilla.keyword.dwarf", Language::Spanish, "enano|enana");
+    dictionary.register("vanilla.keyword.night_elf", Language::Spanish, "elfo de la noche|elfa de la noche");
+    dictionary.register("vanilla.keyword.undead", Language::Spanish, "no-muerto|no-muerta");
+    dictionary.register("vanilla.keyword.tauren", Language::Spanish, "tauren");
+    dictionary.register("vanilla.keyword.gnome", Language::Spanish, "gnomo|gnoma");
+    dictionary.register("vanilla.keyword.troll", Language::Spanish, "trol");
+}
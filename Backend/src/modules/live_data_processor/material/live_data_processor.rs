@@ -1,19 +1,40 @@
-use crate::modules::live_data_processor::material::Server;
+use crate::modules::live_data_processor::language::init::Init;
+use crate::modules::live_data_processor::material::{LiveFeed, Server, VanillaGrammar};
 use crate::params;
 use crate::util::database::Select;
+use language::domain_value::Language;
+use language::material::Dictionary;
 use std::collections::HashMap;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+static LIVE_FEED_CLEANUP_INTERVAL_SECS: u64 = 60;
 
 pub struct LiveDataProcessor {
     pub servers: RwLock<HashMap<u32, RwLock<Server>>>,
-    pub upload_progress: RwLock<HashMap<u32, u8>>
+    pub upload_progress: RwLock<HashMap<u32, u8>>,
+    pub package_sequences: RwLock<HashMap<u32, u32>>,
+    pub live_feed: Arc<LiveFeed>,
+    pub dictionary: Dictionary,
+    pub vanilla_grammars: Vec<Arc<VanillaGrammar>>,
 }
 
 impl Default for LiveDataProcessor {
     fn default() -> Self {
+        let dictionary = Dictionary::default();
+        Dictionary::init(&dictionary);
+        let vanilla_grammars = [Language::English, Language::German, Language::French, Language::Spanish]
+            .iter()
+            .map(|language| Arc::new(VanillaGrammar::new(&dictionary, *language)))
+            .collect();
+
         LiveDataProcessor {
             servers: RwLock::new(HashMap::new()),
-            upload_progress: RwLock::new(HashMap::new())
+            upload_progress: RwLock::new(HashMap::new()),
+            package_sequences: RwLock::new(HashMap::new()),
+            live_feed: Arc::new(LiveFeed::default()),
+            dictionary,
+            vanilla_grammars,
         }
     }
 }
@@ -29,9 +50,24 @@ impl LiveDataProcessor {
                     servers.insert(server_id, RwLock::new(Server::new(server_id, expansion_id).init(db_main)));
                 });
         }
+
+        let live_feed = Arc::clone(&self.live_feed);
+        std::thread::spawn(move || loop {
+            std::thread::sleep(Duration::from_secs(LIVE_FEED_CLEANUP_INTERVAL_SECS));
+            live_feed.remove_stale_feeds(time_util::now());
+        });
         self
     }
 
+    /// An instance is live as long as one of the servers still processes it.
+    pub fn is_live(&self, instance_meta_id: u32) -> bool {
+        self.servers
+            .read()
+            .unwrap()
+            .values()
+            .any(|server| server.read().unwrap().active_instances.values().any(|unit_instance| unit_instance.instance_meta_id == instance_meta_id && !unit_instance.ready_to_zip))
+    }
+
     pub fn create_server_if_not_exist(&self, db_main: &mut impl Select, server_id: u32) {
         let create_server = {
             let servers = self.servers.read().unwrap();
@@ -50,4 +86,16 @@ impl LiveDataProcessor {
             servers.insert(server_id, RwLock::new(Server::new(server_id, expansion_id).init(db_main)));
         }
     }
+
+    /// Returns the amount of packages that were lost since the last package of this server.
+    /// A sequence that does not increase indicates a restart of the server plugin.
+    pub fn track_package_sequence(&self, server_id: u32, sequence: u32) -> u32 {
+        let mut package_sequences = self.package_sequences.write().unwrap();
+        let lost_packages = match package_sequences.get(&server_id) {
+            Some(last_sequence) if sequence > *last_sequence => sequence - *last_sequence - 1,
+            _ => 0,
+        };
+        package_sequences.insert(server_id, sequence);
+        lost_packages
+    }
 }
@@ -0,0 +1,62 @@
+use crate::modules::live_data_processor::dto::{LiveFeedEvent, LiveFeedPage};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+pub static MAX_EVENTS_PER_INSTANCE: usize = 500;
+pub static STALE_FEED_SECS: u64 = 60 * 60;
+
+/// Keeps the latest events of live streamed instances, viewers poll them by cursor.
+/// Nothing is held open between polls, so viewers never occupy a worker.
+#[derive(Default)]
+pub struct LiveFeed {
+    // Key: instance_meta_id
+    feeds: Mutex<HashMap<u32, InstanceFeed>>,
+}
+
+#[derive(Default)]
+struct InstanceFeed {
+    // Cursor of the next published event
+    next_cursor: u64,
+    events: VecDeque<LiveFeedEvent>,
+    last_publish: u64,
+}
+
+impl LiveFeed {
+    /// The oldest event is dropped once the instance has too many.
+    pub fn publish(&self, instance_meta_id: u32, event: LiveFeedEvent) {
+        let mut feeds = self.feeds.lock().unwrap();
+        let feed = feeds.entry(instance_meta_id).or_default();
+        feed.events.push_back(event);
+        if feed.events.len() > MAX_EVENTS_PER_INSTANCE {
+            feed.events.pop_front();
+        }
+        feed.next_cursor += 1;
+        feed.last_publish = time_util::now();
+    }
+
+    /// Events from the cursor on, those that were already dropped are skipped.
+    /// A cursor ahead of the feed stems from a feed that was removed meanwhile, so it starts over.
+    pub fn poll(&self, instance_meta_id: u32, cursor: u64) -> LiveFeedPage {
+        let feeds = self.feeds.lock().unwrap();
+        let feed = match feeds.get(&instance_meta_id) {
+            Some(feed) => feed,
+            None => return LiveFeedPage { cursor: 0, events: Vec::new() },
+        };
+
+        let first_cursor = feed.next_cursor - feed.events.len() as u64;
+        let cursor = if cursor > feed.next_cursor { first_cursor } else { cursor.max(first_cursor) };
+        LiveFeedPage {
+            cursor: feed.next_cursor,
+            events: feed.events.iter().skip((cursor - first_cursor) as usize).cloned().collect(),
+        }
+    }
+
+    /// Instances that publish nothing anymore would otherwise keep their events forever.
+    pub fn remove_stale_feeds(&self, now: u64) {
+        self.feeds.lock().unwrap().retain(|_, feed| feed.last_publish + STALE_FEED_SECS > now);
+    }
+
+    pub fn amount_events(&self, instance_meta_id: u32) -> usize {
+        self.feeds.lock().unwrap().get(&instance_meta_id).map(|feed| feed.events.len()).unwrap_or(0)
+    }
+}
@@ -1,8 +1,10 @@
 pub use self::active_map::*;
 pub use self::attempt::Attempt;
 pub use self::live_data_processor::LiveDataProcessor;
+pub use self::live_feed::{LiveFeed, MAX_EVENTS_PER_INSTANCE, STALE_FEED_SECS};
 pub use self::participant::Participant;
 pub use self::server::Server;
+pub use self::vanilla_grammar::{GrammarRule, VanillaGrammar};
 pub use self::wow_retail_classic_parser::WoWRetailClassicParser;
 pub use self::wow_tbc_parser::WoWTBCParser;
 pub use self::wow_vanilla_parser::WoWVanillaParser;
@@ -11,7 +13,9 @@ pub use self::interval_bucket::IntervalBucket;
 
 mod attempt;
 mod live_data_processor;
+mod live_feed;
 mod server;
+mod vanilla_grammar;
 
 mod active_map;
 mod participant;
@@ -1,8 +1,9 @@
 use crate::modules::live_data_processor::domain_value::{Event, NonCommittedEvent, Unit, UnitInstance};
-use crate::modules::live_data_processor::dto::InstanceResetDto;
+use crate::modules::live_data_processor::dto::{InstanceResetDto, LiveFeedEvent};
 use crate::modules::live_data_processor::material::Attempt;
 use crate::params;
 use crate::util::database::Select;
+use crate::util::event_store::{event_store_from_env, EventStore};
 use std::collections::{BTreeSet, HashMap, VecDeque};
 
 pub struct Server {
@@ -34,9 +35,13 @@ pub struct Server {
     pub committed_events: HashMap<(u32, u32), VecDeque<Event>>,
     pub committed_events_count: HashMap<(u32, u32), u32>,
     pub recently_committed_spell_cast_and_aura_applications: HashMap<(u32, u32), VecDeque<Event>>,
+    // (instance_meta_id, event), published to the live feed after each package
+    pub live_feed_events: Vec<(u32, LiveFeedEvent)>,
 
     // PERFORMANCE
     pub cache_unit: HashMap<u64, Unit>,
+
+    pub event_store: Box<dyn EventStore>,
 }
 
 impl Server {
@@ -57,7 +62,9 @@ impl Server {
             active_attempts: HashMap::new(),
             post_processing_last_precessed_event_id: HashMap::new(),
             recently_committed_spell_cast_and_aura_applications: HashMap::new(),
+            live_feed_events: Vec::new(),
             cache_unit: HashMap::new(),
+            event_store: event_store_from_env(),
         }
     }
 
@@ -65,7 +72,7 @@ impl Server {
         // Load active instances
         db_main
             .select_wparams(
-                "SELECT A.id, start_ts, map_id, instance_id, B.member_id, upload_id FROM instance_meta A JOIN instance_uploads B ON A.upload_id = B.id WHERE expired IS NULL AND server_id=:server_id",
+                "SELECT A.id, start_ts, map_id, instance_id, B.member_id, upload_id FROM instance_meta A JOIN instance_uploads B ON A.upload_id = B.id WHERE expired IS NULL AND deleted_ts IS NULL AND server_id=:server_id",
                 |mut row| UnitInstance {
                     instance_meta_id: row.take(0).unwrap(),
                     entered: row.take(1).unwrap(),
@@ -86,7 +93,7 @@ impl Server {
         // Load current_event_id count
         db_main
             .select_wparams(
-                "SELECT instance_id, last_event_id, B.member_id FROM instance_meta A JOIN instance_uploads B ON A.upload_id = B.id WHERE expired IS NULL AND server_id=:server_id",
+                "SELECT instance_id, last_event_id, B.member_id FROM instance_meta A JOIN instance_uploads B ON A.upload_id = B.id WHERE expired IS NULL AND deleted_ts IS NULL AND server_id=:server_id",
                 |mut row| (row.take::<u32, usize>(0).unwrap(), row.take::<u32, usize>(1).unwrap(), row.take::<u32, usize>(2).unwrap()),
                 params!("server_id" => self.server_id),
             )
@@ -98,7 +105,7 @@ impl Server {
         // Load active instance participants
         db_main
             .select_wparams(
-                "SELECT A.id, B.character_id FROM instance_meta A JOIN instance_participants B ON A.id = B.instance_meta_id WHERE A.expired IS NULL AND A.server_id=:server_id",
+                "SELECT A.id, B.character_id FROM instance_meta A JOIN instance_participants B ON A.id = B.instance_meta_id WHERE A.expired IS NULL AND A.deleted_ts IS NULL AND A.server_id=:server_id",
                 |mut row| (row.take::<u32, usize>(0).unwrap(), row.take::<u32, usize>(1).unwrap()),
                 params!("server_id" => self.server_id),
             )
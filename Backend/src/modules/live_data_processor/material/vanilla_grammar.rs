@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use language::domain_value::Language;
+use language::material::Dictionary;
+use language::tools::Get;
+use regex::{Captures, Regex};
+
+static KEYWORDS: [&str; 38] = [
+    "crit",
+    "hit",
+    "physical",
+    "arcane",
+    "fire",
+    "frost",
+    "shadow",
+    "nature",
+    "holy",
+    "block",
+    "parry",
+    "evade",
+    "dodge",
+    "deflect",
+    "absorb",
+    "resist",
+    "glancing",
+    "crushing",
+    "vulnerability",
+    "health",
+    "unknown",
+    "warrior",
+    "paladin",
+    "hunter",
+    "rogue",
+    "priest",
+    "shaman",
+    "mage",
+    "warlock",
+    "druid",
+    "human",
+    "orc",
+    "dwarf",
+    "night_elf",
+    "undead",
+    "tauren",
+    "gnome",
+    "troll",
+];
+
+pub struct GrammarRule(Vec<Regex>);
+
+impl GrammarRule {
+    fn new(template: &str) -> Self {
+        GrammarRule(template.split('\n').map(|pattern| Regex::new(pattern).unwrap()).collect())
+    }
+
+    pub fn captures<'t>(&self, text: &'t str) -> Option<Captures<'t>> {
+        self.0.iter().find_map(|regex| regex.captures(text))
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        self.0.iter().any(|regex| regex.is_match(text))
+    }
+}
+
+/// Combat log sentences of a Vanilla client in a specific locale.
+/// Each rule exposes the same named groups, regardless of the word order of the locale.
+pub struct VanillaGrammar {
+    pub language: Language,
+    /// Matches unit names that still carry the possessive of a player or pet owner
+    pub possessive: GrammarRule,
+    keywords: HashMap<String, &'static str>,
+
+    pub damage_hit_or_crit: GrammarRule,
+    pub damage_hit_or_crit_school: GrammarRule,
+    pub damage_miss: GrammarRule,
+    pub damage_avoid: GrammarRule,
+    pub damage_absorb_resist: GrammarRule,
+    pub damage_immune: GrammarRule,
+    pub damage_spell_hit_or_crit: GrammarRule,
+    pub damage_spell_hit_or_crit_school: GrammarRule,
+    pub damage_periodic: GrammarRule,
+    pub damage_spell_split: GrammarRule,
+    pub damage_spell_miss: GrammarRule,
+    pub damage_spell_avoid: GrammarRule,
+    pub damage_spell_absorb: GrammarRule,
+    pub damage_spell_absorb_self: GrammarRule,
+    pub damage_reflect: GrammarRule,
+    pub damage_proc_resist: GrammarRule,
+    pub damage_spell_immune: GrammarRule,
+    pub damage_shield: GrammarRule,
+    pub spell_cast_attempt: GrammarRule,
+    pub heal_hit: GrammarRule,
+    pub heal_crit: GrammarRule,
+    pub gain: GrammarRule,
+    pub aura_gain: GrammarRule,
+    pub aura_fade: GrammarRule,
+    pub aura_dispel: GrammarRule,
+    pub aura_interrupt: GrammarRule,
+    pub spell_cast_perform_durability: GrammarRule,
+    pub spell_cast_perform: GrammarRule,
+    pub spell_cast_perform_unknown: GrammarRule,
+    pub unit_die_destroyed: GrammarRule,
+    pub unit_slay: GrammarRule,
+    pub bug_damage_spell_hit_or_crit: GrammarRule,
+}
+
+impl VanillaGrammar {
+    pub fn new(dictionary: &Dictionary, language: Language) -> Self {
+        let rule = |name: &str| GrammarRule::new(&dictionary.get(&format!("vanilla.grammar.{}", name), language));
+
+        // The addon reports class and race tokens in English, regardless of the client locale
+        let mut keywords = HashMap::new();
+        for lang in [language, Language::English].iter() {
+            for canonical in KEYWORDS.iter() {
+                for localized in dictionary.get(&format!("vanilla.keyword.{}", canonical), *lang).split('|') {
+                    keywords.entry(localized.to_lowercase()).or_insert(*canonical);
+                }
+            }
+        }
+
+        VanillaGrammar {
+            language,
+            possessive: GrammarRule::new(&dictionary.get("vanilla.possessive", language)),
+            keywords,
+            damage_hit_or_crit: rule("damage_hit_or_crit"),
+            damage_hit_or_crit_school: rule("damage_hit_or_crit_school"),
+            damage_miss: rule("damage_miss"),
+            damage_avoid: rule("damage_avoid"),
+            damage_absorb_resist: rule("damage_absorb_resist"),
+            damage_immune: rule("damage_immune"),
+            damage_spell_hit_or_crit: rule("damage_spell_hit_or_crit"),
+            damage_spell_hit_or_crit_school: rule("damage_spell_hit_or_crit_school"),
+            damage_periodic: rule("damage_periodic"),
+            damage_spell_split: rule("damage_spell_split"),
+            damage_spell_miss: rule("damage_spell_miss"),
+            damage_spell_avoid: rule("damage_spell_avoid"),
+            damage_spell_absorb: rule("damage_spell_absorb"),
+            damage_spell_absorb_self: rule("damage_spell_absorb_self"),
+            damage_reflect: rule("damage_reflect"),
+            damage_proc_resist: rule("damage_proc_resist"),
+            damage_spell_immune: rule("damage_spell_immune"),
+            damage_shield: rule("damage_shield"),
+            spell_cast_attempt: rule("spell_cast_attempt"),
+            heal_hit: rule("heal_hit"),
+            heal_crit: rule("heal_crit"),
+            gain: rule("gain"),
+            aura_gain: rule("aura_gain"),
+            aura_fade: rule("aura_fade"),
+            aura_dispel: rule("aura_dispel"),
+            aura_interrupt: rule("aura_interrupt"),
+            spell_cast_perform_durability: rule("spell_cast_perform_durability"),
+            spell_cast_perform: rule("spell_cast_perform"),
+            spell_cast_perform_unknown: rule("spell_cast_perform_unknown"),
+            unit_die_destroyed: rule("unit_die_destroyed"),
+            unit_slay: rule("unit_slay"),
+            bug_damage_spell_hit_or_crit: rule("bug_damage_spell_hit_or_crit"),
+        }
+    }
+
+    /// Maps a localized token of the combat log to its locale independent name
+    pub fn keyword(&self, token: &str) -> Option<&'static str> {
+        self.keywords.get(&token.to_lowercase()).copied()
+    }
+
+    /// Whether the line is one of the frequent sentences that are distinctive for this locale
+    pub fn recognizes(&self, line: &str) -> bool {
+        self.damage_hit_or_crit.is_match(line)
+            || self.damage_hit_or_crit_school.is_match(line)
+            || self.damage_spell_hit_or_crit.is_match(line)
+            || self.damage_spell_hit_or_crit_school.is_match(line)
+            || self.damage_periodic.is_match(line)
+            || self.heal_hit.is_match(line)
+            || self.heal_crit.is_match(line)
+            || self.spell_cast_attempt.is_match(line)
+    }
+
+    /// Language id as it is used by the localization tables of the data module
+    pub fn data_language_id(&self) -> u8 {
+        self.language as u8 + 1
+    }
+}
@@ -1,11 +1,18 @@
 use crate::modules::data::Data;
 use crate::modules::live_data_processor::dto::{Message, Unit};
-use crate::modules::live_data_processor::material::{ActiveMapMap, CollectActiveMap, Participant};
+use crate::modules::live_data_processor::material::{ActiveMapMap, CollectActiveMap, Participant, VanillaGrammar};
 use std::collections::HashMap;
+use std::sync::Arc;
+
+const LOCALE_DETECTION_LINES: u32 = 500;
 
 pub struct WoWVanillaParser {
     pub server_id: u32,
 
+    pub grammars: Vec<Arc<VanillaGrammar>>,
+    pub grammar: Option<Arc<VanillaGrammar>>,
+    pub lines_without_locale: u32,
+
     pub participants: HashMap<u64, Participant>,
     pub active_map: ActiveMapMap,
     pub pet_owner: HashMap<u64, u64>,
@@ -18,9 +25,12 @@ pub struct WoWVanillaParser {
 }
 
 impl WoWVanillaParser {
-    pub fn new(server_id: u32) -> Self {
+    pub fn new(server_id: u32, grammars: Vec<Arc<VanillaGrammar>>) -> Self {
         WoWVanillaParser {
             server_id,
+            grammars,
+            grammar: None,
+            lines_without_locale: 0,
             participants: Default::default(),
             active_map: Default::default(),
             pet_owner: Default::default(),
@@ -30,6 +40,24 @@ impl WoWVanillaParser {
         }
     }
 
+    /// The locale is detected on the first recognized line. English is assumed until then.
+    pub fn grammar(&mut self, content: &str) -> Arc<VanillaGrammar> {
+        if let Some(grammar) = &self.grammar {
+            return grammar.clone();
+        }
+
+        if let Some(grammar) = self.grammars.iter().find(|grammar| grammar.recognizes(content)) {
+            self.grammar = Some(grammar.clone());
+            return grammar.clone();
+        }
+
+        self.lines_without_locale += 1;
+        if self.lines_without_locale >= LOCALE_DETECTION_LINES {
+            self.grammar = Some(self.grammars[0].clone());
+        }
+        self.grammars[0].clone()
+    }
+
     pub fn collect_participant(&mut self, unit: &Unit, name: &str, now: u64) {
         if let Some(participants) = self.participants.get_mut(&unit.unit_id) {
             participants.add_participation_point(now);
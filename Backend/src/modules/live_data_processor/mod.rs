@@ -3,6 +3,7 @@ pub use self::material::LiveDataProcessor;
 
 mod domain_value;
 mod dto;
+mod language;
 mod material;
 pub mod tools;
 pub mod transfer;
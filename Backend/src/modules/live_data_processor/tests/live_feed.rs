@@ -0,0 +1,78 @@
+use crate::modules::live_data_processor::dto::{LiveFeedEvent, LiveFeedPage};
+use crate::modules::live_data_processor::material::{LiveFeed, MAX_EVENTS_PER_INSTANCE, STALE_FEED_SECS};
+
+fn attempt_event(encounter_id: u32) -> LiveFeedEvent {
+    LiveFeedEvent::Attempt {
+        encounter_id,
+        start_ts: 1000,
+        end_ts: 61000,
+        is_kill: true,
+    }
+}
+
+#[test]
+fn poll_events_of_instance_since_cursor() {
+    // Arrange
+    let live_feed = LiveFeed::default();
+    live_feed.publish(42, attempt_event(1));
+    live_feed.publish(43, attempt_event(2));
+    live_feed.publish(42, attempt_event(3));
+
+    // Act
+    let page = live_feed.poll(42, 0);
+    live_feed.publish(42, attempt_event(4));
+    let next_page = live_feed.poll(42, page.cursor);
+
+    // Assert
+    assert_eq!(page, LiveFeedPage { cursor: 2, events: vec![attempt_event(1), attempt_event(3)] });
+    assert_eq!(next_page, LiveFeedPage { cursor: 3, events: vec![attempt_event(4)] });
+    assert!(live_feed.poll(42, next_page.cursor).events.is_empty());
+    assert_eq!(live_feed.poll(44, 5), LiveFeedPage { cursor: 0, events: Vec::new() });
+}
+
+#[test]
+fn poll_skips_dropped_events() {
+    // Arrange
+    let live_feed = LiveFeed::default();
+    for encounter_id in 0..(MAX_EVENTS_PER_INSTANCE + 2) as u32 {
+        live_feed.publish(42, attempt_event(encounter_id));
+    }
+
+    // Act
+    let page = live_feed.poll(42, 1);
+
+    // Assert
+    assert_eq!(live_feed.amount_events(42), MAX_EVENTS_PER_INSTANCE);
+    assert_eq!(page.cursor, MAX_EVENTS_PER_INSTANCE as u64 + 2);
+    assert_eq!(page.events.len(), MAX_EVENTS_PER_INSTANCE);
+    assert_eq!(page.events[0], attempt_event(2));
+}
+
+#[test]
+fn poll_starts_over_with_cursor_of_removed_feed() {
+    // Arrange
+    let live_feed = LiveFeed::default();
+    live_feed.publish(42, attempt_event(1));
+
+    // Act
+    let page = live_feed.poll(42, 10);
+
+    // Assert
+    assert_eq!(page, LiveFeedPage { cursor: 1, events: vec![attempt_event(1)] });
+}
+
+#[test]
+fn remove_stale_feeds_without_publishing() {
+    // Arrange
+    let live_feed = LiveFeed::default();
+    live_feed.publish(42, attempt_event(1));
+
+    // Act
+    live_feed.remove_stale_feeds(time_util::now());
+    let amount_events_recently_published = live_feed.amount_events(42);
+    live_feed.remove_stale_feeds(time_util::now() + STALE_FEED_SECS + 1);
+
+    // Assert
+    assert_eq!(amount_events_recently_published, 1);
+    assert_eq!(live_feed.amount_events(42), 0);
+}
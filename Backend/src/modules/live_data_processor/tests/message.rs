@@ -59,3 +59,45 @@ fn parse_message_negative_invalid_message_type() {
     // Assert
     assert!(message.is_err());
 }
+
+#[test]
+fn parse_message_v1_positive() {
+    // Arrange
+    let message_vec = vec![
+        1,  // API_Version
+        23, // Message Type
+        38, 0, // Message length
+        5, 0, 0, 0, 0, 0, 0, 0, // Timestamp
+        2, 0, 0, 0, 0, 0, 0, 0, // Message count
+        // Payload: Instance Map
+        249, 0, 0, 0, // MapId
+        42, 0, 0, 0, // InstanceId
+        0, // Difficulty
+        1, 234, 0, 0, 0, 0, 0, 0, 0, // Unit
+    ];
+
+    // Act
+    let message = message_vec.parse_message();
+
+    // Assert
+    assert!(message.is_ok());
+    let message = message.unwrap();
+    assert_eq!(message.api_version, 1);
+    assert_eq!(message.message_length, 38);
+    assert_eq!(message.timestamp, 5);
+    assert_eq!(message.message_count, 2);
+    assert!(matches!(message.message_type, MessageType::InstanceMap(_)));
+}
+
+#[test]
+fn parse_message_v1_negative_length_mismatch() {
+    // Arrange
+    let mut message_vec = vec![1, 23, 255, 0];
+    message_vec.resize(38, 0);
+
+    // Act
+    let message = message_vec.parse_message();
+
+    // Assert
+    assert!(message.is_err());
+}
@@ -1,7 +1,10 @@
 mod byte_reader;
 mod guid;
+mod live_feed;
 mod message;
 mod message_type;
+mod package;
 mod payload_mapper;
 mod server;
 mod try_parse_interrupt;
+mod vanilla_grammar;
@@ -0,0 +1,91 @@
+use crate::modules::live_data_processor::dto::LiveDataProcessorFailure;
+use crate::modules::live_data_processor::tools::PackageParser;
+
+fn v1_package(sequence: u32, payload: &[u8]) -> Vec<u8> {
+    let mut package = vec![1];
+    package.extend_from_slice(&sequence.to_le_bytes());
+    package.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    package.extend_from_slice(&crc32fast::hash(payload).to_le_bytes());
+    package.extend_from_slice(payload);
+    package
+}
+
+#[test]
+fn parse_package_v0_positive() {
+    // Arrange
+    let mut package_vec = vec![0, 23, 20];
+    package_vec.resize(20, 7);
+    package_vec.extend_from_slice(&[0, 9, 4, 8]);
+
+    // Act
+    let package = package_vec.parse_package();
+
+    // Assert
+    assert!(package.is_ok());
+    let package = package.unwrap();
+    assert_eq!(package.api_version, 0);
+    assert_eq!(package.sequence, None);
+    assert_eq!(package.messages.len(), 2);
+    assert_eq!(package.messages[0].len(), 20);
+    assert_eq!(package.messages[1], vec![0, 9, 4, 8]);
+}
+
+#[test]
+fn parse_package_v0_negative_truncated_message() {
+    // Arrange
+    let package_vec = vec![0, 23, 42, 1, 2, 3];
+
+    // Act
+    let package = package_vec.parse_package();
+
+    // Assert
+    assert!(package.is_err());
+}
+
+#[test]
+fn parse_package_v1_positive_long_message() {
+    // Arrange
+    let mut payload = vec![1, 17, 0x2C, 0x01];
+    payload.resize(300, 3);
+    payload.extend_from_slice(&[1, 23, 5, 0, 9]);
+    let package_vec = v1_package(42, &payload);
+
+    // Act
+    let package = package_vec.parse_package();
+
+    // Assert
+    assert!(package.is_ok());
+    let package = package.unwrap();
+    assert_eq!(package.api_version, 1);
+    assert_eq!(package.sequence, Some(42));
+    assert_eq!(package.messages.len(), 2);
+    assert_eq!(package.messages[0].len(), 300);
+    assert_eq!(package.messages[1], vec![1, 23, 5, 0, 9]);
+}
+
+#[test]
+fn parse_package_v1_negative_checksum() {
+    // Arrange
+    let mut package_vec = v1_package(1, &[1, 23, 5, 0, 9]);
+    let last = package_vec.len() - 1;
+    package_vec[last] = 10;
+
+    // Act
+    let package = package_vec.parse_package();
+
+    // Assert
+    assert!(matches!(package, Err(LiveDataProcessorFailure::ChecksumMismatch)));
+}
+
+#[test]
+fn parse_package_v1_negative_payload_length() {
+    // Arrange
+    let mut package_vec = v1_package(1, &[1, 23, 5, 0, 9]);
+    package_vec.push(0);
+
+    // Act
+    let package = package_vec.parse_package();
+
+    // Assert
+    assert!(matches!(package, Err(LiveDataProcessorFailure::InvalidInput)));
+}
@@ -94,7 +94,7 @@ fn parse_spell_damage() {
     ];
 
     // Act + Assert
-    let parse_result1 = server.parse_events(&mut conn, &armory, &data, messages, member_id);
+    let parse_result1 = server.parse_events(&mut conn, &armory, &data, messages, member_id, None);
     assert!(parse_result1.is_ok());
     assert_eq!(server.non_committed_events.get(&caster_unit_id).unwrap().len(), 1);
     assert_eq!(server.committed_events.get(&(caster_instance_id, member_id)).unwrap().len(), 2);
@@ -112,7 +112,7 @@ fn parse_spell_damage() {
         }),
     }];
 
-    let parse_result2 = server.parse_events(&mut conn, &armory, &data, messages, member_id);
+    let parse_result2 = server.parse_events(&mut conn, &armory, &data, messages, member_id, None);
     assert!(parse_result2.is_ok());
     assert_eq!(server.non_committed_events.get(&caster_unit_id).unwrap().len(), 2);
 
@@ -132,7 +132,7 @@ fn parse_spell_damage() {
         }),
     }];
 
-    let parse_result3 = server.parse_events(&mut conn, &armory, &data, messages, member_id);
+    let parse_result3 = server.parse_events(&mut conn, &armory, &data, messages, member_id, None);
     assert!(parse_result3.is_ok());
 
     let messages = vec![Message {
@@ -151,7 +151,7 @@ fn parse_spell_damage() {
         }),
     }];
 
-    let parse_result4 = server.parse_events(&mut conn, &armory, &data, messages, member_id);
+    let parse_result4 = server.parse_events(&mut conn, &armory, &data, messages, member_id, None);
     assert!(parse_result4.is_ok());
     assert_eq!(server.committed_events.get(&(caster_instance_id, member_id)).unwrap().len(), 5);
     assert!(!server.non_committed_events.contains_key(&caster_unit_id));
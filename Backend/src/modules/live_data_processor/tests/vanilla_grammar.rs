@@ -0,0 +1,91 @@
+use language::domain_value::Language;
+use language::material::Dictionary;
+
+use crate::modules::live_data_processor::language::init::Init;
+use crate::modules::live_data_processor::material::VanillaGrammar;
+
+fn grammars() -> Vec<VanillaGrammar> {
+    let dictionary = Dictionary::default();
+    Dictionary::init(&dictionary);
+    vec![
+        VanillaGrammar::new(&dictionary, Language::English),
+        VanillaGrammar::new(&dictionary, Language::German),
+        VanillaGrammar::new(&dictionary, Language::French),
+        VanillaGrammar::new(&dictionary, Language::Spanish),
+    ]
+}
+
+#[test]
+fn detect_locale_of_spell_damage() {
+    // Arrange
+    let grammars = grammars();
+    let lines = vec![
+        (Language::English, "Jaina 's Fireball crits Ragnaros for 2400 Fire damage."),
+        (Language::German, "Jainas Feuerball trifft Ragnaros kritisch für 2400 Feuerschaden."),
+        (Language::French, "Boule de feu de Jaina inflige un coup critique à Ragnaros (2400 points de dégâts (Feu))."),
+        (Language::Spanish, "Bola de Fuego de Jaina golpea críticamente a Ragnaros por 2400 de daño de Fuego."),
+    ];
+
+    for (language, line) in lines {
+        // Act
+        let detected = grammars.iter().find(|grammar| grammar.recognizes(line));
+
+        // Assert
+        assert!(detected.is_some());
+        assert_eq!(detected.unwrap().language, language);
+    }
+}
+
+#[test]
+fn named_groups_follow_locale_word_order() {
+    // Arrange
+    let grammars = grammars();
+    let french = &grammars[2];
+
+    // Act
+    let captures = french.damage_spell_hit_or_crit_school.captures("Boule de feu de Jaina touche Ragnaros et inflige 1200 points de dégâts (Feu).");
+
+    // Assert
+    assert!(captures.is_some());
+    let captures = captures.unwrap();
+    assert_eq!(captures.name("attacker").unwrap().as_str(), "Jaina");
+    assert_eq!(captures.name("spell").unwrap().as_str(), "Boule de feu");
+    assert_eq!(captures.name("victim").unwrap().as_str(), "Ragnaros");
+    assert_eq!(french.keyword(captures.name("kind").unwrap().as_str()), Some("hit"));
+    assert_eq!(french.keyword(captures.name("school").unwrap().as_str()), Some("fire"));
+}
+
+#[test]
+fn keywords_are_canonicalized() {
+    // Arrange
+    let grammars = grammars();
+    let german = &grammars[1];
+
+    // Act
+    let crit = german.keyword("kritisch");
+    let class = german.keyword("Kriegerin");
+    let english_token = german.keyword("Druid");
+    let unknown = german.keyword("Unbekannt");
+
+    // Assert
+    assert_eq!(crit, Some("crit"));
+    assert_eq!(class, Some("warrior"));
+    assert_eq!(english_token, Some("druid"));
+    assert_eq!(unknown, Some("unknown"));
+    assert_eq!(german.keyword("Ragnaros"), None);
+}
+
+#[test]
+fn possessive_only_matches_a_single_owner() {
+    // Arrange
+    let grammars = grammars();
+    let german = &grammars[1];
+
+    // Act
+    let owned = german.possessive.is_match("Jainas Feuerball");
+    let npc = german.possessive.is_match("Schattenwirker des Syndikats");
+
+    // Assert
+    assert!(owned);
+    assert!(!npc);
+}
@@ -312,6 +312,7 @@ impl CombatLogParser for WoWRetailClassicParser {
                         *ts,
                         CharacterDto {
                             server_uid: participant.id,
+                            note: None,
                             character_history: Some(CharacterHistoryDto {
                                 character_info: CharacterInfoDto {
                                     gear: CharacterGearDto {
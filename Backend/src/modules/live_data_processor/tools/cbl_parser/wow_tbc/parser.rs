@@ -515,6 +515,7 @@ impl CombatLogParser for WoWTBCParser {
                         *ts,
                         CharacterDto {
                             server_uid: participant.id,
+                            note: None,
                             character_history: Some(CharacterHistoryDto {
                                 character_info: CharacterInfoDto {
                                     gear: CharacterGearDto {
@@ -569,6 +570,7 @@ impl CombatLogParser for WoWTBCParser {
                     time_util::now() * 1000,
                     CharacterDto {
                         server_uid: participant.id,
+                        note: None,
                         character_history: Some(CharacterHistoryDto {
                             character_info: CharacterInfoDto {
                                 gear: CharacterGearDto {
@@ -1,9 +1,10 @@
 use crate::modules::data::tools::RetrieveSpell;
 use crate::modules::data::Data;
+use crate::modules::live_data_processor::material::VanillaGrammar;
 use std::collections::HashMap;
 
-pub fn parse_spell_args(cache: &mut HashMap<String, Option<u32>>, data: &Data, spell_name: &str) -> Option<u32> {
-    if spell_name == "Unknown" {
+pub fn parse_spell_args(cache: &mut HashMap<String, Option<u32>>, data: &Data, grammar: &VanillaGrammar, spell_name: &str) -> Option<u32> {
+    if grammar.keyword(spell_name) == Some("unknown") {
         return None;
     }
 
@@ -12,7 +13,7 @@ pub fn parse_spell_args(cache: &mut HashMap<String, Option<u32>>, data: &Data, s
         return *spell_id;
     }
 
-    let spell_id = data.get_spell_by_name(1, &spell_name).map(|spell| spell.id);
+    let spell_id = data.get_spell_by_name(1, grammar.data_language_id(), &spell_name).map(|spell| spell.id);
     cache.insert(spell_name, spell_id);
     spell_id
 }
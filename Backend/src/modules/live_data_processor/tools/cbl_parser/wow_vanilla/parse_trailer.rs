@@ -1,28 +1,34 @@
 use crate::modules::live_data_processor::domain_value::HitType;
+use crate::modules::live_data_processor::material::VanillaGrammar;
 
-pub fn parse_trailer(trailer: &str) -> Vec<(Option<u32>, HitType)> {
+pub fn parse_trailer(grammar: &VanillaGrammar, trailer: &str) -> Vec<(Option<u32>, HitType)> {
     let mut result = Vec::new();
     for ind_trailer in trailer.split(") (") {
         let ind_trailer = ind_trailer.replace("(", "").replace(")", "");
-        if ind_trailer == "glancing" {
+        let keyword = grammar.keyword(&ind_trailer);
+        if keyword == Some("glancing") {
             result.push((None, HitType::Glancing));
-        } else if ind_trailer == "crushing" {
+        } else if keyword == Some("crushing") {
             result.push((None, HitType::Crushing));
         } else if !ind_trailer.is_empty() {
-            let parts = ind_trailer.split(' ').collect::<Vec<&str>>();
+            let parts = ind_trailer.splitn(2, ' ').collect::<Vec<&str>>();
+            if parts.len() < 2 {
+                continue;
+            }
 
             // Some private servers seems to have implemented "Vulnerability Bonus" which was removed on 1.9
             // It is decided to ignore this vulnerability trailer.
-            if parts[1] == "vulnerability" {
+            let keyword = grammar.keyword(parts[1]);
+            if keyword == Some("vulnerability") {
                 continue;
             }
 
             if let Ok(amount) = u32::from_str_radix(&parts[0], 10) {
-                let hit_type = match parts[1] {
-                    "resisted" => HitType::PartialResist,
-                    "blocked" => HitType::PartialBlock,
-                    "absorbed" => HitType::PartialAbsorb,
-                    _ => unreachable!(),
+                let hit_type = match keyword {
+                    Some("resist") => HitType::PartialResist,
+                    Some("block") => HitType::PartialBlock,
+                    Some("absorb") => HitType::PartialAbsorb,
+                    _ => continue,
                 };
                 result.push((Some(amount), hit_type));
             }
@@ -1,10 +1,11 @@
 use crate::modules::data::Data;
 use crate::modules::live_data_processor::dto::Unit;
+use crate::modules::live_data_processor::material::VanillaGrammar;
 use crate::modules::live_data_processor::tools::cbl_parser::wow_vanilla::hashed_unit_id::{get_hashed_player_unit_id, get_npc_unit_id};
 use std::collections::HashMap;
 
-pub fn parse_unit(cache: &mut HashMap<String, Unit>, data: &Data, unit_name: &str) -> Option<Unit> {
-    if unit_name == "Unknown" {
+pub fn parse_unit(cache: &mut HashMap<String, Unit>, data: &Data, grammar: &VanillaGrammar, unit_name: &str) -> Option<Unit> {
+    if grammar.keyword(unit_name) == Some("unknown") {
         return None;
     }
 
@@ -14,11 +15,11 @@ pub fn parse_unit(cache: &mut HashMap<String, Unit>, data: &Data, unit_name: &st
     }
 
     let unit;
-    if let Some(unit_id) = get_npc_unit_id(data, &unit_name) {
+    if let Some(unit_id) = get_npc_unit_id(data, grammar.data_language_id(), &unit_name) {
         unit = Unit { is_player: false, unit_id };
     } else {
         // This indicates that something went terribly wrong during parsing
-        if unit_name.contains("'s ") {
+        if grammar.possessive.is_match(&unit_name) {
             return None;
         }
         unit = Unit {
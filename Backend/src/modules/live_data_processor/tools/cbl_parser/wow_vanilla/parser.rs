@@ -94,61 +94,18 @@ POWERGAINOTHEROTHER
 impl CombatLogParser for WoWVanillaParser {
     fn parse_cbl_line(&mut self, data: &Data, event_ts: u64, content: &str) -> Option<Vec<MessageType>> {
         lazy_static! {
-            static ref RE_DAMAGE_HIT_OR_CRIT: Regex = Regex::new(r"(.+[^\s]) (cr|h)its (.+[^\s]) for (\d+)\.\s?(.*)").unwrap();
-            static ref RE_DAMAGE_HIT_OR_CRIT_SCHOOL: Regex = Regex::new(r"(.+[^\s]) (cr|h)its (.+[^\s]) for (\d+) ([a-zA-Z]+) damage\.\s?(.*)").unwrap();
-            static ref RE_DAMAGE_MISS: Regex = Regex::new(r"(.+[^\s]) misses (.+[^\s])\.").unwrap();
-            static ref RE_DAMAGE_BLOCK_PARRY_EVADE_DODGE_DEFLECT: Regex = Regex::new(r"(.+[^\s]) attacks\. (.+[^\s]) (blocks|parries|evades|dodges|deflects)\.").unwrap();
-            static ref RE_DAMAGE_ABSORB_RESIST: Regex = Regex::new(r"(.+[^\s]) attacks\. (.+[^\s]) (absorbs|resists) all the damage\.").unwrap();
-            static ref RE_DAMAGE_IMMUNE: Regex = Regex::new(r"(.+[^\s]) attacks but (.+[^\s]) is immune\.").unwrap();
-
-            static ref RE_DAMAGE_SPELL_HIT_OR_CRIT: Regex = Regex::new(r"(.+[^\s])\s's (.+[^\s]) (cr|h)its (.+[^\s]) for (\d+)\.\s?(.*)").unwrap();
-            static ref RE_DAMAGE_SPELL_HIT_OR_CRIT_SCHOOL: Regex = Regex::new(r"(.+[^\s])\s's (.+[^\s]) (cr|h)its (.+[^\s]) for (\d+) ([a-zA-Z]+) damage\.\s?(.*)").unwrap();
-            static ref RE_DAMAGE_PERIODIC: Regex = Regex::new(r"(.+[^\s]) suffers (\d+) ([a-zA-Z]+) damage from (.+[^\s])\s's (.+[^\s])\.\s?(.*)").unwrap();
-            static ref RE_DAMAGE_SPELL_SPLIT: Regex = Regex::new(r"(.+[^\s])\s's (.+[^\s]) causes (.+[^\s]) (\d+) damage\.\s?(.*)").unwrap();
-            static ref RE_DAMAGE_SPELL_MISS: Regex = Regex::new(r"(.+[^\s])\s's (.+[^\s]) misse(s|d) (.+[^\s])\.").unwrap();
-            static ref RE_DAMAGE_SPELL_BLOCK_PARRY_EVADE_DODGE_RESIST_DEFLECT: Regex = Regex::new(r"(.+[^\s])\s's (.+[^\s]) was (blocked|parried|evaded|dodged|resisted|deflected) by (.+[^\s])\.").unwrap();
-            static ref RE_DAMAGE_SPELL_ABSORB: Regex = Regex::new(r"(.+[^\s])\s's (.+[^\s]) is absorbed by (.+[^\s])\.").unwrap();
-            static ref RE_DAMAGE_SPELL_ABSORB_SELF: Regex = Regex::new(r"(.+[^\s]) absorbs (.+[^\s])\s's (.+[^\s])\.").unwrap();
-            static ref RE_DAMAGE_REFLECT: Regex = Regex::new(r"(.+[^\s])\s's (.+[^\s]) is reflected back by (.+[^\s])\.").unwrap();
-            static ref RE_DAMAGE_PROC_RESIST: Regex = Regex::new(r"(.+[^\s]) resists (.+[^\s])\s's (.+[^\s])\.").unwrap();
-            static ref RE_DAMAGE_SPELL_IMMUNE: Regex = Regex::new(r"(.+[^\s])\s's (.+[^\s]) fails\. (.+[^\s]) is immune\.").unwrap();
-            static ref RE_SPELL_CAST_ATTEMPT: Regex = Regex::new(r"(.+[^\s]) begins to cast (.+[^\s])\.").unwrap();
-
-            static ref RE_DAMAGE_SHIELD: Regex = Regex::new(r"(.+[^\s]) reflects (\d+) ([a-zA-Z]+) damage to (.+[^\s])\.").unwrap(); // Ability?
-
-            static ref RE_HEAL_HIT: Regex = Regex::new(r"(.+[^\s])\s's (.+[^\s]) heals (.+[^\s]) for (\d+)\.").unwrap();
-            static ref RE_HEAL_CRIT: Regex = Regex::new(r"(.+[^\s])\s's (.+[^\s]) critically heals (.+[^\s]) for (\d+)\.").unwrap();
-            static ref RE_GAIN: Regex = Regex::new(r"(.+[^\s]) gains (\d+) (Health|health|Mana|Rage|Energy|Happiness|Focus) from (.+[^\s])\s's (.+[^\s])\.").unwrap();
-
-            // Somehow track the owner?
-            static ref RE_AURA_GAIN_HARMFUL_HELPFUL: Regex = Regex::new(r"(.+[^\s]) (is afflicted by|gains) (.+[^\s]) \((\d+)\)\.").unwrap();
-            static ref RE_AURA_FADE: Regex = Regex::new(r"(.+[^\s]) fades from (.+[^\s])\.").unwrap();
-
-            // Find dispeller
-            static ref RE_AURA_DISPEL: Regex = Regex::new(r"(.+[^\s])\s's (.+[^\s]) is removed\.").unwrap();
-            static ref RE_AURA_INTERRUPT: Regex = Regex::new(r"(.+[^\s]) interrupts (.+[^\s])\s's (.+[^\s])\.").unwrap();
-
-            static ref RE_SPELL_CAST_PERFORM_DURABILITY: Regex = Regex::new(r"(.+[^\s]) (casts|performs) (.+[^\s]) on (.+[^\s]): (.+)\.").unwrap();
-            static ref RE_SPELL_CAST_PERFORM: Regex = Regex::new(r"(.+[^\s]) (casts|performs) (.+[^\s]) on (.+[^\s])\.").unwrap();
-            static ref RE_SPELL_CAST_PERFORM_UNKNOWN: Regex = Regex::new(r"(.+[^\s]) (casts|performs) (.+[^\s])\.").unwrap();
-
-            static ref RE_UNIT_DIE_DESTROYED: Regex = Regex::new(r"(.+[^\s]) (dies|is destroyed)\.").unwrap();
-            static ref RE_UNIT_SLAY: Regex = Regex::new(r"(.+[^\s]) is slain by (.+[^\s])(!|\.)").unwrap();
-
             static ref RE_ZONE_INFO: Regex = Regex::new(r"ZONE_INFO: ([^&]+)&(.+[^\s])\&(\d+)").unwrap();
-            static ref RE_LOOT: Regex = Regex::new(r"LOOT: ([^&]+)&(.+[^\s]) receives loot: \|c([a-zA-Z0-9]+)\|Hitem:(\d+):(\d+):(\d+):(\d+)\|h\[([a-zA-Z0-9\s']+)\]\|h\|rx(\d+)\.").unwrap();
-
-            // Bugs?
-            static ref RE_BUG_DAMAGE_SPELL_HIT_OR_CRIT: Regex = Regex::new(r"(.+[^\s])\s's (cr|h)its (.+[^\s]) for (\d+)\.\s?(.*)").unwrap();
+            static ref RE_LOOT: Regex = Regex::new(r"LOOT: ([^&]+)&(.+[^\s]) receives loot: \|c([a-zA-Z0-9]+)\|Hitem:(\d+):(\d+):(\d+):(\d+)\|h\[([^\]]+)\]\|h\|rx(\d+)\.").unwrap();
         }
+        let grammar = self.grammar(content);
 
-        if RE_BUG_DAMAGE_SPELL_HIT_OR_CRIT.captures(&content).is_some() {
+        if grammar.bug_damage_spell_hit_or_crit.is_match(content) {
             return None;
         }
 
-        if let Some(captures) = RE_SPELL_CAST_ATTEMPT.captures(&content) {
-            let caster = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(2)?.as_str())?;
+        if let Some(captures) = grammar.spell_cast_attempt.captures(content) {
+            let caster = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("caster")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
 
             return Some(vec![MessageType::SpellCastAttempt(SpellCast {
                 caster,
@@ -158,17 +115,17 @@ impl CombatLogParser for WoWVanillaParser {
             })]);
         }
 
-        if let Some(captures) = RE_GAIN.captures(&content) {
-            if !captures.get(3)?.as_str().contains("ealth") {
+        if let Some(captures) = grammar.gain.captures(content) {
+            if grammar.keyword(captures.name("power")?.as_str()) != Some("health") {
                 return None;
             }
 
-            let target = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let amount = u32::from_str_radix(captures.get(2)?.as_str(), 10).ok()?;
-            let caster = parse_unit(&mut self.cache_unit, data, captures.get(4)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(5)?.as_str())?;
-            self.collect_participant(&caster, captures.get(4)?.as_str(), event_ts);
-            self.collect_participant(&target, captures.get(1)?.as_str(), event_ts);
+            let target = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("target")?.as_str())?;
+            let amount = u32::from_str_radix(captures.name("amount")?.as_str(), 10).ok()?;
+            let caster = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("caster")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            self.collect_participant(&caster, captures.name("caster")?.as_str(), event_ts);
+            self.collect_participant(&target, captures.name("target")?.as_str(), event_ts);
             self.collect_active_map(data, &caster, event_ts);
             self.collect_active_map(data, &target, event_ts);
             let effective_heal = self.participants.get_mut(&target.unit_id).unwrap().attribute_heal(amount);
@@ -195,16 +152,16 @@ impl CombatLogParser for WoWVanillaParser {
         /*
          * Spell Damage
          */
-        if let Some(captures) = RE_DAMAGE_SPELL_HIT_OR_CRIT.captures(&content) {
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(2)?.as_str())?;
-            let mut hit_mask = if captures.get(3)?.as_str() == "cr" { HitType::Crit as u32 } else { HitType::Hit as u32 };
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(4)?.as_str())?;
-            let damage = u32::from_str_radix(captures.get(5)?.as_str(), 10).ok()?;
-            let trailer = parse_trailer(captures.get(6)?.as_str());
+        if let Some(captures) = grammar.damage_spell_hit_or_crit.captures(content) {
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            let mut hit_mask = if grammar.keyword(captures.name("kind")?.as_str()) == Some("crit") { HitType::Crit as u32 } else { HitType::Hit as u32 };
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            let damage = u32::from_str_radix(captures.name("amount")?.as_str(), 10).ok()?;
+            let trailer = parse_trailer(&grammar, captures.name("trailer")?.as_str());
             trailer.iter().for_each(|(_, hit_type)| hit_mask |= hit_type.clone() as u32);
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(4)?.as_str(), event_ts);
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
             self.participants.get_mut(&victim.unit_id).unwrap().attribute_damage(damage);
@@ -233,26 +190,26 @@ impl CombatLogParser for WoWVanillaParser {
             ]);
         }
 
-        if let Some(captures) = RE_DAMAGE_SPELL_HIT_OR_CRIT_SCHOOL.captures(&content) {
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(2)?.as_str())?;
-            let mut hit_mask = if captures.get(3)?.as_str() == "cr" { HitType::Crit as u32 } else { HitType::Hit as u32 };
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(4)?.as_str())?;
-            let damage = u32::from_str_radix(captures.get(5)?.as_str(), 10).ok()?;
-            let school = match captures.get(6)?.as_str() {
-                "Physical" => School::Physical,
-                "Arcane" => School::Arcane,
-                "Fire" => School::Fire,
-                "Frost" => School::Frost,
-                "Shadow" => School::Shadow,
-                "Nature" => School::Nature,
-                "Holy" => School::Holy,
-                _ => unreachable!(),
+        if let Some(captures) = grammar.damage_spell_hit_or_crit_school.captures(content) {
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            let mut hit_mask = if grammar.keyword(captures.name("kind")?.as_str()) == Some("crit") { HitType::Crit as u32 } else { HitType::Hit as u32 };
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            let damage = u32::from_str_radix(captures.name("amount")?.as_str(), 10).ok()?;
+            let school = match grammar.keyword(captures.name("school")?.as_str())? {
+                "physical" => School::Physical,
+                "arcane" => School::Arcane,
+                "fire" => School::Fire,
+                "frost" => School::Frost,
+                "shadow" => School::Shadow,
+                "nature" => School::Nature,
+                "holy" => School::Holy,
+                _ => return None,
             };
-            let trailer = parse_trailer(captures.get(7)?.as_str());
+            let trailer = parse_trailer(&grammar, captures.name("trailer")?.as_str());
             trailer.iter().for_each(|(_, hit_type)| hit_mask |= hit_type.clone() as u32);
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(4)?.as_str(), event_ts);
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
             self.participants.get_mut(&victim.unit_id).unwrap().attribute_damage(damage);
@@ -281,27 +238,27 @@ impl CombatLogParser for WoWVanillaParser {
             ]);
         }
 
-        if let Some(captures) = RE_DAMAGE_PERIODIC.captures(&content) {
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let damage = u32::from_str_radix(captures.get(2)?.as_str(), 10).ok()?;
-            let school = match captures.get(3)?.as_str() {
-                "Physical" => School::Physical,
-                "Arcane" => School::Arcane,
-                "Fire" => School::Fire,
-                "Frost" => School::Frost,
-                "Shadow" => School::Shadow,
-                "Nature" => School::Nature,
-                "Holy" => School::Holy,
-                _ => unreachable!(),
+        if let Some(captures) = grammar.damage_periodic.captures(content) {
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            let damage = u32::from_str_radix(captures.name("amount")?.as_str(), 10).ok()?;
+            let school = match grammar.keyword(captures.name("school")?.as_str())? {
+                "physical" => School::Physical,
+                "arcane" => School::Arcane,
+                "fire" => School::Fire,
+                "frost" => School::Frost,
+                "shadow" => School::Shadow,
+                "nature" => School::Nature,
+                "holy" => School::Holy,
+                _ => return None,
             };
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(4)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(5)?.as_str())?;
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
 
             let mut hit_mask = HitType::Hit as u32;
-            let trailer = parse_trailer(captures.get(6)?.as_str());
+            let trailer = parse_trailer(&grammar, captures.name("trailer")?.as_str());
             trailer.iter().for_each(|(_, hit_type)| hit_mask |= hit_type.clone() as u32);
-            self.collect_participant(&victim, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&attacker, captures.get(4)?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
             self.participants.get_mut(&victim.unit_id).unwrap().attribute_damage(damage);
@@ -330,23 +287,23 @@ impl CombatLogParser for WoWVanillaParser {
             ]);
         }
 
-        if let Some(captures) = RE_DAMAGE_SHIELD.captures(&content) {
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let damage = u32::from_str_radix(captures.get(2)?.as_str(), 10).ok()?;
-            let school = match captures.get(3)?.as_str() {
-                "Physical" => School::Physical,
-                "Arcane" => School::Arcane,
-                "Fire" => School::Fire,
-                "Frost" => School::Frost,
-                "Shadow" => School::Shadow,
-                "Nature" => School::Nature,
-                "Holy" => School::Holy,
-                _ => unreachable!(),
+        if let Some(captures) = grammar.damage_shield.captures(content) {
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let damage = u32::from_str_radix(captures.name("amount")?.as_str(), 10).ok()?;
+            let school = match grammar.keyword(captures.name("school")?.as_str())? {
+                "physical" => School::Physical,
+                "arcane" => School::Arcane,
+                "fire" => School::Fire,
+                "frost" => School::Frost,
+                "shadow" => School::Shadow,
+                "nature" => School::Nature,
+                "holy" => School::Holy,
+                _ => return None,
             };
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(4)?.as_str())?;
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
             let spell_id = 2; // Thats our reflection spell
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(4)?.as_str(), event_ts);
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
             self.participants.get_mut(&victim.unit_id).unwrap().attribute_damage(damage);
@@ -378,15 +335,15 @@ impl CombatLogParser for WoWVanillaParser {
         /*
          * Melee Damage
          */
-        if let Some(captures) = RE_DAMAGE_HIT_OR_CRIT.captures(&content) {
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let mut hit_mask = if captures.get(2)?.as_str() == "cr" { HitType::Crit as u32 } else { HitType::Hit as u32 };
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(3)?.as_str())?;
-            let damage = u32::from_str_radix(captures.get(4)?.as_str(), 10).ok()?;
-            let trailer = parse_trailer(captures.get(5)?.as_str());
+        if let Some(captures) = grammar.damage_hit_or_crit.captures(content) {
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let mut hit_mask = if grammar.keyword(captures.name("kind")?.as_str()) == Some("crit") { HitType::Crit as u32 } else { HitType::Hit as u32 };
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            let damage = u32::from_str_radix(captures.name("amount")?.as_str(), 10).ok()?;
+            let trailer = parse_trailer(&grammar, captures.name("trailer")?.as_str());
             trailer.iter().for_each(|(_, hit_type)| hit_mask |= hit_type.clone() as u32);
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(3)?.as_str(), event_ts);
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
             self.participants.get_mut(&victim.unit_id).unwrap().attribute_damage(damage);
@@ -407,25 +364,25 @@ impl CombatLogParser for WoWVanillaParser {
             })]);
         }
 
-        if let Some(captures) = RE_DAMAGE_HIT_OR_CRIT_SCHOOL.captures(&content) {
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let mut hit_mask = if captures.get(2)?.as_str() == "cr" { HitType::Crit as u32 } else { HitType::Hit as u32 };
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(3)?.as_str())?;
-            let damage = u32::from_str_radix(captures.get(4)?.as_str(), 10).ok()?;
-            let school = match captures.get(5)?.as_str() {
-                "Physical" => School::Physical,
-                "Arcane" => School::Arcane,
-                "Fire" => School::Fire,
-                "Frost" => School::Frost,
-                "Shadow" => School::Shadow,
-                "Nature" => School::Nature,
-                "Holy" => School::Holy,
-                _ => unreachable!(),
+        if let Some(captures) = grammar.damage_hit_or_crit_school.captures(content) {
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let mut hit_mask = if grammar.keyword(captures.name("kind")?.as_str()) == Some("crit") { HitType::Crit as u32 } else { HitType::Hit as u32 };
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            let damage = u32::from_str_radix(captures.name("amount")?.as_str(), 10).ok()?;
+            let school = match grammar.keyword(captures.name("school")?.as_str())? {
+                "physical" => School::Physical,
+                "arcane" => School::Arcane,
+                "fire" => School::Fire,
+                "frost" => School::Frost,
+                "shadow" => School::Shadow,
+                "nature" => School::Nature,
+                "holy" => School::Holy,
+                _ => return None,
             };
-            let trailer = parse_trailer(captures.get(6)?.as_str());
+            let trailer = parse_trailer(&grammar, captures.name("trailer")?.as_str());
             trailer.iter().for_each(|(_, hit_type)| hit_mask |= hit_type.clone() as u32);
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(3)?.as_str(), event_ts);
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
             self.participants.get_mut(&victim.unit_id).unwrap().attribute_damage(damage);
@@ -450,14 +407,14 @@ impl CombatLogParser for WoWVanillaParser {
          * Heal
          */
 
-        if let Some(captures) = RE_HEAL_CRIT.captures(&content) {
-            let caster = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(2)?.as_str())?;
+        if let Some(captures) = grammar.heal_crit.captures(content) {
+            let caster = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("caster")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
             let hit_mask = HitType::Crit as u32;
-            let target = parse_unit(&mut self.cache_unit, data, captures.get(3)?.as_str())?;
-            let amount = u32::from_str_radix(captures.get(4)?.as_str(), 10).ok()?;
-            self.collect_participant(&caster, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&target, captures.get(3)?.as_str(), event_ts);
+            let target = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("target")?.as_str())?;
+            let amount = u32::from_str_radix(captures.name("amount")?.as_str(), 10).ok()?;
+            self.collect_participant(&caster, captures.name("caster")?.as_str(), event_ts);
+            self.collect_participant(&target, captures.name("target")?.as_str(), event_ts);
             self.collect_active_map(data, &caster, event_ts);
             self.collect_active_map(data, &target, event_ts);
             let effective_heal = self.participants.get_mut(&target.unit_id).unwrap().attribute_heal(amount);
@@ -481,14 +438,14 @@ impl CombatLogParser for WoWVanillaParser {
             ]);
         }
 
-        if let Some(captures) = RE_HEAL_HIT.captures(&content) {
-            let caster = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(2)?.as_str())?;
+        if let Some(captures) = grammar.heal_hit.captures(content) {
+            let caster = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("caster")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
             let hit_mask = HitType::Hit as u32;
-            let target = parse_unit(&mut self.cache_unit, data, captures.get(3)?.as_str())?;
-            let amount = u32::from_str_radix(captures.get(4)?.as_str(), 10).ok()?;
-            self.collect_participant(&caster, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&target, captures.get(3)?.as_str(), event_ts);
+            let target = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("target")?.as_str())?;
+            let amount = u32::from_str_radix(captures.name("amount")?.as_str(), 10).ok()?;
+            self.collect_participant(&caster, captures.name("caster")?.as_str(), event_ts);
+            self.collect_participant(&target, captures.name("target")?.as_str(), event_ts);
             self.collect_active_map(data, &caster, event_ts);
             self.collect_active_map(data, &target, event_ts);
             let effective_heal = self.participants.get_mut(&target.unit_id).unwrap().attribute_heal(amount);
@@ -515,12 +472,12 @@ impl CombatLogParser for WoWVanillaParser {
         /*
          * Aura Application
          */
-        if let Some(captures) = RE_AURA_GAIN_HARMFUL_HELPFUL.captures(&content) {
-            let target = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(3)?.as_str())?;
-            let stack_amount = u8::from_str_radix(captures.get(4)?.as_str(), 10).ok()?;
+        if let Some(captures) = grammar.aura_gain.captures(content) {
+            let target = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("target")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            let stack_amount = u8::from_str_radix(captures.name("stacks")?.as_str(), 10).ok()?;
             let caster = Unit { is_player: true, unit_id: 0 };
-            self.collect_participant(&target, captures.get(1)?.as_str(), event_ts);
+            self.collect_participant(&target, captures.name("target")?.as_str(), event_ts);
             self.collect_active_map(data, &target, event_ts);
 
             return Some(vec![MessageType::AuraApplication(AuraApplication {
@@ -532,11 +489,11 @@ impl CombatLogParser for WoWVanillaParser {
             })]);
         }
 
-        if let Some(captures) = RE_AURA_FADE.captures(&content) {
-            let target = parse_unit(&mut self.cache_unit, data, captures.get(2)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(1)?.as_str())?;
+        if let Some(captures) = grammar.aura_fade.captures(content) {
+            let target = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("target")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
             let caster = Unit { is_player: true, unit_id: 0 };
-            self.collect_participant(&target, captures.get(2)?.as_str(), event_ts);
+            self.collect_participant(&target, captures.name("target")?.as_str(), event_ts);
             self.collect_active_map(data, &target, event_ts);
 
             return Some(vec![MessageType::AuraApplication(AuraApplication {
@@ -551,17 +508,17 @@ impl CombatLogParser for WoWVanillaParser {
         /*
          * Spell damage continued
          */
-        if let Some(captures) = RE_DAMAGE_SPELL_SPLIT.captures(&content) {
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(2)?.as_str())?;
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(3)?.as_str())?;
-            let damage = u32::from_str_radix(captures.get(4)?.as_str(), 10).ok()?;
+        if let Some(captures) = grammar.damage_spell_split.captures(content) {
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            let damage = u32::from_str_radix(captures.name("amount")?.as_str(), 10).ok()?;
 
             let mut hit_mask = HitType::Hit as u32;
-            let trailer = parse_trailer(captures.get(5)?.as_str());
+            let trailer = parse_trailer(&grammar, captures.name("trailer")?.as_str());
             trailer.iter().for_each(|(_, hit_type)| hit_mask |= hit_type.clone() as u32);
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(3)?.as_str(), event_ts);
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
             self.participants.get_mut(&victim.unit_id).unwrap().attribute_damage(damage);
@@ -590,12 +547,12 @@ impl CombatLogParser for WoWVanillaParser {
             ]);
         }
 
-        if let Some(captures) = RE_DAMAGE_SPELL_MISS.captures(&content) {
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(2)?.as_str())?;
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(4)?.as_str())?;
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(4)?.as_str(), event_ts);
+        if let Some(captures) = grammar.damage_spell_miss.captures(content) {
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
 
@@ -618,21 +575,21 @@ impl CombatLogParser for WoWVanillaParser {
             ]);
         }
 
-        if let Some(captures) = RE_DAMAGE_SPELL_BLOCK_PARRY_EVADE_DODGE_RESIST_DEFLECT.captures(&content) {
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(2)?.as_str())?;
-            let hit_type = match captures.get(3)?.as_str() {
-                "blocked" => HitType::FullBlock,
-                "parried" => HitType::Parry,
-                "evaded" => HitType::Evade,
-                "dodged" => HitType::Dodge,
-                "deflected" => HitType::Deflect,
-                "resisted" => HitType::FullResist,
-                _ => unreachable!(),
+        if let Some(captures) = grammar.damage_spell_avoid.captures(content) {
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            let hit_type = match grammar.keyword(captures.name("avoid")?.as_str())? {
+                "block" => HitType::FullBlock,
+                "parry" => HitType::Parry,
+                "evade" => HitType::Evade,
+                "dodge" => HitType::Dodge,
+                "deflect" => HitType::Deflect,
+                "resist" => HitType::FullResist,
+                _ => return None,
             };
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(4)?.as_str())?;
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(4)?.as_str(), event_ts);
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
 
@@ -655,12 +612,12 @@ impl CombatLogParser for WoWVanillaParser {
             ]);
         }
 
-        if let Some(captures) = RE_DAMAGE_SPELL_ABSORB.captures(&content) {
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(2)?.as_str())?;
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(3)?.as_str())?;
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(3)?.as_str(), event_ts);
+        if let Some(captures) = grammar.damage_spell_absorb.captures(content) {
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
 
@@ -683,12 +640,12 @@ impl CombatLogParser for WoWVanillaParser {
             ]);
         }
 
-        if let Some(captures) = RE_DAMAGE_SPELL_ABSORB_SELF.captures(&content) {
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(2)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(3)?.as_str())?;
-            self.collect_participant(&victim, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&attacker, captures.get(2)?.as_str(), event_ts);
+        if let Some(captures) = grammar.damage_spell_absorb_self.captures(content) {
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
 
@@ -711,12 +668,12 @@ impl CombatLogParser for WoWVanillaParser {
             ]);
         }
 
-        if let Some(captures) = RE_DAMAGE_REFLECT.captures(&content) {
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(2)?.as_str())?;
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(3)?.as_str())?;
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(3)?.as_str(), event_ts);
+        if let Some(captures) = grammar.damage_reflect.captures(content) {
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
 
@@ -739,12 +696,12 @@ impl CombatLogParser for WoWVanillaParser {
             ]);
         }
 
-        if let Some(captures) = RE_DAMAGE_PROC_RESIST.captures(&content) {
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(2)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(3)?.as_str())?;
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(2)?.as_str(), event_ts);
+        if let Some(captures) = grammar.damage_proc_resist.captures(content) {
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
 
@@ -767,12 +724,12 @@ impl CombatLogParser for WoWVanillaParser {
             ]);
         }
 
-        if let Some(captures) = RE_DAMAGE_SPELL_IMMUNE.captures(&content) {
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(2)?.as_str())?;
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(3)?.as_str())?;
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(3)?.as_str(), event_ts);
+        if let Some(captures) = grammar.damage_spell_immune.captures(content) {
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
 
@@ -798,11 +755,11 @@ impl CombatLogParser for WoWVanillaParser {
         /*
          * Melee Damage continued
          */
-        if let Some(captures) = RE_DAMAGE_MISS.captures(&content) {
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(2)?.as_str())?;
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(2)?.as_str(), event_ts);
+        if let Some(captures) = grammar.damage_miss.captures(content) {
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
 
@@ -817,19 +774,19 @@ impl CombatLogParser for WoWVanillaParser {
             })]);
         }
 
-        if let Some(captures) = RE_DAMAGE_BLOCK_PARRY_EVADE_DODGE_DEFLECT.captures(&content) {
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(2)?.as_str())?;
-            let hit_type = match captures.get(3)?.as_str() {
-                "blocks" => HitType::FullBlock,
-                "parries" => HitType::Parry,
-                "evades" => HitType::Evade,
-                "dodges" => HitType::Dodge,
-                "deflects" => HitType::Deflect,
-                _ => unreachable!(),
+        if let Some(captures) = grammar.damage_avoid.captures(content) {
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            let hit_type = match grammar.keyword(captures.name("avoid")?.as_str())? {
+                "block" => HitType::FullBlock,
+                "parry" => HitType::Parry,
+                "evade" => HitType::Evade,
+                "dodge" => HitType::Dodge,
+                "deflect" => HitType::Deflect,
+                _ => return None,
             };
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(2)?.as_str(), event_ts);
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
 
@@ -844,16 +801,16 @@ impl CombatLogParser for WoWVanillaParser {
             })]);
         }
 
-        if let Some(captures) = RE_DAMAGE_ABSORB_RESIST.captures(&content) {
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(2)?.as_str())?;
-            let hit_type = match captures.get(3)?.as_str() {
-                "absorbs" => HitType::FullAbsorb,
-                "resists" => HitType::FullResist,
-                _ => unreachable!(),
+        if let Some(captures) = grammar.damage_absorb_resist.captures(content) {
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            let hit_type = match grammar.keyword(captures.name("avoid")?.as_str())? {
+                "absorb" => HitType::FullAbsorb,
+                "resist" => HitType::FullResist,
+                _ => return None,
             };
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(2)?.as_str(), event_ts);
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
 
@@ -868,11 +825,11 @@ impl CombatLogParser for WoWVanillaParser {
             })]);
         }
 
-        if let Some(captures) = RE_DAMAGE_IMMUNE.captures(&content) {
-            let attacker = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(2)?.as_str())?;
-            self.collect_participant(&attacker, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&victim, captures.get(2)?.as_str(), event_ts);
+        if let Some(captures) = grammar.damage_immune.captures(content) {
+            let attacker = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            self.collect_participant(&attacker, captures.name("attacker")?.as_str(), event_ts);
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &attacker, event_ts);
             self.collect_active_map(data, &victim, event_ts);
 
@@ -890,12 +847,12 @@ impl CombatLogParser for WoWVanillaParser {
         /*
          * Spell casts
          */
-        if let Some(captures) = RE_SPELL_CAST_PERFORM_DURABILITY.captures(&content) {
-            let caster = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(3)?.as_str())?;
-            let target = parse_unit(&mut self.cache_unit, data, captures.get(4)?.as_str())?;
-            self.collect_participant(&caster, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&target, captures.get(4)?.as_str(), event_ts);
+        if let Some(captures) = grammar.spell_cast_perform_durability.captures(content) {
+            let caster = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("caster")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            let target = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("target")?.as_str())?;
+            self.collect_participant(&caster, captures.name("caster")?.as_str(), event_ts);
+            self.collect_participant(&target, captures.name("target")?.as_str(), event_ts);
             self.collect_active_map(data, &caster, event_ts);
             self.collect_active_map(data, &target, event_ts);
 
@@ -907,12 +864,12 @@ impl CombatLogParser for WoWVanillaParser {
             })]);
         }
 
-        if let Some(captures) = RE_SPELL_CAST_PERFORM.captures(&content) {
-            let caster = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(3)?.as_str())?;
-            let target = parse_unit(&mut self.cache_unit, data, captures.get(4)?.as_str())?;
-            self.collect_participant(&caster, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&target, captures.get(4)?.as_str(), event_ts);
+        if let Some(captures) = grammar.spell_cast_perform.captures(content) {
+            let caster = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("caster")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            let target = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("target")?.as_str())?;
+            self.collect_participant(&caster, captures.name("caster")?.as_str(), event_ts);
+            self.collect_participant(&target, captures.name("target")?.as_str(), event_ts);
             self.collect_active_map(data, &caster, event_ts);
             self.collect_active_map(data, &target, event_ts);
 
@@ -924,10 +881,10 @@ impl CombatLogParser for WoWVanillaParser {
             })]);
         }
 
-        if let Some(captures) = RE_SPELL_CAST_PERFORM_UNKNOWN.captures(&content) {
-            let caster = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(3)?.as_str())?;
-            self.collect_participant(&caster, captures.get(1)?.as_str(), event_ts);
+        if let Some(captures) = grammar.spell_cast_perform_unknown.captures(content) {
+            let caster = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("caster")?.as_str())?;
+            let spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            self.collect_participant(&caster, captures.name("caster")?.as_str(), event_ts);
             self.collect_active_map(data, &caster, event_ts);
 
             return Some(vec![MessageType::SpellCast(SpellCast {
@@ -941,18 +898,18 @@ impl CombatLogParser for WoWVanillaParser {
         /*
          * Unit Death
          */
-        if let Some(captures) = RE_UNIT_DIE_DESTROYED.captures(&content) {
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            self.collect_participant(&victim, captures.get(1)?.as_str(), event_ts);
+        if let Some(captures) = grammar.unit_die_destroyed.captures(content) {
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
             self.collect_active_map(data, &victim, event_ts);
             return Some(vec![MessageType::Death(Death { cause: None, victim })]);
         }
 
-        if let Some(captures) = RE_UNIT_SLAY.captures(&content) {
-            let victim = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let cause = parse_unit(&mut self.cache_unit, data, captures.get(2)?.as_str())?;
-            self.collect_participant(&victim, captures.get(1)?.as_str(), event_ts);
-            self.collect_participant(&cause, captures.get(2)?.as_str(), event_ts);
+        if let Some(captures) = grammar.unit_slay.captures(content) {
+            let victim = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("victim")?.as_str())?;
+            let cause = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("attacker")?.as_str())?;
+            self.collect_participant(&victim, captures.name("victim")?.as_str(), event_ts);
+            self.collect_participant(&cause, captures.name("attacker")?.as_str(), event_ts);
             self.collect_active_map(data, &victim, event_ts);
             self.collect_active_map(data, &cause, event_ts);
             return Some(vec![MessageType::Death(Death { cause: Some(cause), victim })]);
@@ -970,7 +927,7 @@ impl CombatLogParser for WoWVanillaParser {
         for i_content in content_vec {
             if let Some(captures) = RE_LOOT.captures(&i_content) {
                 let timestamp = NaiveDateTime::parse_from_str(captures.get(1)?.as_str(), "%d.%m.%y %H:%M:%S").ok()?.timestamp_millis();
-                let receiver = parse_unit(&mut self.cache_unit, data, captures.get(2)?.as_str())?;
+                let receiver = parse_unit(&mut self.cache_unit, data, &grammar, captures.get(2)?.as_str())?;
                 self.collect_participant(&receiver, captures.get(2)?.as_str(), event_ts);
                 self.collect_active_map(data, &receiver, event_ts);
                 let item_id = u32::from_str_radix(captures.get(4)?.as_str(), 10).ok()?;
@@ -983,7 +940,7 @@ impl CombatLogParser for WoWVanillaParser {
                 let timestamp = NaiveDateTime::parse_from_str(captures.get(1)?.as_str(), "%d.%m.%y %H:%M:%S").ok()?.timestamp_millis();
                 let map_name = captures.get(2)?.as_str().to_string();
                 let instance_id = u32::from_str_radix(captures.get(3)?.as_str(), 10).ok()?;
-                if let Some(map) = data.get_map_by_name(&map_name) {
+                if let Some(map) = data.get_map_by_name(grammar.data_language_id(), &map_name) {
                     self.bonus_messages.push(Message::new_parsed(
                         timestamp as u64,
                         0,
@@ -1006,7 +963,7 @@ impl CombatLogParser for WoWVanillaParser {
 
                 let unit_id = get_hashed_player_unit_id(player_name);
                 if pet_name != "nil" && !pet_name.is_empty() {
-                    let pet_unit = parse_unit(&mut self.cache_unit, data, pet_name)?;
+                    let pet_unit = parse_unit(&mut self.cache_unit, data, &grammar, pet_name)?;
                     self.pet_owner.insert(pet_unit.unit_id, unit_id);
                 }
                 continue;
@@ -1032,7 +989,7 @@ impl CombatLogParser for WoWVanillaParser {
             let unit_id = get_hashed_player_unit_id(player_name);
             let participant = self.participants.entry(unit_id).or_insert_with(|| Participant::new(unit_id, true, player_name.to_string(), event_ts));
             if participant.hero_class_id.is_none() {
-                participant.hero_class_id = Some(match hero_class_local.as_str() {
+                participant.hero_class_id = Some(match grammar.keyword(&hero_class_local)? {
                     "warrior" => 1,
                     "paladin" => 2,
                     "hunter" => 3,
@@ -1041,7 +998,7 @@ impl CombatLogParser for WoWVanillaParser {
                     "shaman" => 7,
                     "mage" => 8,
                     "warlock" => 9,
-                    "Druid" => 11,
+                    "druid" => 11,
                     _ => return None,
                 });
             }
@@ -1055,14 +1012,12 @@ impl CombatLogParser for WoWVanillaParser {
             }
 
             if participant.race_id.is_none() {
-                participant.race_id = Some(match race_local.as_str() {
+                participant.race_id = Some(match grammar.keyword(&race_local)? {
                     "human" => 1,
                     "orc" => 2,
                     "dwarf" => 3,
-                    "night elf" => 4,
-                    "nightelf" => 4,
+                    "night_elf" => 4,
                     "undead" => 5,
-                    "scourge" => 5,
                     "tauren" => 6,
                     "gnome" => 7,
                     "troll" => 8,
@@ -1076,7 +1031,7 @@ impl CombatLogParser for WoWVanillaParser {
             }
 
             if pet_name != "nil" && !pet_name.is_empty() {
-                let pet_unit = parse_unit(&mut self.cache_unit, data, pet_name)?;
+                let pet_unit = parse_unit(&mut self.cache_unit, data, &grammar, pet_name)?;
                 self.pet_owner.insert(pet_unit.unit_id, unit_id);
             }
 
@@ -1117,12 +1072,12 @@ impl CombatLogParser for WoWVanillaParser {
         /*
          * Dispel, Steal and Interrupt
          */
-        if let Some(captures) = RE_AURA_DISPEL.captures(&content) {
+        if let Some(captures) = grammar.aura_dispel.captures(content) {
             let un_aura_caster = Unit { is_player: true, unit_id: 0 };
             let un_aura_spell_id = 42;
-            let target = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let target_spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(2)?.as_str())?;
-            self.collect_participant(&target, captures.get(1)?.as_str(), event_ts);
+            let target = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("target")?.as_str())?;
+            let target_spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            self.collect_participant(&target, captures.name("target")?.as_str(), event_ts);
             self.collect_active_map(data, &target, event_ts);
 
             return Some(vec![MessageType::Dispel(UnAura {
@@ -1135,11 +1090,11 @@ impl CombatLogParser for WoWVanillaParser {
             })]);
         }
 
-        if let Some(captures) = RE_AURA_INTERRUPT.captures(&content) {
-            let un_aura_caster = parse_unit(&mut self.cache_unit, data, captures.get(1)?.as_str())?;
-            let target = parse_unit(&mut self.cache_unit, data, captures.get(2)?.as_str())?;
-            let interrupted_spell_id = parse_spell_args(&mut self.cache_spell_id, data, captures.get(3)?.as_str())?;
-            self.collect_participant(&target, captures.get(2)?.as_str(), event_ts);
+        if let Some(captures) = grammar.aura_interrupt.captures(content) {
+            let un_aura_caster = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("caster")?.as_str())?;
+            let target = parse_unit(&mut self.cache_unit, data, &grammar, captures.name("target")?.as_str())?;
+            let interrupted_spell_id = parse_spell_args(&mut self.cache_spell_id, data, &grammar, captures.name("spell")?.as_str())?;
+            self.collect_participant(&target, captures.name("target")?.as_str(), event_ts);
             self.collect_active_map(data, &target, event_ts);
 
             return Some(vec![
@@ -1279,6 +1234,7 @@ impl CombatLogParser for WoWVanillaParser {
                         *ts,
                         CharacterDto {
                             server_uid: participant.id,
+                            note: None,
                             character_history: Some(CharacterHistoryDto {
                                 character_info: CharacterInfoDto {
                                     gear: CharacterGearDto {
@@ -1333,6 +1289,7 @@ impl CombatLogParser for WoWVanillaParser {
                     time_util::now() * 1000,
                     CharacterDto {
                         server_uid: participant.id,
+                        note: None,
                         character_history: Some(CharacterHistoryDto {
                             character_info: CharacterInfoDto {
                                 gear: CharacterGearDto {
@@ -1383,7 +1340,7 @@ impl CombatLogParser for WoWVanillaParser {
             }
             acc
         });
-        result.push((None, time_util::now() * 1000, CharacterDto { server_uid: 0, character_history: None }));
+        result.push((None, time_util::now() * 1000, CharacterDto { server_uid: 0, note: None, character_history: None }));
         result
     }
 
@@ -533,6 +533,7 @@ impl CombatLogParser for WoWWOTLKParser {
                         *ts,
                         CharacterDto {
                             server_uid: participant.id,
+                            note: None,
                             character_history: Some(CharacterHistoryDto {
                                 character_info: CharacterInfoDto {
                                     gear: CharacterGearDto {
@@ -587,6 +588,7 @@ impl CombatLogParser for WoWWOTLKParser {
                     time_util::now() * 1000,
                     CharacterDto {
                         server_uid: participant.id,
+                        note: None,
                         character_history: Some(CharacterHistoryDto {
                             character_info: CharacterInfoDto {
                                 gear: CharacterGearDto {
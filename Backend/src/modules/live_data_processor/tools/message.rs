@@ -8,22 +8,45 @@ pub trait MessageParser {
 
 impl MessageParser for Vec<u8> {
     fn parse_message(&self) -> Result<Message, LiveDataProcessorFailure> {
-        if self.len() <= 19 {
+        if self.is_empty() {
             return Err(LiveDataProcessorFailure::InvalidInput);
         }
 
-        let api_version = self[0];
-        let message_length = self[2];
-        let timestamp = byte_reader::read_u64(&self[3..11]).unwrap();
-        let message_count = byte_reader::read_u64(&self[11..19]).unwrap();
-        let message_type = self[1].to_message_type(&self[19..])?;
+        match self[0] {
+            // Header: api_version, message_type, length (u8), timestamp, message_count
+            0 => {
+                if self.len() <= 19 {
+                    return Err(LiveDataProcessorFailure::InvalidInput);
+                }
 
-        Ok(Message {
-            api_version,
-            message_length,
-            timestamp,
-            message_count,
-            message_type,
-        })
+                Ok(Message {
+                    api_version: 0,
+                    message_length: self[2] as u16,
+                    timestamp: byte_reader::read_u64(&self[3..11])?,
+                    message_count: byte_reader::read_u64(&self[11..19])?,
+                    message_type: self[1].to_message_type(&self[19..])?,
+                })
+            },
+            // Header: api_version, message_type, length (u16), timestamp, message_count
+            1 => {
+                if self.len() <= 20 {
+                    return Err(LiveDataProcessorFailure::InvalidInput);
+                }
+
+                let message_length = byte_reader::read_u16(&self[2..4])?;
+                if message_length as usize != self.len() {
+                    return Err(LiveDataProcessorFailure::InvalidInput);
+                }
+
+                Ok(Message {
+                    api_version: 1,
+                    message_length,
+                    timestamp: byte_reader::read_u64(&self[4..12])?,
+                    message_count: byte_reader::read_u64(&self[12..20])?,
+                    message_type: self[1].to_message_type(&self[20..])?,
+                })
+            },
+            _ => Err(LiveDataProcessorFailure::InvalidInput),
+        }
     }
 }
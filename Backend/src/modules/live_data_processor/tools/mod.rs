@@ -1,17 +1,21 @@
 pub use self::deserializer::*;
 pub use self::guid::GUID;
 pub use self::message::*;
+pub use self::package::*;
 pub use self::process::*;
 pub use self::unit::*;
+pub use self::upload::create_upload;
 
 pub mod byte_reader;
 mod deserializer;
 mod guid;
 pub mod log_parser;
 mod message;
+mod package;
 pub mod payload_mapper;
 mod process;
 pub mod server;
 mod unit;
+mod upload;
 
 pub mod cbl_parser;
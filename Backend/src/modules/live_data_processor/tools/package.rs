@@ -0,0 +1,69 @@
+use crate::modules::live_data_processor::dto::{LiveDataProcessorFailure, Package};
+use crate::modules::live_data_processor::tools::byte_reader;
+
+pub trait PackageParser {
+    fn parse_package(&self) -> Result<Package, LiveDataProcessorFailure>;
+}
+
+impl PackageParser for Vec<u8> {
+    fn parse_package(&self) -> Result<Package, LiveDataProcessorFailure> {
+        if self.is_empty() {
+            return Err(LiveDataProcessorFailure::InvalidInput);
+        }
+
+        match self[0] {
+            // v0 packages are a plain concatenation of messages with an u8 length at index 2
+            0 => {
+                let mut messages = Vec::new();
+                let mut offset = 0;
+                while offset < self.len() {
+                    if self.len() - offset < 3 || self[offset + 2] == 0 || offset + self[offset + 2] as usize > self.len() {
+                        return Err(LiveDataProcessorFailure::InvalidInput);
+                    }
+                    let message_length = self[offset + 2] as usize;
+                    messages.push(self[offset..(offset + message_length)].to_vec());
+                    offset += message_length;
+                }
+                Ok(Package { api_version: 0, sequence: None, messages })
+            },
+            // Header: api_version, sequence (u32), payload length (u32), CRC32 of the payload (u32)
+            // The payload is a concatenation of messages with an u16 length at index 2
+            1 => {
+                if self.len() < 13 {
+                    return Err(LiveDataProcessorFailure::InvalidInput);
+                }
+
+                let sequence = byte_reader::read_u32(&self[1..5])?;
+                let payload_length = byte_reader::read_u32(&self[5..9])? as usize;
+                let checksum = byte_reader::read_u32(&self[9..13])?;
+                let payload = &self[13..];
+                if payload.len() != payload_length {
+                    return Err(LiveDataProcessorFailure::InvalidInput);
+                }
+                if crc32fast::hash(payload) != checksum {
+                    return Err(LiveDataProcessorFailure::ChecksumMismatch);
+                }
+
+                let mut messages = Vec::new();
+                let mut offset = 0;
+                while offset < payload.len() {
+                    if payload.len() - offset < 4 {
+                        return Err(LiveDataProcessorFailure::InvalidInput);
+                    }
+                    let message_length = byte_reader::read_u16(&payload[(offset + 2)..(offset + 4)])? as usize;
+                    if message_length < 4 || offset + message_length > payload.len() {
+                        return Err(LiveDataProcessorFailure::InvalidInput);
+                    }
+                    messages.push(payload[offset..(offset + message_length)].to_vec());
+                    offset += message_length;
+                }
+                Ok(Package {
+                    api_version: 1,
+                    sequence: Some(sequence),
+                    messages,
+                })
+            },
+            _ => Err(LiveDataProcessorFailure::InvalidInput),
+        }
+    }
+}
@@ -16,13 +16,13 @@ use crate::modules::live_data_processor::domain_value::{AuraApplication, Event,
 use crate::modules::live_data_processor::dto::{CombatState, Death, get_damage_components_total, Loot, Summon};
 use crate::modules::live_data_processor::dto::{LiveDataProcessorFailure, Message, MessageType};
 use crate::modules::live_data_processor::material::Server;
-use crate::modules::live_data_processor::tools::MapUnit;
+use crate::modules::live_data_processor::tools::{create_upload, MapUnit};
 use crate::modules::live_data_processor::tools::server::{try_parse_dispel, try_parse_interrupt, try_parse_spell_steal};
 use crate::params;
 use crate::util::database::{Execute, Select};
 
 impl Server {
-    pub fn parse_events(&mut self, db_main: &mut (impl Select + Execute), armory: &Armory, data: &Data, messages: Vec<Message>, member_id: u32, upload_id: u32) -> Result<(), LiveDataProcessorFailure> {
+    pub fn parse_events(&mut self, db_main: &mut (impl Select + Execute), armory: &Armory, data: &Data, messages: Vec<Message>, member_id: u32, upload_id: Option<u32>) -> Result<(), LiveDataProcessorFailure> {
         println!("Start");
         if messages.is_empty() {
             return Ok(());
@@ -474,7 +474,7 @@ impl Server {
         }
     }
 
-    fn extract_meta_information(&mut self, db_main: &mut (impl Select + Execute), armory: &Armory, message: &Message, member_id: u32, upload_id: u32) {
+    fn extract_meta_information(&mut self, db_main: &mut (impl Select + Execute), armory: &Armory, message: &Message, member_id: u32, upload_id: Option<u32>) {
         match &message.message_type {
             MessageType::Summon(Summon { owner, unit }) => {
                 let summoner = owner.to_unit_add_implicit(&mut self.cache_unit, db_main, armory, self.server_id, &self.summons);
@@ -632,8 +632,9 @@ impl Server {
         }
     }
 
-    fn create_instance_meta(&mut self, db_main: &mut (impl Execute + Select), start_ts: u64, instance_id: u32, map_id: u32, member_id: u32, upload_id: u32) -> Option<u32> {
+    fn create_instance_meta(&mut self, db_main: &mut (impl Execute + Select), start_ts: u64, instance_id: u32, map_id: u32, member_id: u32, upload_id: Option<u32>) -> Option<u32> {
         if !self.active_instances.contains_key(&(instance_id, member_id)) {
+            let upload_id = upload_id.or_else(|| create_upload(db_main, member_id))?;
             let default_privacy_type = db_main.select_wparams_value("SELECT default_privacy_type FROM `account_member` WHERE id=:member_id",
                                                                     |mut row| row.take::<u8, usize>(0), params!("member_id" => member_id)).unwrap();
 
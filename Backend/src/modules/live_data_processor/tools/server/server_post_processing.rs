@@ -4,18 +4,14 @@ use crate::modules::data::tools::{RetrieveEncounterNpc, RetrieveItem};
 use crate::modules::data::Data;
 use crate::modules::live_data_processor::domain_value::get_spell_components_total;
 use crate::modules::live_data_processor::domain_value::{Creature, Event, EventType, Player, Power, PowerType, Unit, UnitInstance};
+use crate::modules::live_data_processor::dto::LiveFeedEvent;
 use crate::modules::live_data_processor::material::{Attempt, Server};
 use crate::modules::live_data_processor::tools::LiveDataDeserializer;
 use crate::params;
 use crate::util::database::{Execute, Select};
+use crate::util::event_store::{EventSubject, StoredEvent};
 use std::collections::{HashMap, VecDeque};
-use std::fs;
-use std::fs::File;
-use std::io::{Read, Write};
 use std::ops::Div;
-use std::path::Path;
-use walkdir::WalkDir;
-use zip::write::FileOptions;
 
 impl Server {
     pub fn perform_post_processing(&mut self, db_main: &mut (impl Execute + Select), data: &Data) {
@@ -94,7 +90,7 @@ impl Server {
                                             if is_committable {
                                                 if let Some(mut attempt) = active_attempts.remove(&encounter_npc.encounter_id) {
                                                     attempt.end_ts = event.timestamp;
-                                                    commit_attempt(db_main, *instance_meta_id, attempt);
+                                                    commit_attempt(db_main, &mut self.live_feed_events, *instance_meta_id, attempt);
                                                 }
                                             }
                                         }
@@ -137,7 +133,7 @@ impl Server {
                                         if is_committable {
                                             if let Some(mut attempt) = active_attempts.remove(&encounter_npc.encounter_id) {
                                                 attempt.end_ts = event.timestamp;
-                                                commit_attempt(db_main, *instance_meta_id, attempt);
+                                                commit_attempt(db_main, &mut self.live_feed_events, *instance_meta_id, attempt);
                                             }
                                         }
                                     },
@@ -156,7 +152,7 @@ impl Server {
                                             if is_committable {
                                                 if let Some(mut attempt) = active_attempts.remove(&encounter_npc.encounter_id) {
                                                     attempt.end_ts = event.timestamp;
-                                                    commit_attempt(db_main, *instance_meta_id, attempt);
+                                                    commit_attempt(db_main, &mut self.live_feed_events, *instance_meta_id, attempt);
                                                 }
                                             }
                                         }
@@ -222,7 +218,7 @@ impl Server {
                                                 attempt.end_ts = event.timestamp;
                                                 attempt.pivot_is_finished = true;
                                                 attempt.creatures_required_to_die.clear(); // We assume death if it evades!
-                                                commit_attempt(db_main, *instance_meta_id, attempt);
+                                                commit_attempt(db_main, &mut self.live_feed_events, *instance_meta_id, attempt);
                                             }
                                         }
                                     },
@@ -271,20 +267,30 @@ impl Server {
                                                 if attempt.creatures_required_to_die.is_empty() {
                                                     if let Some(mut attempt) = active_attempts.remove(&encounter_id) {
                                                         attempt.end_ts = event.timestamp;
-                                                        commit_attempt(db_main, *instance_meta_id, attempt);
+                                                        commit_attempt(db_main, &mut self.live_feed_events, *instance_meta_id, attempt);
                                                     }
                                                 }
                                                 // Commit As Attempt
                                                 else if attempt.creatures_in_combat.is_empty() {
                                                     if let Some(mut attempt) = active_attempts.remove(&encounter_id) {
                                                         attempt.end_ts = event.timestamp;
-                                                        commit_attempt(db_main, *instance_meta_id, attempt);
+                                                        commit_attempt(db_main, &mut self.live_feed_events, *instance_meta_id, attempt);
                                                     }
                                                 }
                                             }
                                         }
                                     }
                                 },
+                                EventType::Death { murder } => {
+                                    self.live_feed_events.push((
+                                        *instance_meta_id,
+                                        LiveFeedEvent::Death {
+                                            timestamp: event.timestamp,
+                                            character_id: player.character_id,
+                                            murder: murder.clone(),
+                                        },
+                                    ));
+                                },
                                 EventType::AuraApplication(aura_app) => {
                                     if aura_app.spell_id == 62670 || aura_app.spell_id == 62650 || aura_app.spell_id == 62671 || aura_app.spell_id == 62702 {
                                         if let Some((_, attempt)) = active_attempts.iter_mut().find(|(encounter_id, _)| **encounter_id == 126) {
@@ -299,6 +305,12 @@ impl Server {
 
                     process_ranking(&event.subject, &event, data, active_attempts);
                 }
+
+                if let Some(last_event) = committed_events.back() {
+                    for attempt in active_attempts.values() {
+                        self.live_feed_events.push((*instance_meta_id, rolling_meters(attempt, last_event.timestamp)));
+                    }
+                }
             }
         }
     }
@@ -319,54 +331,28 @@ impl Server {
     }
 
     fn save_committed_events_to_disk(&mut self) {
-        let storage_path = std::env::var("INSTANCE_STORAGE_PATH").expect("storage path must be set!");
-        let mut open_options = std::fs::File::options();
-        open_options.append(true);
-        open_options.create(true);
-
         for (instance_id, active_instance) in self.active_instances.iter() {
             if let Some(committable_events) = self.committed_events.get_mut(&instance_id) {
-                let _result = std::fs::create_dir_all(format!("{}/{}/{}", storage_path, self.server_id, active_instance.instance_meta_id));
-
-                // Find first event that is committable from the back
-                if let Some(extraction_index) = committable_events.iter().rposition(|_| true) {
-                    let mut drained_events = committable_events.drain(..(extraction_index + 1)).collect::<Vec<Event>>();
-                    drained_events.sort_by(|left, right| {
-                        let by_event_type = left.event.to_u8().cmp(&right.event.to_u8());
-                        if let std::cmp::Ordering::Equal = by_event_type {
-                            return left.timestamp.cmp(&right.timestamp);
-                        }
-                        by_event_type
-                    });
-
-                    let mut last_opened_event_type_index = 0;
-                    let mut opened_file = open_options.open(format!("{}/{}/{}/{}", storage_path, self.server_id, active_instance.instance_meta_id, 0));
-                    for event in drained_events {
-                        if event.event.to_u8() != last_opened_event_type_index {
-                            last_opened_event_type_index = event.event.to_u8();
-                            opened_file = opened_file.and_then(|file| {
-                                drop(file);
-                                open_options.open(format!("{}/{}/{}/{}", storage_path, self.server_id, active_instance.instance_meta_id, event.event.to_u8()))
-                            })
-                        }
-                        if let Ok(file) = &mut opened_file {
-                            let _ = file.write(event.deserialize().as_bytes());
-                            let _ = file.write(&[10]);
-                        }
-                    }
-                }
+                let stored_events = committable_events
+                    .drain(..)
+                    .map(|event| StoredEvent {
+                        event_type: event.event.to_u8(),
+                        timestamp: event.timestamp,
+                        subject: match &event.subject {
+                            Unit::Player(Player { character_id, .. }) => EventSubject::Player(*character_id),
+                            Unit::Creature(Creature { creature_id, .. }) => EventSubject::Creature(*creature_id),
+                        },
+                        content: event.deserialize(),
+                    })
+                    .collect::<Vec<StoredEvent>>();
+                let _ = self.event_store.append(self.server_id, active_instance.instance_meta_id, stored_events);
             }
         }
     }
 
     fn zip_instances(&mut self) {
-        let storage_path = std::env::var("INSTANCE_STORAGE_PATH").expect("storage path must be set");
         for (key, instance) in self.active_instances.clone() {
-            let dst_file = format!("{}/{}/{}.zip", storage_path, self.server_id, instance.instance_meta_id);
-            let src_dir = format!("{}/{}/{}", storage_path, self.server_id, instance.instance_meta_id);
-            if Path::new(&src_dir).exists() && zip_directory(src_dir.clone(), dst_file).is_ok() {
-                let _ = fs::remove_dir_all(&src_dir);
-            }
+            let _ = self.event_store.finalize(self.server_id, instance.instance_meta_id);
             self.instance_participants.remove(&instance.instance_meta_id);
             self.active_attempts.remove(&key);
             self.active_instances.remove(&key);
@@ -374,37 +360,6 @@ impl Server {
     }
 }
 
-fn zip_directory(src_dir: String, dst_file: String) -> zip::result::ZipResult<()> {
-    let path = Path::new(&dst_file);
-    let file = File::create(&path)?;
-
-    let walkdir = WalkDir::new(src_dir.clone());
-    let it = walkdir.into_iter();
-
-    let mut zip = zip::ZipWriter::new(file);
-    let options = FileOptions::default().compression_method(zip::CompressionMethod::Bzip2).unix_permissions(0o755);
-
-    let mut buffer = Vec::new();
-    for entry in it {
-        let path = entry.as_ref().unwrap().path();
-        let name = path.strip_prefix(Path::new(&src_dir)).unwrap();
-
-        if path.is_file() {
-            #[allow(deprecated)]
-            zip.start_file_from_path(name, options)?;
-            let mut f = File::open(path)?;
-            f.read_to_end(&mut buffer)?;
-            zip.write_all(&*buffer)?;
-            buffer.clear();
-        } else if !name.as_os_str().is_empty() {
-            #[allow(deprecated)]
-            zip.add_directory_from_path(name, options)?;
-        }
-    }
-    zip.finish()?;
-    Result::Ok(())
-}
-
 fn process_ranking(unit: &Unit, event: &Event, data: &Data, active_attempts: &mut HashMap<u32, Attempt>) {
     if let Unit::Player(Player { character_id, .. }) = unit.get_owner_or_self() {
         match &event.event {
@@ -451,7 +406,23 @@ fn process_ranking(unit: &Unit, event: &Event, data: &Data, active_attempts: &mu
     }
 }
 
-fn commit_attempt(db_main: &mut (impl Execute + Select), instance_meta_id: u32, mut attempt: Attempt) {
+fn rolling_meters(attempt: &Attempt, timestamp: u64) -> LiveFeedEvent {
+    let mut damage = attempt.ranking_damage.iter().map(|(character_id, amount)| (*character_id, *amount)).collect::<Vec<(u32, u32)>>();
+    damage.sort_by(|left, right| right.1.cmp(&left.1));
+    let mut heal = attempt.ranking_heal.iter().map(|(character_id, amount)| (*character_id, *amount)).collect::<Vec<(u32, u32)>>();
+    heal.sort_by(|left, right| right.1.cmp(&left.1));
+    let mut threat = attempt.ranking_threat.iter().map(|(character_id, amount)| (*character_id, *amount)).collect::<Vec<(u32, i32)>>();
+    threat.sort_by(|left, right| right.1.cmp(&left.1));
+    LiveFeedEvent::Meters {
+        encounter_id: attempt.encounter_id,
+        timestamp,
+        damage,
+        heal,
+        threat,
+    }
+}
+
+fn commit_attempt(db_main: &mut (impl Execute + Select), live_feed_events: &mut Vec<(u32, LiveFeedEvent)>, instance_meta_id: u32, mut attempt: Attempt) {
     // Likely a false positive
     if attempt.end_ts - attempt.start_ts <= 5000 {
         return;
@@ -526,6 +497,15 @@ fn commit_attempt(db_main: &mut (impl Execute + Select), instance_meta_id: u32,
         "INSERT INTO `instance_attempt` (`instance_meta_id`, `encounter_id`, `start_ts`, `end_ts`, `is_kill`) VALUES (:instance_meta_id, :encounter_id, :start_ts, :end_ts, :is_kill)",
         params.clone(),
     );
+    live_feed_events.push((
+        instance_meta_id,
+        LiveFeedEvent::Attempt {
+            encounter_id,
+            start_ts: attempt.start_ts,
+            end_ts: attempt.end_ts,
+            is_kill,
+        },
+    ));
 
     if !is_kill {
         return;
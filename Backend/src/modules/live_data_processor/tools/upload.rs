@@ -0,0 +1,11 @@
+use crate::params;
+use crate::util::database::{Execute, Select};
+
+/// Opens a new session in `instance_uploads` that owns every instance created by it
+pub fn create_upload(db_main: &mut (impl Execute + Select), member_id: u32) -> Option<u32> {
+    let upload_params = params!("member_id" => member_id, "ts" => time_util::now());
+    if !db_main.execute_wparams("INSERT INTO `instance_uploads` (`member_id`, `timestamp`) VALUES (:member_id, :ts)", upload_params.clone()) {
+        return None;
+    }
+    db_main.select_wparams_value("SELECT id FROM `instance_uploads` WHERE `member_id`=:member_id AND `timestamp`=:ts ORDER BY id DESC LIMIT 1", |mut row| row.take::<u32, usize>(0).unwrap(), upload_params)
+}
@@ -0,0 +1,20 @@
+use crate::modules::account::guard::Viewer;
+use crate::modules::instance::dto::InstanceFailure;
+use crate::modules::instance::tools::InstanceAccess;
+use crate::modules::instance::Instance;
+use crate::modules::live_data_processor::dto::LiveFeedPage;
+use crate::modules::live_data_processor::LiveDataProcessor;
+use rocket::State;
+use rocket_contrib::json::Json;
+
+/// Events of a live streamed instance since the cursor: committed attempts, deaths and rolling meters.
+/// Viewers poll again with the returned cursor, starting with 0.
+#[openapi]
+#[get("/live/<instance_meta_id>/<cursor>")]
+pub fn get_live_feed(me: State<LiveDataProcessor>, instance: State<Instance>, viewer: Viewer, instance_meta_id: u32, cursor: u64) -> Result<Json<LiveFeedPage>, InstanceFailure> {
+    instance.check_access(instance_meta_id, &viewer)?;
+    if !me.is_live(instance_meta_id) {
+        return Err(InstanceFailure::InvalidInput);
+    }
+    Ok(Json(me.live_feed.poll(instance_meta_id, cursor)))
+}
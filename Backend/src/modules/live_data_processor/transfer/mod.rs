@@ -1,3 +1,4 @@
 pub mod instance_reset;
+pub mod live_feed;
 pub mod package;
 pub mod upload;
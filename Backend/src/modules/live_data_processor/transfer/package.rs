@@ -1,9 +1,10 @@
 use crate::modules::account::guard::ServerOwner;
-use crate::modules::live_data_processor::dto::LiveDataProcessorFailure;
-use crate::modules::live_data_processor::tools::ProcessMessages;
+use crate::modules::live_data_processor::dto::{LiveDataProcessorFailure, PackageAcknowledgement};
+use crate::modules::live_data_processor::tools::{PackageParser, ProcessMessages};
 use crate::modules::live_data_processor::LiveDataProcessor;
 use rocket::http::ContentType;
 use rocket::{Data, State};
+use rocket_contrib::json::Json;
 
 use crate::modules::armory::Armory;
 use crate::modules::data::Data as DomainData;
@@ -12,7 +13,9 @@ use rocket_multipart_form_data::{MultipartFormData, MultipartFormDataField, Mult
 
 #[openapi(skip)]
 #[post("/package", format = "multipart/form-data", data = "<data>")]
-pub fn get_package(mut db_main: MainDb, me: State<LiveDataProcessor>, armory: State<Armory>, domain_data: State<DomainData>, owner: ServerOwner, content_type: &ContentType, data: Data) -> Result<(), LiveDataProcessorFailure> {
+pub fn get_package(
+    mut db_main: MainDb, me: State<LiveDataProcessor>, armory: State<Armory>, domain_data: State<DomainData>, owner: ServerOwner, content_type: &ContentType, data: Data,
+) -> Result<Json<PackageAcknowledgement>, LiveDataProcessorFailure> {
     let mut options = MultipartFormDataOptions::new();
     options.allowed_fields.push(MultipartFormDataField::bytes("payload").size_limit(2 * 1024 * 1024));
 
@@ -23,18 +26,16 @@ pub fn get_package(mut db_main: MainDb, me: State<LiveDataProcessor>, armory: St
     if let Some(raw_fields) = payload {
         if let Some(raw_field) = raw_fields.get_mut(0) {
             let RawField { content_type: _, file_name: _, raw } = raw_field;
-            if raw.is_empty() {
-                return Err(LiveDataProcessorFailure::InvalidInput);
-            }
-
-            let mut messages = Vec::new();
-            while !raw.is_empty() {
-                if raw[2] == 0 {
-                    return Err(LiveDataProcessorFailure::InvalidInput);
-                }
-                messages.push(raw.drain(..(raw[2] as usize)).collect());
-            }
-            return me.parse_messages(&mut *db_main, owner.0, &armory, &domain_data, messages, owner.0);
+            let package = raw.parse_package()?;
+            let lost_packages = package.sequence.map(|sequence| me.track_package_sequence(owner.0, sequence)).unwrap_or(0);
+            let amount_messages = package.messages.len() as u32;
+            let rejected_messages = me.parse_messages(&mut *db_main, owner.0, &armory, &domain_data, package.messages, owner.1)?;
+            return Ok(Json(PackageAcknowledgement {
+                sequence: package.sequence,
+                accepted_messages: amount_messages - rejected_messages,
+                rejected_messages,
+                lost_packages,
+            }));
         }
     }
     Err(LiveDataProcessorFailure::InvalidInput)
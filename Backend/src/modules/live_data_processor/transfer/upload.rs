@@ -15,8 +15,7 @@ use crate::modules::live_data_processor::LiveDataProcessor;
 use crate::modules::live_data_processor::material::{WoWRetailClassicParser, WoWTBCParser, WoWVanillaParser, WoWWOTLKParser};
 use crate::modules::live_data_processor::tools::cbl_parser::CombatLogParser;
 use crate::modules::live_data_processor::tools::log_parser::parse_cbl;
-use crate::modules::live_data_processor::tools::ProcessMessages;
-use crate::params;
+use crate::modules::live_data_processor::tools::{create_upload, ProcessMessages};
 use crate::util::database::{Execute, Select};
 use rocket_contrib::json::Json;
 
@@ -65,11 +64,7 @@ pub fn upload_log(mut db_main: MainDb, auth: Authenticate, me: State<LiveDataPro
     let mut zip = zip::ZipArchive::new(reader).map_err(|_| LiveDataProcessorFailure::InvalidZipFile)?;
 
     // Create Upload Id
-    let upload_time = time_util::now();
-    let upload_params = params!("member_id" => auth.0, "ts" => upload_time);
-    db_main.0.execute_wparams("INSERT INTO `instance_uploads` (`member_id`, `timestamp`) VALUES (:member_id, :ts)", upload_params.clone());
-    let upload_id: u32 = db_main.0.select_wparams_value("SELECT id FROM `instance_uploads` WHERE `member_id`=:member_id AND `timestamp`=:ts",
-                                                        |mut row| row.take::<u32, usize>(0).unwrap(), upload_params).unwrap();
+    let upload_id = create_upload(&mut *db_main, auth.0).ok_or_else(|| LiveDataProcessorFailure::DatabaseFailure("Failed to create upload".to_owned()))?;
 
     let storage_path = std::env::var("INSTANCE_STORAGE_PATH").expect("storage path must be set");
     if std::fs::create_dir_all(&format!("{}/zips", storage_path)).is_ok() {
@@ -107,7 +102,7 @@ pub fn upload_log(mut db_main: MainDb, auth: Authenticate, me: State<LiveDataPro
         if server.expansion_id == 1 {
             return parse(
                 &me,
-                WoWVanillaParser::new(server_id as u32),
+                WoWVanillaParser::new(server_id as u32, me.vanilla_grammars.clone()),
                 &mut *db_main,
                 &data,
                 &armory,
@@ -158,7 +153,7 @@ pub fn get_upload_progress(me: State<LiveDataProcessor>, auth: Authenticate) ->
 
 fn parse(me: &LiveDataProcessor, mut parser: impl CombatLogParser, db_main: &mut (impl Select + Execute), data: &DataMaterial, armory: &Armory, content: &str, start_time: u64, end_time: u64, member_id: u32, upload_id: u32) -> Result<(), LiveDataProcessorFailure> {
     if let Some((server_id, messages)) = parse_cbl(&mut parser, &me, &mut *db_main, data, armory, content, start_time, end_time, member_id) {
-        return me.process_messages(&mut *db_main, server_id as u32, &armory, &data, messages, member_id, upload_id);
+        return me.process_messages(&mut *db_main, server_id as u32, &armory, &data, messages, member_id, Some(upload_id));
     }
     Err(LiveDataProcessorFailure::InvalidInput)
 }
@@ -72,6 +72,7 @@ fn character_tooltip() {
     };
     let character_dto = CharacterDto {
         server_uid: 433356,
+        note: None,
         character_history: Some(character_history_dto.to_owned()),
     };
 
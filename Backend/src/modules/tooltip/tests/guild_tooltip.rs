@@ -60,6 +60,7 @@ fn build_character_history(enable_character_guild: bool, guild_id: u32) -> Chara
         facial: None,
         arena_teams: vec![],
         timestamp: 0,
+        first_seen: 0,
     }
 }
 
@@ -189,6 +189,7 @@ fn shadowmourne_socketed_and_enchanted() {
     };
     let character_dto = CharacterDto {
         server_uid: 43356,
+        note: None,
         character_history: Some(character_history_dto),
     };
 
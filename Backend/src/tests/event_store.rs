@@ -0,0 +1,117 @@
+use crate::util::event_store::{EventFilter, EventStore, EventSubject, IndexedEventStore, StoredEvent, ZipEventStore};
+use std::collections::BTreeSet;
+
+fn set_storage_path() {
+    let storage_path = std::env::temp_dir().join("rpll_event_store_tests");
+    std::env::set_var("INSTANCE_STORAGE_PATH", storage_path.to_str().unwrap());
+}
+
+fn stored_event(event_type: u8, timestamp: u64, subject: EventSubject) -> StoredEvent {
+    let subject_content = match subject {
+        EventSubject::Creature(creature_id) => format!("[0,{},15990]", creature_id),
+        EventSubject::Player(character_id) => format!("[1,{}]", character_id),
+    };
+    StoredEvent {
+        event_type,
+        timestamp,
+        subject,
+        content: format!("[0,{},{},true]", timestamp, subject_content),
+    }
+}
+
+fn events() -> Vec<StoredEvent> {
+    vec![
+        stored_event(2, 3000, EventSubject::Player(5)),
+        stored_event(2, 1000, EventSubject::Player(5)),
+        stored_event(2, 2000, EventSubject::Creature(77)),
+        stored_event(4, 1500, EventSubject::Player(6)),
+    ]
+}
+
+fn filter() -> EventFilter {
+    let mut subjects = BTreeSet::new();
+    subjects.insert(EventSubject::Player(5));
+    EventFilter {
+        start_ts: Some(1000),
+        end_ts: Some(2500),
+        subjects: Some(subjects),
+    }
+}
+
+fn assert_store(store: &impl EventStore, instance_meta_id: u32) {
+    // Arrange
+    set_storage_path();
+    store.delete(1, instance_meta_id);
+
+    // Act
+    store.append(1, instance_meta_id, events()).unwrap();
+    store.finalize(1, instance_meta_id).unwrap();
+    let all_events = store.read(1, instance_meta_id, 2, &EventFilter::default()).unwrap();
+    let filtered_events = store.read(1, instance_meta_id, 2, &filter()).unwrap();
+    let missing_events = store.read(1, instance_meta_id, 9, &EventFilter::default()).unwrap();
+    store.delete(1, instance_meta_id);
+
+    // Assert
+    assert_eq!(all_events, vec!["[0,1000,[1,5],true]", "[0,2000,[0,77,15990],true]", "[0,3000,[1,5],true]"]);
+    assert_eq!(filtered_events, vec!["[0,1000,[1,5],true]"]);
+    assert!(missing_events.is_empty());
+    assert!(store.read(1, instance_meta_id, 2, &EventFilter::default()).unwrap().is_empty());
+}
+
+#[test]
+fn zip_event_store_read_and_filter() {
+    assert_store(&ZipEventStore, 1);
+}
+
+#[test]
+fn indexed_event_store_read_and_filter() {
+    assert_store(&IndexedEventStore, 2);
+}
+
+#[test]
+fn indexed_event_store_appends_across_packages() {
+    // Arrange
+    set_storage_path();
+    let store = IndexedEventStore;
+    store.delete(1, 3);
+
+    // Act
+    store.append(1, 3, events()).unwrap();
+    store.append(1, 3, vec![stored_event(2, 4000, EventSubject::Player(5))]).unwrap();
+    let filter = EventFilter {
+        start_ts: Some(2500),
+        ..EventFilter::default()
+    };
+    let filtered_events = store.read(1, 3, 2, &filter).unwrap();
+    store.delete(1, 3);
+
+    // Assert
+    assert_eq!(filtered_events, vec!["[0,3000,[1,5],true]", "[0,4000,[1,5],true]"]);
+}
+
+#[test]
+fn parse_event_header_of_player_and_creature() {
+    assert_eq!(EventSubject::parse_event_header("[12,1000,[1,5],true]"), Some((1000, EventSubject::Player(5))));
+    assert_eq!(EventSubject::parse_event_header("[12,1000,[0,77,15990,[1,5]],true]"), Some((1000, EventSubject::Creature(77))));
+    assert_eq!(EventSubject::parse_event_header("[12,1000]"), None);
+}
+
+#[test]
+fn indexed_event_store_reads_zip_instances() {
+    // Arrange
+    set_storage_path();
+    ZipEventStore.delete(1, 4);
+    ZipEventStore.append(1, 4, events()).unwrap();
+
+    // Act
+    let active_events = IndexedEventStore.read(1, 4, 2, &filter()).unwrap();
+    IndexedEventStore.append(1, 4, vec![stored_event(2, 4000, EventSubject::Player(5))]).unwrap();
+    IndexedEventStore.finalize(1, 4).unwrap();
+    let finalized_events = IndexedEventStore.read(1, 4, 2, &EventFilter::default()).unwrap();
+    IndexedEventStore.delete(1, 4);
+
+    // Assert
+    assert_eq!(active_events, vec!["[0,1000,[1,5],true]"]);
+    assert_eq!(finalized_events, vec!["[0,1000,[1,5],true]", "[0,2000,[0,77,15990],true]", "[0,3000,[1,5],true]", "[0,4000,[1,5],true]"]);
+    assert!(ZipEventStore.read(1, 4, 2, &EventFilter::default()).unwrap().is_empty());
+}
@@ -1,4 +1,5 @@
 pub use test_container::TestContainer;
 
+mod event_store;
 mod ordering;
 mod test_container;
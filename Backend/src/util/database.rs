@@ -1,6 +1,6 @@
 use crate::mysql::{Row, Value};
 #[cfg(test)]
-use mockall::automock;
+use mockall::{automock, mock};
 
 #[macro_export]
 macro_rules! params {
@@ -34,9 +34,27 @@ pub trait Select {
     fn select_wparams_value<T: 'static, F: 'static + (Fn(Row) -> T)>(&mut self, query_str: &str, process_row: F, params: std::vec::Vec<(std::string::String, Value)>) -> Option<T>;
 }
 
+// Functions that both read and write take a single connection
+#[cfg(test)]
+mock! {
+    pub Database {}
+    trait Execute {
+        fn execute_one(&mut self, query_str: &str) -> bool;
+        fn execute_wparams(&mut self, query_str: &str, params: std::vec::Vec<(std::string::String, Value)>) -> bool;
+        fn execute_batch_wparams<T: 'static, F: 'static + (Fn(T) -> std::vec::Vec<(std::string::String, Value)>)>(&mut self, query_str: &str, params: Vec<T>, params_process: F) -> bool;
+    }
+    trait Select {
+        fn select<T: 'static, F: 'static + (Fn(Row) -> T)>(&mut self, query_str: &str, process_row: F) -> Vec<T>;
+        fn select_wparams<T: 'static, F: 'static + (Fn(Row) -> T)>(&mut self, query_str: &str, process_row: F, params: std::vec::Vec<(std::string::String, Value)>) -> Vec<T>;
+        fn select_value<T: 'static, F: 'static + (Fn(Row) -> T)>(&mut self, query_str: &str, process_row: F) -> Option<T>;
+        fn select_wparams_value<T: 'static, F: 'static + (Fn(Row) -> T)>(&mut self, query_str: &str, process_row: F, params: std::vec::Vec<(std::string::String, Value)>) -> Option<T>;
+    }
+}
+
 impl Execute for crate::mysql::Conn {
+    // Text protocol, as transaction statements can't be prepared
     fn execute_one(&mut self, query_str: &str) -> bool {
-        self.prep_exec(query_str, ()).is_ok()
+        self.query(query_str).is_ok()
     }
 
     fn execute_wparams(&mut self, query_str: &str, params: std::vec::Vec<(std::string::String, Value)>) -> bool {
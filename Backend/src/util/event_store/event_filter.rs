@@ -0,0 +1,19 @@
+use crate::util::event_store::EventSubject;
+use std::collections::BTreeSet;
+
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    pub start_ts: Option<u64>,
+    pub end_ts: Option<u64>,
+    pub subjects: Option<BTreeSet<EventSubject>>,
+}
+
+impl EventFilter {
+    pub fn is_unbounded(&self) -> bool {
+        self.start_ts.is_none() && self.end_ts.is_none() && self.subjects.is_none()
+    }
+
+    pub fn accepts(&self, timestamp: u64, subject: &EventSubject) -> bool {
+        self.start_ts.map(|start_ts| timestamp >= start_ts).unwrap_or(true) && self.end_ts.map(|end_ts| timestamp <= end_ts).unwrap_or(true) && self.subjects.as_ref().map(|subjects| subjects.contains(subject)).unwrap_or(true)
+    }
+}
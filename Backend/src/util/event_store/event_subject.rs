@@ -0,0 +1,43 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EventSubject {
+    Creature(u64),
+    Player(u32),
+}
+
+impl EventSubject {
+    pub fn to_bytes(self) -> [u8; 9] {
+        let (kind, id) = match self {
+            EventSubject::Creature(creature_id) => (0, creature_id),
+            EventSubject::Player(character_id) => (1, character_id as u64),
+        };
+        let mut bytes = [kind; 9];
+        bytes[1..].copy_from_slice(&id.to_le_bytes());
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut id = [0; 8];
+        id.copy_from_slice(bytes.get(1..9)?);
+        let id = u64::from_le_bytes(id);
+        match bytes[0] {
+            0 => Some(EventSubject::Creature(id)),
+            1 => Some(EventSubject::Player(id as u32)),
+            _ => None,
+        }
+    }
+
+    /// Reads timestamp and subject of an exported event, e.g. "[id,timestamp,[1,character_id],...]"
+    pub fn parse_event_header(line: &str) -> Option<(u64, Self)> {
+        let mut header = line.strip_prefix('[')?.splitn(3, ',');
+        let _id = header.next()?;
+        let timestamp = header.next()?.parse::<u64>().ok()?;
+        let mut subject = header.next()?.strip_prefix('[')?.splitn(3, &[',', ']'][..]);
+        let kind = subject.next()?;
+        let id = subject.next()?;
+        match kind {
+            "0" => Some((timestamp, EventSubject::Creature(id.parse().ok()?))),
+            "1" => Some((timestamp, EventSubject::Player(id.parse().ok()?))),
+            _ => None,
+        }
+    }
+}
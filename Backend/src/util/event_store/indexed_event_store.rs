@@ -0,0 +1,141 @@
+use crate::util::event_store::store::storage_path;
+use crate::util::event_store::{EventFilter, EventStore, EventSubject, StoredEvent, ZipEventStore};
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+// timestamp (8), subject (9), offset (8), length (4)
+const INDEX_ENTRY_SIZE: usize = 29;
+
+/// Keeps the events of each type uncompressed in a data file and a fixed size index entry per event next to it.
+/// Filtered reads only scan the index and then seek to the matching events.
+/// Instances that were stored before switching to this store remain in the zip format and are delegated to the ZipEventStore.
+pub struct IndexedEventStore;
+
+struct IndexEntry {
+    timestamp: u64,
+    subject: EventSubject,
+    offset: u64,
+    length: u32,
+}
+
+impl IndexEntry {
+    fn to_bytes(&self) -> [u8; INDEX_ENTRY_SIZE] {
+        let mut bytes = [0; INDEX_ENTRY_SIZE];
+        bytes[0..8].copy_from_slice(&self.timestamp.to_le_bytes());
+        bytes[8..17].copy_from_slice(&self.subject.to_bytes());
+        bytes[17..25].copy_from_slice(&self.offset.to_le_bytes());
+        bytes[25..29].copy_from_slice(&self.length.to_le_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut timestamp = [0; 8];
+        timestamp.copy_from_slice(&bytes[0..8]);
+        let mut offset = [0; 8];
+        offset.copy_from_slice(&bytes[17..25]);
+        let mut length = [0; 4];
+        length.copy_from_slice(&bytes[25..29]);
+        Some(IndexEntry {
+            timestamp: u64::from_le_bytes(timestamp),
+            subject: EventSubject::from_bytes(&bytes[8..17])?,
+            offset: u64::from_le_bytes(offset),
+            length: u32::from_le_bytes(length),
+        })
+    }
+}
+
+fn instance_path(server_id: u32, instance_meta_id: u32) -> String {
+    format!("{}/{}/{}.indexed", storage_path(), server_id, instance_meta_id)
+}
+
+fn is_zip_format(server_id: u32, instance_meta_id: u32) -> bool {
+    let storage_path = storage_path();
+    !Path::new(&instance_path(server_id, instance_meta_id)).exists()
+        && (Path::new(&format!("{}/{}/{}.zip", storage_path, server_id, instance_meta_id)).exists() || Path::new(&format!("{}/{}/{}", storage_path, server_id, instance_meta_id)).exists())
+}
+
+impl EventStore for IndexedEventStore {
+    fn append(&self, server_id: u32, instance_meta_id: u32, mut events: Vec<StoredEvent>) -> io::Result<()> {
+        if is_zip_format(server_id, instance_meta_id) {
+            return ZipEventStore.append(server_id, instance_meta_id, events);
+        }
+
+        let instance_path = instance_path(server_id, instance_meta_id);
+        fs::create_dir_all(&instance_path)?;
+
+        let mut open_options = File::options();
+        open_options.append(true);
+        open_options.create(true);
+
+        events.sort_by(|left, right| left.event_type.cmp(&right.event_type).then(left.timestamp.cmp(&right.timestamp)));
+        let mut opened_files: Option<(u8, File, File, u64)> = None;
+        for event in events {
+            if opened_files.as_ref().map(|(event_type, _, _, _)| *event_type != event.event_type).unwrap_or(true) {
+                let data_file = open_options.open(format!("{}/{}.data", instance_path, event.event_type))?;
+                let index_file = open_options.open(format!("{}/{}.index", instance_path, event.event_type))?;
+                let offset = data_file.metadata()?.len();
+                opened_files = Some((event.event_type, data_file, index_file, offset));
+            }
+            if let Some((_, data_file, index_file, offset)) = &mut opened_files {
+                data_file.write_all(event.content.as_bytes())?;
+                data_file.write_all(&[10])?;
+                let entry = IndexEntry {
+                    timestamp: event.timestamp,
+                    subject: event.subject,
+                    offset: *offset,
+                    length: event.content.len() as u32,
+                };
+                index_file.write_all(&entry.to_bytes())?;
+                *offset += event.content.len() as u64 + 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn finalize(&self, server_id: u32, instance_meta_id: u32) -> io::Result<()> {
+        if is_zip_format(server_id, instance_meta_id) {
+            return ZipEventStore.finalize(server_id, instance_meta_id);
+        }
+        Ok(())
+    }
+
+    fn read(&self, server_id: u32, instance_meta_id: u32, event_type: u8, filter: &EventFilter) -> io::Result<Vec<String>> {
+        if is_zip_format(server_id, instance_meta_id) {
+            return ZipEventStore.read(server_id, instance_meta_id, event_type, filter);
+        }
+
+        let instance_path = instance_path(server_id, instance_meta_id);
+        let mut data_file = match File::open(format!("{}/{}.data", instance_path, event_type)) {
+            Ok(file) => file,
+            Err(_) => return Ok(Vec::new()),
+        };
+
+        if filter.is_unbounded() {
+            let mut content = String::new();
+            data_file.read_to_string(&mut content)?;
+            return Ok(content.lines().map(|line| line.to_owned()).collect());
+        }
+
+        let index = fs::read(format!("{}/{}.index", instance_path, event_type))?;
+        let mut events = Vec::new();
+        let mut buffer = Vec::new();
+        for entry in index.chunks_exact(INDEX_ENTRY_SIZE).filter_map(IndexEntry::from_bytes) {
+            if !filter.accepts(entry.timestamp, &entry.subject) {
+                continue;
+            }
+            buffer.resize(entry.length as usize, 0);
+            data_file.seek(SeekFrom::Start(entry.offset))?;
+            data_file.read_exact(&mut buffer)?;
+            events.push(String::from_utf8_lossy(&buffer).into_owned());
+        }
+        Ok(events)
+    }
+
+    fn delete(&self, server_id: u32, instance_meta_id: u32) {
+        let _ = fs::remove_dir_all(instance_path(server_id, instance_meta_id));
+        ZipEventStore.delete(server_id, instance_meta_id);
+    }
+}
@@ -0,0 +1,13 @@
+pub use self::event_filter::EventFilter;
+pub use self::event_subject::EventSubject;
+pub use self::indexed_event_store::IndexedEventStore;
+pub use self::store::{event_store_from_env, EventStore};
+pub use self::stored_event::StoredEvent;
+pub use self::zip_event_store::ZipEventStore;
+
+mod event_filter;
+mod event_subject;
+mod indexed_event_store;
+mod store;
+mod stored_event;
+mod zip_event_store;
@@ -0,0 +1,25 @@
+use crate::util::event_store::{EventFilter, IndexedEventStore, StoredEvent, ZipEventStore};
+use std::io;
+
+/// Storage of the committed events of an instance, one stream per event type.
+/// Events are stored in their exported JSON representation.
+pub trait EventStore: Send + Sync {
+    fn append(&self, server_id: u32, instance_meta_id: u32, events: Vec<StoredEvent>) -> io::Result<()>;
+    /// Called once the live data processor does not append to this instance anymore
+    fn finalize(&self, server_id: u32, instance_meta_id: u32) -> io::Result<()>;
+    fn read(&self, server_id: u32, instance_meta_id: u32, event_type: u8, filter: &EventFilter) -> io::Result<Vec<String>>;
+    fn delete(&self, server_id: u32, instance_meta_id: u32);
+}
+
+/// INSTANCE_EVENT_STORE selects the implementation, "zip" (default) or "indexed".
+/// The indexed store keeps reading instances that were stored as zip.
+pub fn event_store_from_env() -> Box<dyn EventStore> {
+    match std::env::var("INSTANCE_EVENT_STORE").as_deref() {
+        Ok("indexed") => Box::new(IndexedEventStore),
+        _ => Box::new(ZipEventStore),
+    }
+}
+
+pub(super) fn storage_path() -> String {
+    std::env::var("INSTANCE_STORAGE_PATH").expect("storage path must be set")
+}
@@ -0,0 +1,9 @@
+use crate::util::event_store::EventSubject;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredEvent {
+    pub event_type: u8,
+    pub timestamp: u64,
+    pub subject: EventSubject,
+    pub content: String,
+}
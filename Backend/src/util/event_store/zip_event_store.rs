@@ -0,0 +1,107 @@
+use crate::util::event_store::store::storage_path;
+use crate::util::event_store::{EventFilter, EventStore, EventSubject, StoredEvent};
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::{Read, Write};
+use std::path::Path;
+use walkdir::WalkDir;
+use zip::write::FileOptions;
+
+/// Appends to one text file per event type while the instance is active.
+/// Finalizing compresses the directory into a bzip2 zip, which has to be decompressed as a whole for every read.
+pub struct ZipEventStore;
+
+impl EventStore for ZipEventStore {
+    fn append(&self, server_id: u32, instance_meta_id: u32, mut events: Vec<StoredEvent>) -> io::Result<()> {
+        let instance_path = format!("{}/{}/{}", storage_path(), server_id, instance_meta_id);
+        fs::create_dir_all(&instance_path)?;
+
+        let mut open_options = File::options();
+        open_options.append(true);
+        open_options.create(true);
+
+        events.sort_by(|left, right| left.event_type.cmp(&right.event_type).then(left.timestamp.cmp(&right.timestamp)));
+        let mut opened_file: Option<(u8, File)> = None;
+        for event in events {
+            if opened_file.as_ref().map(|(event_type, _)| *event_type != event.event_type).unwrap_or(true) {
+                opened_file = Some((event.event_type, open_options.open(format!("{}/{}", instance_path, event.event_type))?));
+            }
+            if let Some((_, file)) = &mut opened_file {
+                file.write_all(event.content.as_bytes())?;
+                file.write_all(&[10])?;
+            }
+        }
+        Ok(())
+    }
+
+    fn finalize(&self, server_id: u32, instance_meta_id: u32) -> io::Result<()> {
+        let storage_path = storage_path();
+        let dst_file = format!("{}/{}/{}.zip", storage_path, server_id, instance_meta_id);
+        let src_dir = format!("{}/{}/{}", storage_path, server_id, instance_meta_id);
+        if Path::new(&src_dir).exists() {
+            zip_directory(src_dir.clone(), dst_file)?;
+            fs::remove_dir_all(&src_dir)?;
+        }
+        Ok(())
+    }
+
+    fn read(&self, server_id: u32, instance_meta_id: u32, event_type: u8, filter: &EventFilter) -> io::Result<Vec<String>> {
+        let storage_path = storage_path();
+        let zip_path = format!("{}/{}/{}.zip", storage_path, server_id, instance_meta_id);
+        let mut content = String::new();
+        if Path::new(&zip_path).exists() {
+            // Can fail if the zip file wants to be read before its ready
+            let mut zip = zip::ZipArchive::new(File::open(zip_path)?)?;
+            let read_result = zip.by_name(&event_type.to_string()).map(|mut file| file.read_to_string(&mut content));
+            if read_result.is_err() {
+                return Ok(Vec::new());
+            }
+        } else if let Ok(file_content) = fs::read_to_string(format!("{}/{}/{}/{}", storage_path, server_id, instance_meta_id, event_type)) {
+            content = file_content;
+        }
+
+        Ok(content
+            .lines()
+            .filter(|line| filter.is_unbounded() || EventSubject::parse_event_header(line).map(|(timestamp, subject)| filter.accepts(timestamp, &subject)).unwrap_or(false))
+            .map(|line| line.to_owned())
+            .collect())
+    }
+
+    fn delete(&self, server_id: u32, instance_meta_id: u32) {
+        let storage_path = storage_path();
+        let _ = fs::remove_dir_all(format!("{}/{}/{}", storage_path, server_id, instance_meta_id));
+        let _ = fs::remove_file(format!("{}/{}/{}.zip", storage_path, server_id, instance_meta_id));
+    }
+}
+
+fn zip_directory(src_dir: String, dst_file: String) -> zip::result::ZipResult<()> {
+    let path = Path::new(&dst_file);
+    let file = File::create(path)?;
+
+    let walkdir = WalkDir::new(src_dir.clone());
+    let it = walkdir.into_iter();
+
+    let mut zip = zip::ZipWriter::new(file);
+    let options = FileOptions::default().compression_method(zip::CompressionMethod::Bzip2).unix_permissions(0o755);
+
+    let mut buffer = Vec::new();
+    for entry in it {
+        let path = entry.as_ref().unwrap().path();
+        let name = path.strip_prefix(Path::new(&src_dir)).unwrap();
+
+        if path.is_file() {
+            #[allow(deprecated)]
+            zip.start_file_from_path(name, options)?;
+            let mut f = File::open(path)?;
+            f.read_to_end(&mut buffer)?;
+            zip.write_all(&buffer)?;
+            buffer.clear();
+        } else if !name.as_os_str().is_empty() {
+            #[allow(deprecated)]
+            zip.add_directory_from_path(name, options)?;
+        }
+    }
+    zip.finish()?;
+    Result::Ok(())
+}
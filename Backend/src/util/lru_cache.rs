@@ -0,0 +1,61 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+/// Map that holds at most `capacity` entries, inserting into a full cache evicts the least recently used entry
+#[derive(Debug)]
+pub struct LruCache<K, V> {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<K, (u64, V)>,
+    usage: BTreeMap<u64, K>,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        LruCache {
+            capacity,
+            tick: 0,
+            entries: HashMap::new(),
+            usage: BTreeMap::new(),
+        }
+    }
+
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let tick = self.next_tick();
+        let (last_used, value) = self.entries.get_mut(key)?;
+        self.usage.remove(last_used);
+        self.usage.insert(tick, key.clone());
+        *last_used = tick;
+        Some(value)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let tick = self.next_tick();
+        if let Some((last_used, _)) = self.entries.remove(&key) {
+            self.usage.remove(&last_used);
+        } else if self.entries.len() >= self.capacity {
+            let least_recently_used = *self.usage.keys().next().unwrap();
+            let evicted_key = self.usage.remove(&least_recently_used).unwrap();
+            self.entries.remove(&evicted_key);
+        }
+        self.usage.insert(tick, key.clone());
+        self.entries.insert(key, (tick, value));
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
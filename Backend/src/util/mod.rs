@@ -1,4 +1,6 @@
 pub mod database;
+pub mod event_store;
 pub mod hash_str;
+pub mod lru_cache;
 pub mod ordering;
 pub mod rpll_table;
@@ -1,11 +1,13 @@
 use strum_macros::{EnumCount, EnumIter};
 
 #[repr(u8)]
-#[derive(EnumCount, EnumIter, PartialEq)]
+#[derive(Debug, Clone, Copy, EnumCount, EnumIter, PartialEq)]
 pub enum Language {
     English = 0,
     German = 1,
     Japanese = 2,
+    French = 3,
+    Spanish = 4,
 }
 
 impl Language {
@@ -14,6 +16,8 @@ impl Language {
             0 => Language::English,
             1 => Language::German,
             2 => Language::Japanese,
+            3 => Language::French,
+            4 => Language::Spanish,
             _ => Language::English,
         }
     }
@@ -38,4 +38,21 @@ mod tests {
 
         assert_eq!(dictionary.get("Test", Language::Japanese), "Test2");
     }
+
+    #[test]
+    fn romance_languages_are_registered_independently() {
+        let dictionary = Dictionary::default();
+        dictionary.register("Test", Language::French, "Essai");
+        dictionary.register("Test", Language::Spanish, "Prueba");
+
+        assert_eq!(dictionary.get("Test", Language::French), "Essai");
+        assert_eq!(dictionary.get("Test", Language::Spanish), "Prueba");
+    }
+
+    #[test]
+    fn from_u8_is_inverse_of_repr() {
+        assert!(Language::from_u8(Language::French as u8) == Language::French);
+        assert!(Language::from_u8(Language::Spanish as u8) == Language::Spanish);
+        assert!(Language::from_u8(255) == Language::English);
+    }
 }
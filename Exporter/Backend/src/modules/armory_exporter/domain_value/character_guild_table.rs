@@ -5,4 +5,5 @@ pub struct CharacterGuildTable {
     pub guild_name: String,
     pub rank_index: u8,
     pub rank_name: String,
+    pub public_note: String,
 }
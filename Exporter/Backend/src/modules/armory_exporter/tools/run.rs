@@ -41,6 +41,8 @@ impl ArmoryExporter {
                     character_table.character_id,
                     CharacterDto {
                         server_uid: salt_u32_u64(character_table.character_id),
+                        // The public guild note may carry a character claim token
+                        note: guild.as_ref().map(|char_guild_table| char_guild_table.public_note.clone()).filter(|public_note| !public_note.is_empty()),
                         character_history: Some(CharacterHistoryDto {
                             character_info: CharacterInfoDto {
                                 gear: CharacterGearDto {
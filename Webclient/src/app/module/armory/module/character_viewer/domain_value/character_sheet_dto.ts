@@ -0,0 +1,25 @@
+export interface CharacterSheetDto {
+    strength: number;
+    agility: number;
+    stamina: number;
+    intellect: number;
+    spirit: number;
+    health: number;
+    mana: number;
+    armor: number;
+    attack_power: number;
+    ranged_attack_power: number;
+    spell_power: number;
+    healing_power: number;
+    hit_chance: number;
+    crit_chance: number;
+    haste: number;
+    spell_hit_chance: number;
+    spell_crit_chance: number;
+    spell_haste: number;
+    defense: number;
+    dodge_chance: number;
+    parry_chance: number;
+    block_chance: number;
+    resilience: number;
+}
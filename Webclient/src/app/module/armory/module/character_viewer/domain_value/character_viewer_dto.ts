@@ -1,5 +1,6 @@
 import {SelectOption} from "../../../../../template/input/select_input/domain_value/select_option";
 import {ProfessionDto} from "./profession_dto";
+import {CharacterSheetDto} from "./character_sheet_dto";
 import {TalentSpecializationDto} from "./talent_specialization_dto";
 
 export interface CharacterViewerDto {
@@ -41,4 +42,5 @@ export interface CharacterViewerDto {
     profession2: ProfessionDto | undefined;
     talent_specialization: TalentSpecializationDto | undefined;
     stats: Array<{ stat_type: string; stat_value: number }>;
+    sheet: CharacterSheetDto | undefined;
 }